@@ -30,6 +30,7 @@ pub trait EmitCode: Write + std::fmt::Debug {
                     Variant::Normal => "Normal",
                     Variant::Extended => "Extended",
                     Variant::Simd => "Simd",
+                    Variant::Atomic => "Atomic",
                 };
                 self.write_all(
                     format!("    pub const {name}: Opcode = Opcode::{variant}(0x{opcode:0x});\n")
@@ -50,6 +51,7 @@ use crate::runtime::{
     error::TrapKind,
     exec::{ExecutionContext, ExecutionContextActions, LabelType},
     instance::addr::Address,
+    simd::{self, WasmFloat},
     values::Ref,
 };
 "#;
@@ -50,14 +50,16 @@ fn data_table_item(inst: &Option<Instruction>, variant: &Variant) -> String {
     match inst {
         Some(i) => format!(
             "    InstructionData {{
-        opcode:   {}({:#x}),
-        name:     \"{}\",
-        operands: {},
+        opcode:    {}({:#x}),
+        name:      \"{}\",
+        operands:  {},
+        fuel_cost: {},
     }},\n",
             variant.opcode_variant(),
             i.opcode,
             i.name,
-            i.operands
+            i.operands,
+            i.fuel_cost
         ),
         _ => "    BAD_INSTRUCTION,\n".into(),
     }
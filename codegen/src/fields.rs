@@ -35,3 +35,9 @@ pub fn hex(s: &str) -> u32 {
     }
     result
 }
+
+/// Parse a decimal number, panicking on failure.
+pub fn decimal(s: &str) -> u32 {
+    s.parse()
+        .unwrap_or_else(|e| panic!("bad decimal field {}: {}", s, e))
+}
@@ -29,6 +29,9 @@ pub struct Instruction {
     /// The operands descriptor.
     operands: String,
 
+    /// The amount of fuel charged for executing the instruction.
+    fuel_cost: u32,
+
     /// The body of the execution function.
     body: String,
 }
@@ -36,25 +39,28 @@ pub struct Instruction {
 impl Default for Instruction {
     fn default() -> Self {
         Self {
-            typename: "".into(),
-            name:     "ILLEGAL".into(),
-            operands: "None".into(),
-            opcode:   0,
-            body:     "".into(),
+            typename:  "".into(),
+            name:      "ILLEGAL".into(),
+            operands:  "None".into(),
+            opcode:    0,
+            fuel_cost: 1,
+            body:      "".into(),
         }
     }
 }
 
 impl Instruction {
     /// Create a new [Instruction] from fields in the file.
-    /// They should be ordered: |opcode, name, operands|.
+    /// They should be ordered: |opcode, name, operands, fuel cost|. The fuel
+    /// cost is optional, and defaults to 1.
     fn new(fields: Vec<&str>) -> Instruction {
         Instruction {
-            typename: fields::typename(fields[1]),
-            name:     fields[1].to_string(),
-            opcode:   fields::hex(fields[0]) as u8,
-            operands: fields::operands(fields[2]),
-            body:     String::new(),
+            typename:  fields::typename(fields[1]),
+            name:      fields[1].to_string(),
+            opcode:    fields::hex(fields[0]) as u8,
+            operands:  fields::operands(fields[2]),
+            fuel_cost: fields.get(3).map_or(1, |f| fields::decimal(f)),
+            body:      String::new(),
         }
     }
 }
@@ -63,6 +69,7 @@ enum Variant {
     Normal,
     Extended,
     Simd,
+    Atomic,
 }
 
 impl Variant {
@@ -71,6 +78,7 @@ impl Variant {
             Self::Normal => "",
             Self::Extended => "EXTENDED_",
             Self::Simd => "SIMD_",
+            Self::Atomic => "ATOMIC_",
         }
     }
 
@@ -79,6 +87,7 @@ impl Variant {
             Self::Normal => "Opcode::Normal",
             Self::Extended => "Opcode::Extended",
             Self::Simd => "Opcode::Simd",
+            Self::Atomic => "Opcode::Atomic",
         }
     }
 }
@@ -123,10 +132,10 @@ fn read_instruction_list(file: &str, variant: Variant) -> io::Result<Instruction
         }
 
         // Get the fields for an instruction descriptor, expecting 3: opcode, name,
-        // parse.
+        // parse, and optionally a 4th: fuel cost.
         let fields = line.split(',').map(|l| l.trim()).collect::<Vec<&str>>();
 
-        if fields.len() != 3 {
+        if !(3..=4).contains(&fields.len()) {
             println!("Unhandled line {}", line);
             continue;
         }
@@ -157,6 +166,7 @@ pub fn generate_exec_code() -> io::Result<()> {
         read_instruction_list("../codegen/master_ops_list.csv", Variant::Normal)?,
         read_instruction_list("../codegen/master_extended_ops_list.csv", Variant::Extended)?,
         read_instruction_list("../codegen/master_simd_ops_list.csv", Variant::Simd)?,
+        read_instruction_list("../codegen/master_atomic_ops_list.csv", Variant::Atomic)?,
     ];
 
     // Emit the file containing the code and descriptor structs.
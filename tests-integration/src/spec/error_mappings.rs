@@ -48,10 +48,13 @@ fn matches_loader_error(failure: &str, error: &LoaderError) -> bool {
 
 fn matches_runtime_error(failure: &str, error: &RuntimeErrorKind) -> bool {
     match error {
-        RuntimeErrorKind::CallStackExhaustion => failure == "call stack exhausted",
+        RuntimeErrorKind::CallStackExhaustion | RuntimeErrorKind::ValueStackExhaustion => {
+            failure == "call stack exhausted"
+        }
         RuntimeErrorKind::ImportMismatch(..) => failure == "incompatible import type",
         RuntimeErrorKind::ImportNotFound(..) => failure == "unknown import",
         RuntimeErrorKind::Trap(trap_kind) => matches_trap(failure, trap_kind),
+        RuntimeErrorKind::UncaughtException { .. } => failure == "uncaught exception",
         _ => false,
     }
 }
@@ -61,6 +64,7 @@ fn matches_trap(failure: &str, trap: &TrapKind) -> bool {
         TrapKind::CallIndirectTypeMismatch => failure == "indirect call type mismatch",
         TrapKind::IntegerDivideByZero => failure == "integer divide by zero",
         TrapKind::IntegerOverflow => failure == "integer overflow",
+        TrapKind::NullExceptionReference => failure == "null exception reference",
         TrapKind::InvalidConversionToInteger => failure == "invalid conversion to integer",
         TrapKind::OutOfBoundsMemoryAccess(..) => failure == "out of bounds memory access",
         TrapKind::OutOfBoundsTableAccess(..) => {
@@ -68,6 +72,8 @@ fn matches_trap(failure: &str, trap: &TrapKind) -> bool {
         }
         TrapKind::Unreachable => failure == "unreachable",
         TrapKind::UninitializedElement => failure.starts_with("uninitialized element"),
+        TrapKind::UnalignedAtomic => failure == "unaligned atomic",
+        TrapKind::ExpectedSharedMemory => failure == "expected shared memory",
         _ => false,
     }
 }
@@ -99,6 +105,13 @@ fn matches_bin_parse_error(failure: &str, bin_parse_err: &BinaryParseError) -> b
         BinaryParseErrorKind::InvalidOpcode(_) => failure == "illegal opcode",
         BinaryParseErrorKind::LEB128Error(le) => matches_leb_error(failure, le),
         BinaryParseErrorKind::MalformedImportKind(_) => failure == "malformed import kind",
+        BinaryParseErrorKind::MalformedLimitsFlags(_) => [
+            "integer representation too long",
+            "integer too large",
+            "malformed limits flags",
+        ]
+        .contains(&failure),
+        BinaryParseErrorKind::MalformedTagAttribute(_) => failure == "malformed tag attribute",
         BinaryParseErrorKind::MalformedRefType(_) => failure == "malformed reference type",
         BinaryParseErrorKind::MalformedSectionId(_) => failure == "malformed section id",
         BinaryParseErrorKind::SectionTooLong => failure == "section size mismatch",
@@ -143,6 +156,8 @@ fn matches_parse_error(failure: &str, parse_err: &ParseError) -> bool {
             "constant out of range",
         ]
         .contains(&failure),
+        ParseErrorKind::InvalidLaneLength => failure == "invalid lane length",
+        ParseErrorKind::MalformedLaneIndex => failure == "malformed lane index",
         ParseErrorKind::ResolveError(re) => matches_resolve_error(failure, re),
         // This should really only be unexpected token, but blocks end up parsing
         // out-of-order param/result/type as instructions. One approach to improve this
@@ -183,6 +198,9 @@ fn matches_validation_error(failure: &str, err: &ValidationError) -> bool {
         ValidationErrorKind::AlignmentTooLarge(_) => {
             failure == "alignment must not be larger than natural"
         }
+        ValidationErrorKind::AlignmentNotNatural(_) => {
+            failure == "alignment must be equal to natural"
+        }
         ValidationErrorKind::BreakTypeMismatch => failure == "type mismatch",
         ValidationErrorKind::DuplicateExport => failure == "duplicate export name",
         ValidationErrorKind::ExpectedNum { .. } => failure == "type mismatch",
@@ -191,14 +209,20 @@ fn matches_validation_error(failure: &str, err: &ValidationError) -> bool {
         ValidationErrorKind::InvalidConstantInstruction => {
             failure == "constant expression required"
         }
+        ValidationErrorKind::InvalidLaneIndex(_) => failure == "invalid lane index",
         ValidationErrorKind::InvalidLimits => {
             failure == "size minimum must not be greater than maximum"
         }
         ValidationErrorKind::ImmutableGlobal => failure == "global is immutable",
-        ValidationErrorKind::MemoryTooLarge => {
-            failure == "memory size must be at most 65536 pages (4GiB)"
-        }
-        ValidationErrorKind::MultipleMemories => failure == "multiple memories",
+        ValidationErrorKind::MemoryTooLarge => [
+            "memory size must be at most 65536 pages (4GiB)",
+            "memory size must be at most 2^48 pages (256TiB)",
+        ]
+        .contains(&failure),
+        ValidationErrorKind::NonEmptyTagResult => failure == "non-empty tag result type",
+        ValidationErrorKind::OffsetOutOfRange => failure == "offset out of range",
+        ValidationErrorKind::ReturnTypeMismatch => failure == "type mismatch",
+        ValidationErrorKind::SharedMemoryWithoutMax => failure == "shared memory must have maximum",
         ValidationErrorKind::TypeMismatch { .. } => failure == "type mismatch",
         ValidationErrorKind::UndeclaredFunctionRef => failure == "undeclared function reference",
         ValidationErrorKind::UnknownData => failure.starts_with("unknown data segment"),
@@ -209,6 +233,7 @@ fn matches_validation_error(failure: &str, err: &ValidationError) -> bool {
         ValidationErrorKind::UnknownLocal { .. } => failure == "unknown local",
         ValidationErrorKind::UnknownMemory => failure.starts_with("unknown memory"),
         ValidationErrorKind::UnknownTable => failure.starts_with("unknown table"),
+        ValidationErrorKind::UnknownTag => failure.starts_with("unknown tag"),
         ValidationErrorKind::UnknownType => failure == "unknown type",
         ValidationErrorKind::UnsupportedSelect => failure == "invalid result arity",
         ValidationErrorKind::UnusedValues => failure == "type mismatch",
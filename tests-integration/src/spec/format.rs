@@ -6,7 +6,7 @@ use {
         token::Token,
     },
     wrausmt_runtime::{
-        runtime::values::{Num, Ref, Value},
+        runtime::values::Num,
         syntax::{
             self as modulesyntax,
             location::Location,
@@ -33,6 +33,7 @@ pub trait SpecParser {
     fn try_meta_cmd(&mut self) -> Result<Option<Cmd>>;
     fn try_assert_return(&mut self) -> Result<Option<Assertion>>;
     fn try_assert_exhaustion(&mut self) -> Result<Option<Assertion>>;
+    fn try_assert_exception(&mut self) -> Result<Option<Assertion>>;
     fn try_assert_trap(&mut self) -> Result<Option<Assertion>>;
     fn try_assert_invalid(&mut self) -> Result<Option<Assertion>>;
     fn try_assert_malformed(&mut self) -> Result<Option<Assertion>>;
@@ -41,6 +42,7 @@ pub trait SpecParser {
     fn try_const(&mut self) -> Result<Option<Const>>;
     fn try_num_type(&mut self) -> Result<Option<NumType>>;
     fn try_nan_pat(&mut self, nt: NumType) -> Result<Option<NaNPat>>;
+    fn expect_v128_pat(&mut self) -> Result<NumPat>;
     fn expect_num(&mut self, nt: NumType) -> Result<Num>;
 }
 
@@ -199,6 +201,7 @@ impl<R: Read> SpecParser for Parser<R> {
         self.first_of(&[
             Self::try_assert_return,
             Self::try_assert_exhaustion,
+            Self::try_assert_exception,
             Self::try_assert_trap,
             Self::try_assert_invalid,
             Self::try_assert_malformed,
@@ -259,6 +262,18 @@ impl<R: Read> SpecParser for Parser<R> {
         Ok(Some(Assertion::Exhaustion { action, failure }))
     }
 
+    fn try_assert_exception(&mut self) -> Result<Option<Assertion>> {
+        pctx!(self, "try assert exception");
+        if !self.try_expr_start("assert_exception")? {
+            return Ok(None);
+        }
+
+        let action = self.expect_action()?;
+        self.expect_close()?;
+
+        Ok(Some(Assertion::Exception { action }))
+    }
+
     fn try_assert_trap(&mut self) -> Result<Option<Assertion>> {
         pctx!(self, "try assert trap");
         if !self.try_expr_start("assert_trap")? {
@@ -335,6 +350,7 @@ impl<R: Read> SpecParser for Parser<R> {
             let result = match reftype {
                 RefType::Func => ActionResult::Func,
                 RefType::Extern => ActionResult::Extern,
+                RefType::Exn => ActionResult::Exn,
             };
             return Ok(Some(result));
         }
@@ -353,6 +369,11 @@ impl<R: Read> SpecParser for Parser<R> {
         let nt = self.try_num_type()?;
 
         match nt {
+            Some(NumType::V128) => {
+                let pat = self.expect_v128_pat()?;
+                self.expect_close()?;
+                Ok(Some(ActionResult::NumPat(pat)))
+            }
             Some(nt) => {
                 if let Some(nanpat) = self.try_nan_pat(nt)? {
                     self.expect_close()?;
@@ -407,6 +428,7 @@ impl<R: Read> SpecParser for Parser<R> {
             Some("i64.const") => Some(NumType::I64),
             Some("f32.const") => Some(NumType::F32),
             Some("f64.const") => Some(NumType::F64),
+            Some("v128.const") => Some(NumType::V128),
             _ => None,
         };
         match found {
@@ -435,6 +457,40 @@ impl<R: Read> SpecParser for Parser<R> {
         }
     }
 
+    /// A v128 result. The lanes of float shapes may be NaN patterns.
+    fn expect_v128_pat(&mut self) -> Result<NumPat> {
+        pctx!(self, "expect v128 pat");
+        let kw = self.peek_keyword()?.map(|kw| kw.as_str());
+        let lanetype = match kw {
+            Some("f32x4") => NumType::F32,
+            Some("f64x2") => NumType::F64,
+            _ => return Ok(NumPat::Num(Num::V128(self.expect_v128()?))),
+        };
+        self.advance()?;
+        let mut pat = LanesPat {
+            lanetype,
+            value: 0,
+            nans: [None; 4],
+        };
+        let bits = pat.lane_bits();
+        for i in 0..128 / bits {
+            match self.try_nan_pat(lanetype)? {
+                Some(nanpat) => pat.nans[i] = Some(nanpat),
+                None => {
+                    let lane = match lanetype {
+                        NumType::F32 => self.expect_f32()?.to_bits() as u128,
+                        _ => self.expect_f64()?.to_bits() as u128,
+                    };
+                    pat.value |= lane << (i * bits);
+                }
+            }
+        }
+        Ok(match pat.nans.iter().any(Option::is_some) {
+            true => NumPat::Lanes(pat),
+            false => NumPat::Num(Num::V128(pat.value)),
+        })
+    }
+
     fn expect_num(&mut self, nt: NumType) -> Result<Num> {
         pctx!(self, "expect num");
         let result = match nt {
@@ -442,6 +498,7 @@ impl<R: Read> SpecParser for Parser<R> {
             NumType::I64 => Num::I64(self.expect_i64()? as u64),
             NumType::F32 => Num::F32(self.expect_f32()?),
             NumType::F64 => Num::F64(self.expect_f64()?),
+            NumType::V128 => Num::V128(self.expect_v128()?),
         };
         Ok(result)
     }
@@ -533,25 +590,13 @@ pub enum Const {
     RefHost(u32),
 }
 
-impl From<Const> for Value {
-    fn from(c: Const) -> Value {
-        match c {
-            Const::Num(p) => p.into(),
-            Const::RefHost(h) => Value::Ref(Ref::Extern(h)),
-            Const::RefNull(t) => match t {
-                RefType::Func => Value::Ref(Ref::Null(RefType::Func)),
-                RefType::Extern => Value::Ref(Ref::Null(RefType::Extern)),
-            },
-        }
-    }
-}
-
 #[rustfmt::skip]
 /// ```text
 /// assertion:
 ///   ( assert_return <action> <result>* )       ;; assert action has expected results
 ///   ( assert_trap <action> <failure> )         ;; assert action traps with given failure string
 ///   ( assert_exhaustion <action> <failure> )   ;; assert action exhausts system resources
+///   ( assert_exception <action> )              ;; assert action throws an uncaught exception
 ///   ( assert_malformed <module> <failure> )    ;; assert module cannot be decoded with given failure string
 ///   ( assert_invalid <module> <failure> )      ;; assert module is invalid with given failure string
 ///   ( assert_unlinkable <module> <failure> )   ;; assert module fails to link
@@ -571,6 +616,9 @@ pub enum Assertion {
     action:  Action,
     failure: String,
   },
+  Exception {
+    action: Action,
+  },
   Malformed {
     module:  Module,
     failure: String,
@@ -600,6 +648,7 @@ pub enum ActionResult {
     NumPat(NumPat),
     Extern,
     Func,
+    Exn,
 }
 
 /// ```text
@@ -607,11 +656,13 @@ pub enum ActionResult {
 ///   <value>                                    ;; literal result
 ///   nan:canonical                              ;; NaN in canonical form
 ///   nan:arithmetic                             ;; NaN with 1 in MSB of payload
+///   <shape> <num_pat>*                         ;; v128 lanes
 /// ```
 #[derive(Copy, Clone, Debug)]
 pub enum NumPat {
     Num(Num),
     NaNPat(NaNPat),
+    Lanes(LanesPat),
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -637,6 +688,42 @@ impl NaNPat {
     }
 }
 
+/// The lanes of a `f32x4` or `f64x2` result, some of which are NaN patterns.
+#[derive(Clone, Copy, Debug)]
+pub struct LanesPat {
+    lanetype: NumType,
+    /// The lanes that aren't NaN patterns.
+    value:    u128,
+    nans:     [Option<NaNPat>; 4],
+}
+
+impl LanesPat {
+    fn lane_bits(&self) -> usize {
+        match self.lanetype {
+            NumType::F32 => 32,
+            _ => 64,
+        }
+    }
+
+    pub fn accepts(&self, n: Num) -> bool {
+        let Num::V128(v) = n else {
+            return false;
+        };
+        let bits = self.lane_bits();
+        let mask = u128::MAX >> (128 - bits);
+        (0..128 / bits).all(|i| {
+            let lane = (v >> (i * bits)) & mask;
+            match self.nans[i] {
+                Some(nanpat) => nanpat.accepts(match self.lanetype {
+                    NumType::F32 => Num::F32(f32::from_bits(lane as u32)),
+                    _ => Num::F64(f64::from_bits(lane as u64)),
+                }),
+                None => lane == (self.value >> (i * bits)) & mask,
+            }
+        })
+    }
+}
+
 /// ```text
 /// meta:
 ///  ( script <name>? <script> )                ;; name a subscript
@@ -2,28 +2,36 @@ use {
     super::{
         error::{CmdError, Failure, Result, SpecTestError, TestFailureError},
         error_mappings::verify_failure,
-        format::{Action, ActionResult, Assertion, Cmd, CmdEntry, Module, NumPat, SpecTestScript},
+        format::{
+            Action, ActionResult, Assertion, Cmd, CmdEntry, Const, Module, NumPat, SpecTestScript,
+        },
         log_tag::Tag,
     },
     std::{
         collections::HashMap,
-        panic::{catch_unwind, PanicInfo},
+        panic::{catch_unwind, AssertUnwindSafe, PanicInfo},
         rc::Rc,
     },
     wrausmt_common::logger::{Logger, PrintLogger},
     wrausmt_format::{
-        compiler::compile_module, file_loader::FileLoader, loader::Loader, text::string::WasmString,
+        binary::{encode::encode_wasm_data, parse_wasm_data},
+        compiler::compile_module,
+        file_loader::FileLoader,
+        loader::{Loader, LoaderError},
+        text::string::WasmString,
     },
     wrausmt_runtime::{
         runtime::{
             instance::ModuleInstance,
-            values::{Num, Ref, Value},
+            values::{ExternRef, Num, Ref, Value},
             Runtime,
         },
-        syntax::{types::RefType, Id},
+        syntax::{self, types::RefType, Id, Resolved, UncompiledExpr, Unvalidated},
     },
 };
 
+type SyntaxModule = syntax::Module<Resolved, Unvalidated, UncompiledExpr<Resolved>>;
+
 pub type CmdResult<T> = std::result::Result<T, CmdError>;
 pub type TestResult<T> = std::result::Result<T, TestFailureError>;
 
@@ -62,6 +70,9 @@ pub enum RunSet {
 pub struct RunConfig<'a> {
     pub runset:             RunSet,
     pub failures_to_ignore: &'a [&'a str],
+    /// If true, each module is encoded to the binary format and parsed again
+    /// before it's loaded.
+    pub binary_roundtrip:   bool,
 }
 
 impl RunSet {
@@ -101,6 +112,8 @@ pub struct SpecTestRunner {
     runtime:       Runtime,
     latest_module: Option<Rc<ModuleInstance>>,
     named_modules: HashMap<Id, Rc<ModuleInstance>>,
+    /// The host objects created for `ref.extern` constants, by their number.
+    host_refs:     HashMap<u32, ExternRef>,
     logger:        PrintLogger,
 }
 
@@ -111,6 +124,21 @@ fn module_data(strings: Vec<WasmString>) -> Box<[u8]> {
         .collect()
 }
 
+fn encode(m: &SyntaxModule) -> CmdResult<Box<[u8]>> {
+    let mut data = vec![];
+    encode_wasm_data(m, &mut data).map_err(LoaderError::from)?;
+    Ok(data.into_boxed_slice())
+}
+
+/// Encode the module to the binary format and parse the result. The binary
+/// format doesn't carry the module ID, so it's copied from the original.
+fn binary_roundtrip(m: SyntaxModule) -> CmdResult<SyntaxModule> {
+    let data = encode(&m)?;
+    let mut decoded = parse_wasm_data(&mut data.as_ref()).map_err(LoaderError::from)?;
+    decoded.id = m.id;
+    Ok(decoded)
+}
+
 impl SpecTestRunner {
     pub fn new() -> Self {
         let mut runtime = Runtime::new();
@@ -130,6 +158,24 @@ impl SpecTestRunner {
         .ok_or_else(|| CmdError::NoModule(modname.clone()))
     }
 
+    /// The value of a constant argument. A host reference refers to a host
+    /// object holding its number, and the same number always refers to the
+    /// same object.
+    fn const_value(&mut self, c: Const) -> Value {
+        match c {
+            Const::Num(n) => n.into(),
+            Const::RefNull(t) => Value::Ref(Ref::Null(t)),
+            Const::RefHost(h) => {
+                let runtime = &mut self.runtime;
+                (*self
+                    .host_refs
+                    .entry(h)
+                    .or_insert_with(|| runtime.alloc_extern(Rc::new(h))))
+                .into()
+            }
+        }
+    }
+
     fn handle_action(&mut self, action: Action) -> CmdResult<Vec<Value>> {
         match action {
             Action::Invoke {
@@ -141,7 +187,7 @@ impl SpecTestRunner {
                 self.logger.log(Tag::Spec, || {
                     format!("INVOKE ACTION {:?} {} {:?}", modname, name, params)
                 });
-                let values: Vec<Value> = params.into_iter().map(|p| p.into()).collect();
+                let values: Vec<Value> = params.into_iter().map(|p| self.const_value(p)).collect();
                 Ok(self.runtime.call(&module_instance, &name, &values)?)
             }
             Action::Get { modname, name } => {
@@ -177,6 +223,11 @@ impl SpecTestRunner {
                         return Err(TestFailureError::ResultMismatch { result, expect });
                     }
                 }
+                ActionResult::NumPat(NumPat::Lanes(lanes)) => {
+                    if !matches!(result, Value::Num(n) if lanes.accepts(n)) {
+                        return Err(TestFailureError::ResultMismatch { result, expect });
+                    }
+                }
                 ActionResult::Func => {
                     if !matches!(
                         result,
@@ -193,19 +244,40 @@ impl SpecTestRunner {
                         return Err(TestFailureError::ResultMismatch { result, expect });
                     }
                 }
+                ActionResult::Exn => {
+                    if !matches!(
+                        result,
+                        Value::Ref(Ref::Null(RefType::Exn)) | Value::Ref(Ref::Exn(_))
+                    ) {
+                        return Err(TestFailureError::ResultMismatch { result, expect });
+                    }
+                }
             }
         }
         Ok(())
     }
 
-    fn handle_module(&mut self, m: Module) -> CmdResult<(Option<Id>, Rc<ModuleInstance>)> {
+    fn handle_module(
+        &mut self,
+        m: Module,
+        runconfig: &RunConfig,
+    ) -> CmdResult<(Option<Id>, Rc<ModuleInstance>)> {
         match m {
             Module::Module(m) => {
+                let m = if runconfig.binary_roundtrip {
+                    binary_roundtrip(m)?
+                } else {
+                    m
+                };
                 let compiled = compile_module(m)?;
                 Ok((compiled.id.clone(), self.runtime.load(compiled)?))
             }
             Module::Binary(n, b) => {
-                let data = module_data(b);
+                let mut data = module_data(b);
+                if runconfig.binary_roundtrip {
+                    let m = parse_wasm_data(&mut data.as_ref()).map_err(LoaderError::from)?;
+                    data = encode(&m)?;
+                }
                 Ok((n, self.runtime.load_wasm_data(&mut data.as_ref())?))
             }
             Module::Quote(n, b) => {
@@ -220,7 +292,7 @@ impl SpecTestRunner {
             .log(Tag::Spec, || format!("EXECUTE CMD {:?}", cmd));
         match cmd {
             Cmd::Module(m) => {
-                let (name, modinst) = self.handle_module(m)?;
+                let (name, modinst) = self.handle_module(m, runconfig)?;
                 if let Some(name) = name {
                     self.named_modules.insert(name, modinst.clone());
                 }
@@ -260,19 +332,23 @@ impl SpecTestRunner {
                         verify_failure(result, &failure).map_err(|e| e.into())
                     }
                     Assertion::ModuleTrap { module, failure } => {
-                        let result = self.handle_module(module);
+                        let result = self.handle_module(module, runconfig);
                         verify_failure(result, &failure).map_err(|e| e.into())
                     }
                     Assertion::Malformed { module, failure } => {
-                        let result = self.handle_module(module);
+                        let result = self.handle_module(module, runconfig);
                         verify_failure(result, &failure).map_err(|e| e.into())
                     }
                     Assertion::Exhaustion { action, failure } => {
                         let result = self.handle_action(action);
                         verify_failure(result, &failure).map_err(|e| e.into())
                     }
+                    Assertion::Exception { action } => {
+                        let result = self.handle_action(action);
+                        verify_failure(result, "uncaught exception").map_err(|e| e.into())
+                    }
                     Assertion::Unlinkable { module, failure } => {
-                        let result = self.handle_module(module);
+                        let result = self.handle_module(module, runconfig);
                         verify_failure(result, &failure).map_err(|e| e.into())
                     }
                     Assertion::Invalid { module, failure } => {
@@ -281,7 +357,9 @@ impl SpecTestRunner {
                         }
                         let result = unsafe {
                             let pself = self as *mut Self;
-                            catch_unwind(|| (*pself).handle_module(module))
+                            catch_unwind(AssertUnwindSafe(|| {
+                                (*pself).handle_module(module, runconfig)
+                            }))
                         };
                         match result {
                             Ok(result) => verify_failure(result, &failure).map_err(|e| e.into()),
@@ -0,0 +1,23 @@
+(module
+  (import "env" "fail" (func $fail))
+
+  (func $inner (param i32) (result i32)
+    local.get 0
+    i32.const 0
+    i32.div_u)
+
+  (func $middle (export "middle") (param i32) (result i32)
+    local.get 0
+    call $inner)
+
+  (func (export "outer") (param i32) (result i32)
+    local.get 0
+    call $middle)
+
+  (func $tail (export "tail") (param i32) (result i32)
+    local.get 0
+    return_call $inner)
+
+  (func $host (export "host")
+    call $fail)
+)
@@ -0,0 +1,185 @@
+use {
+    std::rc::Rc,
+    wrausmt_format::{
+        binary::encode::encode_wasm_data, file_loader::FileLoader, loader::compile_wasm_data,
+        text::parse_wast_data,
+    },
+    wrausmt_runtime::{
+        runtime::{
+            error::{RuntimeError, TrapKind},
+            instance::ModuleInstance,
+            Runtime,
+        },
+        syntax::{
+            location::{Location, SourceMap},
+            types::FunctionType,
+        },
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const FILE: &str = "tests/backtrace/data/backtrace.wat";
+
+fn runtime() -> Runtime {
+    let mut runtime = Runtime::new();
+    let void = FunctionType {
+        params: Box::new([]),
+        result: Box::new([]),
+    };
+    runtime.register_host_func("env", "fail", void, |_, _| Err(TrapKind::Unreachable)?);
+    runtime
+}
+
+fn load() -> Result<(Runtime, Rc<ModuleInstance>)> {
+    let mut runtime = runtime();
+    let module = runtime.load_file(FILE)?;
+    Ok((runtime, module))
+}
+
+fn locations(err: &RuntimeError) -> Vec<Option<Location>> {
+    err.backtrace().iter().map(|frame| frame.location).collect()
+}
+
+fn names(err: &RuntimeError) -> Vec<String> {
+    err.backtrace()
+        .iter()
+        .map(|frame| match &frame.name {
+            Some(name) => name.to_string(),
+            None => "-".to_owned(),
+        })
+        .collect()
+}
+
+#[test]
+fn trap_backtrace() -> Result<()> {
+    let (mut runtime, module) = load()?;
+
+    let err = runtime.call(&module, "outer", &[1u32.into()]).unwrap_err();
+    assert!(matches!(
+        err.as_trap_error(),
+        Some(TrapKind::IntegerDivideByZero)
+    ));
+    assert_eq!(names(&err), ["$inner", "$middle", "-"]);
+    let indices: Vec<_> = err.backtrace().iter().map(|f| f.func_index()).collect();
+    assert_eq!(indices, [Some(1), Some(2), Some(3)]);
+    assert!(err
+        .backtrace()
+        .iter()
+        .all(|f| Rc::ptr_eq(&f.module, &module)));
+    // The division comes after the two operands are pushed.
+    assert!(err.backtrace()[0].offset > 0);
+
+    let msg = err.to_string();
+    let mut lines = msg.lines();
+    assert_eq!(lines.next(), Some("Trap(IntegerDivideByZero)"));
+    assert_eq!(lines.next(), Some("wasm backtrace:"));
+    assert!(lines.next().unwrap().starts_with("  0: $inner (func[1]+0x"));
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("  1: $middle (func[2]+0x"));
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("  2: <anonymous> (func[3]+0x"));
+    assert_eq!(lines.next(), None);
+
+    // The stack was unwound, so the runtime can still be used.
+    let err = runtime.call(&module, "middle", &[0u32.into()]).unwrap_err();
+    assert_eq!(names(&err), ["$inner", "$middle"]);
+    Ok(())
+}
+
+#[test]
+fn tail_call_backtrace() -> Result<()> {
+    let (mut runtime, module) = load()?;
+
+    // The frame of the function that made the tail call was replaced.
+    let err = runtime.call(&module, "tail", &[1u32.into()]).unwrap_err();
+    assert_eq!(names(&err), ["$inner"]);
+    Ok(())
+}
+
+#[test]
+fn host_error_backtrace() -> Result<()> {
+    let (mut runtime, module) = load()?;
+
+    // Host functions don't have frames, so the innermost frame is the call.
+    let err = runtime.call(&module, "host", &[]).unwrap_err();
+    assert!(matches!(err.as_trap_error(), Some(TrapKind::Unreachable)));
+    assert_eq!(names(&err), ["$host"]);
+    assert_eq!(err.backtrace()[0].func_index(), Some(5));
+    Ok(())
+}
+
+#[test]
+fn no_backtrace_outside_guest() -> Result<()> {
+    let (mut runtime, module) = load()?;
+
+    let err = runtime.call(&module, "outer", &[]).unwrap_err();
+    assert!(err.backtrace().is_empty());
+    Ok(())
+}
+
+#[test]
+fn text_trap_locations() -> Result<()> {
+    let (mut runtime, module) = load()?;
+
+    let err = runtime.call(&module, "outer", &[1u32.into()]).unwrap_err();
+    let lines: Vec<_> = locations(&err)
+        .into_iter()
+        .map(|l| l.map(|l| l.line))
+        .collect();
+    // The division, and the two calls that led to it.
+    assert_eq!(lines, [Some(7), Some(11), Some(15)]);
+
+    let msg = err.to_string();
+    let inner = msg.lines().nth(2).unwrap();
+    assert!(inner.ends_with(&format!(" at {}", err.backtrace()[0].location.unwrap())));
+    assert!(inner.contains(") at 7:"));
+    Ok(())
+}
+
+#[test]
+fn binary_trap_locations() -> Result<()> {
+    let module = parse_wast_data(&mut std::fs::read(FILE)?.as_slice())?;
+    let mut data = vec![];
+    encode_wasm_data(&module, &mut data)?;
+    let compiled = compile_wasm_data(&mut data.as_slice())?;
+
+    let mut runtime = runtime();
+    let module = runtime.load_compiled(&compiled)?;
+    let err = runtime.call(&module, "middle", &[1u32.into()]).unwrap_err();
+
+    // Binary locations are the offsets of the instructions in the module.
+    // The body of $inner is `local.get 0; i32.const 0; i32.div_u; end`.
+    let inner = [0x20, 0x00, 0x41, 0x00, 0x6e, 0x0b];
+    let body = data.windows(inner.len()).position(|w| w == inner).unwrap();
+    let Some(location) = err.backtrace()[0].location else {
+        panic!("no location for {:?}", err.backtrace()[0]);
+    };
+    assert_eq!(location, Location {
+        line: 0,
+        pos:  body as u32 + 4,
+    });
+    assert!(err.to_string().contains(&format!(
+        "(func[1]+{:#x}) at {:#x}",
+        err.backtrace()[0].offset,
+        body + 4
+    )));
+    Ok(())
+}
+
+#[test]
+fn source_map_lookup() {
+    let at = |pos| Location { line: 1, pos };
+    let map = SourceMap::new([(0, at(1)), (1, at(1)), (3, at(5)), (8, at(9))]);
+    // Consecutive offsets with the same location are merged.
+    assert_eq!(map.entries(), [(0, at(1)), (3, at(5)), (8, at(9))]);
+    assert_eq!(map.location(0), Some(at(1)));
+    assert_eq!(map.location(2), Some(at(1)));
+    assert_eq!(map.location(3), Some(at(5)));
+    assert_eq!(map.location(100), Some(at(9)));
+    assert_eq!(SourceMap::default().location(0), None);
+}
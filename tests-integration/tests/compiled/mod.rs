@@ -0,0 +1,150 @@
+use {
+    std::{fs::File, rc::Rc},
+    wrausmt_format::{compiler::compile_module, file_loader::compile_file, text::parse_wast_data},
+    wrausmt_runtime::{
+        runtime::{linker::Linker, Runtime},
+        syntax::{
+            types::{
+                ExternType, FunctionType, GlobalType, IndexType, Limits, MemType, NumType, RefType,
+                TableType,
+            },
+            ExportType, ImportType, Validated,
+        },
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn instantiate_repeatedly() -> Result<()> {
+    let module = Rc::new(compile_file("tests/host_access/data/host_access.wat")?);
+
+    let mut runtime = Runtime::new();
+    let first = runtime.load_compiled(&module)?;
+    let second = runtime.load_compiled(&module)?;
+
+    // Each instance has its own state.
+    runtime.call(&first, "count", &[])?;
+    assert_eq!(runtime.call(&first, "count", &[])?, [2u32.into()]);
+    assert_eq!(runtime.call(&second, "count", &[])?, [1u32.into()]);
+    runtime.memory(&first, "mem")?.write(0, 7u32)?;
+    assert_eq!(
+        runtime.call(&second, "load", &[0u32.into()])?,
+        [0u32.into()]
+    );
+
+    // The module can be instantiated in another runtime too.
+    let mut other = Runtime::new();
+    let third = Linker::new().instantiate(&mut other, &module)?;
+    assert_eq!(other.call(&third, "count", &[])?, [1u32.into()]);
+    assert_eq!(other.call(&third, "load", &[16u32.into()])?, [
+        u32::from_le_bytes(*b"hell").into()
+    ]);
+    Ok(())
+}
+
+fn global(mutable: bool) -> ExternType<Validated> {
+    ExternType::Global(GlobalType {
+        mutable,
+        valtype: NumType::I32.into(),
+    })
+}
+
+#[test]
+fn signatures() -> Result<()> {
+    let i32_unop = FunctionType {
+        params: Box::new([NumType::I32.into()]),
+        result: Box::new([NumType::I32.into()]),
+    };
+    let expected_imports = [
+        ImportType {
+            modname:    "env",
+            name:       "mem",
+            externtype: ExternType::Memory(MemType::new(
+                IndexType::I32,
+                Limits {
+                    lower: 1,
+                    upper: None,
+                },
+                false,
+            )),
+        },
+        ImportType {
+            modname:    "env",
+            name:       "table",
+            externtype: ExternType::Table(TableType::new(
+                Limits {
+                    lower: 2,
+                    upper: None,
+                },
+                RefType::Func,
+            )),
+        },
+        ImportType {
+            modname:    "env",
+            name:       "base",
+            externtype: global(false),
+        },
+        ImportType {
+            modname:    "env",
+            name:       "counter",
+            externtype: global(true),
+        },
+        ImportType {
+            modname:    "env",
+            name:       "double",
+            externtype: ExternType::Func(i32_unop.clone()),
+        },
+    ];
+    // Exports of imported items have the imported types.
+    let expected_exports = [
+        ExportType {
+            name:       "counter",
+            externtype: global(true),
+        },
+        ExportType {
+            name:       "table",
+            externtype: expected_imports[1].externtype.clone(),
+        },
+        ExportType {
+            name:       "inc",
+            externtype: ExternType::Func(i32_unop.clone()),
+        },
+        ExportType {
+            name:       "run",
+            externtype: ExternType::Func(i32_unop),
+        },
+    ];
+
+    let mut file = File::open("tests/linking/data/env_importer.wat")?;
+    let module = compile_module(parse_wast_data(&mut file)?)?;
+    assert_eq!(module.imports().collect::<Vec<_>>(), expected_imports);
+    assert_eq!(module.exports().collect::<Vec<_>>(), expected_exports);
+
+    let module = compile_file("tests/linking/data/env_importer.wat")?;
+    assert_eq!(module.imports().collect::<Vec<_>>(), expected_imports);
+    assert_eq!(module.exports().collect::<Vec<_>>(), expected_exports);
+    Ok(())
+}
+
+#[test]
+fn instance_exports() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let module = compile_file("tests/host_access/data/host_access.wat")?;
+    let instance = runtime.load_compiled(&module)?;
+
+    let exports: Vec<_> = instance
+        .exports()
+        .iter()
+        .map(|e| ExportType {
+            name:       &e.name,
+            externtype: e.externtype.clone(),
+        })
+        .collect();
+    assert_eq!(exports, module.exports().collect::<Vec<_>>());
+    assert_eq!(exports[1], ExportType {
+        name:       "counter",
+        externtype: global(true),
+    });
+    Ok(())
+}
@@ -0,0 +1,30 @@
+(module
+  (memory (export "mem") 1)
+
+  (func $add (param $a i32) (param $b i32) (result i32)
+    (local $sum i32)
+    local.get $a
+    local.get $b
+    i32.add
+    local.set $sum
+    local.get $sum)
+
+  (func $twice (export "twice") (param i32) (result i32)
+    block (result i32)
+      local.get 0
+      local.get 0
+      call $add
+    end
+    i32.const 1
+    i32.add)
+
+  (func $store (export "store") (param i32 i32)
+    local.get 0
+    local.get 1
+    i32.store
+    local.get 0
+    i32.const 4
+    i32.add
+    local.get 1
+    i32.store)
+)
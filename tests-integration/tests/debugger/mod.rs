@@ -0,0 +1,265 @@
+use {
+    std::{cell::RefCell, rc::Rc},
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{
+        debug::{Breakpoint, DebugContext, PauseReason, Resume, Watchpoint, MAX_WATCHPOINT_LEN},
+        error::RuntimeErrorKind,
+        instance::{
+            addr::{self, Address},
+            ExternalVal, ModuleInstance,
+        },
+        values::Value,
+        Runtime,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// What the debugger saw when execution paused.
+#[derive(Debug)]
+struct Pause {
+    func:     String,
+    instr:    &'static str,
+    reason:   PauseReason,
+    locals:   Vec<Value>,
+    operands: Vec<Value>,
+    labels:   usize,
+}
+
+impl Pause {
+    fn new(ctx: &DebugContext) -> Self {
+        Pause {
+            func:     ctx.frame().to_string(),
+            instr:    ctx.instruction().name,
+            reason:   ctx.reason().clone(),
+            locals:   ctx.locals().unwrap().to_vec(),
+            operands: ctx.operands().unwrap().to_vec(),
+            labels:   ctx.labels().unwrap().len(),
+        }
+    }
+}
+
+type Pauses = Rc<RefCell<Vec<Pause>>>;
+
+fn load() -> Result<(Runtime, Rc<ModuleInstance>)> {
+    let mut runtime = Runtime::new();
+    let module = runtime.load_file("tests/debugger/data/debug.wat")?;
+    Ok((runtime, module))
+}
+
+/// Attach a debugger that records each pause, and resumes as `resume` says.
+fn record(runtime: &mut Runtime, resume: impl Fn(&Pause) -> Resume + 'static) -> Pauses {
+    let pauses = Pauses::default();
+    let recorded = pauses.clone();
+    runtime.attach_debugger(move |ctx: &mut DebugContext| {
+        let pause = Pause::new(ctx);
+        let next = resume(&pause);
+        recorded.borrow_mut().push(pause);
+        Ok(next)
+    });
+    pauses
+}
+
+fn instrs(pauses: &Pauses) -> Vec<&'static str> {
+    pauses.borrow().iter().map(|p| p.instr).collect()
+}
+
+fn func(module: &ModuleInstance, idx: usize) -> Address<addr::Function> {
+    module.funcs()[idx]
+}
+
+#[test]
+fn breakpoint() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    let pauses = record(&mut runtime, |_| Resume::Continue);
+    let add = Breakpoint {
+        func:   func(&module, 0),
+        offset: 0,
+    };
+    runtime.add_breakpoint(add);
+
+    let results = runtime.call(&module, "twice", &[5u32.into()])?;
+    assert_eq!(results, [11u32.into()]);
+    {
+        let pauses = pauses.borrow();
+        assert_eq!(pauses.len(), 1);
+        let pause = &pauses[0];
+        assert_eq!(pause.reason, PauseReason::Breakpoint(add));
+        assert!(pause.func.starts_with("$add (func[0]+0x0) at 6:"));
+        assert_eq!(pause.instr, "local.get");
+        assert_eq!(pause.locals, [5u32.into(), 5u32.into(), 0u32.into()]);
+        assert!(pause.operands.is_empty());
+        // The label for the function body.
+        assert_eq!(pause.labels, 1);
+    }
+
+    assert!(runtime.remove_breakpoint(add));
+    assert!(!runtime.remove_breakpoint(add));
+    runtime.call(&module, "twice", &[5u32.into()])?;
+    assert_eq!(pauses.borrow().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn step() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    let pauses = record(&mut runtime, |_| Resume::Step);
+    runtime.break_next();
+
+    runtime.call(&module, "twice", &[5u32.into()])?;
+    assert_eq!(instrs(&pauses), [
+        "block",
+        "local.get",
+        "local.get",
+        "call",
+        "local.get",
+        "local.get",
+        "i32.add",
+        "local.set",
+        "local.get",
+        "end",
+        "end",
+        "i32.const",
+        "i32.add",
+        "end",
+    ]);
+    let pauses = pauses.borrow();
+    assert!(pauses.iter().all(|p| p.reason == PauseReason::Step));
+    // Before the `i32.add` of $add, and after the block's `end`.
+    assert_eq!(pauses[6].operands, [5u32.into(), 5u32.into()]);
+    assert_eq!(pauses[6].labels, 1);
+    assert_eq!(pauses[10].labels, 2);
+    assert_eq!(pauses[11].operands, [10u32.into()]);
+    assert_eq!(pauses[11].labels, 1);
+
+    // Stepping ends with the call.
+    runtime.call(&module, "twice", &[5u32.into()])?;
+    assert_eq!(pauses.len(), 14);
+    Ok(())
+}
+
+#[test]
+fn step_over_and_out() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    let pauses = record(&mut runtime, |_| Resume::StepOver);
+    runtime.break_next();
+    runtime.call(&module, "twice", &[5u32.into()])?;
+    // The instructions of $add run without pausing.
+    assert_eq!(instrs(&pauses), [
+        "block",
+        "local.get",
+        "local.get",
+        "call",
+        "end",
+        "i32.const",
+        "i32.add",
+        "end",
+    ]);
+
+    let pauses = record(&mut runtime, |p| match p.func.starts_with("$add") {
+        true => Resume::StepOut,
+        false => Resume::Continue,
+    });
+    runtime.add_breakpoint(Breakpoint {
+        func:   func(&module, 0),
+        offset: 0,
+    });
+    runtime.call(&module, "twice", &[5u32.into()])?;
+    let pauses = pauses.borrow();
+    assert_eq!(pauses.len(), 2);
+    assert!(pauses[0].func.starts_with("$add"));
+    // Paused in $twice, once the call has returned its result.
+    assert!(pauses[1].func.starts_with("$twice"));
+    assert_eq!(pauses[1].instr, "end");
+    assert_eq!(pauses[1].operands, [10u32.into()]);
+    Ok(())
+}
+
+#[test]
+fn watchpoint() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    let pauses = record(&mut runtime, |_| Resume::Continue);
+    let Some(ExternalVal::Memory(mem)) = module.resolve("mem").map(|e| e.addr) else {
+        panic!("no memory");
+    };
+    let watchpoint = Watchpoint { mem, range: 4..8 };
+    runtime.add_watchpoint(watchpoint.clone())?;
+
+    // Ranges that run past the end of memory, or are too long, are refused.
+    for range in [65534..65538, 0..MAX_WATCHPOINT_LEN + 1] {
+        let err = runtime
+            .add_watchpoint(Watchpoint { mem, range })
+            .unwrap_err();
+        assert!(matches!(err.kind, RuntimeErrorKind::InvalidWatchpoint));
+    }
+    assert_eq!(runtime.watchpoints(), std::slice::from_ref(&watchpoint));
+
+    runtime.call(&module, "store", &[0u32.into(), 7u32.into()])?;
+    {
+        let pauses = pauses.borrow();
+        // Only the second store writes to the watched bytes.
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].instr, "i32.store");
+        assert!(pauses[0].func.starts_with("$store"));
+        assert_eq!(pauses[0].reason, PauseReason::Watchpoint {
+            watchpoint: watchpoint.clone(),
+            old:        Box::new([0, 0, 0, 0]),
+            new:        Box::new([7, 0, 0, 0]),
+        });
+    }
+
+    // Writing the same bytes again doesn't change them.
+    runtime.call(&module, "store", &[0u32.into(), 7u32.into()])?;
+    assert_eq!(pauses.borrow().len(), 1);
+
+    assert!(runtime.remove_watchpoint(&watchpoint));
+    runtime.call(&module, "store", &[0u32.into(), 8u32.into()])?;
+    assert_eq!(pauses.borrow().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn inspect_memory() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    let seen = Rc::new(RefCell::new(vec![]));
+    let recorded = seen.clone();
+    runtime.attach_debugger(move |ctx: &mut DebugContext| {
+        recorded
+            .borrow_mut()
+            .push(ctx.memory(0)?.data[0..4].to_vec());
+        Ok(Resume::Continue)
+    });
+    let store = func(&module, 2);
+    // Each call pauses before it stores anything, so the second call sees
+    // what the first one stored.
+    runtime.add_breakpoint(Breakpoint {
+        func:   store,
+        offset: 0,
+    });
+    runtime.call(&module, "store", &[0u32.into(), 3u32.into()])?;
+    runtime.call(&module, "store", &[0u32.into(), 9u32.into()])?;
+    assert_eq!(*seen.borrow(), [vec![0, 0, 0, 0], vec![3, 0, 0, 0]]);
+    Ok(())
+}
+
+#[test]
+fn debugger_error_stops_execution() -> Result<()> {
+    let (mut runtime, module) = load()?;
+    runtime.attach_debugger(|_: &mut DebugContext| Err(RuntimeErrorKind::Exit(3))?);
+    runtime.break_next();
+
+    let err = runtime.call(&module, "twice", &[5u32.into()]).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::Exit(3)));
+    assert!(err.backtrace()[0].to_string().starts_with("$twice"));
+
+    // The runtime can still be used, and doesn't pause once detached.
+    runtime.detach_debugger();
+    runtime.add_breakpoint(Breakpoint {
+        func:   func(&module, 0),
+        offset: 0,
+    });
+    assert_eq!(runtime.call(&module, "twice", &[5u32.into()])?, [
+        11u32.into()
+    ]);
+    Ok(())
+}
@@ -0,0 +1,156 @@
+(module
+  (tag $e0 (export "e0"))
+  (tag $e1 (export "e1") (param i32))
+  (tag $e2 (param i32 i64))
+
+  (func $throw_e1 (param i32)
+    (throw $e1 (local.get 0)))
+
+  ;; Returns the payload of e1 when it's caught, and -1 otherwise.
+  (func (export "catch") (param i32) (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (if (local.get 0) (then (call $throw_e1 (local.get 0)))))
+      (i32.const -1)))
+
+  ;; Only exceptions with tag e0 are caught; e1 is passed to the caller.
+  (func (export "catch_other") (param i32) (result i32)
+    (block $h
+      (try_table (catch $e0 $h)
+        (call $throw_e1 (local.get 0)))
+      (return (i32.const -1)))
+    (i32.const 0))
+
+  (func (export "catch_all") (result i32)
+    (block $h
+      (try_table (catch_all $h)
+        (throw $e0))
+      (return (i32.const 0)))
+    (i32.const 1))
+
+  ;; The exception caught with catch_ref is rethrown and caught again by the
+  ;; outer try_table.
+  (func (export "rethrow") (param i32) (result i32)
+    (block $outer (result i32)
+      (try_table (catch $e1 $outer)
+        (block $inner (result i32 exnref)
+          (try_table (catch_ref $e1 $inner)
+            (call $throw_e1 (local.get 0)))
+          (unreachable))
+        (throw_ref))
+      (i32.const -1)))
+
+  (func (export "catch_all_ref") (result i32)
+    (block $h (result exnref)
+      (try_table (catch_all_ref $h)
+        (throw $e0))
+      (unreachable))
+    (ref.is_null))
+
+  (func (export "multi") (result i32 i64)
+    (block $h (result i32 i64)
+      (try_table (catch $e2 $h)
+        (throw $e2 (i32.const 7) (i64.const 42)))
+      (unreachable)))
+
+  ;; Values and labels of the unwound frames are discarded.
+  (func $deep (param i32)
+    (i32.const 99)
+    (block
+      (if (i32.eqz (local.get 0))
+        (then (throw $e1 (i32.const 5))))
+      (call $deep (i32.sub (local.get 0) (i32.const 1))))
+    (drop))
+  (func (export "deep") (param i32) (result i32)
+    (i32.const 1)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (call $deep (local.get 0)))
+      (i32.const -1))
+    (i32.add))
+
+  ;; Throw and catch an exception n times, and return n.
+  (func (export "catch_many") (param i32) (result i32)
+    (local $caught i32)
+    (loop $l
+      (if (i32.lt_u (local.get $caught) (local.get 0))
+        (then
+          (block $h (result i32)
+            (try_table (catch $e1 $h)
+              (call $throw_e1 (local.get $caught)))
+            (unreachable))
+          (local.set $caught (i32.add (i32.const 1)))
+          (br $l))))
+    (local.get $caught))
+
+  ;; The same, but catching references to the exceptions.
+  (func (export "catch_ref_many") (param i32) (result i32)
+    (local $caught i32)
+    (loop $l
+      (if (i32.lt_u (local.get $caught) (local.get 0))
+        (then
+          (block $h (result exnref)
+            (try_table (catch_all_ref $h)
+              (throw $e0))
+            (unreachable))
+          (drop)
+          (local.set $caught (i32.add (local.get $caught) (i32.const 1)))
+          (br $l))))
+    (local.get $caught))
+
+  ;; Throw e0 n times, catching a reference to it, rethrowing it with
+  ;; throw_ref, and catching a reference to it again before dropping it.
+  (func (export "rethrow_many") (param i32) (result i32)
+    (local $caught i32)
+    (loop $l
+      (if (i32.lt_u (local.get $caught) (local.get 0))
+        (then
+          (block $outer (result exnref)
+            (try_table (catch_all_ref $outer)
+              (block $inner (result exnref)
+                (try_table (catch_all_ref $inner)
+                  (throw $e0))
+                (unreachable))
+              (throw_ref))
+            (unreachable))
+          (drop)
+          (local.set $caught (i32.add (local.get $caught) (i32.const 1)))
+          (br $l))))
+    (local.get $caught))
+
+  (global $kept (mut exnref) (ref.null exn))
+
+  ;; Keep a reference to an exception with e1 and the payload in a global.
+  (func (export "keep") (param i32)
+    (block $h (result i32 exnref)
+      (try_table (catch_ref $e1 $h)
+        (call $throw_e1 (local.get 0)))
+      (unreachable))
+    (global.set $kept)
+    (drop))
+
+  ;; Rethrow the kept exception, returning its payload.
+  (func (export "rethrow_kept") (result i32)
+    (block $h (result i32)
+      (try_table (catch $e1 $h)
+        (throw_ref (global.get $kept)))
+      (unreachable)))
+
+  ;; Hold references to n exceptions at once.
+  (func $hold (export "hold") (param i32)
+    (local $exn exnref)
+    (if (local.get 0)
+      (then
+        (block $h (result exnref)
+          (try_table (catch_all_ref $h)
+            (throw $e0))
+          (unreachable))
+        (local.set $exn)
+        (call $hold (i32.sub (local.get 0) (i32.const 1))))))
+
+  (func (export "uncaught") (param i32)
+    (throw $e1 (local.get 0)))
+
+  (func (export "throw_null")
+    (throw_ref (ref.null exn)))
+)
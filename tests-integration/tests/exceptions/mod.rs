@@ -0,0 +1,159 @@
+use {
+    wrausmt_format::{
+        binary::encode::encode_wasm_data, file_loader::FileLoader, loader::Loader,
+        text::parse_wast_data,
+    },
+    wrausmt_runtime::runtime::{
+        config::RuntimeConfig,
+        error::{RuntimeErrorKind, TrapKind},
+        instance::ExternalVal,
+        Runtime,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn throw_and_catch() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/exceptions/data/exceptions.wat")?;
+
+    assert_eq!(runtime.call(&mod_inst, "catch", &[42u32.into()])?, [
+        42u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "catch", &[0u32.into()])?, [(-1i32
+        as u32)
+        .into()]);
+    assert_eq!(runtime.call(&mod_inst, "catch_all", &[])?, [1u32.into()]);
+    // Results are returned to the host starting with the last one.
+    assert_eq!(runtime.call(&mod_inst, "multi", &[])?, [
+        42u64.into(),
+        7u32.into()
+    ]);
+    Ok(())
+}
+
+#[test]
+fn rethrow() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/exceptions/data/exceptions.wat")?;
+
+    assert_eq!(runtime.call(&mod_inst, "rethrow", &[3u32.into()])?, [
+        3u32.into()
+    ]);
+    assert_eq!(
+        runtime.call(&mod_inst, "catch_all_ref", &[])?,
+        [0u32.into()]
+    );
+    Ok(())
+}
+
+#[test]
+fn unwinding() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/exceptions/data/exceptions.wat")?;
+
+    assert_eq!(runtime.call(&mod_inst, "deep", &[10u32.into()])?, [
+        6u32.into()
+    ]);
+    // The runtime is still usable after an exception escapes to the host.
+    runtime
+        .call(&mod_inst, "catch_other", &[1u32.into()])
+        .unwrap_err();
+    assert_eq!(runtime.call(&mod_inst, "deep", &[3u32.into()])?, [
+        6u32.into()
+    ]);
+    Ok(())
+}
+
+#[test]
+fn uncaught() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/exceptions/data/exceptions.wat")?;
+
+    let e1 = match mod_inst.resolve("e1").map(|e| &e.addr) {
+        Some(ExternalVal::Tag(addr)) => *addr,
+        _ => panic!("e1 is not an exported tag"),
+    };
+    let err = runtime
+        .call(&mod_inst, "uncaught", &[9u32.into()])
+        .unwrap_err();
+    match err.kind {
+        RuntimeErrorKind::UncaughtException { tag, payload } => {
+            assert_eq!(tag, e1);
+            assert_eq!(payload, [9u32.into()]);
+        }
+        kind => panic!("unexpected error {kind:?}"),
+    }
+
+    let err = runtime.call(&mod_inst, "throw_null", &[]).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::Trap(TrapKind::NullExceptionReference)
+    ));
+    Ok(())
+}
+
+#[test]
+fn exception_limit() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_exceptions: 4,
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/exceptions/data/exceptions.wat")?;
+
+    // Exceptions that nothing can refer to once they're caught, or once they
+    // escape to the host, are freed.
+    assert_eq!(runtime.call(&mod_inst, "catch_many", &[1000u32.into()])?, [
+        1000u32.into()
+    ]);
+    for _ in 0..10 {
+        runtime
+            .call(&mod_inst, "uncaught", &[1u32.into()])
+            .unwrap_err();
+    }
+
+    // Exceptions caught by reference are kept while the program refers to
+    // them, and freed in any order once it doesn't.
+    runtime.call(&mod_inst, "keep", &[42u32.into()])?;
+    assert_eq!(
+        runtime.call(&mod_inst, "catch_ref_many", &[1000u32.into()])?,
+        [1000u32.into()]
+    );
+    assert_eq!(
+        runtime.call(&mod_inst, "rethrow_many", &[1000u32.into()])?,
+        [1000u32.into()]
+    );
+    assert_eq!(
+        runtime.call(&mod_inst, "rethrow_kept", &[])?,
+        [42u32.into()]
+    );
+
+    // With the kept exception, only three more can be held at once.
+    runtime.call(&mod_inst, "hold", &[3u32.into()])?;
+    let err = runtime.call(&mod_inst, "hold", &[4u32.into()]).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::ExceptionLimitExceeded));
+    assert_eq!(
+        runtime.call(&mod_inst, "rethrow_kept", &[])?,
+        [42u32.into()]
+    );
+    Ok(())
+}
+
+#[test]
+fn binary_round_trip() -> Result<()> {
+    let src = std::fs::read("tests/exceptions/data/exceptions.wat")?;
+    let module = parse_wast_data(&mut src.as_slice())?;
+    let mut data = vec![];
+    encode_wasm_data(&module, &mut data)?;
+
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_wasm_data(&mut data.as_slice())?;
+    assert_eq!(runtime.call(&mod_inst, "rethrow", &[3u32.into()])?, [
+        3u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "deep", &[4u32.into()])?, [
+        6u32.into()
+    ]);
+    Ok(())
+}
@@ -0,0 +1,16 @@
+(module
+  (table $objs (export "objs") 4 externref)
+  (table $funcs (export "funcs") 1 funcref)
+
+  (func (export "identity") (param externref) (result externref)
+    (local.get 0))
+
+  (func (export "is_null") (param externref) (result i32)
+    (ref.is_null (local.get 0)))
+
+  (func (export "store") (param $i i32) (param $obj externref)
+    (table.set $objs (local.get $i) (local.get $obj)))
+
+  (func (export "load") (param $i i32) (result externref)
+    (table.get $objs (local.get $i)))
+)
@@ -0,0 +1,86 @@
+use {
+    std::rc::Rc,
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::{
+        runtime::{
+            error::{RuntimeErrorKind, TrapKind},
+            values::{Ref, Value},
+            Runtime,
+        },
+        syntax::types::RefType,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn as_ref(val: &Value) -> Ref {
+    match val {
+        Value::Ref(r) => *r,
+        _ => panic!("{val:?} is not a reference"),
+    }
+}
+
+#[test]
+fn host_objects() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/externref/data/externref.wat")?;
+
+    let greeting = Rc::new(String::from("hello"));
+    let handle = runtime.alloc_extern(greeting.clone());
+
+    let res = runtime.call(&mod_inst, "identity", &[handle.into()])?;
+    assert_eq!(res, [handle.into()]);
+    let obj = runtime.extern_object::<String>(as_ref(&res[0]))?.unwrap();
+    assert!(Rc::ptr_eq(&obj, &greeting));
+
+    // Objects are only retrieved as their own type.
+    assert!(runtime.extern_object::<u32>(Ref::Extern(handle))?.is_none());
+
+    let null = Ref::Null(RefType::Extern);
+    assert!(runtime.extern_object::<String>(null)?.is_none());
+    assert_eq!(runtime.call(&mod_inst, "is_null", &[null.into()])?, [
+        1u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "is_null", &[handle.into()])?, [
+        0u32.into()
+    ]);
+    Ok(())
+}
+
+#[test]
+fn table_get_set() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/externref/data/externref.wat")?;
+
+    let first = runtime.alloc_extern(Rc::new(1u64));
+    let second = runtime.alloc_extern(Rc::new(2u64));
+
+    // The host and the program see each other's writes.
+    runtime.table_set(&mod_inst, "objs", 1, Ref::Extern(first))?;
+    assert_eq!(runtime.call(&mod_inst, "load", &[1u32.into()])?, [
+        first.into()
+    ]);
+    runtime.call(&mod_inst, "store", &[2u32.into(), second.into()])?;
+    let elem = runtime.table_get(&mod_inst, "objs", 2)?;
+    assert_eq!(*runtime.extern_object::<u64>(elem)?.unwrap(), 2);
+    assert_eq!(
+        runtime.table_get(&mod_inst, "objs", 0)?,
+        Ref::Null(RefType::Extern)
+    );
+
+    let err = runtime.table_get(&mod_inst, "objs", 4).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::Trap(TrapKind::OutOfBoundsTableAccess(4, 4))
+    ));
+    let err = runtime
+        .table_set(&mod_inst, "funcs", 0, Ref::Extern(first))
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::ValueTypeMismatch { .. }
+    ));
+    let err = runtime.table_get(&mod_inst, "load", 0).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::MethodNotFound(_)));
+    Ok(())
+}
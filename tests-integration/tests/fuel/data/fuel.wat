@@ -0,0 +1,24 @@
+(module
+  (func $add (export "add") (param i32 i32) (result i32)
+    (i32.add (local.get 0) (local.get 1)))
+
+  (func (export "call_add") (result i32)
+    (call $add (i32.const 1) (i32.const 2)))
+
+  ;; Count down from n to 0, returning the number of iterations.
+  (func (export "count") (param $n i32) (result i32)
+    (local $i i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $n)))
+        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next)))
+    (local.get $i))
+
+  (memory (export "mem") 1)
+
+  ;; Fill the first n bytes of memory with 1.
+  (func (export "fill") (param $n i32)
+    (memory.fill (i32.const 0) (i32.const 1) (local.get $n)))
+)
@@ -0,0 +1,141 @@
+use {
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{config::RuntimeConfig, error::RuntimeErrorKind, Runtime},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn fuel_is_opt_in() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+    assert_eq!(runtime.fuel(), None);
+
+    let res = runtime.call(&mod_inst, "count", &[1000u32.into()])?;
+    assert_eq!(res, [1000u32.into()]);
+    assert_eq!(runtime.fuel(), None);
+    Ok(())
+}
+
+#[test]
+fn fuel_costs() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        fuel: Some(100),
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+
+    // local.get, local.get, i32.add, end.
+    runtime.call(&mod_inst, "add", &[1u32.into(), 2u32.into()])?;
+    assert_eq!(runtime.fuel(), Some(96));
+
+    // i32.const, i32.const, call (5), end, and the body of add.
+    runtime.call(&mod_inst, "call_add", &[])?;
+    assert_eq!(runtime.fuel(), Some(84));
+    Ok(())
+}
+
+#[test]
+fn bulk_length_cost() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        fuel: Some(1000),
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+
+    // i32.const, i32.const, local.get, memory.fill (5), end.
+    runtime.call(&mod_inst, "fill", &[0u32.into()])?;
+    assert_eq!(runtime.fuel(), Some(991));
+
+    // Another unit for every 64 bytes.
+    runtime.call(&mod_inst, "fill", &[640u32.into()])?;
+    assert_eq!(runtime.fuel(), Some(972));
+
+    // A fill that can't be paid for doesn't happen, and can be resumed.
+    let err = runtime
+        .call(&mod_inst, "fill", &[65536u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::OutOfFuel));
+    assert_eq!(runtime.memory(&mod_inst, "mem")?.read::<u8>(65535)?, 0);
+    runtime.add_fuel(1000);
+    runtime.resume()?;
+    assert_eq!(runtime.memory(&mod_inst, "mem")?.read::<u8>(65535)?, 1);
+    Ok(())
+}
+
+#[test]
+fn out_of_fuel_resume() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+    runtime.set_fuel(Some(1000));
+
+    let err = runtime
+        .call(&mod_inst, "count", &[1000u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::OutOfFuel));
+
+    // Execution stops before the first instruction that can't be paid for.
+    let left = runtime.fuel().unwrap();
+    assert!(left < 5, "{left} fuel left");
+
+    // Resuming without more fuel fails again without making progress.
+    let err = runtime.resume().unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::OutOfFuel));
+
+    // Add fuel a bit at a time until the call completes.
+    let mut resumes = 0;
+    let res = loop {
+        runtime.add_fuel(5000);
+        match runtime.resume() {
+            Ok(res) => break res,
+            Err(e) if matches!(e.kind, RuntimeErrorKind::OutOfFuel) => resumes += 1,
+            Err(e) => Err(e)?,
+        }
+    };
+    assert_eq!(res, [1000u32.into()]);
+    assert!(resumes > 0);
+
+    let err = runtime.resume().unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::NothingSuspended));
+    Ok(())
+}
+
+#[test]
+fn call_abandons_suspended() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+    runtime.set_fuel(Some(100));
+
+    let err = runtime
+        .call(&mod_inst, "count", &[1000u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::OutOfFuel));
+
+    runtime.add_fuel(100);
+    let res = runtime.call(&mod_inst, "add", &[1u32.into(), 2u32.into()])?;
+    assert_eq!(res, [3u32.into()]);
+
+    let err = runtime.resume().unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::NothingSuspended));
+    Ok(())
+}
+
+#[test]
+fn resume_in_callee() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/fuel/data/fuel.wat")?;
+    runtime.set_fuel(Some(0));
+
+    // Stop for every unit of fuel charged, in both the caller and the callee.
+    let mut res = runtime.call(&mod_inst, "call_add", &[]);
+    let mut stops = 0;
+    while let Err(e) = res {
+        assert!(matches!(e.kind, RuntimeErrorKind::OutOfFuel));
+        stops += 1;
+        runtime.add_fuel(1);
+        res = runtime.resume();
+    }
+    assert_eq!(res?, [3u32.into()]);
+    assert_eq!(stops, 12);
+    Ok(())
+}
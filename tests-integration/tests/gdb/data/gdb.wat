@@ -0,0 +1,20 @@
+(module
+  (memory 1)
+
+  (func $add (param $a i32) (param $b i32) (result i32)
+    (local $sum i32)
+    local.get $a
+    local.get $b
+    i32.add
+    local.set $sum
+    local.get $sum)
+
+  (func (export "twice") (param i32) (result i32)
+    local.get 0
+    local.get 0
+    call $add)
+
+  (func (export "store") (param i32 i32)
+    (i32.store (local.get 0) (local.get 1))
+    (i32.store offset=4 (local.get 0) (local.get 1)))
+)
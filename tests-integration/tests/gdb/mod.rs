@@ -0,0 +1,218 @@
+use {
+    std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    },
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_gdb::GdbStub,
+    wrausmt_runtime::runtime::{
+        error::{self, RuntimeErrorKind},
+        values::Value,
+        Runtime,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// The client side of the protocol, as a debugger would speak it.
+struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    no_ack: bool,
+}
+
+impl Client {
+    fn connect(port: u16) -> Self {
+        let stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        Client {
+            reader: BufReader::new(stream.try_clone().unwrap()),
+            writer: stream,
+            no_ack: false,
+        }
+    }
+
+    fn send(&mut self, data: &str) {
+        let checksum = data.bytes().fold(0u8, |sum, b| sum.wrapping_add(b));
+        write!(self.writer, "${data}#{checksum:02x}").unwrap();
+        if !self.no_ack {
+            assert_eq!(self.read_byte(), b'+', "{data} wasn't acknowledged");
+        }
+    }
+
+    fn read_byte(&mut self) -> u8 {
+        let mut byte = [0u8];
+        self.reader.read_exact(&mut byte).unwrap();
+        byte[0]
+    }
+
+    /// Read a reply, and acknowledge it.
+    fn reply(&mut self) -> String {
+        assert_eq!(self.read_byte(), b'$');
+        let mut data = vec![];
+        self.reader.read_until(b'#', &mut data).unwrap();
+        data.pop();
+        let mut checksum = [0u8; 2];
+        self.reader.read_exact(&mut checksum).unwrap();
+        if !self.no_ack {
+            self.writer.write_all(b"+").unwrap();
+        }
+        String::from_utf8(data).unwrap()
+    }
+
+    fn request(&mut self, data: &str) -> String {
+        self.send(data);
+        self.reply()
+    }
+}
+
+/// Call `name` with a stub attached, while `script` plays the client.
+fn debug(
+    name: &str,
+    args: &[Value],
+    script: impl FnOnce(Client) + Send + 'static,
+) -> Result<(Runtime, error::Result<Vec<Value>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    let client = thread::spawn(move || script(Client::connect(port)));
+
+    let mut runtime = Runtime::new();
+    let module = runtime.load_file("tests/gdb/data/gdb.wat")?;
+    let stub = GdbStub::accept(&listener)?;
+    runtime.attach_debugger(stub.clone());
+    runtime.break_next();
+    let result = runtime.call(&module, name, args);
+    stub.finished(&result);
+
+    client.join().map_err(|_| "the client failed")?;
+    Ok((runtime, result))
+}
+
+#[test]
+fn registers_and_breakpoints() -> Result<()> {
+    let (_, result) = debug("twice", &[5u32.into()], |mut client| {
+        assert!(client
+            .request("qSupported:multiprocess+")
+            .contains("PacketSize"));
+        assert_eq!(client.request("?"), "T05thread:1;");
+        // The pc is at the start of func[1], and its parameter is 5. The
+        // registers of the locals that it doesn't have are unavailable.
+        let registers = client.request("g");
+        assert_eq!(registers.len(), 16 + 32 * 32);
+        assert_eq!(&registers[..16], "0000000001000000");
+        assert_eq!(&registers[16..48], format!("05{}", "0".repeat(30)));
+        assert_eq!(&registers[48..], "x".repeat(31 * 32));
+
+        // Break at the start of $add, which is func[0].
+        assert_eq!(client.request("Z0,0,1"), "OK");
+        assert_eq!(client.request("c"), "T05thread:1;");
+        assert_eq!(client.request("p0"), "0000000000000000");
+        assert_eq!(client.request("p1"), format!("05{}", "0".repeat(30)));
+        assert_eq!(client.request("p3"), "0".repeat(32));
+        assert_eq!(client.request("p4"), "x".repeat(32));
+        assert_eq!(client.request("p21"), "E01");
+        assert_eq!(client.request("z0,0,1"), "OK");
+
+        // `local.get` takes 5 bytes: the opcode and the local index.
+        assert_eq!(client.request("vCont;s:1"), "T05thread:1;");
+        assert_eq!(client.request("p0"), "0500000000000000");
+        assert_eq!(client.request("c"), "W00");
+    })?;
+    assert_eq!(result?, [10u32.into()]);
+    Ok(())
+}
+
+#[test]
+fn target_description() -> Result<()> {
+    let (_, result) = debug("twice", &[5u32.into()], |mut client| {
+        assert!(client
+            .request("qSupported:xmlRegisters=i386")
+            .contains("qXfer:features:read+"));
+
+        // Read the description in small parts, as a client would.
+        let mut xml = String::new();
+        loop {
+            let reply = client.request(&format!(
+                "qXfer:features:read:target.xml:{:x},40",
+                xml.len()
+            ));
+            let (marker, part) = reply.split_at(1);
+            xml.push_str(part);
+            if marker == "l" {
+                break;
+            }
+            assert_eq!(marker, "m");
+        }
+        assert!(xml.contains(r#"<reg name="pc" bitsize="64" type="code_ptr" regnum="0"/>"#));
+        assert!(xml.contains(r#"<reg name="l31" bitsize="128" type="uint128"/>"#));
+        assert!(!xml.contains("l32"));
+        assert_eq!(client.request("qXfer:features:read:other.xml:0,40"), "E01");
+
+        assert!(client
+            .request("qRegisterInfo0")
+            .starts_with("name:pc;bitsize:64;offset:0;"));
+        assert!(client
+            .request("qRegisterInfo20")
+            .starts_with("name:l31;bitsize:128;offset:504;"));
+        assert_eq!(client.request("qRegisterInfo21"), "E45");
+        assert_eq!(client.request("c"), "W00");
+    })?;
+    assert_eq!(result?, [10u32.into()]);
+    Ok(())
+}
+
+#[test]
+fn memory_and_watchpoints() -> Result<()> {
+    let (_, result) = debug("store", &[8u32.into(), 7u32.into()], |mut client| {
+        assert_eq!(client.request("m8,8"), "0000000000000000");
+        // Out of bounds.
+        assert_eq!(client.request("mffff,10"), "E01");
+
+        // Ranges past the end of memory, or too long, are refused.
+        assert_eq!(client.request("Z2,fffe,4"), "E01");
+        assert_eq!(client.request("Z2,0,1000"), "E01");
+
+        // Watch the bytes written by the second store.
+        assert_eq!(client.request("Z2,c,4"), "OK");
+        assert_eq!(client.request("c"), "T05watch:c;thread:1;");
+        assert_eq!(client.request("m8,8"), "0700000007000000");
+
+        // Stop the program.
+        client.send("k");
+    })?;
+    assert!(matches!(result.unwrap_err().kind, RuntimeErrorKind::Killed));
+    Ok(())
+}
+
+#[test]
+fn disconnect() -> Result<()> {
+    let (runtime, result) = debug("twice", &[5u32.into()], |mut client| {
+        // A packet with a bad checksum is rejected.
+        client.writer.write_all(b"$g#00").unwrap();
+        assert_eq!(client.read_byte(), b'-');
+
+        assert_eq!(client.request("QStartNoAckMode"), "OK");
+        client.no_ack = true;
+        assert_eq!(client.request("Z0,0,1"), "OK");
+    })?;
+    // Without a client, the program runs to completion, without the
+    // breakpoints that the client set.
+    assert_eq!(result?, [10u32.into()]);
+    assert!(runtime.breakpoints().is_empty());
+    Ok(())
+}
+
+#[test]
+fn oversized_packet() -> Result<()> {
+    let (runtime, result) = debug("twice", &[5u32.into()], |mut client| {
+        assert_eq!(client.request("Z0,0,1"), "OK");
+        // A packet that doesn't end within the packet size that the stub
+        // advertised disconnects the client, instead of being buffered.
+        let _ = write!(client.writer, "${}", "0".repeat(0x2000));
+        let mut byte = [0u8];
+        assert!(!matches!(client.reader.read(&mut byte), Ok(1)));
+    })?;
+    assert_eq!(result?, [10u32.into()]);
+    assert!(runtime.breakpoints().is_empty());
+    Ok(())
+}
@@ -0,0 +1,22 @@
+(module
+  (memory (export "mem") 1 2)
+  (data (i32.const 16) "hello")
+
+  (global $counter (export "counter") (mut i32) (i32.const 0))
+  (global (export "limit") i32 (i32.const 10))
+
+  (table $funcs (export "funcs") 2 3 funcref)
+
+  (func (export "load") (param $addr i32) (result i32)
+    (i32.load (local.get $addr)))
+
+  (func (export "store") (param $addr i32) (param $val i64)
+    (i64.store (local.get $addr) (local.get $val)))
+
+  (func (export "count") (result i32)
+    (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+    (global.get $counter))
+
+  (func (export "table_size") (result i32)
+    (table.size $funcs))
+)
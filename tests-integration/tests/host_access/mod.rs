@@ -0,0 +1,117 @@
+use {
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::{
+        runtime::{
+            error::{RuntimeErrorKind, TrapKind},
+            values::{Ref, Value},
+            Runtime,
+        },
+        syntax::types::RefType,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn memory() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/host_access/data/host_access.wat")?;
+
+    let mut mem = runtime.memory(&mod_inst, "mem")?;
+    assert_eq!(mem.size(), 1);
+    assert_eq!(mem.read_str(16, 5)?, "hello");
+    mem.write(0, 0x12345678u32)?;
+    mem.write(4, -2.5f64)?;
+    mem.write_str(32, "world")?;
+    assert_eq!(mem.read_bytes(0, 2)?, [0x78, 0x56]);
+    assert_eq!(mem.read::<f64>(4)?, -2.5);
+
+    assert_eq!(runtime.call(&mod_inst, "load", &[0u32.into()])?, [
+        0x12345678u32.into()
+    ]);
+    runtime.call(&mod_inst, "store", &[
+        100u32.into(),
+        0x0102030405060708u64.into(),
+    ])?;
+
+    let mut mem = runtime.memory(&mod_inst, "mem")?;
+    assert_eq!(mem.read::<u64>(100)?, 0x0102030405060708);
+    assert_eq!(mem.read::<u16>(100)?, 0x0708);
+    assert_eq!(mem.read_str(32, 5)?, "world");
+    mem.data_mut()[200] = 0xff;
+    assert_eq!(mem.read::<i8>(200)?, -1);
+
+    let err = mem.read::<u32>(65534).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::Trap(TrapKind::OutOfBoundsMemoryAccess(..))
+    ));
+    mem.write_bytes(30, &[0xc0, 0x00])?;
+    let err = mem.read_str(30, 2).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::InvalidUtf8(_)));
+
+    // The memory has a maximum of two pages.
+    assert_eq!(mem.grow(1)?, Some(1));
+    assert_eq!(mem.grow(1)?, None);
+    assert_eq!(mem.size(), 2);
+    assert_eq!(mem.data().len(), 2 * 65536);
+    mem.write(65534, 7u32)?;
+    assert_eq!(runtime.call(&mod_inst, "load", &[65534u32.into()])?, [
+        7u32.into()
+    ]);
+
+    let err = runtime.memory(&mod_inst, "counter").err().unwrap();
+    assert!(matches!(err.kind, RuntimeErrorKind::MethodNotFound(_)));
+    Ok(())
+}
+
+#[test]
+fn globals() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/host_access/data/host_access.wat")?;
+
+    runtime.set_global(&mod_inst, "counter", 41u32.into())?;
+    assert_eq!(runtime.call(&mod_inst, "count", &[])?, [42u32.into()]);
+    assert_eq!(runtime.get_global(&mod_inst, "counter")?, 42u32.into());
+
+    let err = runtime
+        .set_global(&mod_inst, "limit", 11u32.into())
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::ImmutableGlobal(_)));
+    assert_eq!(runtime.get_global(&mod_inst, "limit")?, 10u32.into());
+
+    let err = runtime
+        .set_global(&mod_inst, "counter", Value::from(1u64))
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::ValueTypeMismatch { .. }
+    ));
+    assert_eq!(runtime.get_global(&mod_inst, "counter")?, 42u32.into());
+    Ok(())
+}
+
+#[test]
+fn tables() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/host_access/data/host_access.wat")?;
+
+    assert_eq!(runtime.table_size(&mod_inst, "funcs")?, 2);
+    let null = Ref::Null(RefType::Func);
+    assert_eq!(runtime.table_grow(&mod_inst, "funcs", 1, null)?, Some(2));
+    assert_eq!(runtime.call(&mod_inst, "table_size", &[])?, [3u32.into()]);
+
+    // The table has a maximum of three elements.
+    assert_eq!(runtime.table_grow(&mod_inst, "funcs", 1, null)?, None);
+    assert_eq!(runtime.table_size(&mod_inst, "funcs")?, 3);
+    assert_eq!(runtime.table_get(&mod_inst, "funcs", 2)?, null);
+
+    let err = runtime
+        .table_grow(&mod_inst, "funcs", 1, Ref::Null(RefType::Extern))
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::ValueTypeMismatch { .. }
+    ));
+    Ok(())
+}
@@ -0,0 +1,27 @@
+(module
+  (type $binop (func (param i32 i32) (result i32)))
+  (import "host" "sub" (func $sub (type $binop)))
+  (import "host" "sum_bytes" (func $sum_bytes (param i32 i32) (result i32)))
+  (memory 1)
+  (data (i32.const 16) "\01\02\03\04")
+  (table funcref (elem $sub))
+  (func (export "call") (param i32 i32) (result i32)
+        local.get 0
+        local.get 1
+        call $sub
+    )
+  (func (export "call_indirect") (param i32 i32) (result i32)
+        local.get 0
+        local.get 1
+        i32.const 0
+        call_indirect (type $binop)
+    )
+  (func (export "sum") (result i32)
+        i32.const 16
+        i32.const 4
+        call $sum_bytes
+    )
+  (func (export "ref") (result funcref)
+        ref.func $sub
+    )
+  )
@@ -1,6 +1,12 @@
 use {
     wrausmt_format::file_loader::FileLoader,
-    wrausmt_runtime::runtime::{values::Value, Runtime},
+    wrausmt_runtime::{
+        runtime::{
+            values::{Ref, Value},
+            Runtime,
+        },
+        syntax::types::{FunctionType, NumType},
+    },
 };
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
@@ -18,3 +24,39 @@ fn importer() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn host_importer() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let i32_binop = FunctionType {
+        params: Box::new([NumType::I32.into(), NumType::I32.into()]),
+        result: Box::new([NumType::I32.into()]),
+    };
+    let sub = runtime.register_host_func("host", "sub", i32_binop.clone(), |_, args| {
+        let l: u32 = args[0].try_into()?;
+        let r: u32 = args[1].try_into()?;
+        Ok(vec![(l - r).into()])
+    });
+    runtime.register_host_func("host", "sum_bytes", i32_binop, |caller, args| {
+        let b: usize = args[0].try_into()?;
+        let n: usize = args[1].try_into()?;
+        let bytes = caller.memory()?.read(0, b, n)?;
+        Ok(vec![bytes.iter().map(|b| *b as u32).sum::<u32>().into()])
+    });
+
+    let importer = runtime.load_file("tests/importing/data/hostimporter.wat")?;
+
+    let res = runtime.call(&importer, "call", &[100u32.into(), 25u32.into()])?;
+    assert_eq!(res, vec![75u32.into()]);
+
+    let res = runtime.call(&importer, "call_indirect", &[100u32.into(), 30u32.into()])?;
+    assert_eq!(res, vec![70u32.into()]);
+
+    let res = runtime.call(&importer, "sum", &[])?;
+    assert_eq!(res, vec![10u32.into()]);
+
+    let res = runtime.call(&importer, "ref", &[])?;
+    assert_eq!(res, vec![Value::Ref(Ref::Func(sub))]);
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ mod importing;
 mod leb128 {
     use {
         std::io::ErrorKind,
-        wrausmt_format::binary::leb128::{LEB128Error, ReadLeb128},
+        wrausmt_format::binary::leb128::{LEB128Error, ReadLeb128, WriteLeb128},
     };
 
     macro_rules! assert_err {
@@ -150,4 +150,39 @@ mod leb128 {
         let res = data.as_slice().read_i64_leb_128().unwrap();
         assert_eq!(res, -128);
     }
+
+    #[test]
+    fn test_leb128_write() -> Result<()> {
+        for v in [0, 8, 64, 127, 128, 0x7FFFFFFF, 0xFFFFFFF8, 0xFFFFFFFF] {
+            let mut data = vec![];
+            data.write_u32_leb_128(v)?;
+            assert_eq!(data.as_slice().read_u32_leb_128()?, v);
+        }
+
+        for v in [0, 1, -1, 63, 64, -64, -65, -128, i32::MAX, i32::MIN] {
+            let mut data = vec![];
+            data.write_i32_leb_128(v)?;
+            assert_eq!(data.as_slice().read_i32_leb_128()?, v);
+        }
+
+        for v in [0, 0x7FFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF] {
+            let mut data = vec![];
+            data.write_u64_leb_128(v)?;
+            assert_eq!(data.as_slice().read_u64_leb_128()?, v);
+        }
+
+        for v in [0, -1, -128, i64::MAX, i64::MIN] {
+            let mut data = vec![];
+            data.write_i64_leb_128(v)?;
+            assert_eq!(data.as_slice().read_i64_leb_128()?, v);
+        }
+
+        let mut data = vec![];
+        data.write_u32_leb_128(128)?;
+        data.write_i32_leb_128(-128)?;
+        data.write_i32_leb_128(64)?;
+        assert_eq!(data, vec![0x80, 0x01, 0x80, 0x7f, 0xC0, 0x00]);
+
+        Ok(())
+    }
 }
@@ -1,8 +1,26 @@
+mod backtrace;
 mod blockops;
+mod compiled;
 mod cprogs;
+mod debugger;
+mod exceptions;
+mod externref;
+mod fuel;
+mod gdb;
+mod host_access;
 mod importing;
+mod limits;
+mod linking;
 mod mem;
+mod memory64;
+mod multimemory;
 mod multiresult;
+mod names;
+mod recursion;
+mod roundtrip;
+mod simd;
 mod spec;
 mod table;
+mod threads;
 mod validation;
+mod wasi;
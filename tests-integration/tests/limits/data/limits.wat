@@ -0,0 +1,10 @@
+(module
+  (memory 1)
+  (table 4 funcref)
+
+  (func (export "grow_mem") (param $pages i32) (result i32)
+    (memory.grow (local.get $pages)))
+
+  (func (export "grow_table") (param $elems i32) (result i32)
+    (table.grow (ref.null func) (local.get $elems)))
+)
@@ -0,0 +1,63 @@
+use {
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{config::RuntimeConfig, error::RuntimeErrorKind, Runtime},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const PAGE: usize = 65536;
+
+#[test]
+fn memory_limit() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_memory_bytes: Some(3 * PAGE),
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/limits/data/limits.wat")?;
+
+    let res = runtime.call(&mod_inst, "grow_mem", &[2u32.into()])?;
+    assert_eq!(res, [1u32.into()]);
+
+    let err = runtime
+        .call(&mod_inst, "grow_mem", &[1u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::MemoryLimitExceeded));
+
+    // The limit covers every memory in the store, so a second instance of the
+    // module no longer fits.
+    let err = runtime
+        .load_file("tests/limits/data/limits.wat")
+        .unwrap_err();
+    assert!(err.to_string().contains("MemoryLimitExceeded"));
+    Ok(())
+}
+
+#[test]
+fn table_limit() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_table_elements: Some(10),
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/limits/data/limits.wat")?;
+
+    let res = runtime.call(&mod_inst, "grow_table", &[6u32.into()])?;
+    assert_eq!(res, [4u32.into()]);
+
+    let err = runtime
+        .call(&mod_inst, "grow_table", &[1u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::TableLimitExceeded));
+    Ok(())
+}
+
+#[test]
+fn no_limit_by_default() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/limits/data/limits.wat")?;
+
+    let res = runtime.call(&mod_inst, "grow_mem", &[16u32.into()])?;
+    assert_eq!(res, [1u32.into()]);
+    let res = runtime.call(&mod_inst, "grow_table", &[1000u32.into()])?;
+    assert_eq!(res, [4u32.into()]);
+    Ok(())
+}
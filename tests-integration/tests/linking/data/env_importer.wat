@@ -0,0 +1,19 @@
+(module
+  (import "env" "mem" (memory 1))
+  (import "env" "table" (table $table 2 funcref))
+  (import "env" "base" (global $base i32))
+  (import "env" "counter" (global $counter (mut i32)))
+  (import "env" "double" (func $double (param i32) (result i32)))
+
+  (export "counter" (global $counter))
+  (export "table" (table $table))
+
+  (func $inc (export "inc") (param i32) (result i32)
+    (i32.add (local.get 0) (i32.const 1)))
+  (elem (i32.const 1) $inc)
+
+  (func (export "run") (param i32) (result i32)
+    (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+    (i32.store (i32.const 0) (call $double (local.get 0)))
+    (i32.add (global.get $base) (i32.load (i32.const 0))))
+)
@@ -0,0 +1,198 @@
+use {
+    wrausmt_format::file_loader::{compile_file, FileLoader},
+    wrausmt_runtime::{
+        runtime::{
+            error::RuntimeErrorKind, instance::ExternalVal, linker::Linker, values::Ref, Runtime,
+        },
+        syntax::types::{FunctionType, IndexType, Limits, MemType, NumType, RefType, TableType},
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn instances_with_different_imports() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let importee = runtime.load_file("tests/importing/data/importee.wat")?;
+    let importer = compile_file("tests/importing/data/importer.wat")?;
+
+    let mut linker = Linker::new();
+    linker.define_instance("src", &importee)?;
+    let first = linker.instantiate(&mut runtime, &importer)?;
+
+    // Wire the second instance's import to a different function.
+    let mut linker = Linker::new();
+    linker.define_instance("importee", &importee)?;
+    linker.alias("importee", "f1", "src", "f2")?;
+    let second = linker.instantiate(&mut runtime, &importer)?;
+
+    assert_eq!(runtime.call(&first, "test", &[100u32.into()])?, [
+        125u32.into()
+    ]);
+    assert_eq!(runtime.call(&second, "test", &[100u32.into()])?, [
+        115u32.into()
+    ]);
+    Ok(())
+}
+
+#[test]
+fn host_definitions() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let i32_unop = FunctionType {
+        params: Box::new([NumType::I32.into()]),
+        result: Box::new([NumType::I32.into()]),
+    };
+    let double = runtime.alloc_host_func(i32_unop, |_, args| {
+        let v: u32 = args[0].try_into()?;
+        Ok(vec![(v * 2).into()])
+    });
+    let mem = runtime.alloc_memory(MemType::new(IndexType::I32, Limits::default(), false))?;
+    let table = runtime.alloc_table(TableType::new(
+        Limits {
+            lower: 2,
+            upper: None,
+        },
+        RefType::Func,
+    ))?;
+    let base = runtime.alloc_global(1000u32.into(), false);
+    let counter = runtime.alloc_global(0u32.into(), true);
+
+    let mut linker = Linker::new();
+    linker
+        .define(&runtime, "env", "double", ExternalVal::Func(double))?
+        .define(&runtime, "env", "mem", ExternalVal::Memory(mem))?
+        .define(&runtime, "env", "table", ExternalVal::Table(table))?
+        .define(&runtime, "env", "base", ExternalVal::Global(base))?
+        .define(&runtime, "env", "counter", ExternalVal::Global(counter))?;
+
+    // The host memory has no pages, so it doesn't match the import.
+    let err = linker
+        .instantiate(
+            &mut runtime,
+            &compile_file("tests/linking/data/env_importer.wat")?,
+        )
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::ImportMismatch(..)));
+
+    let mem = runtime.alloc_memory(MemType::new(
+        IndexType::I32,
+        Limits {
+            lower: 1,
+            upper: None,
+        },
+        false,
+    ))?;
+    linker.allow_shadowing(true);
+    linker.define(&runtime, "env", "mem", ExternalVal::Memory(mem))?;
+    let inst = linker.instantiate(
+        &mut runtime,
+        &compile_file("tests/linking/data/env_importer.wat")?,
+    )?;
+    linker.define_instance("importer", &inst)?;
+
+    assert_eq!(runtime.call(&inst, "run", &[21u32.into()])?, [
+        1042u32.into()
+    ]);
+    assert_eq!(
+        runtime.call(&inst, "run", &[1u32.into()])?,
+        [1002u32.into()]
+    );
+
+    // The imported items are shared with the host.
+    assert_eq!(runtime.get_global(&inst, "counter")?, 2u32.into());
+    runtime.set_global(&inst, "counter", 10u32.into())?;
+    runtime.call(&inst, "run", &[0u32.into()])?;
+    assert_eq!(runtime.get_global(&inst, "counter")?, 11u32.into());
+    let Some(ExternalVal::Func(inc)) = linker.get("importer", "inc") else {
+        panic!("inc is not a function");
+    };
+    assert_eq!(runtime.table_get(&inst, "table", 1)?, Ref::Func(inc));
+    assert_eq!(runtime.table_size(&inst, "table")?, 2);
+    Ok(())
+}
+
+#[test]
+fn unresolved_imports() -> Result<()> {
+    let mut runtime = Runtime::new();
+
+    // Modules registered with the runtime aren't visible to a linker.
+    let importee = runtime.load_file("tests/importing/data/importee.wat")?;
+    runtime.register("src", importee.clone());
+    let err = Linker::new()
+        .instantiate(
+            &mut runtime,
+            &compile_file("tests/importing/data/importer.wat")?,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::UnresolvedImports(ref imports)
+            if imports == &[("src".to_owned(), "f2".to_owned())]
+    ));
+
+    let mem = runtime.alloc_memory(MemType::new(
+        IndexType::I32,
+        Limits {
+            lower: 1,
+            upper: None,
+        },
+        false,
+    ))?;
+    let mut linker = Linker::new();
+    linker.define(&runtime, "env", "mem", ExternalVal::Memory(mem))?;
+    let err = linker
+        .instantiate(
+            &mut runtime,
+            &compile_file("tests/linking/data/env_importer.wat")?,
+        )
+        .unwrap_err();
+    let RuntimeErrorKind::UnresolvedImports(imports) = err.kind else {
+        panic!("unexpected error {err:?}");
+    };
+    let names: Vec<_> = imports.iter().map(|(m, n)| format!("{m}::{n}")).collect();
+    assert_eq!(names, [
+        "env::table",
+        "env::base",
+        "env::counter",
+        "env::double"
+    ]);
+
+    linker.define_instance("src", &importee)?;
+    let err = linker.alias("src", "f1", "src", "f2").unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::DuplicateDefinition(..)
+    ));
+
+    linker.alias_module("src", "other")?;
+    assert!(linker.get("other", "f2").is_some());
+    let err = linker.alias_module("nope", "env").unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::ModuleNotFound(_)));
+    Ok(())
+}
+
+#[test]
+fn definitions_from_another_runtime() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let importee = runtime.load_file("tests/importing/data/importee.wat")?;
+    let importer = compile_file("tests/importing/data/importer.wat")?;
+
+    // The other runtime has items at the same addresses, which would be
+    // linked in place of the importee's if the linker didn't check.
+    let mut other = Runtime::new();
+    other.load_file("tests/importing/data/importee.wat")?;
+
+    let mut linker = Linker::new();
+    linker.define_instance("src", &importee)?;
+    let err = linker.instantiate(&mut other, &importer).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::RuntimeMismatch));
+
+    let mem = other.alloc_memory(MemType::new(IndexType::I32, Limits::default(), false))?;
+    let err = linker
+        .define(&other, "env", "mem", ExternalVal::Memory(mem))
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::RuntimeMismatch));
+
+    linker.instantiate(&mut runtime, &importer)?;
+    Ok(())
+}
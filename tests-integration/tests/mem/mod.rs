@@ -1,7 +1,6 @@
 use {
-    std::convert::TryInto,
     wrausmt_format::file_loader::FileLoader,
-    wrausmt_runtime::{runner, runtime::Runtime},
+    wrausmt_runtime::runtime::{error::RuntimeErrorKind, Runtime},
 };
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
@@ -11,18 +10,17 @@ fn memfloat() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
+    let put32_f = runtime.typed_func::<(u32, f32), ()>(&mod_inst, "put32_f")?;
+    let get32_f = runtime.typed_func::<u32, f32>(&mod_inst, "get32_f")?;
+    let put64_f = runtime.typed_func::<(u32, f64), ()>(&mod_inst, "put64_f")?;
+    let get64_f = runtime.typed_func::<u32, f64>(&mod_inst, "get64_f")?;
 
-    exec_method!("put32_f", 0, 8745897.5f32)?;
-    let mut res1 = exec_method!("get32_f", 0)?;
-    let v1: f32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 8745897.5f32);
+    put32_f.call(runtime, (0, 8745897.5f32))?;
+    assert_eq!(get32_f.call(runtime, 0)?, 8745897.5f32);
 
     let c2: f64 = 897459874895.625;
-    exec_method!("put64_f", 0, c2)?;
-    let mut res1 = exec_method!("get64_f", 0)?;
-    let v1: f64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, c2);
+    put64_f.call(runtime, (0, c2))?;
+    assert_eq!(get64_f.call(runtime, 0)?, c2);
 
     Ok(())
 }
@@ -32,28 +30,22 @@ fn meminstr32_get() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
+    let put32 = runtime.typed_func::<(u32, u32), ()>(&mod_inst, "put32")?;
+    put32.call(runtime, (0, 0x8176F5F3u32))?;
 
-    exec_method!("put32", 0, 0x8176F5F3u32)?;
-    let mut res1 = exec_method!("get32", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0x8176F5F3u32);
+    let get = |runtime: &mut Runtime, name: &str| -> Result<u32> {
+        Ok(runtime
+            .typed_func::<u32, u32>(&mod_inst, name)?
+            .call(runtime, 0)?)
+    };
+    assert_eq!(get(runtime, "get32")?, 0x8176F5F3u32);
+    assert_eq!(get(runtime, "get32_8u")?, 0xF3u32);
+    assert_eq!(get(runtime, "get32_8s")?, ((0xF3 - 0x100) as u32));
+    assert_eq!(get(runtime, "get32_16u")?, 0xF5F3);
+    assert_eq!(get(runtime, "get32_16s")?, ((0xF5F3 - 0x10000) as u32));
 
-    let mut res1 = exec_method!("get32_8u", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0xF3u32);
-
-    let mut res1 = exec_method!("get32_8s", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, ((0xF3 - 0x100) as u32));
-
-    let mut res1 = exec_method!("get32_16u", 0)?;
-    let v1: i32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0xF5F3);
-
-    let mut res1 = exec_method!("get32_16s", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, ((0xF5F3 - 0x10000) as u32));
+    let get32_16s = runtime.typed_func::<i32, i32>(&mod_inst, "get32_16s")?;
+    assert_eq!(get32_16s.call(runtime, 0)?, 0xF5F3 - 0x10000);
 
     Ok(())
 }
@@ -63,17 +55,15 @@ fn meminstr32_put() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
+    let put32_8 = runtime.typed_func::<(u32, u32), ()>(&mod_inst, "put32_8")?;
+    let put32_16 = runtime.typed_func::<(u32, u32), ()>(&mod_inst, "put32_16")?;
+    let get32 = runtime.typed_func::<u32, u32>(&mod_inst, "get32")?;
 
-    exec_method!("put32_8", 0, 0x8176F5F3u32)?;
-    let mut res1 = exec_method!("get32", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0xF3u8).into());
+    put32_8.call(runtime, (0, 0x8176F5F3u32))?;
+    assert_eq!(get32.call(runtime, 0)?, (0xF3u8).into());
 
-    exec_method!("put32_16", 0, 0x8176F5F3u32)?;
-    let mut res1 = exec_method!("get32", 0)?;
-    let v1: u32 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0xF5F3u16).into());
+    put32_16.call(runtime, (0, 0x8176F5F3u32))?;
+    assert_eq!(get32.call(runtime, 0)?, (0xF5F3u16).into());
     Ok(())
 }
 
@@ -82,37 +72,24 @@ fn meminstr64_get() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
-
-    exec_method!("put64", 0, 0x873646368176F5F3u64)?;
-
-    let mut res1 = exec_method!("get64", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0x873646368176F5F3u64);
-
-    let mut res1 = exec_method!("get64_8u", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0xF3u64);
-
-    let mut res1 = exec_method!("get64_8s", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, ((0xF3 - 0x100) as u64));
-
-    let mut res1 = exec_method!("get64_16u", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0xF5F3u64);
-
-    let mut res1 = exec_method!("get64_16s", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, ((0xF5F3 - 0x10000) as u64));
-
-    let mut res1 = exec_method!("get64_32u", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, 0x8176F5F3u64);
-
-    let mut res1 = exec_method!("get64_32s", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, ((0x8176F5F3i64 - 0x100000000i64) as u64));
+    let put64 = runtime.typed_func::<(u32, u64), ()>(&mod_inst, "put64")?;
+    put64.call(runtime, (0, 0x873646368176F5F3u64))?;
+
+    let get = |runtime: &mut Runtime, name: &str| -> Result<u64> {
+        Ok(runtime
+            .typed_func::<u32, u64>(&mod_inst, name)?
+            .call(runtime, 0)?)
+    };
+    assert_eq!(get(runtime, "get64")?, 0x873646368176F5F3u64);
+    assert_eq!(get(runtime, "get64_8u")?, 0xF3u64);
+    assert_eq!(get(runtime, "get64_8s")?, ((0xF3 - 0x100) as u64));
+    assert_eq!(get(runtime, "get64_16u")?, 0xF5F3u64);
+    assert_eq!(get(runtime, "get64_16s")?, ((0xF5F3 - 0x10000) as u64));
+    assert_eq!(get(runtime, "get64_32u")?, 0x8176F5F3u64);
+    assert_eq!(
+        get(runtime, "get64_32s")?,
+        ((0x8176F5F3i64 - 0x100000000i64) as u64)
+    );
     Ok(())
 }
 
@@ -121,34 +98,27 @@ fn meminstr64_put() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
-
-    exec_method!("put64_8", 0, 0x873646368176F5F3u64)?;
-    let mut res1 = exec_method!("get64", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0xF3u64));
-
-    exec_method!("put64_16", 0, 0x873646368176F5F3u64)?;
-    let mut res1 = exec_method!("get64", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0xF5F3u64));
-
-    exec_method!("put64_32", 0, 0x873646368176F5F3u64)?;
-    let mut res1 = exec_method!("get64", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0x8176F5F3u64));
-
-    exec_method!("put64_8", 0, 0x78u64)?;
-    exec_method!("put64_8", 1, 0x56u64)?;
-    exec_method!("put64_8", 2, 0x34u64)?;
-    exec_method!("put64_8", 3, 0x12u64)?;
-    exec_method!("put64_8", 4, 0xabu64)?;
-    exec_method!("put64_8", 5, 0xcdu64)?;
-    exec_method!("put64_8", 6, 0xefu64)?;
-    exec_method!("put64_8", 7, 0x77u64)?;
-    let mut res1 = exec_method!("get64", 0)?;
-    let v1: u64 = res1.remove(0).try_into()?;
-    assert_eq!(v1, (0x77efcdab12345678u64));
+    let put64_8 = runtime.typed_func::<(u32, u64), ()>(&mod_inst, "put64_8")?;
+    let put64_16 = runtime.typed_func::<(u32, u64), ()>(&mod_inst, "put64_16")?;
+    let put64_32 = runtime.typed_func::<(u32, u64), ()>(&mod_inst, "put64_32")?;
+    let get64 = runtime.typed_func::<u32, u64>(&mod_inst, "get64")?;
+
+    put64_8.call(runtime, (0, 0x873646368176F5F3u64))?;
+    assert_eq!(get64.call(runtime, 0)?, 0xF3u64);
+
+    put64_16.call(runtime, (0, 0x873646368176F5F3u64))?;
+    assert_eq!(get64.call(runtime, 0)?, 0xF5F3u64);
+
+    put64_32.call(runtime, (0, 0x873646368176F5F3u64))?;
+    assert_eq!(get64.call(runtime, 0)?, 0x8176F5F3u64);
+
+    for (i, b) in [0x78u64, 0x56, 0x34, 0x12, 0xab, 0xcd, 0xef, 0x77]
+        .into_iter()
+        .enumerate()
+    {
+        put64_8.call(runtime, (i as u32, b))?;
+    }
+    assert_eq!(get64.call(runtime, 0)?, 0x77efcdab12345678u64);
     Ok(())
 }
 
@@ -157,31 +127,74 @@ fn memoffset() -> Result<()> {
     let runtime = &mut Runtime::new();
     let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
 
-    runner!(runtime, &mod_inst);
+    let put32_f = runtime.typed_func::<(u32, f32), ()>(&mod_inst, "put32_f")?;
+    let get32_f = runtime.typed_func::<u32, f32>(&mod_inst, "get32_f")?;
+    let put64_f = runtime.typed_func::<(u32, f64), ()>(&mod_inst, "put64_f")?;
+    let get64_f = runtime.typed_func::<u32, f64>(&mod_inst, "get64_f")?;
 
     for i in 0..10 {
-        let v = 2.3f32 * i as f32;
-        println!("WRITE {:?}", v);
-        exec_method!("put32_f", i * 4, v)?;
+        put32_f.call(runtime, (i * 4, 2.3f32 * i as f32))?;
     }
     for i in 0..10 {
-        let v = 2.3f32 * i as f32;
-        let res1 = exec_method!("get32_f", i * 4)?;
-        println!("READ BACK {:?}", res1);
-        let v1 = res1.first().unwrap();
-        assert_eq!(v1, &v.into());
+        assert_eq!(get32_f.call(runtime, i * 4)?, 2.3f32 * i as f32);
     }
 
     for i in 0..10 {
-        let v = 2.3f64 * i as f64;
-        exec_method!("put64_f", i * 8, v)?;
+        put64_f.call(runtime, (i * 8, 2.3f64 * i as f64))?;
     }
     for i in 0..10 {
-        let v = 2.3f64 * i as f64;
-        let res1 = exec_method!("get64_f", i * 8)?;
-        let v1 = res1.first().unwrap();
-        assert_eq!(v1, &v.into());
+        assert_eq!(get64_f.call(runtime, i * 8)?, 2.3f64 * i as f64);
     }
 
     Ok(())
 }
+
+#[test]
+fn typed_func_other_runtime() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
+    let get32 = runtime.typed_func::<u32, u32>(&mod_inst, "get32")?;
+
+    // The other runtime has its own store, even though its instance of the
+    // module has the same addresses.
+    let other = &mut Runtime::new();
+    other.load_file("tests/mem/data/meminstr.wasm")?;
+    let err = get32.call(other, 0).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::RuntimeMismatch));
+
+    assert_eq!(get32.call(runtime, 0)?, 0);
+    Ok(())
+}
+
+#[test]
+fn typed_func_mismatch() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/mem/data/meminstr.wasm")?;
+
+    let err = runtime
+        .typed_func::<u32, u32>(&mod_inst, "get64")
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::FunctionTypeMismatch { .. }
+    ));
+    let err = runtime
+        .typed_func::<(u32, u32), ()>(&mod_inst, "put64")
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::FunctionTypeMismatch { .. }
+    ));
+    let err = runtime.typed_func::<(), ()>(&mod_inst, "nope").unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::MethodNotFound(_)));
+
+    // Untyped calls check the argument types too.
+    let err = runtime
+        .call(&mod_inst, "put64", &[0u32.into(), 1u32.into()])
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::ValueTypeMismatch { .. }
+    ));
+    Ok(())
+}
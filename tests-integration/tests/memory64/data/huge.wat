@@ -0,0 +1,2 @@
+;; 2^40 pages, which the host can't allocate.
+(module (memory i64 0x100_0000_0000))
@@ -0,0 +1,108 @@
+(module
+  (memory i64 1 4)
+  (data (i64.const 0) "\01\02\03\04")
+
+  (func (export "load") (param i64) (result i32)
+    (i32.load8_u (local.get 0)))
+  (func (export "load_offset") (param i64) (result i32)
+    (i32.load8_u offset=2 (local.get 0)))
+  (func (export "load_far") (param i64) (result i32)
+    (i32.load8_u offset=4294967296 (local.get 0)))
+  (func (export "store") (param i64 i32)
+    (i32.store8 (local.get 0) (local.get 1)))
+  (func (export "size") (result i64)
+    (memory.size))
+  (func (export "grow") (param i64) (result i64)
+    (memory.grow (local.get 0)))
+  (func (export "fill") (param i64 i32 i64)
+    (memory.fill (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "copy") (param i64 i64 i64)
+    (memory.copy (local.get 0) (local.get 1) (local.get 2)))
+)
+
+(assert_return (invoke "load" (i64.const 0)) (i32.const 1))
+(assert_return (invoke "load_offset" (i64.const 1)) (i32.const 4))
+(assert_trap (invoke "load" (i64.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "load" (i64.const 0x1_0000_0000)) "out of bounds memory access")
+(assert_trap (invoke "load" (i64.const -1)) "out of bounds memory access")
+(assert_trap (invoke "load_offset" (i64.const -1)) "out of bounds memory access")
+(assert_trap (invoke "load_far" (i64.const 0)) "out of bounds memory access")
+
+(invoke "store" (i64.const 100) (i32.const 0x55))
+(assert_return (invoke "load" (i64.const 100)) (i32.const 0x55))
+
+(assert_return (invoke "size") (i64.const 1))
+(assert_return (invoke "grow" (i64.const 2)) (i64.const 1))
+(assert_return (invoke "size") (i64.const 3))
+(assert_return (invoke "grow" (i64.const 2)) (i64.const -1))
+(assert_return (invoke "grow" (i64.const 0x1_0000_0000)) (i64.const -1))
+(assert_return (invoke "load" (i64.const 196607)) (i32.const 0))
+
+(invoke "fill" (i64.const 200) (i32.const 7) (i64.const 3))
+(assert_return (invoke "load" (i64.const 202)) (i32.const 7))
+(assert_return (invoke "load" (i64.const 203)) (i32.const 0))
+(assert_trap (invoke "fill" (i64.const -1) (i32.const 7) (i64.const 2)) "out of bounds memory access")
+
+(invoke "copy" (i64.const 300) (i64.const 0) (i64.const 4))
+(assert_return (invoke "load" (i64.const 303)) (i32.const 4))
+(assert_trap (invoke "copy" (i64.const 0) (i64.const -1) (i64.const 2)) "out of bounds memory access")
+
+;; A memory with 64-bit indices can be larger than 4GiB.
+(module (memory i64 0 0x1_0000_0000))
+
+(assert_invalid
+  (module (memory i64 0 0x1_0000_0000_0001))
+  "memory size must be at most 2^48 pages (256TiB)"
+)
+(assert_invalid
+  (module (memory i64 1) (func (drop (i32.load (i32.const 0)))))
+  "type mismatch"
+)
+(assert_invalid
+  (module (memory i64 1) (func (drop (memory.grow (i32.const 0)))))
+  "type mismatch"
+)
+(assert_invalid
+  (module (memory i64 1) (func (result i32) (memory.size)))
+  "type mismatch"
+)
+(assert_invalid
+  (module (memory i64 1) (data (i32.const 0) ""))
+  "type mismatch"
+)
+
+;; The count of a copy between memories of different index types is an i32.
+(module
+  (memory $a i64 1)
+  (memory $b 1)
+  (data (memory $b) (i32.const 0) "\2a")
+  (func (export "copy_b_to_a") (param i64 i32 i32)
+    (memory.copy $a $b (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "load_a") (param i64) (result i32)
+    (i32.load8_u $a (local.get 0)))
+)
+
+(invoke "copy_b_to_a" (i64.const 8) (i32.const 0) (i32.const 1))
+(assert_return (invoke "load_a" (i64.const 8)) (i32.const 0x2a))
+
+;; Imported memories must have the same index type.
+(module (memory (export "mem64") i64 1))
+(register "m64")
+(module (import "m64" "mem64" (memory i64 1)))
+(assert_unlinkable
+  (module (import "m64" "mem64" (memory 1)))
+  "incompatible import type"
+)
+
+;; Growth that the host can't allocate fails like growth beyond the maximum.
+(module
+  (memory i64 1)
+  (func (export "grow") (param i64) (result i64)
+    (memory.grow (local.get 0)))
+  (func (export "size") (result i64)
+    (memory.size))
+)
+
+(assert_return (invoke "grow" (i64.const 0x100_0000_0000)) (i64.const -1))
+(assert_return (invoke "size") (i64.const 1))
+(assert_return (invoke "grow" (i64.const 1)) (i64.const 1))
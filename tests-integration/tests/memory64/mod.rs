@@ -0,0 +1,39 @@
+use {
+    tests::spec::{
+        error::Result,
+        loader::parse_and_run,
+        runner::{RunConfig, RunSet},
+    },
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::Runtime,
+};
+
+#[test]
+fn memory64() -> Result<()> {
+    parse_and_run("tests/memory64/data/memory64.wast", RunConfig {
+        runset:             RunSet::All,
+        failures_to_ignore: &[],
+        binary_roundtrip:   false,
+    })
+}
+
+/// The index type and 64-bit limits survive the binary encoder and parser.
+#[test]
+fn memory64_binary_roundtrip() -> Result<()> {
+    parse_and_run("tests/memory64/data/memory64.wast", RunConfig {
+        runset:             RunSet::All,
+        failures_to_ignore: &[],
+        binary_roundtrip:   true,
+    })
+}
+
+/// A memory that's too large for the host to allocate fails instantiation,
+/// instead of aborting.
+#[test]
+fn memory64_allocation_failure() {
+    let mut runtime = Runtime::new();
+    let err = runtime
+        .load_file("tests/memory64/data/huge.wat")
+        .unwrap_err();
+    assert!(err.to_string().contains("OutOfMemory"), "{err}");
+}
@@ -0,0 +1,30 @@
+(module
+  (memory $a 1)
+  (memory $b 2)
+  (data (memory $a) (i32.const 0) "\01\02\03\04")
+  (data (memory $b) (i32.const 0) "\11\12\13\14")
+  (data $passive "\21\22\23\24")
+
+  (func (export "load_a") (param i32) (result i32)
+    (i32.load8_u $a (local.get 0)))
+  (func (export "load_b") (param i32) (result i32)
+    (i32.load8_u $b (local.get 0)))
+  (func (export "store_b") (param i32 i32)
+    (i32.store8 $b (local.get 0) (local.get 1)))
+
+  (func (export "size_a") (result i32) (memory.size $a))
+  (func (export "size_b") (result i32) (memory.size $b))
+  (func (export "grow_b") (param i32) (result i32)
+    (memory.grow $b (local.get 0)))
+
+  (func (export "copy_a_to_b") (param $dst i32) (param $src i32) (param $n i32)
+    (memory.copy $b $a (local.get $dst) (local.get $src) (local.get $n)))
+  (func (export "fill_b") (param $dst i32) (param $val i32) (param $n i32)
+    (memory.fill $b (local.get $dst) (local.get $val) (local.get $n)))
+  (func (export "init_b") (param $dst i32) (param $src i32) (param $n i32)
+    (memory.init $b $passive (local.get $dst) (local.get $src) (local.get $n)))
+
+  (func (export "load_lane_b") (param i32) (result i32)
+    (i8x16.extract_lane_u 3
+      (v128.load8_lane $b 3 (local.get 0) (v128.const i64x2 0 0))))
+)
@@ -0,0 +1,135 @@
+use {
+    wrausmt_format::{
+        binary::encode::encode_wasm_data, file_loader::FileLoader, loader::Loader,
+        text::parse_wast_data,
+    },
+    wrausmt_runtime::runtime::{
+        error::{RuntimeErrorKind, TrapKind},
+        Runtime,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const PAGE: u32 = 65536;
+
+#[test]
+fn memories_are_independent() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/multimemory/data/multimemory.wat")?;
+
+    assert_eq!(runtime.call(&mod_inst, "load_a", &[0u32.into()])?, [
+        1u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[0u32.into()])?, [
+        0x11u32.into()
+    ]);
+
+    runtime.call(&mod_inst, "store_b", &[1u32.into(), 0x99u32.into()])?;
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[1u32.into()])?, [
+        0x99u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_a", &[1u32.into()])?, [
+        2u32.into()
+    ]);
+
+    // Only the second memory has a second page.
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[PAGE.into()])?, [
+        0u32.into()
+    ]);
+    let err = runtime
+        .call(&mod_inst, "load_a", &[PAGE.into()])
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        RuntimeErrorKind::Trap(TrapKind::OutOfBoundsMemoryAccess(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn size_and_grow() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/multimemory/data/multimemory.wat")?;
+
+    assert_eq!(runtime.call(&mod_inst, "size_a", &[])?, [1u32.into()]);
+    assert_eq!(runtime.call(&mod_inst, "size_b", &[])?, [2u32.into()]);
+    assert_eq!(runtime.call(&mod_inst, "grow_b", &[1u32.into()])?, [
+        2u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "size_b", &[])?, [3u32.into()]);
+    assert_eq!(runtime.call(&mod_inst, "size_a", &[])?, [1u32.into()]);
+    Ok(())
+}
+
+#[test]
+fn bulk_memory() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/multimemory/data/multimemory.wat")?;
+
+    let args = [8u32.into(), 0u32.into(), 4u32.into()];
+    runtime.call(&mod_inst, "copy_a_to_b", &args)?;
+    for i in 0..4 {
+        let res = runtime.call(&mod_inst, "load_b", &[(8 + i).into()])?;
+        assert_eq!(res, [(1 + i).into()]);
+    }
+
+    runtime.call(&mod_inst, "fill_b", &[
+        0u32.into(),
+        0x55u32.into(),
+        2u32.into(),
+    ])?;
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[1u32.into()])?, [
+        0x55u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[2u32.into()])?, [
+        0x13u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_a", &[1u32.into()])?, [
+        2u32.into()
+    ]);
+
+    runtime.call(&mod_inst, "init_b", &[
+        16u32.into(),
+        1u32.into(),
+        3u32.into(),
+    ])?;
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[16u32.into()])?, [
+        0x22u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_a", &[16u32.into()])?, [
+        0u32.into()
+    ]);
+    Ok(())
+}
+
+#[test]
+fn lane_access() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/multimemory/data/multimemory.wat")?;
+
+    let res = runtime.call(&mod_inst, "load_lane_b", &[2u32.into()])?;
+    assert_eq!(res, [0x13u32.into()]);
+    Ok(())
+}
+
+/// Memory indices other than 0 are encoded with a flag in the alignment.
+#[test]
+fn binary_memargs() -> Result<()> {
+    let src = std::fs::read("tests/multimemory/data/multimemory.wat")?;
+    let module = parse_wast_data(&mut src.as_slice())?;
+    let mut data = vec![];
+    encode_wasm_data(&module, &mut data)?;
+
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_wasm_data(&mut data.as_slice())?;
+    assert_eq!(runtime.call(&mod_inst, "load_a", &[0u32.into()])?, [
+        1u32.into()
+    ]);
+    assert_eq!(runtime.call(&mod_inst, "load_b", &[0u32.into()])?, [
+        0x11u32.into()
+    ]);
+    let res = runtime.call(&mod_inst, "load_lane_b", &[2u32.into()])?;
+    assert_eq!(res, [0x13u32.into()]);
+    Ok(())
+}
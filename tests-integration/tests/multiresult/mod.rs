@@ -17,3 +17,14 @@ fn multiresult() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn multiresult_typed() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/multiresult/data/multiresult.wat")?;
+
+    let test = runtime.typed_func::<(), (u64, u32)>(&mod_inst, "test")?;
+    assert_eq!(test.call(&mut runtime, ())?, (0xFF42, 0x42));
+
+    Ok(())
+}
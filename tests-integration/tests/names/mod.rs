@@ -0,0 +1,174 @@
+use {
+    wrausmt_format::{
+        binary::{encode::encode_wasm_data, parse_wasm_data},
+        loader::{compile_wasm_data, compile_wast_data},
+        text::{
+            parse_wast_data,
+            print::{print_wast_data, InstructionStyle},
+        },
+    },
+    wrausmt_runtime::{
+        runtime::{
+            instance::{ExternalVal, ModuleInstance},
+            Runtime,
+        },
+        syntax::{
+            types::{FunctionType, NumType},
+            CustomField, Id,
+        },
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const SRC: &str = r#"(module
+  (import "env" "log" (func (param i32)))
+  (func (export "f") (param i32) (result i32) (local i32)
+    block
+      local.get 0
+      local.set 1
+    end
+    local.get 1)
+  (global (mut i32) (i32.const 0))
+  (memory 1))"#;
+
+fn name(s: &str) -> Vec<u8> {
+    [&[s.len() as u8], s.as_bytes()].concat()
+}
+
+fn name_map(names: &[(u8, &str)]) -> Vec<u8> {
+    let mut map = vec![names.len() as u8];
+    for (idx, s) in names {
+        map.push(*idx);
+        map.extend(name(s));
+    }
+    map
+}
+
+fn subsection(id: u8, content: Vec<u8>) -> Vec<u8> {
+    [vec![id, content.len() as u8], content].concat()
+}
+
+/// Encode `SRC` as a binary module with the provided name section.
+fn with_names(content: Vec<u8>) -> Result<Vec<u8>> {
+    let mut module = parse_wast_data(&mut SRC.as_bytes())?;
+    module.customs.push(CustomField {
+        name:    "name".to_owned(),
+        content: content.into_boxed_slice(),
+    });
+    let mut data = vec![];
+    encode_wasm_data(&module, &mut data)?;
+    Ok(data)
+}
+
+fn names() -> Vec<u8> {
+    [
+        subsection(0, name("m")),
+        subsection(1, name_map(&[(0, "log"), (1, "f")])),
+        subsection(
+            2,
+            [vec![1], vec![1], name_map(&[(0, "x"), (1, "tmp")])].concat(),
+        ),
+        subsection(3, [vec![1], vec![1], name_map(&[(0, "done")])].concat()),
+        subsection(4, name_map(&[(0, "unop"), (1, "unop")])),
+        subsection(6, name_map(&[(0, "mem")])),
+        subsection(7, name_map(&[(0, "not an id")])),
+    ]
+    .concat()
+}
+
+#[test]
+fn binary_names() -> Result<()> {
+    let module = parse_wasm_data(&mut with_names(names())?.as_slice())?;
+
+    let id = |s: &str| Some(Id::try_from(s).unwrap());
+    assert_eq!(module.id, id("$m"));
+    assert_eq!(module.imports[0].id, id("$log"));
+    assert_eq!(module.funcs[0].id, id("$f"));
+    assert_eq!(module.funcs[0].locals[0].id, id("$tmp"));
+    assert_eq!(module.types[0].id, id("$unop"));
+    // Names are unique in each index space, and must be valid identifiers.
+    assert_eq!(module.types[1].id, None);
+    assert_eq!(module.globals[0].id, None);
+
+    let mut text = vec![];
+    print_wast_data(&module, InstructionStyle::Flat, &mut text)?;
+    parse_wast_data(&mut text.as_slice())?;
+    assert_eq!(
+        String::from_utf8(text)?,
+        r#"(module $m
+  (type $unop (func (param i32) (result i32)))
+  (type (func (param i32)))
+  (import "env" "log" (func $log (type 1)))
+  (func $f (type $unop) (param $x i32) (result i32)
+    (local $tmp i32)
+    block $done
+      local.get $x
+      local.set $tmp
+    end
+    local.get $tmp)
+  (memory $mem 1)
+  (global (mut i32) i32.const 0)
+  (export "f" (func $f)))
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn malformed_names_ignored() -> Result<()> {
+    // The function name map claims two entries, but only has one.
+    let mut content = [
+        subsection(0, name("m")),
+        subsection(1, name_map(&[(0, "log")])),
+    ]
+    .concat();
+    content[6] = 2;
+    let module = parse_wasm_data(&mut with_names(content.clone())?.as_slice())?;
+    assert_eq!(module.id, None);
+    assert_eq!(module.imports[0].id, None);
+    assert_eq!(module.customs[0].content, content.into_boxed_slice());
+    Ok(())
+}
+
+#[test]
+fn function_instance_names() -> Result<()> {
+    let src = r#"(module
+      (func $add (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1)))
+      (func (export "anon")))"#;
+    let wast = compile_wast_data(&mut src.as_bytes())?;
+    let wasm = compile_wasm_data(&mut with_names(names())?.as_slice())?;
+
+    let mut runtime = Runtime::new();
+    let log = runtime.register_host_func(
+        "env",
+        "log",
+        FunctionType {
+            params: Box::new([NumType::I32.into()]),
+            result: Box::new([]),
+        },
+        |_, _| Ok(vec![]),
+    );
+    let wast_instance = runtime.load_compiled(&wast)?;
+    let wasm_instance = runtime.load_compiled(&wasm)?;
+
+    let func_name = |runtime: &Runtime, instance: &ModuleInstance, name: &str| match instance
+        .resolve(name)
+        .map(|e| e.addr)
+    {
+        Some(ExternalVal::Func(addr)) => runtime.func_name(addr),
+        _ => panic!("{name} isn't an exported function"),
+    };
+    assert_eq!(
+        func_name(&runtime, &wast_instance, "add")?,
+        Some(Id::try_from("$add")?)
+    );
+    assert_eq!(func_name(&runtime, &wast_instance, "anon")?, None);
+    assert_eq!(
+        func_name(&runtime, &wasm_instance, "f")?,
+        Some(Id::try_from("$f")?)
+    );
+    assert_eq!(runtime.func_name(log)?, None);
+    Ok(())
+}
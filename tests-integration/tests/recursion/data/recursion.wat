@@ -0,0 +1,46 @@
+(module
+  ;; Sum the integers from 0 to n, recursing once per integer.
+  (func $sum (export "sum") (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 0))
+      (else
+        (i32.add
+          (local.get $n)
+          (call $sum (i32.sub (local.get $n) (i32.const 1)))))))
+
+  ;; The same, but through call_indirect.
+  (type $unop (func (param i32) (result i32)))
+  (table funcref (elem $sum_indirect $sum_tail_indirect))
+  (func $sum_indirect (export "sum_indirect") (param $n i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (i32.const 0))
+      (else
+        (i32.add
+          (local.get $n)
+          (call_indirect (type $unop)
+            (i32.sub (local.get $n) (i32.const 1))
+            (i32.const 0))))))
+
+  (func $runaway (export "runaway")
+    (call $runaway))
+
+  ;; The same sum, accumulated through tail calls.
+  (func $sum_tail (export "sum_tail") (param $n i32) (param $acc i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (local.get $acc))
+      (else
+        (return_call $sum_tail
+          (i32.sub (local.get $n) (i32.const 1))
+          (i32.add (local.get $acc) (local.get $n))))))
+
+  (type $binop (func (param i32 i32) (result i32)))
+  (func $sum_tail_indirect (export "sum_tail_indirect")
+    (param $n i32) (param $acc i32) (result i32)
+    (if (result i32) (i32.eqz (local.get $n))
+      (then (local.get $acc))
+      (else
+        (return_call_indirect (type $binop)
+          (i32.sub (local.get $n) (i32.const 1))
+          (i32.add (local.get $acc) (local.get $n))
+          (i32.const 1)))))
+)
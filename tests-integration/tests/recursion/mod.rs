@@ -0,0 +1,89 @@
+use {
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{
+        config::RuntimeConfig, error::RuntimeErrorKind, values::Value, Runtime,
+    },
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+// Deep enough that nesting a native frame for every call would overflow the
+// stack of the test thread.
+const DEPTH: u32 = 8_000;
+
+#[test]
+fn deep_recursion() -> Result<()> {
+    let mut runtime = Runtime::new();
+    let mod_inst = runtime.load_file("tests/recursion/data/recursion.wat")?;
+
+    let expected: Value = (DEPTH * (DEPTH + 1) / 2).into();
+    let res = runtime.call(&mod_inst, "sum", &[DEPTH.into()])?;
+    assert_eq!(res, [expected]);
+
+    let res = runtime.call(&mod_inst, "sum_indirect", &[DEPTH.into()])?;
+    assert_eq!(res, [expected]);
+    Ok(())
+}
+
+#[test]
+fn call_depth_limit() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_call_depth: 1000,
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/recursion/data/recursion.wat")?;
+
+    let res = runtime.call(&mod_inst, "sum", &[900u32.into()])?;
+    assert_eq!(res, [(900u32 * 901 / 2).into()]);
+
+    let err = runtime
+        .call(&mod_inst, "sum", &[1000u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::CallStackExhaustion));
+
+    // The runtime is still usable after the stack was unwound.
+    let err = runtime.call(&mod_inst, "runaway", &[]).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::CallStackExhaustion));
+    let res = runtime.call(&mod_inst, "sum", &[10u32.into()])?;
+    assert_eq!(res, [55u32.into()]);
+    Ok(())
+}
+
+#[test]
+fn value_stack_limit() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_value_stack: 1000,
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/recursion/data/recursion.wat")?;
+
+    // Each active call of sum holds its parameter, and the operand it will
+    // add to the result of the recursive call.
+    let res = runtime.call(&mod_inst, "sum", &[400u32.into()])?;
+    assert_eq!(res, [(400u32 * 401 / 2).into()]);
+
+    let err = runtime
+        .call(&mod_inst, "sum", &[600u32.into()])
+        .unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::ValueStackExhaustion));
+    Ok(())
+}
+
+#[test]
+fn tail_calls_reuse_frame() -> Result<()> {
+    let mut runtime = Runtime::with_config(RuntimeConfig {
+        max_call_depth: 10,
+        max_value_stack: 100,
+        ..RuntimeConfig::default()
+    });
+    let mod_inst = runtime.load_file("tests/recursion/data/recursion.wat")?;
+
+    let n = 50_000u32;
+    let expected: Value = (n * (n + 1) / 2).into();
+    let res = runtime.call(&mod_inst, "sum_tail", &[n.into(), 0u32.into()])?;
+    assert_eq!(res, [expected]);
+
+    let res = runtime.call(&mod_inst, "sum_tail_indirect", &[n.into(), 0u32.into()])?;
+    assert_eq!(res, [expected]);
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use {
+    std::path::PathBuf,
+    tests::spec::{
+        format::{Assertion, Cmd, Module},
+        loader::{parse, parse_and_run},
+        runner::{RunConfig, RunSet},
+    },
+    wrausmt_format::{
+        binary::encode::encode_wasm_data,
+        text::{
+            parse_wast_data,
+            print::{print_wast_data, InstructionStyle},
+        },
+    },
+    wrausmt_runtime::syntax::{self, Resolved, UncompiledExpr, Unvalidated},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+type SyntaxModule = syntax::Module<Resolved, Unvalidated, UncompiledExpr<Resolved>>;
+
+fn spec_scripts() -> Result<Vec<PathBuf>> {
+    let mut scripts: Vec<PathBuf> = std::fs::read_dir("tests/spec/data")?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<std::io::Result<_>>()?;
+    scripts.sort();
+    Ok(scripts)
+}
+
+/// Run the whole spec corpus again, passing every module through the binary
+/// encoder and parser before it's loaded.
+#[test]
+fn spec_binary_roundtrip() -> Result<()> {
+    let failures: Vec<String> = spec_scripts()?
+        .into_iter()
+        .filter(|path| path.file_stem().is_some_and(|s| s != "env"))
+        .filter_map(|path| {
+            parse_and_run(&path, RunConfig {
+                runset:             RunSet::All,
+                failures_to_ignore: &[],
+                binary_roundtrip:   true,
+            })
+            .err()
+            .map(|e| format!("{}: {:?}", path.display(), e))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    Ok(())
+}
+
+#[test]
+fn encode_simple_module() -> Result<()> {
+    let src = r#"(module (func (export "f") (result i32) i32.const 42))"#;
+    let module = parse_wast_data(&mut src.as_bytes())?;
+
+    let mut data = vec![];
+    encode_wasm_data(&module, &mut data)?;
+
+    #[rustfmt::skip]
+    let expected = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00,
+        // types: [] -> [i32]
+        0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f,
+        // funcs: type 0
+        0x03, 0x02, 0x01, 0x00,
+        // exports: "f" func 0
+        0x07, 0x05, 0x01, 0x01, b'f', 0x00, 0x00,
+        // data count: 0
+        0x0c, 0x01, 0x00,
+        // code: no locals, i32.const 42, end
+        0x0a, 0x06, 0x01, 0x04, 0x00, 0x41, 0x2a, 0x0b,
+    ];
+    assert_eq!(data, expected);
+    Ok(())
+}
+
+/// Print the module, parse the result, and check that the reparsed module
+/// encodes to the same binary as the original.
+fn text_roundtrip(module: &SyntaxModule, style: InstructionStyle) -> Result<()> {
+    let mut text = vec![];
+    print_wast_data(module, style, &mut text)?;
+    let reparsed = parse_wast_data(&mut text.as_slice())
+        .map_err(|e| format!("{e:?}\n{}", String::from_utf8_lossy(&text)))?;
+
+    let mut expected = vec![];
+    encode_wasm_data(module, &mut expected)?;
+    let mut actual = vec![];
+    encode_wasm_data(&reparsed, &mut actual)?;
+    if actual != expected {
+        Err(format!(
+            "reparsed module differs\n{}",
+            String::from_utf8_lossy(&text)
+        ))?;
+    }
+    Ok(())
+}
+
+fn spec_text_roundtrip_failures() -> Result<Vec<String>> {
+    let mut failures: Vec<String> = vec![];
+    for path in spec_scripts()? {
+        let script = parse(&mut std::fs::File::open(&path)?)?;
+        let modules = script.cmds.iter().filter_map(|c| match &c.cmd {
+            Cmd::Module(Module::Module(m)) => Some((m, c.location)),
+            Cmd::Assertion(
+                Assertion::Invalid { module, .. }
+                | Assertion::Unlinkable { module, .. }
+                | Assertion::ModuleTrap { module, .. },
+            ) => match module {
+                Module::Module(m) => Some((m, c.location)),
+                _ => None,
+            },
+            _ => None,
+        });
+        for (module, location) in modules {
+            for style in [InstructionStyle::Flat, InstructionStyle::Folded] {
+                if let Err(e) = text_roundtrip(module, style) {
+                    failures.push(format!("{}:{location:?} {style:?}: {e}", path.display()));
+                }
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Print every text module in the spec corpus in both instruction styles, and
+/// parse it back again.
+#[test]
+fn spec_text_roundtrip() -> Result<()> {
+    // Parsing the flat form of the deeply nested blocks in loop.wast needs
+    // more stack than the default test thread has.
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(|| {
+            let failures = spec_text_roundtrip_failures().unwrap();
+            assert!(failures.is_empty(), "{}", failures.join("\n"));
+        })?
+        .join()
+        .unwrap_or_else(|e| std::panic::resume_unwind(e));
+    Ok(())
+}
+
+#[test]
+fn print_simple_module() -> Result<()> {
+    let src = r#"(module
+        (memory 1)
+        (func $f (export "f") (param $x i32) (result i32)
+          (block $b (result i32)
+            (i32.load offset=4 align=2 (local.get $x))))
+        (data (i32.const 0) "a\"b\00"))"#;
+    let module = parse_wast_data(&mut src.as_bytes())?;
+
+    let mut flat = vec![];
+    print_wast_data(&module, InstructionStyle::Flat, &mut flat)?;
+    assert_eq!(
+        String::from_utf8(flat)?,
+        r#"(module
+  (type (func (param $x i32) (result i32)))
+  (func $f (type 0) (param $x i32) (result i32)
+    block $b (result i32)
+      local.get $x
+      i32.load offset=4 align=2
+    end)
+  (memory 1)
+  (export "f" (func $f))
+  (data (memory 0) (offset i32.const 0) "a\"b\00"))
+"#
+    );
+
+    let mut folded = vec![];
+    print_wast_data(&module, InstructionStyle::Folded, &mut folded)?;
+    assert_eq!(
+        String::from_utf8(folded)?,
+        r#"(module
+  (type (func (param $x i32) (result i32)))
+  (func $f (type 0) (param $x i32) (result i32)
+    (block $b (result i32)
+      (local.get $x)
+      (i32.load offset=4 align=2)))
+  (memory 1)
+  (export "f" (func $f))
+  (data (memory 0) (offset (i32.const 0)) "a\"b\00"))
+"#
+    );
+    Ok(())
+}
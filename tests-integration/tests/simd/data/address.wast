@@ -0,0 +1,34 @@
+;; Tests for the offsets of SIMD memory instructions.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "load-offset-0") (param i32) (result v128) (v128.load offset=0 (local.get 0)))
+  (func (export "load-offset-1") (param i32) (result v128) (v128.load offset=1 align=1 (local.get 0)))
+  (func (export "load-offset-65520") (param i32) (result v128) (v128.load offset=65520 align=1 (local.get 0)))
+  (func (export "load-offset-max") (param i32) (result v128) (v128.load offset=4294967295 (local.get 0)))
+  (func (export "store-offset-0") (param i32) (result v128) (v128.store offset=0 (local.get 0) (v128.const i32x4 1 2 3 4)) (v128.load (local.get 0)))
+  (func (export "store-offset-65520") (param i32) (result v128) (v128.store offset=65520 (local.get 0) (v128.const i32x4 5 6 7 8)) (v128.load offset=65520 (local.get 0)))
+  (func (export "store-offset-max") (param i32) (v128.store offset=4294967295 (local.get 0) (v128.const i32x4 0 0 0 0)))
+)
+
+(assert_return (invoke "load-offset-0" (i32.const 0)) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+(assert_return (invoke "load-offset-1" (i32.const 0)) (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "load-offset-0" (i32.const 1)) (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "load-offset-1" (i32.const 1)) (v128.const i8x16 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17))
+(assert_return (invoke "load-offset-0" (i32.const 17)) (v128.const i8x16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32))
+(assert_return (invoke "load-offset-1" (i32.const 17)) (v128.const i8x16 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33))
+(assert_return (invoke "load-offset-65520" (i32.const 0)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_trap (invoke "load-offset-65520" (i32.const 1)) "out of bounds memory access")
+(assert_trap (invoke "load-offset-max" (i32.const 0)) "out of bounds memory access")
+(assert_trap (invoke "load-offset-max" (i32.const 1)) "out of bounds memory access")
+(assert_return (invoke "store-offset-0" (i32.const 128)) (v128.const i32x4 1 2 3 4))
+(assert_return (invoke "store-offset-65520" (i32.const 0)) (v128.const i32x4 5 6 7 8))
+(assert_trap (invoke "store-offset-65520" (i32.const 1)) "out of bounds memory access")
+(assert_trap (invoke "store-offset-max" (i32.const 0)) "out of bounds memory access")
+
+;; Offsets are unsigned, and must fit in the index type of the memory.
+
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load offset=-1 (i32.const 0))))") "unknown operator")
+(assert_malformed (module quote "(memory 1) (func (v128.store offset=-1 (i32.const 0) (v128.const i32x4 0 0 0 0)))") "unknown operator")
+(assert_invalid (module (memory 1) (func (drop (v128.load offset=4294967296 (i32.const 0))))) "offset out of range")
@@ -0,0 +1,72 @@
+;; Tests for the alignment of SIMD memory instructions.
+
+(module (memory 1) (func (drop (v128.load align=1 (i32.const 0))) (drop (v128.load align=2 (i32.const 0))) (drop (v128.load align=4 (i32.const 0))) (drop (v128.load align=8 (i32.const 0))) (drop (v128.load align=16 (i32.const 0)))))
+(module (memory 1) (func (v128.store align=1 (i32.const 0) (v128.const i32x4 0 0 0 0)) (v128.store align=2 (i32.const 0) (v128.const i32x4 0 0 0 0)) (v128.store align=4 (i32.const 0) (v128.const i32x4 0 0 0 0)) (v128.store align=8 (i32.const 0) (v128.const i32x4 0 0 0 0)) (v128.store align=16 (i32.const 0) (v128.const i32x4 0 0 0 0))))
+(module (memory 1) (func (drop (v128.load8x8_s align=1 (i32.const 0))) (drop (v128.load8x8_s align=2 (i32.const 0))) (drop (v128.load8x8_s align=4 (i32.const 0))) (drop (v128.load8x8_s align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load8x8_u align=1 (i32.const 0))) (drop (v128.load8x8_u align=2 (i32.const 0))) (drop (v128.load8x8_u align=4 (i32.const 0))) (drop (v128.load8x8_u align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load16x4_s align=1 (i32.const 0))) (drop (v128.load16x4_s align=2 (i32.const 0))) (drop (v128.load16x4_s align=4 (i32.const 0))) (drop (v128.load16x4_s align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load16x4_u align=1 (i32.const 0))) (drop (v128.load16x4_u align=2 (i32.const 0))) (drop (v128.load16x4_u align=4 (i32.const 0))) (drop (v128.load16x4_u align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load32x2_s align=1 (i32.const 0))) (drop (v128.load32x2_s align=2 (i32.const 0))) (drop (v128.load32x2_s align=4 (i32.const 0))) (drop (v128.load32x2_s align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load32x2_u align=1 (i32.const 0))) (drop (v128.load32x2_u align=2 (i32.const 0))) (drop (v128.load32x2_u align=4 (i32.const 0))) (drop (v128.load32x2_u align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load8_splat align=1 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load16_splat align=1 (i32.const 0))) (drop (v128.load16_splat align=2 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load32_splat align=1 (i32.const 0))) (drop (v128.load32_splat align=2 (i32.const 0))) (drop (v128.load32_splat align=4 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load64_splat align=1 (i32.const 0))) (drop (v128.load64_splat align=2 (i32.const 0))) (drop (v128.load64_splat align=4 (i32.const 0))) (drop (v128.load64_splat align=8 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load32_zero align=1 (i32.const 0))) (drop (v128.load32_zero align=2 (i32.const 0))) (drop (v128.load32_zero align=4 (i32.const 0)))))
+(module (memory 1) (func (drop (v128.load64_zero align=1 (i32.const 0))) (drop (v128.load64_zero align=2 (i32.const 0))) (drop (v128.load64_zero align=4 (i32.const 0))) (drop (v128.load64_zero align=8 (i32.const 0)))))
+
+(assert_invalid (module (memory 1) (func (drop (v128.load align=32 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (v128.store align=32 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load8x8_s align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load8x8_u align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load16x4_s align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load16x4_u align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load32x2_s align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load32x2_u align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load8_splat align=2 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load16_splat align=4 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load32_splat align=8 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load64_splat align=16 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load32_zero align=8 (i32.const 0))))) "alignment must not be larger than natural")
+(assert_invalid (module (memory 1) (func (drop (v128.load64_zero align=16 (i32.const 0))))) "alignment must not be larger than natural")
+
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (v128.store align=3 (i32.const 0) (v128.const i32x4 0 0 0 0)))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (v128.store align=0 (i32.const 0) (v128.const i32x4 0 0 0 0)))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8x8_s align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8x8_s align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8x8_u align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8x8_u align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16x4_s align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16x4_s align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16x4_u align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16x4_u align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32x2_s align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32x2_s align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32x2_u align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32x2_u align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8_splat align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8_splat align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16_splat align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load16_splat align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32_splat align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32_splat align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load64_splat align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load64_splat align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32_zero align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load32_zero align=0 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load64_zero align=3 (i32.const 0))))") "alignment")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load64_zero align=0 (i32.const 0))))") "alignment")
+
+;; Alignment is only a hint, and doesn't trap.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "load-unaligned") (param i32) (result v128) (v128.load align=16 (local.get 0)))
+  (func (export "store-unaligned") (param i32 v128) (result v128) (v128.store align=16 (local.get 0) (local.get 1)) (v128.load align=1 (local.get 0)))
+)
+
+(assert_return (invoke "load-unaligned" (i32.const 1)) (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "store-unaligned" (i32.const 3) (v128.const i32x4 1 2 3 4)) (v128.const i32x4 1 2 3 4))
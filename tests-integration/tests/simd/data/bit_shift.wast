@@ -0,0 +1,364 @@
+;; Tests for the shl, shr_s and shr_u instructions, whose shift count is taken modulo the lane width.
+
+(module
+  (func (export "i8x16.shl") (param v128 i32) (result v128) (i8x16.shl (local.get 0) (local.get 1)))
+  (func (export "i8x16.shr_s") (param v128 i32) (result v128) (i8x16.shr_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.shr_u") (param v128 i32) (result v128) (i8x16.shr_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.shl") (param v128 i32) (result v128) (i16x8.shl (local.get 0) (local.get 1)))
+  (func (export "i16x8.shr_s") (param v128 i32) (result v128) (i16x8.shr_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.shr_u") (param v128 i32) (result v128) (i16x8.shr_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.shl") (param v128 i32) (result v128) (i32x4.shl (local.get 0) (local.get 1)))
+  (func (export "i32x4.shr_s") (param v128 i32) (result v128) (i32x4.shr_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.shr_u") (param v128 i32) (result v128) (i32x4.shr_u (local.get 0) (local.get 1)))
+  (func (export "i64x2.shl") (param v128 i32) (result v128) (i64x2.shl (local.get 0) (local.get 1)))
+  (func (export "i64x2.shr_s") (param v128 i32) (result v128) (i64x2.shr_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.shr_u") (param v128 i32) (result v128) (i64x2.shr_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 0)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 1)) (v128.const i8x16 0 2 -2 4 -4 -2 0 -4 2 -128 -128 -86 84 -56 56 6))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 7)) (v128.const i8x16 0 -128 -128 0 0 -128 0 0 -128 0 0 -128 0 0 0 -128))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 9)) (v128.const i8x16 0 2 -2 4 -4 -2 0 -4 2 -128 -128 -86 84 -56 56 6))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 15)) (v128.const i8x16 0 -128 -128 0 0 -128 0 0 -128 0 0 -128 0 0 0 -128))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 2147483647)) (v128.const i8x16 0 -128 -128 0 0 -128 0 0 -128 0 0 -128 0 0 0 -128))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -1)) (v128.const i8x16 0 -128 -128 0 0 -128 0 0 -128 0 0 -128 0 0 0 -128))
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 0)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 1)) (v128.const i8x16 0 0 -1 1 -1 63 -64 63 -64 32 -32 42 -43 50 -50 1))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 7)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 9)) (v128.const i8x16 0 0 -1 1 -1 63 -64 63 -64 32 -32 42 -43 50 -50 1))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 15)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 2147483647)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -1)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 0)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 1)) (v128.const i8x16 0 0 127 1 127 63 64 63 64 32 96 42 85 50 78 1))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 7)) (v128.const i8x16 0 0 1 0 1 0 1 0 1 0 1 0 1 0 1 0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 9)) (v128.const i8x16 0 0 127 1 127 63 64 63 64 32 96 42 85 50 78 1))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 15)) (v128.const i8x16 0 0 1 0 1 0 1 0 1 0 1 0 1 0 1 0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const 2147483647)) (v128.const i8x16 0 0 1 0 1 0 1 0 1 0 1 0 1 0 1 0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -1)) (v128.const i8x16 0 0 1 0 1 0 1 0 1 0 1 0 1 0 1 0))
+(assert_return (invoke "i8x16.shr_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3) (i32.const -8)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 0)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 1)) (v128.const i16x8 0 2 -2 4 -2 0 -4 2))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 15)) (v128.const i16x8 0 -32768 -32768 0 -32768 0 0 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 17)) (v128.const i16x8 0 2 -2 4 -2 0 -4 2))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 31)) (v128.const i16x8 0 -32768 -32768 0 -32768 0 0 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 2147483647)) (v128.const i16x8 0 -32768 -32768 0 -32768 0 0 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -1)) (v128.const i16x8 0 -32768 -32768 0 -32768 0 0 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 0)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 1)) (v128.const i16x8 -32768 -32768 -21846 21844 510 512 24690 -24690))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 15)) (v128.const i16x8 0 0 -32768 0 -32768 0 -32768 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 17)) (v128.const i16x8 -32768 -32768 -21846 21844 510 512 24690 -24690))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 31)) (v128.const i16x8 0 0 -32768 0 -32768 0 -32768 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 2147483647)) (v128.const i16x8 0 0 -32768 0 -32768 0 -32768 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -1)) (v128.const i16x8 0 0 -32768 0 -32768 0 -32768 -32768))
+(assert_return (invoke "i16x8.shl" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 0)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 1)) (v128.const i16x8 0 0 -1 1 16383 -16384 16383 -16384))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 15)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 17)) (v128.const i16x8 0 0 -1 1 16383 -16384 16383 -16384))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 31)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 2147483647)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -1)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 0)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 1)) (v128.const i16x8 8192 -8192 10922 -10923 127 128 6172 -6173))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 15)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 17)) (v128.const i16x8 8192 -8192 10922 -10923 127 128 6172 -6173))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 31)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 2147483647)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -1)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.shr_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 0)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 1)) (v128.const i16x8 0 0 32767 1 16383 16384 16383 16384))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 15)) (v128.const i16x8 0 0 1 0 0 1 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 17)) (v128.const i16x8 0 0 32767 1 16383 16384 16383 16384))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 31)) (v128.const i16x8 0 0 1 0 0 1 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const 2147483647)) (v128.const i16x8 0 0 1 0 0 1 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -1)) (v128.const i16x8 0 0 1 0 0 1 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (i32.const -16)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 0)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 1)) (v128.const i16x8 8192 24576 10922 21845 127 128 6172 26595))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 15)) (v128.const i16x8 0 1 0 1 0 0 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 17)) (v128.const i16x8 8192 24576 10922 21845 127 128 6172 26595))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 31)) (v128.const i16x8 0 1 0 1 0 0 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const 2147483647)) (v128.const i16x8 0 1 0 1 0 0 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -1)) (v128.const i16x8 0 1 0 1 0 0 0 1))
+(assert_return (invoke "i16x8.shr_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (i32.const -16)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 0)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 1)) (v128.const i32x4 0 2 -2 4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 31)) (v128.const i32x4 0 -2147483648 -2147483648 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 33)) (v128.const i32x4 0 2 -2 4))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 63)) (v128.const i32x4 0 -2147483648 -2147483648 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const 2147483647)) (v128.const i32x4 0 -2147483648 -2147483648 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const -1)) (v128.const i32x4 0 -2147483648 -2147483648 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 0 1 -1 2) (i32.const -32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 0)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 1)) (v128.const i32x4 -2 0 -4 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 31)) (v128.const i32x4 -2147483648 0 0 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 33)) (v128.const i32x4 -2 0 -4 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 63)) (v128.const i32x4 -2147483648 0 0 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 2147483647)) (v128.const i32x4 -2147483648 0 0 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -1)) (v128.const i32x4 -2147483648 0 0 0))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 0)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 1)) (v128.const i32x4 -2147483648 -1431655766 131070 246913578))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 31)) (v128.const i32x4 0 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 33)) (v128.const i32x4 -2147483648 -1431655766 131070 246913578))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 63)) (v128.const i32x4 0 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 2147483647)) (v128.const i32x4 0 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -1)) (v128.const i32x4 0 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.shl" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 0)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 1)) (v128.const i32x4 0 0 -1 1))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 31)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 33)) (v128.const i32x4 0 0 -1 1))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 63)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const 2147483647)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const -1)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 0 1 -1 2) (i32.const -32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 0)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 1)) (v128.const i32x4 1073741823 -1073741824 1073741823 536870912))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 31)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 33)) (v128.const i32x4 1073741823 -1073741824 1073741823 536870912))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 63)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 2147483647)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -1)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 0)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 1)) (v128.const i32x4 -536870912 715827882 32767 61728394))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 31)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 33)) (v128.const i32x4 -536870912 715827882 32767 61728394))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 63)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 2147483647)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -1)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.shr_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 0)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 1)) (v128.const i32x4 0 0 2147483647 1))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 31)) (v128.const i32x4 0 0 1 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 33)) (v128.const i32x4 0 0 2147483647 1))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 63)) (v128.const i32x4 0 0 1 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const 2147483647)) (v128.const i32x4 0 0 1 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const -1)) (v128.const i32x4 0 0 1 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 0 1 -1 2) (i32.const -32)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 0)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 1)) (v128.const i32x4 1073741823 1073741824 1073741823 536870912))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 31)) (v128.const i32x4 0 1 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 33)) (v128.const i32x4 1073741823 1073741824 1073741823 536870912))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 63)) (v128.const i32x4 0 1 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const 2147483647)) (v128.const i32x4 0 1 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -1)) (v128.const i32x4 0 1 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (i32.const -32)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 0)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 1)) (v128.const i32x4 1610612736 715827882 32767 61728394))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 31)) (v128.const i32x4 1 0 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 33)) (v128.const i32x4 1610612736 715827882 32767 61728394))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 63)) (v128.const i32x4 1 0 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const 2147483647)) (v128.const i32x4 1 0 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -1)) (v128.const i32x4 1 0 0 0))
+(assert_return (invoke "i32x4.shr_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (i32.const -32)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 0)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 1)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 63)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 65)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 127)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const 2147483647)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const -1)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 0 1) (i32.const -64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 0)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 1)) (v128.const i64x2 -2 4))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 63)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 65)) (v128.const i64x2 -2 4))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 127)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const 2147483647)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const -1)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 -1 2) (i32.const -64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 0)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 1)) (v128.const i64x2 -2 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 63)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 65)) (v128.const i64x2 -2 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 127)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 2147483647)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -1)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 0)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 1)) (v128.const i64x2 -9223372036854775808 -6148914691236517206))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 63)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 65)) (v128.const i64x2 -9223372036854775808 -6148914691236517206))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 127)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 2147483647)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -1)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 0)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 1)) (v128.const i64x2 8589934590 -9773436690))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 63)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 64)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 65)) (v128.const i64x2 8589934590 -9773436690))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 127)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const 2147483647)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const -1)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.shl" (v128.const i64x2 4294967295 -4886718345) (i32.const -64)) (v128.const i64x2 4294967295 -4886718345))
+
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 0)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 63)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 65)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 127)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const 2147483647)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const -1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 0 1) (i32.const -64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 0)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 1)) (v128.const i64x2 -1 1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 63)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 65)) (v128.const i64x2 -1 1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 127)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const 2147483647)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const -1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -1 2) (i32.const -64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 0)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 1)) (v128.const i64x2 4611686018427387903 -4611686018427387904))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 63)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 65)) (v128.const i64x2 4611686018427387903 -4611686018427387904))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 127)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 2147483647)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 0)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 1)) (v128.const i64x2 2305843009213693952 3074457345618258602))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 63)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 65)) (v128.const i64x2 2305843009213693952 3074457345618258602))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 127)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 2147483647)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 0)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 1)) (v128.const i64x2 2147483647 -2443359173))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 63)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 64)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 65)) (v128.const i64x2 2147483647 -2443359173))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 127)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const 2147483647)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const -1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 4294967295 -4886718345) (i32.const -64)) (v128.const i64x2 4294967295 -4886718345))
+
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 0)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 63)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 65)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 127)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const 2147483647)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const -1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 0 1) (i32.const -64)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 0)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 1)) (v128.const i64x2 9223372036854775807 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 63)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 65)) (v128.const i64x2 9223372036854775807 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 127)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const 2147483647)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const -1)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 -1 2) (i32.const -64)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 0)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 1)) (v128.const i64x2 4611686018427387903 4611686018427387904))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 63)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 65)) (v128.const i64x2 4611686018427387903 4611686018427387904))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 127)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const 2147483647)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 9223372036854775807 -9223372036854775808) (i32.const -64)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 0)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 1)) (v128.const i64x2 2305843009213693952 3074457345618258602))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 63)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 65)) (v128.const i64x2 2305843009213693952 3074457345618258602))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 127)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const 2147483647)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4611686018427387904 6148914691236517205) (i32.const -64)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 0)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 1)) (v128.const i64x2 2147483647 9223372034411416635))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 63)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 64)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 65)) (v128.const i64x2 2147483647 9223372034411416635))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 127)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const 2147483647)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const -1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.shr_u" (v128.const i64x2 4294967295 -4886718345) (i32.const -64)) (v128.const i64x2 4294967295 -4886718345))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.shl (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shl (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shl (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_s (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_s (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_u (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_u (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shr_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shl (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shl (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shl (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_s (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_s (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_u (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_u (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.shr_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shl (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shl (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shl (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_s (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_s (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_u (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_u (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.shr_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shl (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shl (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shl (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_s (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_s (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_u (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_u (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.shr_u (i32.const 0)))) "type mismatch")
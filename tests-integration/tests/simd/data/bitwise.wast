@@ -0,0 +1,228 @@
+;; Tests for the v128 bitwise instructions.
+
+(module
+  (func (export "v128.not") (param v128) (result v128) (v128.not (local.get 0)))
+  (func (export "v128.and") (param v128 v128) (result v128) (v128.and (local.get 0) (local.get 1)))
+  (func (export "v128.or") (param v128 v128) (result v128) (v128.or (local.get 0) (local.get 1)))
+  (func (export "v128.xor") (param v128 v128) (result v128) (v128.xor (local.get 0) (local.get 1)))
+  (func (export "v128.andnot") (param v128 v128) (result v128) (v128.andnot (local.get 0) (local.get 1)))
+  (func (export "v128.bitselect") (param v128 v128 v128) (result v128) (v128.bitselect (local.get 0) (local.get 1) (local.get 2)))
+)
+
+(assert_return (invoke "v128.not" (v128.const i32x4 0 0 0 0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.not" (v128.const i32x4 -1 -1 -1 -1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.not" (v128.const i32x4 252645135 252645135 252645135 252645135)) (v128.const i32x4 -252645136 -252645136 -252645136 -252645136))
+(assert_return (invoke "v128.not" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 16711935 16711935 16711935 16711935))
+(assert_return (invoke "v128.not" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1431655766 -1431655766 -1431655766 -1431655766))
+(assert_return (invoke "v128.not" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 257551646 646662109 -1016396001 -1742308512))
+(assert_return (invoke "v128.not" (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 2098042849 -1563263336 -575706686 -2075319012))
+(assert_return (invoke "v128.not" (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -123588255 63472542 -662326313 -1368456923))
+(assert_return (invoke "v128.not" (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1554865212 1977773539 -1080662660 1592982349))
+(assert_return (invoke "v128.not" (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1425117923 1821554872 1232102274 462768722))
+
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 123588254 -63472543 662326312 1368456922))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350))
+(assert_return (invoke "v128.and" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 251662080 251662080 251662080 251662080))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 84215045 84215045 84215045 84215045))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 393729 151259138 201590784 118031375))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 33687566 218956039 33555981 184683011))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 118296078 201788417 118098952 16780810))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 50463491 168624652 592387 17630210))
+(assert_return (invoke "v128.and" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 185470220 51185415 101649421 67765517))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1426085120 1426085120 1426085120 1426085120))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -268430848 -654264320 1006696448 1728087040))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -2113902592 1560314112 570463744 2063653376))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 117493248 -67077120 654330880 1359019520))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1560239360 -1979674112 1073781248 -1593834496))
+(assert_return (invoke "v128.and" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1426035456 -1828698368 -1241474048 -469715712))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 1426085120 1426085120 1426085120 1426085120))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 1342443585 1364463616 336875584 1162937365))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 5259284 1426391365 5248021 1360023617))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 89474068 1410683969 89146368 1360024656))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 22020417 1380372 1078005761 17105936))
+(assert_return (invoke "v128.and" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 17057044 289752389 335877205 1145050373))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -268430848 -654264320 1006696448 1728087040))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1342443585 1364463616 336875584 1162937365))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -2136866816 1495564322 537956384 1670414467))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 262784 -667666400 605046816 1099990170))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1610480959 -2011918336 39040 554239122))
+(assert_return (invoke "v128.and" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1610219520 -1855717374 881104992 1682474125))
+
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 123588254 -63472543 662326312 1368456922))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350))
+(assert_return (invoke "v128.or" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -15728881 -15728881 -15728881 -15728881))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1600085855 1600085855 1600085855 1600085855))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -5300241 -545276113 1067450351 1876922271))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -1879085281 1596952431 794795839 2143281135))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 257937311 -12615825 796872495 1604321247))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1352683569 -1893753057 1332715407 -1357967425))
+(assert_return (invoke "v128.or" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1357943009 -1620095153 -1081106561 -277889105))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -11141291 -11141291 -11141291 -11141291))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -5832735 -9109726 -7012384 -2490465))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -852194 -13762713 -11468995 -5046301))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -10616930 -13107359 -8716504 -7274534))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -11337789 -14811364 -9830525 -15859790))
+(assert_return (invoke "v128.or" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -15794404 -9568441 -7340163 -9764947))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -11141291 -11141291 -11141291 -11141291))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -168339467 -579469961 2111176181 2011026911))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -671646369 1568527735 2002114429 2146951159))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 1465769951 -42500747 2004835709 1440088031))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -145229865 -547498147 1434312663 -178432521))
+(assert_return (invoke "v128.or" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -10519203 -679651497 -136323715 -176163331))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -5832735 -9109726 -7012384 -2490465))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -168339467 -579469961 2111176181 2011026911))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -218727681 -578963097 1054146301 2147213055))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -134226177 -42468253 1073675496 2010775263))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -201935901 -612517314 2097019619 -404912961))
+(assert_return (invoke "v128.or" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -72450051 -612499609 -1096811267 -402934337))
+
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 123588254 -63472543 662326312 1368456922))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 16711935 16711935 16711935 16711935))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1431655766 -1431655766 -1431655766 -1431655766))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 257551646 646662109 -1016396001 -1742308512))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 2098042849 -1563263336 -575706686 -2075319012))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -123588255 63472542 -662326313 -1368456923))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1554865212 1977773539 -1080662660 1592982349))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1425117923 1821554872 1232102274 462768722))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -267390961 -267390961 -267390961 -267390961))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 1515870810 1515870810 1515870810 1515870810))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -5693970 -696535251 865859567 1758890896))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -1912772847 1377996392 761239858 1958598124))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 139641233 -214404242 678773543 1587540437))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -1403147060 -2062377709 1332123020 -1375597635))
+(assert_return (invoke "v128.xor" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -1543413229 -1671280568 -1182755982 -345654622))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1437226411 -1437226411 -1437226411 -1437226411))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 262598113 645154594 -1013708832 -1730577505))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 2113050398 -1574076825 -581932739 -2068699677))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -128110178 53969761 -663047384 -1366294054))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1548901571 1964862748 -1083611773 1577974706))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1410241052 1819129927 1234133885 459950765))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -1437226411 -1437226411 -1437226411 -1437226411))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 -1510783052 -1943933577 1774300597 848089546))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 -676905653 142136370 1996866408 786927542))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 1376295883 -1453184716 1915689341 80063375))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 -167250282 -548878519 356306902 -195538457))
+(assert_return (invoke "v128.xor" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 -27576247 -969403886 -472200920 -1321213704))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 262598113 645154594 -1013708832 -1730577505))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1510783052 -1943933577 1774300597 848089546))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 1918139135 -2074527419 516189917 476798588))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -134488961 625198147 468628680 910785093))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1408545058 1399401022 2096980579 -959152083))
+(assert_return (invoke "v128.xor" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1537769469 1243217765 -1977916259 -2085408462))
+
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 16711935 16711935 16711935 16711935))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1431655766 -1431655766 -1431655766 -1431655766))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 257551646 646662109 -1016396001 -1742308512))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 2098042849 -1563263336 -575706686 -2075319012))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -123588255 63472542 -662326313 -1368456923))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1554865212 1977773539 -1080662660 1592982349))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1425117923 1821554872 1232102274 462768722))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 983055 983055 983055 983055))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 168430090 168430090 168430090 168430090))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 252251406 101385997 51054351 134613760))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 218957569 33689096 219089154 67962124))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 134349057 50856718 134546183 235864325))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 202181644 84020483 252052748 235014925))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 252645135 252645135 252645135 252645135) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 67174915 201459720 150995714 184879618))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1442797056 -1442797056 -1442797056 -1442797056))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 251718912 637552384 -1023408384 -1744798976))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 2097190656 -1577026048 -587175680 -2080365312))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -134205184 50365184 -671042816 -1375731456))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1543527424 1962962176 -1090493184 1577122560))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -16711936 -16711936 -16711936 -16711936) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1409323520 1811986432 1224762112 453003776))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 5570645 5570645 5570645 5570645))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 89212180 67192149 1094780181 268718400))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 1426396481 5264400 1426407744 71632148))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 1342181697 20971796 1342509397 71631109))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1409635348 1430275393 353650004 1414549829))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1414598721 1141903376 1095778560 286605392))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 10879201 7602210 9699552 14221471))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765)) (v128.const i32x4 -1599995232 -2011125726 679520416 579371146))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -257551647 -646662110 1016396000 1742308511)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -2098042850 1563263335 575706685 2075319011)) (v128.const i32x4 1879315169 -2142226432 478439616 71894044))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 123588254 -63472543 662326312 1368456922)) (v128.const i32x4 -257814431 21004290 411349184 642318341))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1554865213 -1977773540 1080662659 -1592982350)) (v128.const i32x4 1352929312 1365256226 1016356960 1188069389))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 -257551647 -646662110 1016396000 1742308511) (v128.const i32x4 -1425117924 -1821554873 -1232102275 -462768723)) (v128.const i32x4 1352667873 1209055264 135291008 59834386))
+
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 0 0 0) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -16711936 -16711936 -16711936 -16711936)) (v128.const i32x4 16711935 16711935 16711935 16711935))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1911762831 1290612834 285492080 -963360434) (v128.const i32x4 482100589 126977120 1249561393 -1028059603) (v128.const i32x4 1597267956 527857785 -383674904 -712727931)) (v128.const i32x4 244076665 216380512 56249201 -960689108))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1215733874 -1527074253 1894761440 -389786520) (v128.const i32x4 711054691 -123214623 1715721672 -2061199978) (v128.const i32x4 -351730874 1529124305 -685295057 2020437864)) (v128.const i32x4 -1553344729 -1599868879 1885849568 -314256130))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 1393749689 2119313709 1376743239 -2031828107) (v128.const i32x4 1495002094 1775296419 -1381205950 1322458394) (v128.const i32x4 -675559561 1092034768 847657927 -376001788)) (v128.const i32x4 1527967417 1758658339 -1624347833 -2033871074))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1864287122 -694423619 11023445 606888203) (v128.const i32x4 -1877775165 641392207 284568698 324396265) (v128.const i32x4 -1825158123 1974520918 1142913911 1912969763)) (v128.const i32x4 -1876874042 1452989981 283653213 559178955))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1014485515 -2142303086 1622113812 1373141822) (v128.const i32x4 -1129336678 -842986497 -1857036135 1818090731) (v128.const i32x4 1464846032 169400255 -854038447 -706147954)) (v128.const i32x4 -341297958 -976679726 1347414168 2044517743))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 1729606553 -1124737395 605932775 1945602962) (v128.const i32x4 31950613 1859497235 1925672279 -2015955166) (v128.const i32x4 1176360604 -120872238 1360955124 2085052217)) (v128.const i32x4 1207407513 -1093300351 584969703 -203969774))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -827881479 1698752968 -1001362709 -927580743) (v128.const i32x4 -274816165 -1919906972 1771685634 960767325) (v128.const i32x4 -769037045 968640040 1814847497 713068149)) (v128.const i32x4 -273184935 -1526675124 1167189771 432287033))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1402266765 90680856 2121688151 -64268714) (v128.const i32x4 -970313904 485330754 -372252678 -1859838539) (v128.const i32x4 1107990819 203557798 -1481107593 1253588736)) (v128.const i32x4 -2077606029 351243840 1853344991 -651356491))
+
+(assert_return (invoke "v128.and" (v128.const f32x4 0x1p+0 -0x1p+0 nan -inf) (v128.const i32x4 2147483647 2147483647 2147483647 2147483647)) (v128.const f32x4 0x1p+0 0x1p+0 nan inf))
+(assert_return (invoke "v128.xor" (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15) (v128.const i64x2 -1 0)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 8 9 10 11 12 13 14 15))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (v128.not (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.not ))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.and (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.and (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.and (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.or (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.or (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.or (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.xor (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.xor (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.xor (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.andnot (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.andnot (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.andnot (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.bitselect (i32.const 0) (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.bitselect (v128.const i32x4 0 0 0 0) (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.bitselect (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.bitselect (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
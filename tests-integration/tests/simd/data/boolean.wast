@@ -0,0 +1,126 @@
+;; Tests for the any_true, all_true and bitmask instructions.
+
+(module
+  (func (export "v128.any_true") (param v128) (result i32) (v128.any_true (local.get 0)))
+  (func (export "i8x16.all_true") (param v128) (result i32) (i8x16.all_true (local.get 0)))
+  (func (export "i8x16.bitmask") (param v128) (result i32) (i8x16.bitmask (local.get 0)))
+  (func (export "i16x8.all_true") (param v128) (result i32) (i16x8.all_true (local.get 0)))
+  (func (export "i16x8.bitmask") (param v128) (result i32) (i16x8.bitmask (local.get 0)))
+  (func (export "i32x4.all_true") (param v128) (result i32) (i32x4.all_true (local.get 0)))
+  (func (export "i32x4.bitmask") (param v128) (result i32) (i32x4.bitmask (local.get 0)))
+  (func (export "i64x2.all_true") (param v128) (result i32) (i64x2.all_true (local.get 0)))
+  (func (export "i64x2.bitmask") (param v128) (result i32) (i64x2.bitmask (local.get 0)))
+)
+
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 1 0 0 0)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0 0 0 -2147483648)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 0 0 1 0)) (i32.const 1))
+(assert_return (invoke "v128.any_true" (v128.const i32x4 -1 -1 -1 -1)) (i32.const 1))
+
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 0 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (i32.const 0))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 -64 -2 -86 1 -1 2 85 1 -128 1 -1 100 100 -1 126 -1)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 100 1 2 126 1 -86 1 126 1 -2 64 100 -2 2 64 127)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 2 -128 85 2 -1 1 -128 3 100 -64 -100 -100 85 64 126 127)) (i32.const 1))
+(assert_return (invoke "i8x16.all_true" (v128.const i8x16 126 -1 64 3 -64 -100 64 -1 2 100 127 -64 -2 3 100 1)) (i32.const 1))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1)) (i32.const 65535))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 0 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (i32.const 21844))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 -64 -2 -86 1 -1 2 85 1 -128 1 -1 100 100 -1 126 -1)) (i32.const 42263))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 100 1 2 126 1 -86 1 126 1 -2 64 100 -2 2 64 127)) (i32.const 4640))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 2 -128 85 2 -1 1 -128 3 100 -64 -100 -100 85 64 126 127)) (i32.const 3666))
+(assert_return (invoke "i8x16.bitmask" (v128.const i8x16 126 -1 64 3 -64 -100 64 -1 2 100 127 -64 -2 3 100 1)) (i32.const 6322))
+
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 0 0 0 0 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 1 1 1 1 1 1 1 1)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 1 1 1 1 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 0 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 -1 21845 21845 -21846 -12345 12345 -1 -1)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 16384 -12345 -1 1 -16384 12345 -16384 255)) (i32.const 1))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 -21846 0 12345 -21846 -32768 2 -12345 1)) (i32.const 0))
+(assert_return (invoke "i16x8.all_true" (v128.const i16x8 32766 -16384 32767 -32767 255 255 -12345 -1)) (i32.const 1))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 0 0 0 0 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 1 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1)) (i32.const 255))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 1 1 1 1 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 0 1 1 1 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (i32.const 164))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 -1 21845 21845 -21846 -12345 12345 -1 -1)) (i32.const 217))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 16384 -12345 -1 1 -16384 12345 -16384 255)) (i32.const 86))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 -21846 0 12345 -21846 -32768 2 -12345 1)) (i32.const 89))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 32766 -16384 32767 -32767 255 255 -12345 -1)) (i32.const 202))
+
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 1 1 1 1)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 -1 -1 -1 -1)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0 1 1 1)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 0 1 -1 2)) (i32.const 0))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 -1 1073741824 2147483646 -1073741824)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 2147483647 -1 2147483646 -1073741824)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 2147483647 123456789 2147483646 -2147483648)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 65535 2147483646 2 -1)) (i32.const 1))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0 0 0 0)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 1 1 1 1)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 -1 -1 -1 -1)) (i32.const 15))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 1 1 1 0)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0 1 1 1)) (i32.const 0))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 0 1 -1 2)) (i32.const 4))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 -1 1073741824 2147483646 -1073741824)) (i32.const 9))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 2147483647 -1 2147483646 -1073741824)) (i32.const 10))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 2147483647 123456789 2147483646 -2147483648)) (i32.const 8))
+(assert_return (invoke "i32x4.bitmask" (v128.const i32x4 65535 2147483646 2 -1)) (i32.const 8))
+
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 0 0)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 1 1)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 -1 -1)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 1 0)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 0 1)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 0 1)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 1 -1)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 -1 2)) (i32.const 1))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 2 0)) (i32.const 0))
+(assert_return (invoke "i64x2.all_true" (v128.const i64x2 6148914691236517205 -4886718345)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 0 0)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 1 1)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 -1 -1)) (i32.const 3))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 1 0)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 0 1)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 0 1)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 1 -1)) (i32.const 2))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 -1 2)) (i32.const 1))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 2 0)) (i32.const 0))
+(assert_return (invoke "i64x2.bitmask" (v128.const i64x2 6148914691236517205 -4886718345)) (i32.const 2))
+
+;; Type check
+
+(assert_invalid (module (func (result i32) (v128.any_true (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (v128.any_true ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i8x16.all_true (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i8x16.all_true ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i8x16.bitmask (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i8x16.bitmask ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.all_true (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.all_true ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.bitmask (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.bitmask ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.all_true (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.all_true ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.bitmask (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.bitmask ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i64x2.all_true (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i64x2.all_true ))) "type mismatch")
+(assert_invalid (module (func (result i32) (i64x2.bitmask (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i64x2.bitmask ))) "type mismatch")
+(assert_malformed (module quote "(func (result i32) (v128.all_true (v128.const i32x4 0 0 0 0)))") "unknown operator")
+(assert_malformed (module quote "(func (result i32) (f32x4.all_true (v128.const i32x4 0 0 0 0)))") "unknown operator")
@@ -0,0 +1,123 @@
+;; Tests for the v128.const instruction, in each of its shapes.
+
+(module
+  (func (export "const-0") (result v128) (v128.const i8x16 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF 0xFF))
+  (func (export "const-1") (result v128) (v128.const i8x16 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80 -0x80))
+  (func (export "const-2") (result v128) (v128.const i8x16 255 255 255 255 255 255 255 255 255 255 255 255 255 255 255 255))
+  (func (export "const-3") (result v128) (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128))
+  (func (export "const-4") (result v128) (v128.const i8x16 0 0 0 0 0 0 0 0 1_0 1_0 1_0 1_0 1_0 1_0 1_0 1_0))
+  (func (export "const-5") (result v128) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+  (func (export "const-6") (result v128) (v128.const i16x8 0xFFFF 0xFFFF 0xFFFF 0xFFFF 0xFFFF 0xFFFF 0xFFFF 0xFFFF))
+  (func (export "const-7") (result v128) (v128.const i16x8 -0x8000 -0x8000 -0x8000 -0x8000 -0x8000 -0x8000 -0x8000 -0x8000))
+  (func (export "const-8") (result v128) (v128.const i16x8 65535 65535 65535 65535 65535 65535 65535 65535))
+  (func (export "const-9") (result v128) (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768))
+  (func (export "const-10") (result v128) (v128.const i16x8 +32767 0x7f_ff 1 2 3 4 5 6))
+  (func (export "const-11") (result v128) (v128.const i32x4 0xFFFFFFFF 0xFFFFFFFF 0xFFFFFFFF 0xFFFFFFFF))
+  (func (export "const-12") (result v128) (v128.const i32x4 -0x80000000 -0x80000000 -0x80000000 -0x80000000))
+  (func (export "const-13") (result v128) (v128.const i32x4 4294967295 4294967295 4294967295 4294967295))
+  (func (export "const-14") (result v128) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+  (func (export "const-15") (result v128) (v128.const i32x4 0x1234_5678 0 1 -1))
+  (func (export "const-16") (result v128) (v128.const i64x2 0xFFFFFFFFFFFFFFFF 0xFFFFFFFFFFFFFFFF))
+  (func (export "const-17") (result v128) (v128.const i64x2 -0x8000000000000000 -0x8000000000000000))
+  (func (export "const-18") (result v128) (v128.const i64x2 18446744073709551615 -9223372036854775808))
+  (func (export "const-19") (result v128) (v128.const f32x4 0x1p127 -0x1p127 0x1.fffffep127 -0x1.fffffep127))
+  (func (export "const-20") (result v128) (v128.const f32x4 1e38 -1e38 1e-38 -1e-38))
+  (func (export "const-21") (result v128) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+  (func (export "const-22") (result v128) (v128.const f32x4 inf -inf nan -nan))
+  (func (export "const-23") (result v128) (v128.const f32x4 nan:0x1 -nan:0x7fffff +nan:0x200000 +inf))
+  (func (export "const-24") (result v128) (v128.const f32x4 0 -0 +0.0 1_000.000_1))
+  (func (export "const-25") (result v128) (v128.const f32x4 123456789 0x1.000002p+0 0.1 1.5e1))
+  (func (export "const-26") (result v128) (v128.const f64x2 0x1p1023 -0x1.fffffffffffffp1023))
+  (func (export "const-27") (result v128) (v128.const f64x2 0x1p-1074 -0x1p-1022))
+  (func (export "const-28") (result v128) (v128.const f64x2 inf -nan))
+  (func (export "const-29") (result v128) (v128.const f64x2 nan:0x1 -nan:0xfffffffffffff))
+  (func (export "const-30") (result v128) (v128.const f64x2 0.1 1e308))
+  (func (export "const-31") (result v128) (v128.const f64x2 -0 1_2.5e-1_0))
+)
+
+(assert_return (invoke "const-0" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-1" ) (v128.const i32x4 -2139062144 -2139062144 -2139062144 -2139062144))
+(assert_return (invoke "const-2" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-3" ) (v128.const i32x4 -2139062144 -2139062144 -2139062144 -2139062144))
+(assert_return (invoke "const-4" ) (v128.const i32x4 0 0 168430090 168430090))
+(assert_return (invoke "const-5" ) (v128.const i32x4 50462976 117835012 185207048 252579084))
+(assert_return (invoke "const-6" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-7" ) (v128.const i32x4 -2147450880 -2147450880 -2147450880 -2147450880))
+(assert_return (invoke "const-8" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-9" ) (v128.const i32x4 -2147450880 -2147450880 -2147450880 -2147450880))
+(assert_return (invoke "const-10" ) (v128.const i32x4 2147450879 131073 262147 393221))
+(assert_return (invoke "const-11" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-12" ) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "const-13" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-14" ) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "const-15" ) (v128.const i32x4 305419896 0 1 -1))
+(assert_return (invoke "const-16" ) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "const-17" ) (v128.const i32x4 0 -2147483648 0 -2147483648))
+(assert_return (invoke "const-18" ) (v128.const i32x4 -1 -1 0 -2147483648))
+(assert_return (invoke "const-19" ) (v128.const i32x4 2130706432 -16777216 2139095039 -8388609))
+(assert_return (invoke "const-20" ) (v128.const i32x4 2123789977 -23693671 7136238 -2140347410))
+(assert_return (invoke "const-21" ) (v128.const i32x4 1 -2147483647 8388608 -2139095040))
+(assert_return (invoke "const-22" ) (v128.const i32x4 2139095040 -8388608 2143289344 -4194304))
+(assert_return (invoke "const-23" ) (v128.const i32x4 2139095041 -1 2141192192 2139095040))
+(assert_return (invoke "const-24" ) (v128.const i32x4 0 -2147483648 0 1148846082))
+(assert_return (invoke "const-25" ) (v128.const i32x4 1290500515 1065353217 1036831949 1097859072))
+(assert_return (invoke "const-26" ) (v128.const i32x4 0 2145386496 -1 -1048577))
+(assert_return (invoke "const-27" ) (v128.const i32x4 1 0 0 -2146435072))
+(assert_return (invoke "const-28" ) (v128.const i32x4 0 2146435072 0 -524288))
+(assert_return (invoke "const-29" ) (v128.const i32x4 1 2146435072 -1 -1))
+(assert_return (invoke "const-30" ) (v128.const i32x4 -1717986918 1069128089 -2048145248 2145504499))
+(assert_return (invoke "const-31" ) (v128.const i32x4 0 -2147483648 -500134854 1041594766))
+
+;; Constants in globals and as arguments
+
+(module
+  (global $g v128 (v128.const f32x4 1 2 3 4))
+  (func (export "get") (result v128) (global.get $g))
+  (func (export "id") (param v128) (result v128) (local.get 0))
+)
+
+(assert_return (invoke "get" ) (v128.const f32x4 0x1p+0 0x1p+1 0x1.8p+1 0x1p+2))
+(assert_return (invoke "id" (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15)) (v128.const i64x2 506097522914230528 1084818905618843912))
+(assert_return (invoke "id" (v128.const i16x8 1 2 3 4 5 6 7 8)) (v128.const i32x4 131073 262147 393221 524295))
+(assert_return (invoke "id" (v128.const f64x2 0x1p+0 -0x1p+1)) (v128.const i64x2 4607182418800017408 -4611686018427387904))
+
+;; Lanes out of range
+
+(assert_malformed (module quote "(func (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 256) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 -129) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i16x8 0 0 0 0 0 0 0 65536) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i16x8 0 0 0 0 0 0 0 -32769) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i32x4 0 0 0 4294967296) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i32x4 0 0 0 -2147483649) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i64x2 0 18446744073709551616) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const i64x2 0 -9223372036854775809) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const f32x4 0 0 0 0x1p128) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const f32x4 0 0 0 1e39) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const f32x4 0 0 0 nan:0x800000) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const f64x2 0 0x1p1024) drop)") "constant out of range")
+(assert_malformed (module quote "(func (v128.const f64x2 0 nan:0x10000000000000) drop)") "constant out of range")
+
+;; The wrong number of lanes, or a missing shape
+
+(assert_malformed (module quote "(func (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i16x8 0 0 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i16x8 0 0 0 0 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i32x4 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i32x4 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i64x2 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i64x2 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const f32x4 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const f32x4 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const f64x2 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const f64x2 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i32x8 0 0 0 0 0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i32x4 0.0 0 0 0) drop)") "unexpected token")
+(assert_malformed (module quote "(func (v128.const i8x16 nan 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) drop)") "unexpected token")
+
+;; Type check
+
+(assert_invalid (module (func (result i32) (v128.const i32x4 0 0 0 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32.const 0))) "type mismatch")
+(assert_invalid (module (global v128 (i32.const 0))) "type mismatch")
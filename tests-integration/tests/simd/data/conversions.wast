@@ -0,0 +1,89 @@
+;; Tests for the conversions between vectors of different lane types.
+
+(module
+  (func (export "f32x4.convert_i32x4_s") (param v128) (result v128) (f32x4.convert_i32x4_s (local.get 0)))
+  (func (export "f32x4.convert_i32x4_u") (param v128) (result v128) (f32x4.convert_i32x4_u (local.get 0)))
+  (func (export "f64x2.convert_low_i32x4_s") (param v128) (result v128) (f64x2.convert_low_i32x4_s (local.get 0)))
+  (func (export "f64x2.convert_low_i32x4_u") (param v128) (result v128) (f64x2.convert_low_i32x4_u (local.get 0)))
+  (func (export "f32x4.demote_f64x2_zero") (param v128) (result v128) (f32x4.demote_f64x2_zero (local.get 0)))
+  (func (export "f64x2.promote_low_f32x4") (param v128) (result v128) (f64x2.promote_low_f32x4 (local.get 0)))
+  (func (export "i8x16.narrow_i16x8_s") (param v128 v128) (result v128) (i8x16.narrow_i16x8_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.narrow_i16x8_u") (param v128 v128) (result v128) (i8x16.narrow_i16x8_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.narrow_i32x4_s") (param v128 v128) (result v128) (i16x8.narrow_i32x4_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.narrow_i32x4_u") (param v128 v128) (result v128) (i16x8.narrow_i32x4_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 0 1 -1 2147483647)) (v128.const f32x4 0x0p+0 0x1p+0 -0x1p+0 0x1p+31))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0 1 -1 2147483647)) (v128.const f32x4 0x0p+0 0x1p+0 0x1p+32 0x1p+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 0 1 -1 2147483647)) (v128.const f64x2 0x0p+0 0x1p+0))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 0 1 -1 2147483647)) (v128.const f64x2 0x0p+0 0x1p+0))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 -2147483648 16777217 -16777217 -129)) (v128.const f32x4 -0x1p+31 0x1p+24 -0x1p+24 -0x1.02p+7))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 -2147483648 16777217 -16777217 -129)) (v128.const f32x4 0x1p+31 0x1p+24 0x1.fep+31 0x1.fffffep+31))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 -2147483648 16777217 -16777217 -129)) (v128.const f64x2 -0x1p+31 0x1.000001p+24))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 -2147483648 16777217 -16777217 -129)) (v128.const f64x2 0x1p+31 0x1.000001p+24))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 2147483520 123456789 16777217 -1)) (v128.const f32x4 0x1.fffffep+30 0x1.d6f346p+26 0x1p+24 -0x1p+0))
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 2147483520 123456789 16777217 -1)) (v128.const f32x4 0x1.fffffep+30 0x1.d6f346p+26 0x1p+24 0x1p+32))
+(assert_return (invoke "f64x2.convert_low_i32x4_s" (v128.const i32x4 2147483520 123456789 16777217 -1)) (v128.const f64x2 0x1.fffffep+30 0x1.d6f3454p+26))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 2147483520 123456789 16777217 -1)) (v128.const f64x2 0x1.fffffep+30 0x1.d6f3454p+26))
+
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p+0 -0x1p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p-1 -0x1p-1 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f32x4 inf -inf 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 inf -inf)) (v128.const f32x4 inf -inf 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 nan -nan)) (v128.const f32x4 nan:canonical nan:canonical 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1.dee7a4ad4b81fp-167 -0x1.dee7a4ad4b81fp-167)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1.11b0ec57e649ap+166 0x1.ffffffp+127)) (v128.const f32x4 inf inf 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1.fffffep+127 0x1.999999999999ap-4)) (v128.const f32x4 0x1.fffffep+127 0x1.99999ap-4 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.demote_f64x2_zero" (v128.const f64x2 0x1p-149 0x1p-150)) (v128.const f32x4 0x1p-149 0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f64x2 0x1p-126 -0x1p-126))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 inf -inf nan -nan)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 0x0p+0)) (v128.const f64x2 nan:arithmetic 0x1.921fb6p+2))
+
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (v128.const i16x8 255 -129 256 128 -12345 12345 256 255)) (v128.const i8x16 0 1 -1 2 127 -128 127 -128 127 -128 127 127 -128 127 127 127))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767) (v128.const i16x8 255 -129 256 128 -12345 12345 256 255)) (v128.const i8x16 0 1 0 2 -1 0 -1 0 -1 0 -1 -128 0 -1 -1 -1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (v128.const i16x8 -21846 21845 -16384 16384 -32767 32766 -32768 32767)) (v128.const i8x16 127 -128 127 -128 127 127 127 -128 -128 127 -128 127 -128 127 -128 127))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345) (v128.const i16x8 -21846 21845 -16384 16384 -32767 32766 -32768 32767)) (v128.const i8x16 -1 0 -1 0 -1 -1 -1 0 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i16x8 128 256 -129 255 0 0 0 0) (v128.const i16x8 2 -1 1 0 0 0 0 0)) (v128.const i8x16 127 127 -128 127 0 0 0 0 2 -1 1 0 0 0 0 0))
+(assert_return (invoke "i8x16.narrow_i16x8_u" (v128.const i16x8 128 256 -129 255 0 0 0 0) (v128.const i16x8 2 -1 1 0 0 0 0 0)) (v128.const i8x16 -128 -1 0 -1 0 0 0 0 2 0 1 0 0 0 0 0))
+
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 0 1 -1 2) (v128.const i32x4 65535 -32769 65536 32768)) (v128.const i16x8 0 1 -1 2 32767 -32768 32767 32767))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 0 1 -1 2) (v128.const i32x4 65535 -32769 65536 32768)) (v128.const i16x8 0 1 0 2 -1 0 -1 -32768))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (v128.const i32x4 123456789 65535 1431655765 -1073741824)) (v128.const i16x8 32767 -32768 32767 32767 32767 32767 32767 -32768))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824) (v128.const i32x4 123456789 65535 1431655765 -1073741824)) (v128.const i16x8 -1 0 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (v128.const i32x4 1073741824 2147483646 -2147483648 2147483647)) (v128.const i16x8 -32768 32767 32767 32767 32767 32767 -32768 32767))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789) (v128.const i32x4 1073741824 2147483646 -2147483648 2147483647)) (v128.const i16x8 0 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i16x8.narrow_i32x4_s" (v128.const i32x4 32768 65536 -32769 65535) (v128.const i32x4 2 -1 1 0)) (v128.const i16x8 32767 32767 -32768 32767 2 -1 1 0))
+(assert_return (invoke "i16x8.narrow_i32x4_u" (v128.const i32x4 32768 65536 -32769 65535) (v128.const i32x4 2 -1 1 0)) (v128.const i16x8 -32768 -1 0 -1 2 0 1 0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f32x4.convert_i32x4_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.convert_i32x4_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.convert_i32x4_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.convert_i32x4_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.convert_low_i32x4_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.convert_low_i32x4_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.convert_low_i32x4_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.convert_low_i32x4_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.demote_f64x2_zero (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.demote_f64x2_zero ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.promote_low_f32x4 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.promote_low_f32x4 ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.narrow_i16x8_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.narrow_i32x4_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
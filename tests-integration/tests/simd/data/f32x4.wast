@@ -0,0 +1,196 @@
+;; Tests for the f32x4 min, max, abs and neg instructions.
+
+(module
+  (func (export "f32x4.min") (param v128 v128) (result v128) (f32x4.min (local.get 0) (local.get 1)))
+  (func (export "f32x4.max") (param v128 v128) (result v128) (f32x4.max (local.get 0) (local.get 1)))
+  (func (export "f32x4.abs") (param v128) (result v128) (f32x4.abs (local.get 0)))
+  (func (export "f32x4.neg") (param v128) (result v128) (f32x4.neg (local.get 0)))
+)
+
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x1p-1 0x0p+0 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x1p-126 0x0p+0 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x0p+0 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x0p+0 -0x1p+0 -0x0p+0 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 -0x1p-149 -0x0p+0 -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x0p+0 -0x1.fffffep+127 -0x0p+0 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x0p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1p+0 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x1p+0 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p+0 -0x1.fffffep+127 -0x1p+0 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-1 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1p-1 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x1p-1 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1p-1 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-1 -0x1p+0 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p-1 -0x1.fffffep+127 -0x1p-1 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-149 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-149 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-149 -0x1p-126 0x1p-149 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x1p-149 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1p-149 -0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-149 -0x1p+0 -0x1p-149 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p-149 -0x1.fffffep+127 -0x1p-149 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-126 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-126 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1p-126 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x1p-126 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1p-126 -0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-126 -0x1p+0 -0x1p-126 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p-126 -0x1.fffffep+127 -0x1p-126 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x1.fffffep+127 -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 -inf -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.921fb6p+2 -0x1.fffffep+127 0x1.921fb6p+2 -inf))
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 0x0p+0 0x1p+0 0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x0p+0 0x1p-149 0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 0x0p+0 0x1.fffffep+127 0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x0p+0 0x1p-1 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x0p+0 0x1p-126 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x0p+0 inf -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+0 0x1p+0 0x1.fffffep+127 0x1p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x1p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1p+0 inf -0x1p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-1 0x1p-1 0x1p+0 0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-1 0x1p-1 0x1.fffffep+127 0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x1p-1 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p-1 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1p-1 inf -0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-149 0x1p-149 0x1p+0 0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x1p-149 0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 0x1p-149 0x1.fffffep+127 0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x1p-149 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p-149 0x1p-1 -0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1p-149 inf -0x1p-149))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-126 0x1p-126 0x1p+0 0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x1p-126 0x1p-126 0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 0x1p-126 0x1.fffffep+127 0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x1p-126 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p-126 0x1p-1 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1p-126 inf -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf 0x1.fffffep+127 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.fffffep+127 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -0x1.fffffep+127))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 0x1.921fb6p+2 inf 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.max" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+
+(assert_return (invoke "f32x4.abs" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 0x0p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.abs" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x1p-1 0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.abs" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 0x1p-126 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.abs" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf inf nan nan))
+(assert_return (invoke "f32x4.abs" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 0x0p+0)) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 -0x1p-1 0x1p-1 -0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 -0x1p-126 0x1p-126 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf -nan nan))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 0x0p+0)) (v128.const f32x4 -nan:0x200000 -0x1.921fb6p+2 -0x0p+0 -0x0p+0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f32x4.min (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.min (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.min (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.max (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.max (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.max (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.abs (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.abs ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.neg (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.neg ))) "type mismatch")
@@ -0,0 +1,362 @@
+;; Tests for the f32x4 add, sub, mul, div and sqrt instructions.
+
+(module
+  (func (export "f32x4.add") (param v128 v128) (result v128) (f32x4.add (local.get 0) (local.get 1)))
+  (func (export "f32x4.sub") (param v128 v128) (result v128) (f32x4.sub (local.get 0) (local.get 1)))
+  (func (export "f32x4.mul") (param v128 v128) (result v128) (f32x4.mul (local.get 0) (local.get 1)))
+  (func (export "f32x4.div") (param v128 v128) (result v128) (f32x4.div (local.get 0) (local.get 1)))
+  (func (export "f32x4.sqrt") (param v128) (result v128) (f32x4.sqrt (local.get 0)))
+)
+
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+1 0x0p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.8p+0 0x1p-1 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+0 0x1p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.d21fb6p+2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x0p+0 -0x1p+1 -0x1p-1 -0x1.8p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.521fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-1 0x1p-1 0x1.8p+0 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p+0 0x0p+0 0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-1 0x1p-1 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.b21fb6p+2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p-1 -0x1.8p+0 0x0p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.721fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-149 0x1p-149 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-148 0x0p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1.000002p-126 -0x1.fffffcp-127 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 -0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x0p+0 -0x1p-148 0x1.fffffcp-127 -0x1.000002p-126))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-126 0x1p-126 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1.000002p-126 0x1.fffffcp-127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-125 0x0p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 -0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.fffffcp-127 -0x1.000002p-126 0x0p+0 -0x1p-125))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 inf 0x0p+0))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x0p+0 -inf inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.fffffep+127))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf -inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf -inf nan:canonical -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1.d21fb6p+2 0x1.521fb6p+2 0x1.b21fb6p+2 0x1.721fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.921fb6p+3))
+
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 0x0p+0 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 -0x1p-1 0x1p-1 -0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 -0x1p-126 0x1p-126 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic -0x1.921fb6p+2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-149 0x1p-149 -0x1p-126 0x1p-126))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p+0 0x1p+0 0x0p+0 0x1p+1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 0x1.8p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+0 0x1p+0 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic -0x1.521fb6p+2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+1 0x0p+0 -0x1.8p+0 -0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.d21fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 0x1.8p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 0x1p+0 0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-1 0x1p-1 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic -0x1.721fb6p+2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.8p+0 0x1p-1 -0x1p+0 0x0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.b21fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-149 0x1p-149 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 -0x1p-1 0x1p-1 0x0p+0 0x1p-148))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 -0x1.fffffcp-127 0x1.000002p-126 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic -0x1.921fb6p+2 -0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-148 0x0p+0 -0x1.000002p-126 0x1.fffffcp-127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1p-126 0x1p-126 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 -0x1p-1 0x1p-1 0x1.fffffcp-127 0x1.000002p-126))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 0x1p-125 -0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic -0x1.921fb6p+2 -0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.000002p-126 -0x1.fffffcp-127 -0x1p-125 0x0p+0))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x0p+0 inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf 0x0p+0 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.fffffep+127))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf -inf -inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf -inf -inf nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1.521fb6p+2 0x1.d21fb6p+2 0x1.721fb6p+2 0x1.b21fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.sub" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x0p+0))
+
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x0p+0 0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p-149 0x1p-149 -0x1p-126 0x1p-126))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p+2))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-2 -0x1p-2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-127 -0x1p-127 0x1.fffffep+126 -0x1.fffffep+126))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p+1 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-1 0x1p-1 -0x1p-2 0x1p-2))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x1p-127 0x1p-127))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+126 0x1.fffffep+126 -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p+1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1.fffffep-22 -0x1.fffffep-22))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.8p-147 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-149 0x1p-149 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep-22 0x1.fffffep-22 -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.8p-147))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-127 -0x1p-127 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1.fffffep+1 -0x1.fffffep+1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.921fb6p-124 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-126 0x1p-126 -0x1p-127 0x1p-127))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1.fffffep+1 0x1.fffffep+1 -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.921fb6p-124))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.fffffep+126 -0x1.fffffep+126 0x1.fffffep-22 -0x1.fffffep-22))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1.fffffep+1 -0x1.fffffep+1 inf -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+126 0x1.fffffep+126))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1.fffffep-22 0x1.fffffep-22 -0x1.fffffep+1 0x1.fffffep+1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical inf -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1.921fb6p+2 -0x1.921fb6p+2 0x1.921fb6p+1 -0x1.921fb6p+1))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 0x1.8p-147 -0x1.8p-147 0x1.921fb6p-124 -0x1.921fb6p-124))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.mul" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1.3bd3cep+5))
+
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p+1 -0x1p+1 inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+126 -0x1p+126 0x1p-128 -0x1p-128))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.45f306p-3 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p+1 0x1p+1))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf inf -0x1p+126 0x1p+126))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p-128 0x1p-128 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.45f306p-3))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf 0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p+0 -0x1p+0 inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+125 -0x1p+125 0x1p-129 -0x1p-129))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.45f306p-4 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-1 0x1p-1 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf inf -0x1p+125 0x1p+125))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p-129 0x1p-129 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.45f306p-4))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf 0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-148 -0x1p-148 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-23 -0x1p-23 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x0p+0 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-149 0x1p-149 -0x1p-148 0x1p-148))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x1p-23 0x1p-23))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf 0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p-125 -0x1p-125 0x1p+23 -0x1p+23))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+0 -0x1p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.45f3p-129 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1p-126 0x1p-126 -0x1p-125 0x1p-125))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -0x1p+23 0x1p+23 -0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x0p+0 0x0p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.45f3p-129))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf -inf 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 0x0p+0 -0x0p+0 nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic 0x1.45f304p+125 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -0x1.fffffep+127 0x1.fffffep+127 -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -0x1p+0 0x1p+0 -0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -0x1.45f304p+125))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic inf -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 -inf inf -inf inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 -inf inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 nan:canonical nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic nan:canonical))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 nan:arithmetic nan:arithmetic nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const f32x4 nan:arithmetic nan:arithmetic inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const f32x4 0x1.921fb6p+2 -0x1.921fb6p+2 0x1.921fb6p+3 -0x1.921fb6p+3))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const f32x4 inf -inf inf -inf))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const f32x4 0x1.921fb8p-126 -0x1.921fb8p-126 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.div" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const f32x4 nan:canonical nan:canonical nan:arithmetic 0x1p+0))
+
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 nan:canonical))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1.6a09e6p-1 nan:canonical 0x1.6a09e6p-75 nan:canonical))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p-63 nan:canonical 0x1.fffffep+63 nan:canonical))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf nan:canonical nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1p+2 0x1p+1)) (v128.const f32x4 nan:arithmetic 0x1.40d932p+1 0x1p+1 0x1.6a09e6p+0))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 0x1p-2 0x1.4484cp-100 0x1.93e594p+99 0x1.8p+1)) (v128.const f32x4 0x1p-1 0x1.203afap-50 0x1.c6bf52p+49 0x1.bb67aep+0))
+(assert_return (invoke "f32x4.sqrt" (v128.const f32x4 0x1.4p+3 0x0p+0 0x0p+0 0x0p+0)) (v128.const f32x4 0x1.94c584p+1 0x0p+0 0x0p+0 0x0p+0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f32x4.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.mul (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.mul (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.mul (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.div (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.div (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.div (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.sqrt (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.sqrt ))) "type mismatch")
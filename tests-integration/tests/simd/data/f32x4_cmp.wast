@@ -0,0 +1,523 @@
+;; Tests for the f32x4 comparison instructions.
+
+(module
+  (func (export "f32x4.eq") (param v128 v128) (result v128) (f32x4.eq (local.get 0) (local.get 1)))
+  (func (export "f32x4.ne") (param v128 v128) (result v128) (f32x4.ne (local.get 0) (local.get 1)))
+  (func (export "f32x4.lt") (param v128 v128) (result v128) (f32x4.lt (local.get 0) (local.get 1)))
+  (func (export "f32x4.gt") (param v128 v128) (result v128) (f32x4.gt (local.get 0) (local.get 1)))
+  (func (export "f32x4.le") (param v128 v128) (result v128) (f32x4.le (local.get 0) (local.get 1)))
+  (func (export "f32x4.ge") (param v128 v128) (result v128) (f32x4.ge (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.eq" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ne" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 -1 -1 -1 0))
+
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.lt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "f32x4.le" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x0p+0 0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p+0 0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-1 0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-149 0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1p-126 0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 inf inf inf inf) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 inf inf -inf -inf) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -inf -inf -inf -inf) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan nan nan nan) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan nan -nan -nan) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 -nan -nan -nan -nan) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000) (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 nan:0x200000 nan:0x200000 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x0p+0 -0x0p+0)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p+0 -0x1p+0 0x1p-1 -0x1p-1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1p-149 -0x1p-149 0x1p-126 -0x1p-126)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 0x1.fffffep+127 -0x1.fffffep+127 inf -inf)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "f32x4.ge" (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2) (v128.const f32x4 nan -nan nan:0x200000 0x1.921fb6p+2)) (v128.const i32x4 0 0 0 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f32x4.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.lt (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.lt (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.lt (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.gt (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.gt (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.gt (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.le (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.le (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.le (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ge (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ge (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ge (v128.const i32x4 0 0 0 0)))) "type mismatch")
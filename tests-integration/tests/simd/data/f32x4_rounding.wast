@@ -0,0 +1,55 @@
+;; Tests for the f32x4 ceil, floor, trunc and nearest instructions.
+
+(module
+  (func (export "f32x4.ceil") (param v128) (result v128) (f32x4.ceil (local.get 0)))
+  (func (export "f32x4.floor") (param v128) (result v128) (f32x4.floor (local.get 0)))
+  (func (export "f32x4.trunc") (param v128) (result v128) (f32x4.trunc (local.get 0)))
+  (func (export "f32x4.nearest") (param v128) (result v128) (f32x4.nearest (local.get 0)))
+)
+
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x1p+0 -0x0p+0 0x1p+0 -0x0p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x1p+0 -0x0p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const f32x4 nan:arithmetic 0x1.cp+2 0x1p+1 -0x1p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x1.4p+1 -0x1.4p+1 0x1.fffffep-2 -0x1.fffffep-2)) (v128.const f32x4 0x1.8p+1 -0x1p+1 0x1p+0 -0x0p+0))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x1.d9999ap+1 -0x1.d9999ap+1 0x1.e8481p+19 0x1.000002p+23)) (v128.const f32x4 0x1p+2 -0x1.8p+1 0x1.e8482p+19 0x1.000002p+23))
+(assert_return (invoke "f32x4.ceil" (v128.const f32x4 0x1.fffffep+22 0x0p+0 0x0p+0 0x0p+0)) (v128.const f32x4 0x1p+23 0x0p+0 0x0p+0 0x0p+0))
+
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x1p+0 0x0p+0 -0x1p+0))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x1p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const f32x4 nan:arithmetic 0x1.8p+2 0x1p+0 -0x1p+1))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x1.4p+1 -0x1.4p+1 0x1.fffffep-2 -0x1.fffffep-2)) (v128.const f32x4 0x1p+1 -0x1.8p+1 0x0p+0 -0x1p+0))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x1.d9999ap+1 -0x1.d9999ap+1 0x1.e8481p+19 0x1.000002p+23)) (v128.const f32x4 0x1.8p+1 -0x1p+2 0x1.e848p+19 0x1.000002p+23))
+(assert_return (invoke "f32x4.floor" (v128.const f32x4 0x1.fffffep+22 0x0p+0 0x0p+0 0x0p+0)) (v128.const f32x4 0x1.fffffcp+22 0x0p+0 0x0p+0 0x0p+0))
+
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const f32x4 nan:arithmetic 0x1.8p+2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x1.4p+1 -0x1.4p+1 0x1.fffffep-2 -0x1.fffffep-2)) (v128.const f32x4 0x1p+1 -0x1p+1 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x1.d9999ap+1 -0x1.d9999ap+1 0x1.e8481p+19 0x1.000002p+23)) (v128.const f32x4 0x1.8p+1 -0x1.8p+1 0x1.e848p+19 0x1.000002p+23))
+(assert_return (invoke "f32x4.trunc" (v128.const f32x4 0x1.fffffep+22 0x0p+0 0x0p+0 0x0p+0)) (v128.const f32x4 0x1.fffffcp+22 0x0p+0 0x0p+0 0x0p+0))
+
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const f32x4 0x0p+0 -0x0p+0 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const f32x4 0x0p+0 -0x0p+0 0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 inf -inf nan -nan)) (v128.const f32x4 inf -inf nan:canonical nan:canonical))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const f32x4 nan:arithmetic 0x1.8p+2 0x1p+1 -0x1p+1))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x1.4p+1 -0x1.4p+1 0x1.fffffep-2 -0x1.fffffep-2)) (v128.const f32x4 0x1p+1 -0x1p+1 0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x1.d9999ap+1 -0x1.d9999ap+1 0x1.e8481p+19 0x1.000002p+23)) (v128.const f32x4 0x1p+2 -0x1p+2 0x1.e848p+19 0x1.000002p+23))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0x1.fffffep+22 0x0p+0 0x0p+0 0x0p+0)) (v128.const f32x4 0x1p+23 0x0p+0 0x0p+0 0x0p+0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f32x4.ceil (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.ceil ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.floor (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.floor ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.trunc (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.trunc ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.nearest (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.nearest ))) "type mismatch")
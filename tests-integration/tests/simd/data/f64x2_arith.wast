@@ -0,0 +1,692 @@
+;; Tests for the f64x2 add, sub, mul, div and sqrt instructions.
+
+(module
+  (func (export "f64x2.add") (param v128 v128) (result v128) (f64x2.add (local.get 0) (local.get 1)))
+  (func (export "f64x2.sub") (param v128 v128) (result v128) (f64x2.sub (local.get 0) (local.get 1)))
+  (func (export "f64x2.mul") (param v128 v128) (result v128) (f64x2.mul (local.get 0) (local.get 1)))
+  (func (export "f64x2.div") (param v128 v128) (result v128) (f64x2.div (local.get 0) (local.get 1)))
+  (func (export "f64x2.sqrt") (param v128) (result v128) (f64x2.sqrt (local.get 0)))
+)
+
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+1 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.8p+0 0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.d21fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 -0x1p+1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 -0x1.8p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.521fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.8p+0 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p+0 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.b21fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1 -0x1.8p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.721fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000002p-1022 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.0000000000001p-1022 -0x0.fffffffffffffp-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0.0000000000002p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0.fffffffffffffp-1022 -0x1.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.0000000000001p-1022 0x0.fffffffffffffp-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1021 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.fffffffffffffp-1022 -0x1.0000000000001p-1022))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x1p-1021))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf 0x0p+0))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.d21fb54442d18p+2 0x1.521fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.b21fb54442d18p+2 0x1.721fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.add" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+3))
+
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 0x1p+1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 0x1.8p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.521fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+1 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1.8p+0 -0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.d21fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1 0x1.8p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.721fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.8p+0 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p+0 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.b21fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 0x0.0000000000002p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0.fffffffffffffp-1022 0x1.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000002p-1022 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1.0000000000001p-1022 0x0.fffffffffffffp-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.fffffffffffffp-1022 0x1.0000000000001p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 0x1p-1021))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1.0000000000001p-1022 -0x0.fffffffffffffp-1022))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1021 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf 0x0p+0))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.521fb54442d18p+2 0x1.d21fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.721fb54442d18p+2 0x1.b21fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sub" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0p+0))
+
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-2 -0x1p-2))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0.8p-1022 -0x0.8p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1022 -0x1.fffffffffffffp+1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-2 0x1p-2))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0.8p-1022 0x0.8p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1022 0x1.fffffffffffffp+1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp-51 -0x1.fffffffffffffp-51))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0.0000000000006p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp-51 0x1.fffffffffffffp-51))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x0.0000000000006p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0.8p-1022 -0x0.8p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1 -0x1.fffffffffffffp+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.921fb54442d18p-1020))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0.8p-1022 0x0.8p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1 0x1.fffffffffffffp+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.921fb54442d18p-1020))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.fffffffffffffp+1022 -0x1.fffffffffffffp+1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.fffffffffffffp-51 -0x1.fffffffffffffp-51))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1 -0x1.fffffffffffffp+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1.fffffffffffffp+1022 0x1.fffffffffffffp+1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1.fffffffffffffp-51 0x1.fffffffffffffp-51))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1 0x1.fffffffffffffp+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.921fb54442d18p+1 -0x1.921fb54442d18p+1))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000006p-1022 -0x0.0000000000006p-1022))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.921fb54442d18p-1020 -0x1.921fb54442d18p-1020))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.mul" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.3bd3cc9be45dep+5))
+
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p+1 -0x1p+1))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+1022 -0x1p+1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0.4p-1022 -0x0.4p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.45f306dc9c883p-3))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p+1 0x1p+1))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+1022 0x1p+1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0.4p-1022 0x0.4p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.45f306dc9c883p-3))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+1021 -0x1p+1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0.2p-1022 -0x0.2p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.45f306dc9c883p-4))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+1021 0x1p+1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0.2p-1022 0x0.2p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.45f306dc9c883p-4))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0.0000000000002p-1022 -0x0.0000000000002p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-52 -0x1p-52))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0.0000000000002p-1022 0x0.0000000000002p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-52 0x1p-52))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1021 -0x1p-1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+52 -0x1p+52))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x0.28be60db9391p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1021 0x1p-1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p+52 0x1p+52))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x0.28be60db9391p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.45f306dc9c882p+1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -0x1.45f306dc9c882p+1021))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic nan:arithmetic))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 -0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.921fb54442d18p+3 -0x1.921fb54442d18p+3))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.921fb54442d19p-1022 -0x1.921fb54442d19p-1022))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1p+0))
+
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.6a09e667f3bcdp-1 nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p-537 nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-511 nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+511 nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 inf -inf)) (v128.const f64x2 inf nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.40d931ff62705p+1))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1p+2 0x1p+1)) (v128.const f64x2 0x1p+1 0x1.6a09e667f3bcdp+0))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1p-2 0x1.4484bfeebc2ap-100)) (v128.const f64x2 0x1p-1 0x1.203af9ee75616p-50))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1.93e5939a08ceap+99 0x1.8p+1)) (v128.const f64x2 0x1.c6bf52634p+49 0x1.bb67ae8584caap+0))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 0x1.4p+3 0x0p+0)) (v128.const f64x2 0x1.94c583ada5b53p+1 0x0p+0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f64x2.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.mul (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.mul (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.mul (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.div (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.div (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.div (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.sqrt (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.sqrt ))) "type mismatch")
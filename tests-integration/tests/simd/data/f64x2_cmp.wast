@@ -0,0 +1,1009 @@
+;; Tests for the f64x2 comparison instructions.
+
+(module
+  (func (export "f64x2.eq") (param v128 v128) (result v128) (f64x2.eq (local.get 0) (local.get 1)))
+  (func (export "f64x2.ne") (param v128 v128) (result v128) (f64x2.ne (local.get 0) (local.get 1)))
+  (func (export "f64x2.lt") (param v128 v128) (result v128) (f64x2.lt (local.get 0) (local.get 1)))
+  (func (export "f64x2.gt") (param v128 v128) (result v128) (f64x2.gt (local.get 0) (local.get 1)))
+  (func (export "f64x2.le") (param v128 v128) (result v128) (f64x2.le (local.get 0) (local.get 1)))
+  (func (export "f64x2.ge") (param v128 v128) (result v128) (f64x2.ge (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.eq" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 -1 0))
+
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.lt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.gt" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.le" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const i64x2 0 -1))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const i64x2 0 0))
+(assert_return (invoke "f64x2.ge" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const i64x2 0 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f64x2.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.lt (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.lt (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.lt (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.gt (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.gt (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.gt (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.le (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.le (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.le (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ge (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ge (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ge (v128.const i32x4 0 0 0 0)))) "type mismatch")
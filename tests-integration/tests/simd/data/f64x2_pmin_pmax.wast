@@ -0,0 +1,341 @@
+;; Tests for the f64x2 pmin and pmax instructions, which return their first operand unless the second is strictly less or greater.
+
+(module
+  (func (export "f64x2.pmin") (param v128 v128) (result v128) (f64x2.pmin (local.get 0) (local.get 1)))
+  (func (export "f64x2.pmax") (param v128 v128) (result v128) (f64x2.pmax (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0p+0 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0p+0 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0p+0 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0p+0 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0p+0 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0p+0 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0p+0 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1p+0 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x1p+0 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1p+0 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x1p+0 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1p-1 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x1p-1 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1p-1 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x1p-1 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x0.0000000000001p-1022 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0.0000000000001p-1022 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x0.0000000000001p-1022 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x0.0000000000001p-1022 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x0.0000000000001p-1022 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p-1022 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1022 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1p-1022 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x1p-1022 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1p-1022 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1p-1022 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1p-1022 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x1p-1022 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x1.fffffffffffffp+1023 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 inf 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.921fb54442d18p+2 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 0x1.921fb54442d18p+2 -inf))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmin" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0p+0 0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x0p+0 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0p+0 -0x0p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x0p+0 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p+0 0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p+0 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p+0 -0x1p+0) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p+0 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1 0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p-1 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1 -0x1p-1) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p-1 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x0.0000000000001p-1022 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x0.0000000000001p-1022 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1p-1022 0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1p-1022 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1p-1022 -0x1p-1022) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1p-1022 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan -nan)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -0x1.fffffffffffffp+1023 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 inf inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan -nan)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -inf -inf) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -inf 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 inf -inf)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan -nan)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 -nan -nan) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 inf -inf)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 inf -inf)) (v128.const f64x2 inf 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan -nan)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+(assert_return (invoke "f64x2.pmax" (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2) (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f64x2.pmin (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.pmin (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.pmin (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.pmax (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.pmax (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.pmax (v128.const i32x4 0 0 0 0)))) "type mismatch")
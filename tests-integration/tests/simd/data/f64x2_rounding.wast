@@ -0,0 +1,83 @@
+;; Tests for the f64x2 ceil, floor, trunc and nearest instructions.
+
+(module
+  (func (export "f64x2.ceil") (param v128) (result v128) (f64x2.ceil (local.get 0)))
+  (func (export "f64x2.floor") (param v128) (result v128) (f64x2.floor (local.get 0)))
+  (func (export "f64x2.trunc") (param v128) (result v128) (f64x2.trunc (local.get 0)))
+  (func (export "f64x2.nearest") (param v128) (result v128) (f64x2.nearest (local.get 0)))
+)
+
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x1p+0 -0x0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x1p+0 -0x0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x1p+0 -0x0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.cp+2))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.8p+0 -0x1.8p+0)) (v128.const f64x2 0x1p+1 -0x1p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.4p+1 -0x1.4p+1)) (v128.const f64x2 0x1.8p+1 -0x1p+1))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.fffffdfc9a9adp-2 -0x1.fffffdfc9a9adp-2)) (v128.const f64x2 0x1p+0 -0x0p+0))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.d99999999999ap+1 -0x1.d99999999999ap+1)) (v128.const f64x2 0x1p+2 -0x1.8p+1))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.e8481p+19 0x1.0000000000001p+52)) (v128.const f64x2 0x1.e8482p+19 0x1.0000000000001p+52))
+(assert_return (invoke "f64x2.ceil" (v128.const f64x2 0x1.fffffffffffffp+51 0x0p+0)) (v128.const f64x2 0x1p+52 0x0p+0))
+
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.8p+2))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.8p+0 -0x1.8p+0)) (v128.const f64x2 0x1p+0 -0x1p+1))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.4p+1 -0x1.4p+1)) (v128.const f64x2 0x1p+1 -0x1.8p+1))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.fffffdfc9a9adp-2 -0x1.fffffdfc9a9adp-2)) (v128.const f64x2 0x0p+0 -0x1p+0))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.d99999999999ap+1 -0x1.d99999999999ap+1)) (v128.const f64x2 0x1.8p+1 -0x1p+2))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.e8481p+19 0x1.0000000000001p+52)) (v128.const f64x2 0x1.e848p+19 0x1.0000000000001p+52))
+(assert_return (invoke "f64x2.floor" (v128.const f64x2 0x1.fffffffffffffp+51 0x0p+0)) (v128.const f64x2 0x1.ffffffffffffep+51 0x0p+0))
+
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.8p+2))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.8p+0 -0x1.8p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.4p+1 -0x1.4p+1)) (v128.const f64x2 0x1p+1 -0x1p+1))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.fffffdfc9a9adp-2 -0x1.fffffdfc9a9adp-2)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.d99999999999ap+1 -0x1.d99999999999ap+1)) (v128.const f64x2 0x1.8p+1 -0x1.8p+1))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.e8481p+19 0x1.0000000000001p+52)) (v128.const f64x2 0x1.e848p+19 0x1.0000000000001p+52))
+(assert_return (invoke "f64x2.trunc" (v128.const f64x2 0x1.fffffffffffffp+51 0x0p+0)) (v128.const f64x2 0x1.ffffffffffffep+51 0x0p+0))
+
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x0p+0 -0x0p+0)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1p+0 -0x1p+0)) (v128.const f64x2 0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1p-1 -0x1p-1)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x0.0000000000001p-1022 -0x0.0000000000001p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1p-1022 -0x1p-1022)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 inf -inf)) (v128.const f64x2 inf -inf))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 nan -nan)) (v128.const f64x2 nan:canonical nan:canonical))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 nan:0x4000000000000 0x1.921fb54442d18p+2)) (v128.const f64x2 nan:arithmetic 0x1.8p+2))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.8p+0 -0x1.8p+0)) (v128.const f64x2 0x1p+1 -0x1p+1))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.4p+1 -0x1.4p+1)) (v128.const f64x2 0x1p+1 -0x1p+1))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.fffffdfc9a9adp-2 -0x1.fffffdfc9a9adp-2)) (v128.const f64x2 0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.d99999999999ap+1 -0x1.d99999999999ap+1)) (v128.const f64x2 0x1p+2 -0x1p+2))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.e8481p+19 0x1.0000000000001p+52)) (v128.const f64x2 0x1.e848p+19 0x1.0000000000001p+52))
+(assert_return (invoke "f64x2.nearest" (v128.const f64x2 0x1.fffffffffffffp+51 0x0p+0)) (v128.const f64x2 0x1p+52 0x0p+0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (f64x2.ceil (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.ceil ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.floor (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.floor ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.trunc (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.trunc ))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.nearest (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.nearest ))) "type mismatch")
@@ -0,0 +1,124 @@
+;; Tests for the i16x8 add, sub, mul and neg instructions.
+
+(module
+  (func (export "i16x8.add") (param v128 v128) (result v128) (i16x8.add (local.get 0) (local.get 1)))
+  (func (export "i16x8.sub") (param v128 v128) (result v128) (i16x8.sub (local.get 0) (local.get 1)))
+  (func (export "i16x8.mul") (param v128 v128) (result v128) (i16x8.mul (local.get 0) (local.get 1)))
+  (func (export "i16x8.neg") (param v128) (result v128) (i16x8.neg (local.get 0)))
+)
+
+(assert_return (invoke "i16x8.add" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 2 0 3 -32768 -32767 32767 -32766))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16385 -16383 21846 -21845 256 257 12346 -12344))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 -2 1 32766 32767 32765 -32768))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 -16385 21844 -21847 254 255 12344 -12346))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 3 1 4 -32767 -32766 -32768 -32765))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16386 -16382 21847 -21844 257 258 12347 -12343))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 -32768 32766 -32767 -2 -1 -3 0))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16385 16383 -10924 10921 -32514 -32513 -20424 20422))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32767 32767 -32766 -1 0 -2 1))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -10923 10922 -32513 -32512 -20423 20423))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32767 32765 -32768 -3 -2 -4 -1))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16386 16382 -10925 10920 -32515 -32514 -20425 20421))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32766 -32768 -32765 0 1 -1 2))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 16385 -10922 10923 -32512 -32511 -20422 20424))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16385 16383 16386 -16385 -16384 -16386 -16383))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 0 -27307 -5462 16639 16640 28729 4039))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16383 -16385 -16382 16383 16384 16382 16385))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -32768 5461 27306 -16129 -16128 -4039 -28729))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21846 21844 21847 -10924 -10923 -10925 -10922))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -27307 5461 -21846 -1 22100 22101 -31346 9500))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21845 -21847 -21844 10921 10922 10920 10923))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -5462 27306 -1 21844 -21591 -21590 -9501 31345))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 256 254 257 -32514 -32513 -32515 -32512))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16639 -16129 22100 -21591 510 511 12600 -12090))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 257 255 258 -32513 -32512 -32514 -32511))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16640 -16128 22101 -21590 511 512 12601 -12089))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12346 12344 12347 -20424 -20423 -20425 -20422))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 28729 -4039 -31346 -9501 12600 12601 24690 0))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12344 -12346 -12343 20422 20423 20421 20424))
+(assert_return (invoke "i16x8.add" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 4039 -28729 9500 31345 -12090 -12089 0 -24690))
+
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32767 -32768 -32766 32767))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -21845 21846 -255 -256 -12345 12345))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 0 2 -1 -32766 -32767 -32765 -32768))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 16385 -21844 21847 -254 -255 -12344 12346))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -2 0 -3 -32768 32767 -32767 32766))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16385 16383 -21846 21845 -256 -257 -12346 12344))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 1 3 0 -32765 -32766 -32764 -32767))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16382 16386 -21843 21848 -253 -254 -12343 12347))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32766 -32768 32765 0 -1 1 -2))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 -16385 10922 -10923 32512 32511 20422 -20424))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 32767 -32767 32766 1 0 2 -1))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 10923 -10922 32513 32512 20423 -20423))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32765 32767 32764 -1 -2 0 -3))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16382 -16386 10921 -10924 32511 32510 20421 -20425))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32768 -32766 32767 2 1 3 0))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16385 -16383 10924 -10921 32514 32513 20424 -20422))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16383 16385 16382 -16383 -16384 -16382 -16385))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -32768 -5461 -27306 16129 16128 4039 28729))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16385 -16383 -16386 16385 16384 16386 16383))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 0 27307 5462 -16639 -16640 -28729 -4039))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21844 21846 21843 -10922 -10923 -10921 -10924))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 5461 -27307 0 -21845 21590 21589 9500 -31346))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21847 -21845 -21848 10923 10922 10924 10921))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 27306 -5462 21845 0 -22101 -22102 31345 -9501))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 254 256 253 -32512 -32513 -32511 -32514))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16129 16639 -21590 22101 0 -1 -12090 12600))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 255 257 254 -32511 -32512 -32510 -32513))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16128 16640 -21589 22102 1 0 -12089 12601))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12344 12346 12343 -20422 -20423 -20421 -20424))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -4039 28729 -9500 -31345 12090 12089 0 24690))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12346 -12344 -12347 20424 20423 20425 20422))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -28729 4039 31346 9501 -12600 -12601 -24690 0))
+
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32767 -32768 -32766 32767))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -21845 21846 -255 -256 -12345 12345))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 2 -2 4 -2 0 -4 2))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -32768 -21846 21844 510 512 24690 -24690))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 32767 -32767 -2 1 -32768 -32766 -1))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 10923 21846 32513 -256 20423 -20423))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -32768 -32768 0 -32768 0 0 -32768))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 -32768 0 -32768 0 -32768 -32768))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 32766 -32766 -4 -32766 0 4 32766))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -32768 -10922 -21844 32258 -512 8078 -8078))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -32767 32767 2 -1 -32768 32766 1))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 -10923 -21846 -32513 256 -20423 20423))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 16384 -16384 -32768 -16384 0 -32768 16384))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 16384 -32768 -16384 0 16384 -16384))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -16384 16384 -32768 16384 0 -32768 -16384))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 -16384 -32768 16384 0 -16384 16384))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 21845 -21845 -21846 10923 -32768 -10922 -10923))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 -29127 7282 -85 21760 -4115 4115))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -21846 21846 21844 21846 0 -21844 -21846))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -32768 7282 14564 -170 -22016 -8230 8230))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 255 -255 510 32513 -32768 32258 -32513))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -85 -170 -511 -256 2247 -2247))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 256 -256 512 -256 0 -512 256))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 21760 -22016 -256 0 14592 -14592))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 12345 -12345 24690 20423 -32768 8078 -20423))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 -4115 -8230 2247 14592 27825 -27825))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -12345 12345 -24690 -20423 -32768 -8078 20423))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 4115 8230 -2247 -14592 -27825 27825))
+
+(assert_return (invoke "i16x8.neg" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32767 -32768 -32766 32767))
+(assert_return (invoke "i16x8.neg" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -21845 21846 -255 -256 -12345 12345))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.mul (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.mul (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.mul (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.neg (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.neg ))) "type mismatch")
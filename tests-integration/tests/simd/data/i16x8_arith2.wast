@@ -0,0 +1,198 @@
+;; Tests for the i16x8 abs, min, max, avgr_u and popcnt instructions.
+
+(module
+  (func (export "i16x8.min_s") (param v128 v128) (result v128) (i16x8.min_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.min_u") (param v128 v128) (result v128) (i16x8.min_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.max_s") (param v128 v128) (result v128) (i16x8.max_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.max_u") (param v128 v128) (result v128) (i16x8.max_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.avgr_u") (param v128 v128) (result v128) (i16x8.avgr_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.abs") (param v128) (result v128) (i16x8.abs (local.get 0)))
+)
+
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 -32768 0 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -16384 0 -21846 0 0 0 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 1 1 -32768 1 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 1 -16384 1 -21846 1 1 1 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -32768 -1 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -16384 -1 -21846 -1 -1 -1 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 2 -32768 2 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 2 -16384 2 -21846 2 2 2 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32766 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32768 -32767 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 16384 -32768 16384 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 16384 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -32768 -16384 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 -16384 -16384 -21846 -16384 -16384 -16384 -16384))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 21845 -32768 21845 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -32768 -21846 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 255 -32768 255 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 255 -16384 255 -21846 255 255 255 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 256 -32768 256 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 256 -16384 256 -21846 255 256 256 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 12345 -32768 12345 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 12345 -16384 12345 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -32768 -12345 -32767))
+(assert_return (invoke "i16x8.min_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -12345 -16384 -12345 -21846 -12345 -12345 -12345 -12345))
+
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 1 1 1 1 1 1))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 1 1 1 1 1 1 1 1))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 2 2 2 2 2 2))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 2 2 2 2 2 2 2 2))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 32767 2 32767 32767 32766 32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 32767 21845 32767 255 256 12345 32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -32768 2 32767 -32768 32766 -32768))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -32768 21845 -32768 255 256 12345 -32768))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 32766 2 32766 32766 32766 32766))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 32766 21845 32766 255 256 12345 32766))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -32767 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -32767 21845 -32767 255 256 12345 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 16384 2 16384 16384 16384 16384))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 16384 16384 16384 255 256 12345 16384))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -16384 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -16384))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 21845 2 21845 21845 21845 21845))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 21845 21845 21845 255 256 12345 21845))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -21846 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -21846 21845 -21846 255 256 12345 -21846))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 255 2 255 255 255 255))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 255 255 255 255 255 255 255 255))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 256 2 256 256 256 256))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 256 256 256 256 255 256 256 256))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 12345 2 12345 12345 12345 12345))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 12345 12345 12345 12345 255 256 12345 12345))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -12345 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.min_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 0 2 32767 0 32766 0))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 0 21845 0 255 256 12345 0))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 1 1 2 32767 1 32766 1))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 1 21845 1 255 256 12345 1))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -1 32766 -1))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -1 21845 -1 255 256 12345 -1))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 2 2 2 32767 2 32766 2))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 2 21845 2 255 256 12345 2))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32766 32766 32766 32767 32766 32766 32766))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32767 32766 -32767))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16384 16384 16384 32767 16384 32766 16384))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 16384 21845 16384 16384 16384 16384 16384))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -16384 32766 -16384))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -16384 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21845 21845 21845 32767 21845 32766 21845))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -21846 32766 -21846))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 255 255 255 32767 255 32766 255))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 255 21845 255 255 256 12345 255))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 256 256 256 32767 256 32766 256))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 256 21845 256 256 256 12345 256))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12345 12345 12345 32767 12345 32766 12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 12345 21845 12345 12345 12345 12345 12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -12345 32766 -12345))
+(assert_return (invoke "i16x8.max_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -12345 21845 -12345 255 256 12345 -12345))
+
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 2 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32767 -1 32767 32767 -32768 32767 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 -16384 32767 -21846 32767 32767 32767 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32768 -1 -32768 -32768 -32768 -32768 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -16384 -32768 -21846 -32768 -32768 -32768 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32766 -1 32766 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32766 -16384 32766 -21846 32766 32766 32766 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32767 -1 -32767 -32767 -32767 -32767 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32767 -16384 -32767 -21846 -32767 -32767 -32767 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16384 -1 16384 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 16384 16384 16384 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16384 -1 -16384 -16384 -16384 -16384 -16384))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21845 -1 21845 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 21845 -16384 21845 -21846 21845 21845 21845 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21846 -1 -21846 -21846 -21846 -21846 -21846))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -21846 -16384 -21846 -21846 -21846 -21846 -21846 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 255 -1 255 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 256 -1 256 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 256 256 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12345 -1 12345 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 12345 12345 12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12345 -1 -12345 -12345 -12345 -12345 -12345))
+(assert_return (invoke "i16x8.max_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345))
+
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -32768 1 16384 16384 16383 16385))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8192 24576 10923 21845 128 128 6173 26596))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 1 -32768 2 16384 16385 16384 16385))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8193 24577 10923 21846 128 129 6173 26596))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32768 -1 -32767 -16385 -16384 -16385 -16384))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -24576 -8192 -21846 -10923 -32641 -32640 -26596 -6173))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 2 -32767 2 16385 16385 16384 16386))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8193 24577 10924 21846 129 129 6174 26597))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16384 -16385 16385 32767 -32768 32767 -32768))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 24576 -24576 27306 -27307 16511 16512 22556 -22557))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16385 -16384 16385 -32768 -32768 32767 -32767))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 24576 -24576 27307 -27307 16512 16512 22557 -22556))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16383 16384 -16385 16384 32767 32767 32766 -32768))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 24575 -24577 27306 -27308 16511 16511 22556 -22557))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16385 16385 -16384 16386 -32768 -32767 -32768 -32767))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 24577 -24575 27307 -27306 16512 16513 22557 -22556))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 8192 8193 -24576 8193 24576 24576 24575 24577))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -32768 19115 30037 8320 8320 14365 -30748))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 24576 24577 -8192 24577 -24576 -24576 -24577 -24575))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -16384 -30037 -19115 24704 24704 30749 -14364))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 10923 10923 -21846 10924 27306 27307 27306 27307))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 19115 -30037 21845 -32768 11050 11051 17095 -28018))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21846 -10923 21846 -27307 -27307 -27308 -27306))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 30037 -19115 -32768 -21846 21973 21973 28018 -17095))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 128 128 -32641 129 16511 16512 16511 16512))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8320 24704 11050 21973 255 256 6300 26723))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 128 129 -32640 129 16512 16512 16511 16513))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8320 24704 11051 21973 256 256 6301 26724))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 6173 6173 -26596 6174 22556 22557 22556 22557))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 14365 30749 17095 28018 6300 6301 12345 -32768))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 26596 26596 -6173 26597 -22557 -22556 -22557 -22556))
+(assert_return (invoke "i16x8.avgr_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -30748 -14364 -28018 -17095 26723 26724 -32768 -12345))
+
+(assert_return (invoke "i16x8.abs" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 1 2 32767 -32768 32766 32767))
+(assert_return (invoke "i16x8.abs" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 16384 21845 21846 255 256 12345 12345))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.min_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.min_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.min_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.min_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.min_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.min_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.max_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.avgr_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.avgr_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.avgr_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.abs (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.abs ))) "type mismatch")
@@ -0,0 +1,377 @@
+;; Tests for the i16x8 comparison instructions.
+
+(module
+  (func (export "i16x8.eq") (param v128 v128) (result v128) (i16x8.eq (local.get 0) (local.get 1)))
+  (func (export "i16x8.ne") (param v128 v128) (result v128) (i16x8.ne (local.get 0) (local.get 1)))
+  (func (export "i16x8.lt_s") (param v128 v128) (result v128) (i16x8.lt_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.gt_s") (param v128 v128) (result v128) (i16x8.gt_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.le_s") (param v128 v128) (result v128) (i16x8.le_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.ge_s") (param v128 v128) (result v128) (i16x8.ge_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.lt_u") (param v128 v128) (result v128) (i16x8.lt_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.gt_u") (param v128 v128) (result v128) (i16x8.gt_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.le_u") (param v128 v128) (result v128) (i16x8.le_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.ge_u") (param v128 v128) (result v128) (i16x8.ge_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 -1 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 -1))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 -1 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.eq" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 -1))
+
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ne" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 0))
+
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 -1 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.lt_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 0 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.gt_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 0))
+
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 -1 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 0 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.le_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 -1))
+
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 -1 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 0 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 0 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.lt_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 0 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 -1 0 0))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.gt_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 0))
+
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 -1 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 0 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 -1 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 0 0 -1 -1))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i16x8.le_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 -1))
+
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 0 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 0 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 0 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 -1 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 0 0 0 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 -1 -1 -1 0))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.ge_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.lt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.gt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.le_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.ge_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
@@ -0,0 +1,21 @@
+;; Tests for the i16x8.extadd_pairwise instructions.
+
+(module
+  (func (export "i16x8.extadd_pairwise_i8x16_s") (param v128) (result v128) (i16x8.extadd_pairwise_i8x16_s (local.get 0)))
+  (func (export "i16x8.extadd_pairwise_i8x16_u") (param v128) (result v128) (i16x8.extadd_pairwise_i8x16_u (local.get 0)))
+)
+
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 1 1 125 -2 -63 21 14 -97))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i8x16 3 -100 100 -86 85 -64 64 -127 126 -128 127 -2 2 -1 1 0)) (v128.const i16x8 -97 14 21 -63 -2 125 1 1))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i8x16 1 2 127 126 64 85 100 3 0 0 0 0 0 0 0 0)) (v128.const i16x8 3 253 149 103 0 0 0 0))
+
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 1 257 381 254 193 277 270 159))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i8x16 3 -100 100 -86 85 -64 64 -127 126 -128 127 -2 2 -1 1 0)) (v128.const i16x8 159 270 277 193 254 381 257 1))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_u" (v128.const i8x16 1 2 127 126 64 85 100 3 0 0 0 0 0 0 0 0)) (v128.const i16x8 3 253 149 103 0 0 0 0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.extadd_pairwise_i8x16_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extadd_pairwise_i8x16_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extadd_pairwise_i8x16_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extadd_pairwise_i8x16_u ))) "type mismatch")
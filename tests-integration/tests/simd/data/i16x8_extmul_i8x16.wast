@@ -0,0 +1,155 @@
+;; Tests for the i16x8.extmul instructions.
+
+(module
+  (func (export "i16x8.extmul_low_i8x16_s") (param v128 v128) (result v128) (i16x8.extmul_low_i8x16_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.extmul_low_i8x16_u") (param v128 v128) (result v128) (i16x8.extmul_low_i8x16_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.extmul_high_i8x16_s") (param v128 v128) (result v128) (i16x8.extmul_high_i8x16_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.extmul_high_i8x16_u") (param v128 v128) (result v128) (i16x8.extmul_high_i8x16_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 0 0 0 0 0 0 0 0 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 0 0 0 0 0 0 0 0 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 1 1 1 1 1 1 1 1 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 1 -1 2 -2 127 -128 126))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 1 1 1 1 1 1 1 1 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -1 1 -2 2 -127 128 -126))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 127 -64 64 -85 86 -100 100 -3))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 2 2 2 2 2 2 2 2 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 2 -2 4 -4 254 -256 252))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 2 2 2 2 2 2 2 2 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -254 128 -128 170 -172 200 -200 6))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -2 2 -4 4 -254 256 -252))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 254 -128 128 -170 172 -200 200 -6))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 127 127 127 127 127 127 127 127 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 127 -127 254 -254 16129 -16256 16002))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 127 127 127 127 127 127 127 127 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -16129 8128 -8128 10795 -10922 12700 -12700 381))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -128 128 -256 256 -16256 16384 -16128))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16256 -8192 8192 -10880 11008 -12800 12800 -384))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 126 126 126 126 126 126 126 126 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 126 -126 252 -252 16002 -16128 15876))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 126 126 126 126 126 126 126 126 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -16002 8064 -8064 10710 -10836 12600 -12600 378))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -127 127 -254 254 -16129 16256 -16002))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16129 -8128 8128 -10795 10922 -12700 12700 -381))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 64 64 64 64 64 64 64 64 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 64 -64 128 -128 8128 -8192 8064))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 64 64 64 64 64 64 64 64 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -8128 4096 -4096 5440 -5504 6400 -6400 192))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -64 64 -128 128 -8128 8192 -8064))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 8128 -4096 4096 -5440 5504 -6400 6400 -192))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 85 85 85 85 85 85 85 85 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 85 -85 170 -170 10795 -10880 10710))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 85 85 85 85 85 85 85 85 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -10795 5440 -5440 7225 -7310 8500 -8500 255))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -86 86 -172 172 -10922 11008 -10836))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 10922 -5504 5504 -7310 7396 -8600 8600 -258))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 100 100 100 100 100 100 100 100 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 100 -100 200 -200 12700 -12800 12600))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 100 100 100 100 100 100 100 100 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -12700 6400 -6400 8500 -8600 10000 -10000 300))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 -100 100 -200 200 -12700 12800 -12600))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 12700 -6400 6400 -8500 8600 -10000 10000 -300))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 3 -3 6 -6 381 -384 378))
+(assert_return (invoke "i16x8.extmul_low_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -381 192 -192 255 -258 300 -300 9))
+
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 0 0 0 0 0 0 0 0 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 0 0 0 0 0 0 0 0 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 1 1 1 1 1 1 1 1 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 1 255 2 254 127 128 126))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 1 1 1 1 1 1 1 1 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 129 64 192 85 170 100 156 3))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 255 -511 510 -766 32385 32640 32130))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 -32641 16320 -16576 21675 -22186 25500 -25756 765))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 2 2 2 2 2 2 2 2 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 2 510 4 508 254 256 252))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 2 2 2 2 2 2 2 2 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 258 128 384 170 340 200 312 6))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 254 -766 508 -1020 32258 32512 32004))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 32766 16256 -16768 21590 -22356 25400 -25912 762))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 127 127 127 127 127 127 127 127 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 127 32385 254 32258 16129 16256 16002))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 127 127 127 127 127 127 127 127 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16383 8128 24384 10795 21590 12700 19812 381))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 128 32640 256 32512 16256 16384 16128))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16512 8192 24576 10880 21760 12800 19968 384))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 126 126 126 126 126 126 126 126 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 126 32130 252 32004 16002 16128 15876))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 126 126 126 126 126 126 126 126 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16254 8064 24192 10710 21420 12600 19656 378))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 129 -32641 258 32766 16383 16512 16254))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 16641 8256 24768 10965 21930 12900 20124 387))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 64 64 64 64 64 64 64 64 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 64 16320 128 16256 8128 8192 8064))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 64 64 64 64 64 64 64 64 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 8256 4096 12288 5440 10880 6400 9984 192))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 192 -16576 384 -16768 24384 24576 24192))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 24768 12288 -28672 16320 32640 19200 29952 576))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 85 85 85 85 85 85 85 85 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 85 21675 170 21590 10795 10880 10710))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 85 85 85 85 85 85 85 85 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 10965 5440 16320 7225 14450 8500 13260 255))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 170 -22186 340 -22356 21590 21760 21420))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 21930 10880 32640 14450 28900 17000 26520 510))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 100 100 100 100 100 100 100 100 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 100 25500 200 25400 12700 12800 12600))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 100 100 100 100 100 100 100 100 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 12900 6400 19200 8500 17000 10000 15600 300))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 156 -25756 312 -25912 19812 19968 19656))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 20124 9984 29952 13260 26520 15600 24336 468))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 5 5 5 5 5 5 5 5)) (v128.const i16x8 0 3 765 6 762 381 384 378))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 -127 64 -64 85 -86 100 -100 3 5 5 5 5 5 5 5 5)) (v128.const i16x8 387 192 576 255 510 300 468 9))
+
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 0 0 0 0 0 0 0 0) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 0 0 0 0 0 0 0 0) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 1 1 1 1 1 1 1 1) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 1 -1 2 -2 127 -128 126))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 1 1 1 1 1 1 1 1) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -1 1 -2 2 -127 128 -126))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 127 -64 64 -85 86 -100 100 -3))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 2 2 2 2 2 2 2 2) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 2 -2 4 -4 254 -256 252))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 2 2 2 2 2 2 2 2) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -254 128 -128 170 -172 200 -200 6))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -2 2 -4 4 -254 256 -252))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 254 -128 128 -170 172 -200 200 -6))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 127 127 127 127 127 127 127 127) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 127 -127 254 -254 16129 -16256 16002))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 127 127 127 127 127 127 127 127) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -16129 8128 -8128 10795 -10922 12700 -12700 381))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -128 128 -256 256 -16256 16384 -16128))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16256 -8192 8192 -10880 11008 -12800 12800 -384))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 126 126 126 126 126 126 126 126) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 126 -126 252 -252 16002 -16128 15876))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 126 126 126 126 126 126 126 126) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -16002 8064 -8064 10710 -10836 12600 -12600 378))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -127 127 -254 254 -16129 16256 -16002))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16129 -8128 8128 -10795 10922 -12700 12700 -381))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 64 64 64 64 64 64 64 64) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 64 -64 128 -128 8128 -8192 8064))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 64 64 64 64 64 64 64 64) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -8128 4096 -4096 5440 -5504 6400 -6400 192))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -64 64 -128 128 -8128 8192 -8064))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 8128 -4096 4096 -5440 5504 -6400 6400 -192))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 85 85 85 85 85 85 85 85) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 85 -85 170 -170 10795 -10880 10710))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 85 85 85 85 85 85 85 85) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -10795 5440 -5440 7225 -7310 8500 -8500 255))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -86 86 -172 172 -10922 11008 -10836))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 10922 -5504 5504 -7310 7396 -8600 8600 -258))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 100 100 100 100 100 100 100 100) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 100 -100 200 -200 12700 -12800 12600))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 100 100 100 100 100 100 100 100) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -12700 6400 -6400 8500 -8600 10000 -10000 300))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 -100 100 -200 200 -12700 12800 -12600))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 12700 -6400 6400 -8500 8600 -10000 10000 -300))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 3 -3 6 -6 381 -384 378))
+(assert_return (invoke "i16x8.extmul_high_i8x16_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -381 192 -192 255 -258 300 -300 9))
+
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 0 0 0 0 0 0 0 0) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 0 0 0 0 0 0 0 0) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 1 1 1 1 1 1 1 1) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 1 255 2 254 127 128 126))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 1 1 1 1 1 1 1 1) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 129 64 192 85 170 100 156 3))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 255 -511 510 -766 32385 32640 32130))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -32641 16320 -16576 21675 -22186 25500 -25756 765))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 2 2 2 2 2 2 2 2) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 2 510 4 508 254 256 252))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 2 2 2 2 2 2 2 2) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 258 128 384 170 340 200 312 6))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 254 -766 508 -1020 32258 32512 32004))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 32766 16256 -16768 21590 -22356 25400 -25912 762))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 127 127 127 127 127 127 127 127) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 127 32385 254 32258 16129 16256 16002))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 127 127 127 127 127 127 127 127) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16383 8128 24384 10795 21590 12700 19812 381))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 128 32640 256 32512 16256 16384 16128))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16512 8192 24576 10880 21760 12800 19968 384))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 126 126 126 126 126 126 126 126) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 126 32130 252 32004 16002 16128 15876))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 126 126 126 126 126 126 126 126) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16254 8064 24192 10710 21420 12600 19656 378))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 129 -32641 258 32766 16383 16512 16254))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 16641 8256 24768 10965 21930 12900 20124 387))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 64 64 64 64 64 64 64 64) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 64 16320 128 16256 8128 8192 8064))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 64 64 64 64 64 64 64 64) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 8256 4096 12288 5440 10880 6400 9984 192))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 192 -16576 384 -16768 24384 24576 24192))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 24768 12288 -28672 16320 32640 19200 29952 576))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 85 85 85 85 85 85 85 85) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 85 21675 170 21590 10795 10880 10710))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 85 85 85 85 85 85 85 85) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 10965 5440 16320 7225 14450 8500 13260 255))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 170 -22186 340 -22356 21590 21760 21420))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 21930 10880 32640 14450 28900 17000 26520 510))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 100 100 100 100 100 100 100 100) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 100 25500 200 25400 12700 12800 12600))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 100 100 100 100 100 100 100 100) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 12900 6400 19200 8500 17000 10000 15600 300))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 156 -25756 312 -25912 19812 19968 19656))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 20124 9984 29952 13260 26520 15600 24336 468))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 5 5 5 5 5 5 5 5 0 1 -1 2 -2 127 -128 126)) (v128.const i16x8 0 3 765 6 762 381 384 378))
+(assert_return (invoke "i16x8.extmul_high_i8x16_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 5 5 5 5 5 5 5 5 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 387 192 576 255 510 300 468 9))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_low_i8x16_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extmul_high_i8x16_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
@@ -0,0 +1,44 @@
+;; Tests for the i16x8.q15mulr_sat_s instruction.
+
+(module
+  (func (export "i16x8.q15mulr_sat_s") (param v128 v128) (result v128) (i16x8.q15mulr_sat_s (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 1 -1 1 -1))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 1 0 1 -1 0 0 0 0))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 -1 1 -1 1))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 1 -1 1 0 0 0 0))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 2 -2 2 -2))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 1 -1 1 -1 0 0 1 -1))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32766 -32767 32765 -32766))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16383 21844 -21845 255 256 12345 -12345))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32767 32767 -32766 32767))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -21845 21846 -255 -256 -12345 12345))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32765 -32766 32764 -32765))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 -16383 21844 -21845 255 256 12344 -12344))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32766 32767 -32765 32766))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 16384 -21844 21845 -255 -256 -12345 12345))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 0 1 16384 -16384 16383 -16383))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 8192 -8192 10923 -10923 128 128 6173 -6172))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 1 -1 -16383 16384 -16383 16384))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -8192 8192 -10922 10923 -127 -128 -6172 6173))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 1 21844 -21845 21844 -21844))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 10923 -10922 14563 -14564 170 171 8230 -8230))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -1 -21845 21846 -21845 21845))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -10923 10923 -14564 14564 -170 -171 -8230 8230))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 255 -255 255 -255))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 128 -127 170 -170 2 2 96 -96))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 256 -256 256 -256))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 128 -128 171 -171 2 2 96 -96))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 1 12345 -12345 12344 -12345))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 6173 -6172 8230 -8230 96 96 4651 -4651))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 -1 -12345 12345 -12344 12345))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -6172 6173 -8230 8230 -96 -96 -4651 4651))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.q15mulr_sat_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.q15mulr_sat_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.q15mulr_sat_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
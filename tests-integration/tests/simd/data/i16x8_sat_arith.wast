@@ -0,0 +1,155 @@
+;; Tests for the i16x8 saturating add and sub instructions.
+
+(module
+  (func (export "i16x8.add_sat_s") (param v128 v128) (result v128) (i16x8.add_sat_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.add_sat_u") (param v128 v128) (result v128) (i16x8.add_sat_u (local.get 0) (local.get 1)))
+  (func (export "i16x8.sub_sat_s") (param v128 v128) (result v128) (i16x8.sub_sat_s (local.get 0) (local.get 1)))
+  (func (export "i16x8.sub_sat_u") (param v128 v128) (result v128) (i16x8.sub_sat_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 2 0 3 32767 -32767 32767 -32766))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16385 -16383 21846 -21845 256 257 12346 -12344))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 0 -2 1 32766 -32768 32765 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 -16385 21844 -21847 254 255 12344 -12346))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 3 1 4 32767 -32766 32767 -32765))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16386 -16382 21847 -21844 257 258 12347 -12343))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32767 32766 32767 32767 -1 32767 0))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 16383 32767 10921 32767 32767 32767 20422))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32767 -32768 -32766 -1 -32768 -2 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 -32768 -10923 -32768 -32513 -32512 -20423 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32767 32765 32767 32767 -2 32767 -1))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 16382 32767 10920 32767 32767 32767 20421))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32766 -32768 -32765 0 -32768 -1 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 -32768 -10922 -32768 -32512 -32511 -20422 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16385 16383 16386 32767 -16384 32767 -16383))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 0 32767 -5462 16639 16640 28729 4039))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16383 -16385 -16382 16383 -32768 16382 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 -32768 5461 -32768 -16129 -16128 -4039 -28729))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21846 21844 21847 32767 -10923 32767 -10922))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 32767 5461 32767 -1 22100 22101 32767 9500))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21845 -21847 -21844 10921 -32768 10920 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -5462 -32768 -1 -32768 -21591 -21590 -9501 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 256 254 257 32767 -32513 32767 -32512))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16639 -16129 22100 -21591 510 511 12600 -12090))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 257 255 258 32767 -32512 32767 -32511))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16640 -16128 22101 -21590 511 512 12601 -12089))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12346 12344 12347 32767 -20423 32767 -20422))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 28729 -4039 32767 -9501 12600 12601 24690 0))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12344 -12346 -12343 20422 -32768 20421 -32768))
+(assert_return (invoke "i16x8.add_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 4039 -28729 9500 -32768 -12090 -12089 0 -24690))
+
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 2 -1 3 -32768 -32767 32767 -32766))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16385 -16383 21846 -21845 256 257 12346 -12344))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 3 -1 4 -32767 -32766 -32768 -32765))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16386 -16382 21847 -21844 257 258 12347 -12343))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 -32768 -1 -32767 -2 -1 -3 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16385 -1 -10924 -1 -32514 -32513 -20424 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32767 -1 -32766 -1 -1 -2 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 -1 -10923 -1 -32513 -32512 -20423 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32767 -1 -32768 -3 -2 -4 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16386 -1 -10925 -1 -32515 -32514 -20425 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32766 -1 -32765 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 -1 -10922 -1 -32512 -32511 -20422 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16385 -1 16386 -16385 -16384 -16386 -16383))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -1 -27307 -5462 16639 16640 28729 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16383 -1 -16382 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -16129 -16128 -4039 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21846 -1 21847 -10924 -10923 -10925 -10922))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -27307 -1 -21846 -1 22100 22101 -31346 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21845 -1 -21844 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -5462 -1 -1 -1 -21591 -21590 -9501 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 256 -1 257 -32514 -32513 -32515 -32512))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16639 -16129 22100 -21591 510 511 12600 -12090))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 257 -1 258 -32513 -32512 -32514 -32511))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16640 -16128 22101 -21590 511 512 12601 -12089))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12346 -1 12347 -20424 -20423 -20425 -20422))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 28729 -4039 -31346 -9501 12600 12601 24690 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12344 -1 -12343 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.add_sat_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -1 -1 -1 -1 -12090 -12089 -1 -1))
+
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 -1 1 -2 -32767 32767 -32766 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16384 16384 -21845 21846 -255 -256 -12345 12345))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 0 2 -1 -32766 32767 -32765 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16383 16385 -21844 21847 -254 -255 -12344 12346))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -2 0 -3 -32768 32767 -32767 32766))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16385 16383 -21846 21845 -256 -257 -12346 12344))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 1 3 0 -32765 32767 -32764 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16382 16386 -21843 21848 -253 -254 -12343 12347))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32766 32767 32765 0 32767 1 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 32767 10922 32767 32512 32511 20422 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 -32768 -32767 -32768 -32768 0 -32768 -1))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -16384 -32768 -10922 -32768 -32768 -32768 -20423))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32765 32767 32764 -1 32767 0 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16382 32767 10921 32767 32511 32510 20421 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32768 -32766 -32768 -32768 1 -32768 0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -16383 -32768 -10921 -32768 -32768 -32768 -20422))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16383 16385 16382 -16383 32767 -16382 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 32767 -5461 32767 16129 16128 4039 28729))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16385 -16383 -16386 -32768 16384 -32768 16383))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 0 -32768 5462 -16639 -16640 -28729 -4039))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21844 21846 21843 -10922 32767 -10921 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 5461 32767 0 32767 21590 21589 9500 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21847 -21845 -21848 -32768 10922 -32768 10921))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 -5462 -32768 0 -22101 -22102 -32768 -9501))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 254 256 253 -32512 32767 -32511 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16129 16639 -21590 22101 0 -1 -12090 12600))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 255 257 254 -32511 32767 -32510 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16128 16640 -21589 22102 1 0 -12089 12601))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12344 12346 12343 -20422 32767 -20421 32767))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -4039 28729 -9500 32767 12090 12089 0 24690))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12346 -12344 -12347 -32768 20423 -32768 20422))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -28729 4039 -32768 9501 -12600 -12601 -24690 0))
+
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 0 0 0 0 0 0 0 0) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 1 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 1 1 1 1 1 1 1 1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -1 -2 0 -3 -32768 32767 -32767 32766))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -16385 16383 -21846 21845 -256 -257 -12346 12344))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 2 1 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 2 2 2 2 2 2 2 2) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32767 32766 0 32765 0 0 1 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16383 0 10922 0 32512 32511 20422 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32768 32767 0 32766 1 0 2 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16384 0 10923 0 32513 32512 20423 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 32766 32765 0 32764 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 32766 32766 32766 32766 32766 32766 32766 32766) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16382 0 10921 0 32511 32510 20421 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -32767 -32768 0 32767 2 1 3 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -32767 -32767 -32767 -32767 -32767 -32767 -32767 -32767) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 16385 0 10924 0 32514 32513 20424 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 16384 16383 0 16382 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 16384 16384 16384 16384 16384 16384 16384 16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 16129 16128 4039 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -16384 -16385 0 -16386 16385 16384 16386 16383))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -16384 -16384 -16384 -16384 -16384 -16384 -16384 -16384) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -32768 0 27307 5462 -16639 -16640 -28729 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 21845 21844 0 21843 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 21845 21845 21845 21845 21845 21845 21845 21845) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 5461 0 0 0 21590 21589 9500 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -21846 -21847 0 -21848 10923 10922 10924 10921))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -21846 -21846 -21846 -21846 -21846 -21846 -21846 -21846) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 27306 0 21845 0 -22101 -22102 31345 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 255 254 0 253 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 255 255 255 255 255 255 255 255) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 256 255 0 254 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 256 256 256 256 256 256 256 256) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 1 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 12345 12344 0 12343 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 12345 12345 12345 12345 12345 12345 12345 12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 0 0 0 0 12090 12089 0 0))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i16x8 -12345 -12346 0 -12347 20424 20423 20425 20422))
+(assert_return (invoke "i16x8.sub_sat_u" (v128.const i16x8 -12345 -12345 -12345 -12345 -12345 -12345 -12345 -12345) (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i16x8 -28729 4039 31346 9501 -12600 -12601 -24690 0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.add_sat_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add_sat_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add_sat_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add_sat_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add_sat_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.add_sat_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.sub_sat_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
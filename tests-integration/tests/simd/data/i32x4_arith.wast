@@ -0,0 +1,137 @@
+;; Tests for the i32x4 add, sub, mul and neg instructions.
+
+(module
+  (func (export "i32x4.add") (param v128 v128) (result v128) (i32x4.add (local.get 0) (local.get 1)))
+  (func (export "i32x4.sub") (param v128 v128) (result v128) (i32x4.sub (local.get 0) (local.get 1)))
+  (func (export "i32x4.mul") (param v128 v128) (result v128) (i32x4.mul (local.get 0) (local.get 1)))
+  (func (export "i32x4.neg") (param v128) (result v128) (i32x4.neg (local.get 0)))
+)
+
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1 2 0 3))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483648 -2147483647 2147483647 1073741825))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741823 1431655766 65536 123456790))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 -2 1))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483646 2147483647 2147483645 1073741823))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741825 1431655764 65534 123456788))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2 3 1 4))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483647 -2147483646 -2147483648 1073741826))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741822 1431655767 65537 123456791))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483647 -2147483648 2147483646 -2147483647))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2 -1 -3 -1073741825))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741823 -715827884 -2147418114 -2024026860))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -2147483648 -2147483647 2147483647 -2147483646))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -2 -1073741824))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 -715827883 -2147418113 -2024026859))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483646 2147483647 2147483645 -2147483648))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -3 -2 -4 -1073741826))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741822 -715827885 -2147418115 -2024026861))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1073741824 1073741825 1073741823 1073741826))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1073741825 -1073741824 -1073741826 -2147483648))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1789569707 1073807359 1197198613))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1073741824 -1073741823 -1073741825 -1073741822))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1073741823 1073741824 1073741822 0))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 357913941 -1073676289 -950285035))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1431655765 1431655766 1431655764 1431655767))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -715827884 -715827883 -715827885 -1789569707))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 357913941 -1431655766 1431721300 1555112554))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 65535 65536 65534 65537))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147418114 -2147418113 -2147418115 1073807359))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073676289 1431721300 131070 123522324))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 123456789 123456790 123456788 123456791))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2024026860 -2024026859 -2024026861 1197198613))
+(assert_return (invoke "i32x4.add" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -950285035 1555112554 123522324 246913578))
+
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 1 -2))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483647 -2147483648 -2147483646 -1073741824))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 -1431655765 -65535 -123456789))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1 0 2 -1))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483646 -2147483647 -2147483645 -1073741823))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741825 -1431655764 -65534 -123456788))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -2 0 -3))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483648 2147483647 -2147483647 -1073741825))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741823 -1431655766 -65536 -123456790))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2 1 3 0))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483645 -2147483646 -2147483644 -1073741822))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741826 -1431655763 -65533 -123456787))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483647 2147483646 -2147483648 2147483645))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 1 1073741823))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741825 715827882 2147418112 2024026858))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -2147483648 2147483647 -2147483647 2147483646))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1 0 2 1073741824))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 715827883 2147418113 2024026859))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483646 2147483645 2147483647 2147483644))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -2 0 1073741822))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741826 715827881 2147418111 2024026857))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1073741824 1073741823 1073741825 1073741822))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1073741823 -1073741824 -1073741822 0))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 -357913941 1073676289 950285035))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1073741824 -1073741825 -1073741823 -1073741826))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1073741825 1073741824 1073741826 -2147483648))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 1789569707 -1073807359 -1197198613))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1431655765 1431655764 1431655766 1431655763))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -715827882 -715827883 -715827881 357913941))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1789569707 0 1431590230 1308198976))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 65535 65534 65536 65533))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147418112 -2147418113 -2147418111 -1073676289))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073807359 -1431590230 0 -123391254))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 123456789 123456788 123456790 123456787))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2024026858 -2024026859 -2024026857 -950285035))
+(assert_return (invoke "i32x4.sub" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1197198613 -1308198976 123391254 0))
+
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 1 -2))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483647 -2147483648 -2147483646 -1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 -1431655765 -65535 -123456789))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 2 -2 4))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2 0 -4 -2147483648))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 -1431655766 131070 246913578))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 2147483647 -2147483647 -2))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1 -2147483648 -2147483646 -1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 715827883 2147418113 2024026859))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -2147483648 -2147483648 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483648 0 0 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 2147483646 -2147483646 -4))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483646 0 4 -2147483648))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 -715827882 2147352578 1900570070))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1073741824 -1073741824 -2147483648))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1073741824 0 -2147483648 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 1073741824 -1073741824 1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1073741824 1073741824 -2147483648))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1073741824 0 -2147483648 0))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1073741824 1073741824 -1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1431655765 -1431655765 -1431655766))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 715827883 -2147483648 -715827882 1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 954437177 -21845 -41152263))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 65535 -65535 131070))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147418113 -2147483648 2147352578 -1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 -21845 -131071 -977718549))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 123456789 -123456789 246913578))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2024026859 -2147483648 1900570070 1073741824))
+(assert_return (invoke "i32x4.mul" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 -41152263 -977718549 -1757895751))
+
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 1 -2))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483647 -2147483648 -2147483646 -1073741824))
+(assert_return (invoke "i32x4.neg" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 -1431655765 -65535 -123456789))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.mul (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.mul (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.mul (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.neg (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.neg ))) "type mismatch")
@@ -0,0 +1,178 @@
+;; Tests for the i32x4 abs, min, max, avgr_u and popcnt instructions.
+
+(module
+  (func (export "i32x4.min_s") (param v128 v128) (result v128) (i32x4.min_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.min_u") (param v128 v128) (result v128) (i32x4.min_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.max_s") (param v128 v128) (result v128) (i32x4.max_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.max_u") (param v128 v128) (result v128) (i32x4.max_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.abs") (param v128) (result v128) (i32x4.abs (local.get 0)))
+)
+
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -2147483648 0 0))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 0 0 0))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1 -2147483648 1 1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1 1 1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -2147483648 -1 -1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 -1 -1 -1))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2 -2147483648 2 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 2 2 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483646 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1073741824 -2147483648 1073741824 1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1073741824 65535 123456789))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1073741824 -2147483648 -1073741824 -1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1431655765 -2147483648 1431655765 1073741824))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 65535 -2147483648 65535 65535))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 65535 65535 65535))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 123456789 -2147483648 123456789 123456789))
+(assert_return (invoke "i32x4.min_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 123456789 65535 123456789))
+
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 1 1))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1 1 1 1))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1 1 1 1))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 2 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2 2 2 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2 2 2 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 2147483647 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 2147483647 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2147483647 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -2147483648 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -2147483648 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 2147483646 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483646 2147483646 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2147483646 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 1073741824 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1073741824 1073741824 1073741824 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 1073741824 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1073741824 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 1431655765 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 1431655765 1431655765 1431655765 1073741824))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1431655765 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 65535 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 65535 65535 65535 65535))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 65535 65535 65535 65535))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 123456789 2))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 123456789 123456789 123456789 123456789))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 123456789 123456789 65535 123456789))
+
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 0 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 0 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1 1 1 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 1 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -1 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2 2 2 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 2 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483647 2147483647 2147483647 2147483647))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 2147483647 2147483647 2147483647))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2147483647 2147483647 2147483647 2147483647))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483646 2147483646 2147483646 2147483646))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 2147483646 2147483646 2147483646))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 2147483646 2147483646 2147483646 2147483646))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1073741824 1073741824 1073741824 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 1073741824 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 1431655765 1073741824 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -1073741824 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 1431655765 2147483646 1431655765))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1431655765 1431655765 1431655765 1431655765))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 65535 65535 65535 65535))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 65535 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 65535 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 123456789 123456789 123456789 123456789))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 123456789 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 123456789 1431655765 123456789 123456789))
+
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1 1 -1 2))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2 2 -1 2))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483647 2147483647 -1 2147483647))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483647 2147483647))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 2147483647 2147483647 2147483647))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -2147483648 -2147483648 -1 -2147483648))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 2147483646 2147483646 -1 2147483646))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 2147483646))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 2147483646 2147483646 2147483646))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1073741824 1073741824 -1 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 1073741824 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1073741824 -1073741824 -1 -1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 1431655765 1431655765 -1 1431655765))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1431655765))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 1431655765 1431655765))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 65535 65535 -1 65535))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 65535 123456789))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 123456789 123456789 -1 123456789))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.max_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1073741824 1431655765 123456789 123456789))
+
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 1 1 2))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824))
+(assert_return (invoke "i32x4.abs" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 1073741824 1431655765 65535 123456789))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.min_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.min_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.min_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.min_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.min_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.min_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.max_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.abs (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.abs ))) "type mismatch")
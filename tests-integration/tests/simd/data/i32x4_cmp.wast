@@ -0,0 +1,417 @@
+;; Tests for the i32x4 comparison instructions.
+
+(module
+  (func (export "i32x4.eq") (param v128 v128) (result v128) (i32x4.eq (local.get 0) (local.get 1)))
+  (func (export "i32x4.ne") (param v128 v128) (result v128) (i32x4.ne (local.get 0) (local.get 1)))
+  (func (export "i32x4.lt_s") (param v128 v128) (result v128) (i32x4.lt_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.gt_s") (param v128 v128) (result v128) (i32x4.gt_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.le_s") (param v128 v128) (result v128) (i32x4.le_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.ge_s") (param v128 v128) (result v128) (i32x4.ge_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.lt_u") (param v128 v128) (result v128) (i32x4.lt_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.gt_u") (param v128 v128) (result v128) (i32x4.gt_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.le_u") (param v128 v128) (result v128) (i32x4.le_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.ge_u") (param v128 v128) (result v128) (i32x4.ge_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 -1))
+
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ne" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 0))
+
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 0))
+
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.gt_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 0))
+
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.le_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 0 -1))
+
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.ge_s" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 -1 -1))
+
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.lt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 0))
+
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.gt_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 0))
+
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 -1 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.le_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 0 -1))
+
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 0 0 0 0) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1 1 1 1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1 -1 -1 -1) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2 2 2 2) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 0 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483647 2147483647 2147483647 2147483647) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 2147483646 2147483646 2147483646 2147483646) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1073741824 1073741824 1073741824 1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1073741824 -1073741824 -1073741824 -1073741824) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 1431655765 1431655765 1431655765 1431655765) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 -1 -1 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 65535 65535 65535 65535) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 0 1 -1 2)) (v128.const i32x4 -1 -1 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 123456789 123456789 123456789 123456789) (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i32x4 0 0 -1 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.lt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.gt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.le_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.ge_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
@@ -0,0 +1,25 @@
+;; Tests for the i32x4.extadd_pairwise instructions.
+
+(module
+  (func (export "i32x4.extadd_pairwise_i16x8_s") (param v128) (result v128) (i32x4.extadd_pairwise_i16x8_s (local.get 0)))
+  (func (export "i32x4.extadd_pairwise_i16x8_u") (param v128) (result v128) (i32x4.extadd_pairwise_i16x8_u (local.get 0)))
+)
+
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 1 1 -1 -1))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 0 -1 511 0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i16x8 -12345 12345 256 255 -21846 21845 -16384 16384)) (v128.const i32x4 0 511 -1 0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i16x8 -32767 32766 -32768 32767 2 -1 1 0)) (v128.const i32x4 -1 -1 1 1))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_s" (v128.const i16x8 1 2 -32768 -32767 -16384 -21846 256 -12345)) (v128.const i32x4 3 -65535 -38230 -12089))
+
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 1 65537 65535 65535))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 65536 65535 511 65536))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 -12345 12345 256 255 -21846 21845 -16384 16384)) (v128.const i32x4 65536 511 65535 65536))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 -32767 32766 -32768 32767 2 -1 1 0)) (v128.const i32x4 65535 65535 65537 1))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 1 2 -32768 -32767 -16384 -21846 256 -12345)) (v128.const i32x4 3 65537 92842 53447))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.extadd_pairwise_i16x8_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extadd_pairwise_i16x8_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extadd_pairwise_i16x8_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extadd_pairwise_i16x8_u ))) "type mismatch")
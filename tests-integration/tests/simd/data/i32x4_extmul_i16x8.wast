@@ -0,0 +1,283 @@
+;; Tests for the i32x4.extmul instructions.
+
+(module
+  (func (export "i32x4.extmul_low_i16x8_s") (param v128 v128) (result v128) (i32x4.extmul_low_i16x8_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.extmul_low_i16x8_u") (param v128 v128) (result v128) (i32x4.extmul_low_i16x8_u (local.get 0) (local.get 1)))
+  (func (export "i32x4.extmul_high_i16x8_s") (param v128 v128) (result v128) (i32x4.extmul_high_i16x8_s (local.get 0) (local.get 1)))
+  (func (export "i32x4.extmul_high_i16x8_u") (param v128 v128) (result v128) (i32x4.extmul_high_i16x8_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 32767 -32768 32766 -32767))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 16384 -16384 21845 -21846))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 255 256 12345 -12345))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -1 1 -2))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -32767 32768 -32766 32767))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -16384 16384 -21845 21846))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -255 -256 -12345 12345))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 2 -2 4))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 65534 -65536 65532 -65534))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 32768 -32768 43690 -43692))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 510 512 24690 -24690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32767 -32767 65534))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073676289 -1073709056 1073643522 -1073676289))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536854528 -536854528 715795115 -715827882))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8355585 8388352 404508615 -404508615))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -32768 32768 -65536))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -1073709056 1073741824 -1073676288 1073709056))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -536870912 536870912 -715816960 715849728))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -8355840 -8388608 -404520960 404520960))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32766 -32766 65532))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073643522 -1073676288 1073610756 -1073643522))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536838144 -536838144 715773270 -715806036))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8355330 8388096 404496270 -404496270))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -32767 32767 -65534))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -1073676289 1073709056 -1073643522 1073676289))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -536854528 536854528 -715795115 715827882))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -8355585 -8388352 -404508615 404508615))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 16384 -16384 32768))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 536854528 -536870912 536838144 -536854528))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 268435456 -268435456 357908480 -357924864))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 4177920 4194304 202260480 -202260480))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -16384 16384 -32768))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -536854528 536870912 -536838144 536854528))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -268435456 268435456 -357908480 357924864))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -4177920 -4194304 -202260480 202260480))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 21845 -21845 43690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 715795115 -715816960 715773270 -715795115))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 357908480 -357908480 477204025 -477225870))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 5570475 5592320 269676525 -269676525))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -21846 21846 -43692))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -715827882 715849728 -715806036 715827882))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -357924864 357924864 -477225870 477247716))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -5570730 -5592576 -269688870 269688870))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 255 -255 510))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 8355585 -8355840 8355330 -8355585))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 4177920 -4177920 5570475 -5570730))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 65025 65280 3147975 -3147975))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 256 -256 512))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 8388352 -8388608 8388096 -8388352))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 4194304 -4194304 5592320 -5592576))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 65280 65536 3160320 -3160320))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 12345 -12345 24690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 404508615 -404520960 404496270 -404508615))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 202260480 -202260480 269676525 -269688870))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 3147975 3160320 152399025 -152399025))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 -12345 12345 -24690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 -404508615 404520960 -404496270 404508615))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 -202260480 202260480 -269676525 269688870))
+(assert_return (invoke "i32x4.extmul_low_i16x8_s" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 -3147975 -3160320 -152399025 152399025))
+
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 0 0 0 0 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 1 65535 2))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 32767 32768 32766 32769))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 16384 49152 21845 43690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 1 1 1 1 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 255 256 12345 53191))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 65535 -131071 131070))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 2147385345 2147450880 2147319810 -2147450881))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 1073725440 -1073790976 1431612075 -1431743146))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -1 -1 -1 -1 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 16711425 16776960 809029575 -809095111))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 2 131070 4))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 65534 65536 65532 65538))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 32768 98304 43690 87380))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 2 2 2 2 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 510 512 24690 106382))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32767 2147385345 65534))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073676289 1073709056 1073643522 1073741823))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536854528 1610563584 715795115 1431590230))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32767 32767 32767 32767 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8355585 8388352 404508615 1742909497))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32768 2147450880 65536))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073709056 1073741824 1073676288 1073774592))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536870912 1610612736 715816960 1431633920))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32768 -32768 -32768 -32768 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8355840 8388608 404520960 1742962688))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32766 2147319810 65532))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073643522 1073676288 1073610756 1073709054))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536838144 1610514432 715773270 1431546540))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 32766 32766 32766 32766 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8355330 8388096 404496270 1742856306))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 32769 -2147450881 65538))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1073741823 1073774592 1073709054 1073807361))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 536887296 1610661888 715838805 1431677610))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -32767 -32767 -32767 -32767 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 8356095 8388864 404533305 1743015879))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 16384 1073725440 32768))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 536854528 536870912 536838144 536887296))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 268435456 805306368 357908480 715816960))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 16384 16384 16384 16384 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 4177920 4194304 202260480 871481344))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 49152 -1073790976 98304))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1610563584 1610612736 1610514432 1610661888))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 805306368 -1879048192 1073725440 2147450880))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -16384 -16384 -16384 -16384 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 12533760 12582912 606781440 -1680523264))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 21845 1431612075 43690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 715795115 715816960 715773270 715838805))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 357908480 1073725440 477204025 954408050))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 21845 21845 21845 21845 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 5570475 5592320 269676525 1161957395))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 43690 -1431743146 87380))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1431590230 1431633920 1431546540 1431677610))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 715816960 2147450880 954408050 1908816100))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -21846 -21846 -21846 -21846 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 11140950 11184640 539353050 -1971052506))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 255 16711425 510))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 8355585 8355840 8355330 8356095))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 4177920 12533760 5570475 11140950))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 255 255 255 255 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 65025 65280 3147975 13563705))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 256 16776960 512))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 8388352 8388608 8388096 8388864))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 4194304 12582912 5592320 11184640))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 256 256 256 256 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 65280 65536 3160320 13616896))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 12345 809029575 24690))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 404508615 404520960 404496270 404533305))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 202260480 606781440 269676525 539353050))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 12345 12345 12345 12345 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 3147975 3160320 152399025 656642895))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 0 1 -1 2 5 5 5 5)) (v128.const i32x4 0 53191 -809095111 106382))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 32767 -32768 32766 -32767 5 5 5 5)) (v128.const i32x4 1742909497 1742962688 1742856306 1743015879))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 16384 -16384 21845 -21846 5 5 5 5)) (v128.const i32x4 871481344 -1680523264 1161957395 -1971052506))
+(assert_return (invoke "i32x4.extmul_low_i16x8_u" (v128.const i16x8 -12345 -12345 -12345 -12345 3 3 3 3) (v128.const i16x8 255 256 12345 -12345 5 5 5 5)) (v128.const i32x4 13563705 13616896 656642895 -1465684815))
+
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 32767 -32768 32766 -32767))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 16384 -16384 21845 -21846))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 255 256 12345 -12345))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -1 1 -2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -32767 32768 -32766 32767))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -16384 16384 -21845 21846))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -255 -256 -12345 12345))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 2 -2 4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 65534 -65536 65532 -65534))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 32768 -32768 43690 -43692))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 510 512 24690 -24690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32767 -32767 65534))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073676289 -1073709056 1073643522 -1073676289))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536854528 -536854528 715795115 -715827882))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8355585 8388352 404508615 -404508615))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -32768 32768 -65536))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -1073709056 1073741824 -1073676288 1073709056))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -536870912 536870912 -715816960 715849728))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -8355840 -8388608 -404520960 404520960))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32766 -32766 65532))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073643522 -1073676288 1073610756 -1073643522))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536838144 -536838144 715773270 -715806036))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8355330 8388096 404496270 -404496270))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -32767 32767 -65534))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -1073676289 1073709056 -1073643522 1073676289))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -536854528 536854528 -715795115 715827882))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -8355585 -8388352 -404508615 404508615))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 16384 -16384 32768))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 536854528 -536870912 536838144 -536854528))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 268435456 -268435456 357908480 -357924864))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 4177920 4194304 202260480 -202260480))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -16384 16384 -32768))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -536854528 536870912 -536838144 536854528))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -268435456 268435456 -357908480 357924864))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -4177920 -4194304 -202260480 202260480))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 21845 -21845 43690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 715795115 -715816960 715773270 -715795115))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 357908480 -357908480 477204025 -477225870))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 5570475 5592320 269676525 -269676525))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -21846 21846 -43692))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -715827882 715849728 -715806036 715827882))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -357924864 357924864 -477225870 477247716))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -5570730 -5592576 -269688870 269688870))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 255 -255 510))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 8355585 -8355840 8355330 -8355585))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 4177920 -4177920 5570475 -5570730))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 65025 65280 3147975 -3147975))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 256 -256 512))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 8388352 -8388608 8388096 -8388352))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 4194304 -4194304 5592320 -5592576))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 65280 65536 3160320 -3160320))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 12345 -12345 24690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 404508615 -404520960 404496270 -404508615))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 202260480 -202260480 269676525 -269688870))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 3147975 3160320 152399025 -152399025))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 -12345 12345 -24690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 -404508615 404520960 -404496270 404508615))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 -202260480 202260480 -269676525 269688870))
+(assert_return (invoke "i32x4.extmul_high_i16x8_s" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 -3147975 -3160320 -152399025 152399025))
+
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 0 0 0 0) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 1 65535 2))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 32767 32768 32766 32769))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 16384 49152 21845 43690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 1 1 1 1) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 255 256 12345 53191))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 65535 -131071 131070))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 2147385345 2147450880 2147319810 -2147450881))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 1073725440 -1073790976 1431612075 -1431743146))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -1 -1 -1 -1) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 16711425 16776960 809029575 -809095111))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 2 131070 4))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 65534 65536 65532 65538))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 32768 98304 43690 87380))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 2 2 2 2) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 510 512 24690 106382))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32767 2147385345 65534))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073676289 1073709056 1073643522 1073741823))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536854528 1610563584 715795115 1431590230))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32767 32767 32767 32767) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8355585 8388352 404508615 1742909497))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32768 2147450880 65536))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073709056 1073741824 1073676288 1073774592))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536870912 1610612736 715816960 1431633920))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32768 -32768 -32768 -32768) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8355840 8388608 404520960 1742962688))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32766 2147319810 65532))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073643522 1073676288 1073610756 1073709054))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536838144 1610514432 715773270 1431546540))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 32766 32766 32766 32766) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8355330 8388096 404496270 1742856306))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 32769 -2147450881 65538))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1073741823 1073774592 1073709054 1073807361))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 536887296 1610661888 715838805 1431677610))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -32767 -32767 -32767 -32767) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 8356095 8388864 404533305 1743015879))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 16384 1073725440 32768))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 536854528 536870912 536838144 536887296))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 268435456 805306368 357908480 715816960))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 16384 16384 16384 16384) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 4177920 4194304 202260480 871481344))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 49152 -1073790976 98304))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1610563584 1610612736 1610514432 1610661888))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 805306368 -1879048192 1073725440 2147450880))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -16384 -16384 -16384 -16384) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 12533760 12582912 606781440 -1680523264))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 21845 1431612075 43690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 715795115 715816960 715773270 715838805))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 357908480 1073725440 477204025 954408050))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 21845 21845 21845 21845) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 5570475 5592320 269676525 1161957395))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 43690 -1431743146 87380))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1431590230 1431633920 1431546540 1431677610))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 715816960 2147450880 954408050 1908816100))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -21846 -21846 -21846 -21846) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 11140950 11184640 539353050 -1971052506))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 255 16711425 510))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 8355585 8355840 8355330 8356095))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 4177920 12533760 5570475 11140950))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 255 255 255 255) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 65025 65280 3147975 13563705))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 256 16776960 512))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 8388352 8388608 8388096 8388864))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 4194304 12582912 5592320 11184640))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 256 256 256 256) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 65280 65536 3160320 13616896))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 12345 809029575 24690))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 404508615 404520960 404496270 404533305))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 202260480 606781440 269676525 539353050))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 12345 12345 12345 12345) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 3147975 3160320 152399025 656642895))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 0 1 -1 2)) (v128.const i32x4 0 53191 -809095111 106382))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 32767 -32768 32766 -32767)) (v128.const i32x4 1742909497 1742962688 1742856306 1743015879))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 16384 -16384 21845 -21846)) (v128.const i32x4 871481344 -1680523264 1161957395 -1971052506))
+(assert_return (invoke "i32x4.extmul_high_i16x8_u" (v128.const i16x8 3 3 3 3 -12345 -12345 -12345 -12345) (v128.const i16x8 5 5 5 5 255 256 12345 -12345)) (v128.const i32x4 13563705 13616896 656642895 -1465684815))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_low_i16x8_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extmul_high_i16x8_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
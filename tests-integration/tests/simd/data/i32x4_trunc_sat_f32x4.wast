@@ -0,0 +1,30 @@
+;; Tests for the i32x4.trunc_sat_f32x4 instructions, which saturate instead of trapping.
+
+(module
+  (func (export "i32x4.trunc_sat_f32x4_s") (param v128) (result v128) (i32x4.trunc_sat_f32x4_s (local.get 0)))
+  (func (export "i32x4.trunc_sat_f32x4_u") (param v128) (result v128) (i32x4.trunc_sat_f32x4_u (local.get 0)))
+)
+
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 1 -1))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x0p+0 -0x0p+0 0x1p+0 -0x1p+0)) (v128.const i32x4 0 0 1 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x1p-1 -0x1p-1 0x1p-149 -0x1p-149)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 2147483647 -2147483648))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x1p-126 -0x1p-126 0x1.fffffep+127 -0x1.fffffep+127)) (v128.const i32x4 0 0 -1 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 2147483647 -2147483648 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 inf -inf nan -nan)) (v128.const i32x4 -1 0 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const i32x4 0 6 1 -1))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 nan:0x200000 0x1.921fb6p+2 0x1.8p+0 -0x1.8p+0)) (v128.const i32x4 0 6 1 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x1.fffffep+30 0x1p+31 -0x1p+31 -0x1.000002p+31)) (v128.const i32x4 2147483520 2147483647 -2147483648 -2147483648))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x1.fffffep+30 0x1p+31 -0x1p+31 -0x1.000002p+31)) (v128.const i32x4 2147483520 -2147483648 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x1.fffffep+31 0x1p+32 -0x1.ccccccp-1 0x1.ccccccp-1)) (v128.const i32x4 2147483647 2147483647 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x1.fffffep+31 0x1p+32 -0x1.ccccccp-1 0x1.ccccccp-1)) (v128.const i32x4 -256 -1 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 0x1.2a05f2p+33 -0x1.2a05f2p+33 0x0p+0 0x0p+0)) (v128.const i32x4 2147483647 -2147483648 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 0x1.2a05f2p+33 -0x1.2a05f2p+33 0x0p+0 0x0p+0)) (v128.const i32x4 -1 0 0 0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i32x4.trunc_sat_f32x4_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.trunc_sat_f32x4_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.trunc_sat_f32x4_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.trunc_sat_f32x4_u ))) "type mismatch")
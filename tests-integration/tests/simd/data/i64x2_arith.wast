@@ -0,0 +1,181 @@
+;; Tests for the i64x2 add, sub, mul and neg instructions.
+
+(module
+  (func (export "i64x2.add") (param v128 v128) (result v128) (i64x2.add (local.get 0) (local.get 1)))
+  (func (export "i64x2.sub") (param v128 v128) (result v128) (i64x2.sub (local.get 0) (local.get 1)))
+  (func (export "i64x2.mul") (param v128 v128) (result v128) (i64x2.mul (local.get 0) (local.get 1)))
+  (func (export "i64x2.neg") (param v128) (result v128) (i64x2.neg (local.get 0)))
+)
+
+(assert_return (invoke "i64x2.add" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 1 2))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 3))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775808 -9223372036854775807))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387905 6148914691236517206))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967296 -4886718344))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 -2 1))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372036854775806 9223372036854775807))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387903 6148914691236517204))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967294 -4886718346))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 2 3))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 1 4))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775807 -9223372036854775806))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387906 6148914691236517207))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967297 -4886718343))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 9223372036854775806 -9223372036854775807))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -2 -1))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387905 -3074457345618258604))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -9223372032559808514 9223372031968057462))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 -9223372036854775808 -9223372036854775807))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 9223372036854775807 -9223372036854775806))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 -3074457345618258603))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -9223372032559808513 9223372031968057463))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 4611686018427387904 4611686018427387905))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 4611686018427387903 4611686018427387906))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -4611686018427387905 -4611686018427387904))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -9223372036854775808 -7686143364045646507))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4611686022722355199 4611686013540669559))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 6148914691236517205 6148914691236517206))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 6148914691236517204 6148914691236517207))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -3074457345618258604 -3074457345618258603))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -7686143364045646507 -6148914691236517206))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 6148914695531484500 6148914686349798860))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 4294967295 4294967296))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 4294967294 4294967297))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372032559808514 -9223372032559808513))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686022722355199 6148914695531484500))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 8589934590 -591751050))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 -4886718345 -4886718344))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 -4886718346 -4886718343))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372031968057462 9223372031968057463))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686013540669559 6148914686349798860))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -591751050 -9773436690))
+
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 1 -2))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 -6148914691236517205))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967295 4886718345))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 2 -1))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775806 -9223372036854775807))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387903 -6148914691236517204))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967294 4886718346))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -2))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -3))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775808 9223372036854775807))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387905 -6148914691236517206))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967296 4886718344))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 2 1))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 3 0))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775805 -9223372036854775806))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387902 -6148914691236517203))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967293 4886718347))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 9223372036854775807 9223372036854775806))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 -9223372036854775808 9223372036854775805))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387903 3074457345618258602))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 9223372032559808512 -9223372031968057464))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 -9223372036854775808 9223372036854775807))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 -9223372036854775807 9223372036854775806))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 1 0))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387904 3074457345618258603))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 9223372032559808513 -9223372031968057463))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 4611686018427387904 4611686018427387903))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 4611686018427387905 4611686018427387902))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -4611686018427387903 -4611686018427387904))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -1537228672809129301))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4611686014132420609 4611686023314106249))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 6148914691236517205 6148914691236517204))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 6148914691236517206 6148914691236517203))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -3074457345618258602 -3074457345618258603))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 1537228672809129301 0))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 6148914686941549910 6148914696123235550))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 4294967295 4294967294))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 4294967296 4294967293))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372032559808512 -9223372032559808513))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686014132420609 -6148914686941549910))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 9181685640))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 -4886718345 -4886718346))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 -4886718344 -4886718347))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372031968057464 9223372031968057463))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686023314106249 -6148914696123235550))
+(assert_return (invoke "i64x2.sub" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -9181685640 0))
+
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967295 -4886718345))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 1 -2))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 -6148914691236517205))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967295 4886718345))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 -2 4))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -2 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -9223372036854775808 -6148914691236517206))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 8589934590 -9773436690))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 0 9223372036854775807))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 -9223372036854775807 -2))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 1 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 3074457345618258603))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 9223372032559808513 -9223372031968057463))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775808 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 0 4611686018427387904))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 -4611686018427387904 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -4611686018427387904 0))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 4611686018427387904))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4611686018427387904 -4611686018427387904))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 0 6148914691236517205))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 -6148914691236517205 -6148914691236517206))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 3074457345618258603 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387904 -8198552921648689607))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1431655765 1628906115))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 0 4294967295))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 -4294967295 8589934590))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372032559808513 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 -1431655765))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -8589934591 -2541551397941975159))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 0 -4886718345))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 4886718345 -9773436690))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372031968057463 -9223372036854775808))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 1628906115))
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -2541551397941975159 5433272109649987409))
+
+(assert_return (invoke "i64x2.neg" (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.neg" (v128.const i64x2 -1 2)) (v128.const i64x2 1 -2))
+(assert_return (invoke "i64x2.neg" (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.neg" (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -4611686018427387904 -6148914691236517205))
+(assert_return (invoke "i64x2.neg" (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -4294967295 4886718345))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i64x2.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.mul (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.mul (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.mul (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.neg (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.neg ))) "type mismatch")
@@ -0,0 +1,16 @@
+;; Tests for the i64x2 abs, min, max, avgr_u and popcnt instructions.
+
+(module
+  (func (export "i64x2.abs") (param v128) (result v128) (i64x2.abs (local.get 0)))
+)
+
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 0 1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 -1 2)) (v128.const i64x2 1 2))
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 9223372036854775807 -9223372036854775808))
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 4611686018427387904 6148914691236517205))
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 4294967295 4886718345))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i64x2.abs (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.abs ))) "type mismatch")
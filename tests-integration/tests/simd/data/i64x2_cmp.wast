@@ -0,0 +1,343 @@
+;; Tests for the i64x2 comparison instructions.
+
+(module
+  (func (export "i64x2.eq") (param v128 v128) (result v128) (i64x2.eq (local.get 0) (local.get 1)))
+  (func (export "i64x2.ne") (param v128 v128) (result v128) (i64x2.ne (local.get 0) (local.get 1)))
+  (func (export "i64x2.lt_s") (param v128 v128) (result v128) (i64x2.lt_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.gt_s") (param v128 v128) (result v128) (i64x2.gt_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.le_s") (param v128 v128) (result v128) (i64x2.le_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.ge_s") (param v128 v128) (result v128) (i64x2.ge_s (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.eq" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ne" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.lt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.le_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 0 0) (v128.const i64x2 0 1)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 0 0) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 0 0) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 0 0) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 0 0) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 1 1) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 1 1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 1 1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 1 1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 1 1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -1 -1) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -1 -1) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -1 -1) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -1 -1) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 2 2) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 2 2) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 2 2) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 2 2) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 2 2) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 9223372036854775807 9223372036854775807) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -9223372036854775808 -9223372036854775808) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4611686018427387904 4611686018427387904) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 6148914691236517205 6148914691236517205) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 0 1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 -1 2)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 4294967295 4294967295) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 9223372036854775807 -9223372036854775808)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4611686018427387904 6148914691236517205)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.ge_s" (v128.const i64x2 -4886718345 -4886718345) (v128.const i64x2 4294967295 -4886718345)) (v128.const i64x2 0 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i64x2.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.lt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.lt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.lt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.gt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.gt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.gt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.le_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.le_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.le_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ge_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ge_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.ge_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+
+;; There are no unsigned comparisons of i64x2 lanes.
+(assert_malformed (module quote "(func (result v128) (i64x2.lt_u (v128.const i64x2 0 0) (v128.const i64x2 0 0)))") "unknown operator")
+(assert_malformed (module quote "(func (result v128) (i64x2.gt_u (v128.const i64x2 0 0) (v128.const i64x2 0 0)))") "unknown operator")
+(assert_malformed (module quote "(func (result v128) (i64x2.le_u (v128.const i64x2 0 0) (v128.const i64x2 0 0)))") "unknown operator")
+(assert_malformed (module quote "(func (result v128) (i64x2.ge_u (v128.const i64x2 0 0) (v128.const i64x2 0 0)))") "unknown operator")
@@ -0,0 +1,315 @@
+;; Tests for the i64x2.extmul instructions.
+
+(module
+  (func (export "i64x2.extmul_low_i32x4_s") (param v128 v128) (result v128) (i64x2.extmul_low_i32x4_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.extmul_low_i32x4_u") (param v128 v128) (result v128) (i64x2.extmul_low_i32x4_u (local.get 0) (local.get 1)))
+  (func (export "i64x2.extmul_high_i32x4_s") (param v128 v128) (result v128) (i64x2.extmul_high_i32x4_s (local.get 0) (local.get 1)))
+  (func (export "i64x2.extmul_high_i32x4_u") (param v128 v128) (result v128) (i64x2.extmul_high_i32x4_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 0 0 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 2147483647 -2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -1073741824 1431655765))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1 1 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 65535 123456789))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 1 -2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 -2147483647 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 -2147483646 -1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 1073741824 -1431655765))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 -65535 -123456789))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -2 4))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4294967294 -4294967296))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4294967292 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -2147483648 2863311530))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2 2 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 131070 246913578))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2147483647))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -2147483647 4294967294))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4611686014132420609 -4611686016279904256))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4611686011984936962 2305843008139952128))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -2305843008139952128 3074457343470774955))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 140735340806145 265121435488629483))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 -2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 2147483648 -4294967296))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 -4611686016279904256 4611686018427387904))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 -4611686014132420608 -2305843009213693952))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 2305843009213693952 -3074457344902430720))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 -140735340871680 -265121435612086272))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2147483646))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -2147483646 4294967292))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4611686011984936962 -4611686014132420608))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4611686009837453316 2305843007066210304))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -2305843007066210304 3074457342039119190))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 140735340740610 265121435365172694))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -1073741824 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 2305843008139952128 -2305843009213693952))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 2305843007066210304 1152921504606846976))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -1152921504606846976 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 70367670435840 132560717806043136))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 -1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 1073741824 -2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 -2305843008139952128 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 -2305843007066210304 -1152921504606846976))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 1152921504606846976 -1537228672451215360))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 -70367670435840 -132560717806043136))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1431655765))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -1431655765 2863311530))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 3074457343470774955 -3074457344902430720))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 3074457342039119190 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -1537228672451215360 2049638229457735225))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 93823560559275 176747623700238585))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 65535))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -65535 131070))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 140735340806145 -140735340871680))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 140735340740610 70367670435840))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -70367670435840 93823560559275))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 4294836225 8090740667115))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 123456789))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -123456789 246913578))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 265121435488629483 -265121435612086272))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 265121435365172694 132560717806043136))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -132560717806043136 176747623700238585))
+(assert_return (invoke "i64x2.extmul_low_i32x4_s" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 8090740667115 15241578750190521))
+
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 0 0 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 4294967295 2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 2147483647 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 3221225472 1431655765))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1 1 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 65535 123456789))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 4294967295))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -8589934591 8589934590))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 9223372030412324865 9223372034707292160))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 9223372026117357570 4611686017353646080))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -4611686021648613376 6148914688373205675))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1 -1 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 281470681677825 530242871100715755))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 8589934590 4))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4294967294 4294967296))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4294967292 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 6442450944 2863311530))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2 2 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 131070 246913578))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2147483647))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 9223372030412324865 4294967294))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4611686014132420609 4611686016279904256))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4611686011984936962 2305843008139952128))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 6917529024419856384 3074457343470774955))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483647 2147483647 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 140735340806145 265121435488629483))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 9223372034707292160 4294967296))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4611686016279904256 4611686018427387904))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4611686014132420608 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 6917529027641081856 3074457344902430720))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -2147483648 -2147483648 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 140735340871680 265121435612086272))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 2147483646))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 9223372026117357570 4294967292))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 4611686011984936962 4611686014132420608))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 4611686009837453316 2305843007066210304))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 6917529021198630912 3074457342039119190))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 2147483646 2147483646 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 140735340740610 265121435365172694))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1073741824))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 4611686017353646080 2147483648))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 2305843008139952128 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 2305843007066210304 1152921504606846976))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 3458764513820540928 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1073741824 1073741824 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 70367670435840 132560717806043136))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 3221225472))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 -4611686021648613376 6442450944))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 6917529024419856384 6917529027641081856))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 6917529021198630912 3458764513820540928))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 -8070450532247928832 4611686017353646080))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 -1073741824 -1073741824 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 211103011307520 397682153418129408))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 1431655765))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 6148914688373205675 2863311530))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 3074457343470774955 3074457344902430720))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 3074457342039119190 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 4611686017353646080 2049638229457735225))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 1431655765 1431655765 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 93823560559275 176747623700238585))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 65535))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 281470681677825 131070))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 140735340806145 140735340871680))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 140735340740610 70367670435840))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 211103011307520 93823560559275))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 65535 65535 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 4294836225 8090740667115))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 0 1 5 5)) (v128.const i64x2 0 123456789))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 -1 2 5 5)) (v128.const i64x2 530242871100715755 246913578))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 2147483647 -2147483648 5 5)) (v128.const i64x2 265121435488629483 265121435612086272))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 2147483646 1073741824 5 5)) (v128.const i64x2 265121435365172694 132560717806043136))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 -1073741824 1431655765 5 5)) (v128.const i64x2 397682153418129408 176747623700238585))
+(assert_return (invoke "i64x2.extmul_low_i32x4_u" (v128.const i32x4 123456789 123456789 3 3) (v128.const i32x4 65535 123456789 5 5)) (v128.const i64x2 8090740667115 15241578750190521))
+
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 2147483647 -2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -1073741824 1431655765))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 65535 123456789))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 -1))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 1 -2))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 -2147483647 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 -2147483646 -1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 1073741824 -1431655765))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 -65535 -123456789))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -2 4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4294967294 -4294967296))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4294967292 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -2147483648 2863311530))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 131070 246913578))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2147483647))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -2147483647 4294967294))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4611686014132420609 -4611686016279904256))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4611686011984936962 2305843008139952128))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -2305843008139952128 3074457343470774955))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 140735340806145 265121435488629483))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 -2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 2147483648 -4294967296))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 -4611686016279904256 4611686018427387904))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 -4611686014132420608 -2305843009213693952))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 2305843009213693952 -3074457344902430720))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 -140735340871680 -265121435612086272))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2147483646))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -2147483646 4294967292))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4611686011984936962 -4611686014132420608))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4611686009837453316 2305843007066210304))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -2305843007066210304 3074457342039119190))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 140735340740610 265121435365172694))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -1073741824 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 2305843008139952128 -2305843009213693952))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 2305843007066210304 1152921504606846976))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -1152921504606846976 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 70367670435840 132560717806043136))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 -1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 1073741824 -2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 -2305843008139952128 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 -2305843007066210304 -1152921504606846976))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 1152921504606846976 -1537228672451215360))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 -70367670435840 -132560717806043136))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1431655765))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -1431655765 2863311530))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 3074457343470774955 -3074457344902430720))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 3074457342039119190 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -1537228672451215360 2049638229457735225))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 93823560559275 176747623700238585))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 65535))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -65535 131070))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 140735340806145 -140735340871680))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 140735340740610 70367670435840))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -70367670435840 93823560559275))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 4294836225 8090740667115))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 123456789))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -123456789 246913578))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 265121435488629483 -265121435612086272))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 265121435365172694 132560717806043136))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -132560717806043136 176747623700238585))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 8090740667115 15241578750190521))
+
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 0 0) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 4294967295 2))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 2147483647 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 3221225472 1431655765))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1 1) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 65535 123456789))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 4294967295))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -8589934591 8589934590))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 9223372030412324865 9223372034707292160))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 9223372026117357570 4611686017353646080))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -4611686021648613376 6148914688373205675))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1 -1) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 281470681677825 530242871100715755))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 8589934590 4))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4294967294 4294967296))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4294967292 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 6442450944 2863311530))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2 2) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 131070 246913578))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2147483647))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 9223372030412324865 4294967294))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4611686014132420609 4611686016279904256))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4611686011984936962 2305843008139952128))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 6917529024419856384 3074457343470774955))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483647 2147483647) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 140735340806145 265121435488629483))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 9223372034707292160 4294967296))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4611686016279904256 4611686018427387904))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4611686014132420608 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 6917529027641081856 3074457344902430720))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -2147483648 -2147483648) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 140735340871680 265121435612086272))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 2147483646))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 9223372026117357570 4294967292))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 4611686011984936962 4611686014132420608))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 4611686009837453316 2305843007066210304))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 6917529021198630912 3074457342039119190))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 2147483646 2147483646) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 140735340740610 265121435365172694))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1073741824))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 4611686017353646080 2147483648))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 2305843008139952128 2305843009213693952))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 2305843007066210304 1152921504606846976))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 3458764513820540928 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1073741824 1073741824) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 70367670435840 132560717806043136))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 3221225472))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 -4611686021648613376 6442450944))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 6917529024419856384 6917529027641081856))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 6917529021198630912 3458764513820540928))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 -8070450532247928832 4611686017353646080))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 -1073741824 -1073741824) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 211103011307520 397682153418129408))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 1431655765))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 6148914688373205675 2863311530))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 3074457343470774955 3074457344902430720))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 3074457342039119190 1537228672451215360))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 4611686017353646080 2049638229457735225))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 1431655765 1431655765) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 93823560559275 176747623700238585))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 65535))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 281470681677825 131070))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 140735340806145 140735340871680))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 140735340740610 70367670435840))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 211103011307520 93823560559275))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 65535 65535) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 4294836225 8090740667115))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 0 1)) (v128.const i64x2 0 123456789))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 -1 2)) (v128.const i64x2 530242871100715755 246913578))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 2147483647 -2147483648)) (v128.const i64x2 265121435488629483 265121435612086272))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 2147483646 1073741824)) (v128.const i64x2 265121435365172694 132560717806043136))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 -1073741824 1431655765)) (v128.const i64x2 397682153418129408 176747623700238585))
+(assert_return (invoke "i64x2.extmul_high_i32x4_u" (v128.const i32x4 3 3 123456789 123456789) (v128.const i32x4 5 5 65535 123456789)) (v128.const i64x2 8090740667115 15241578750190521))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_low_i32x4_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extmul_high_i32x4_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
@@ -0,0 +1,57 @@
+;; Tests for the i8x16 add, sub, mul and neg instructions.
+
+(module
+  (func (export "i8x16.add") (param v128 v128) (result v128) (i8x16.add (local.get 0) (local.get 1)))
+  (func (export "i8x16.sub") (param v128 v128) (result v128) (i8x16.sub (local.get 0) (local.get 1)))
+  (func (export "i8x16.neg") (param v128) (result v128) (i8x16.neg (local.get 0)))
+)
+
+(assert_return (invoke "i8x16.add" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 2 0 3 -1 -128 -127 127 -126 65 -63 86 -85 101 -99 4))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 -2 1 -3 126 127 125 -128 63 -65 84 -87 99 -101 2))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 3 1 4 0 -127 -126 -128 -125 66 -62 87 -84 102 -98 5))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -1 -3 0 -4 125 126 124 127 62 -66 83 -88 98 -102 1))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 -128 126 -127 125 -2 -1 -3 0 -65 63 -44 41 -29 27 -126))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -127 127 -126 126 -1 0 -2 1 -64 64 -43 42 -28 28 -125))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 127 125 -128 124 -3 -2 -4 -1 -66 62 -45 40 -30 26 -127))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -126 -128 -125 127 0 1 -1 2 -63 65 -42 43 -27 29 -124))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 65 63 66 62 -65 -64 -66 -63 -128 0 -107 -22 -92 -36 67))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -63 -65 -62 -66 63 64 62 65 0 -128 21 106 36 92 -61))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 86 84 87 83 -44 -43 -45 -42 -107 21 -86 -1 -71 -15 88))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -85 -87 -84 -88 41 42 40 43 -22 106 -1 84 14 70 -83))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 101 99 102 98 -29 -28 -30 -27 -92 36 -71 14 -56 0 103))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -99 -101 -98 -102 27 28 26 29 -36 92 -15 70 0 56 -97))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 4 2 5 1 -126 -125 -127 -124 67 -61 88 -83 103 -97 6))
+
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 1 -2 2 -127 -128 -126 127 -64 64 -85 86 -100 100 -3))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 0 2 -1 3 -126 -127 -125 -128 -63 65 -84 87 -99 101 -2))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -2 0 -3 1 -128 127 -127 126 -65 63 -86 85 -101 99 -4))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 1 3 0 4 -125 -126 -124 -127 -62 66 -83 88 -98 102 -1))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -3 -1 -4 0 127 126 -128 125 -66 62 -87 84 -102 98 -5))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 126 -128 125 -127 0 -1 1 -2 63 -65 42 -43 27 -29 124))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 127 -127 126 -126 1 0 2 -1 64 -64 43 -42 28 -28 125))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 125 127 124 -128 -1 -2 0 -3 62 -66 41 -44 26 -30 123))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -128 -126 127 -125 2 1 3 0 65 -63 44 -41 29 -27 126))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 63 65 62 66 -63 -64 -62 -65 0 -128 -21 -106 -36 -92 61))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -65 -63 -66 -62 65 64 66 63 -128 0 107 22 92 36 -67))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 84 86 83 87 -42 -43 -41 -44 21 -107 0 -85 -15 -71 82))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -87 -85 -88 -84 43 42 44 41 106 -22 85 0 70 14 -89))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 99 101 98 102 -27 -28 -26 -29 36 -92 15 -70 0 -56 97))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -101 -99 -102 -98 29 28 30 27 92 -36 71 -14 56 0 -103))
+(assert_return (invoke "i8x16.sub" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 2 4 1 5 -124 -125 -123 -126 -61 67 -82 89 -97 103 0))
+
+(assert_return (invoke "i8x16.neg" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 1 -2 2 -127 -128 -126 127 -64 64 -85 86 -100 100 -3))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.add (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.neg (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.neg ))) "type mismatch")
+
+;; There's no i8x16.mul.
+(assert_malformed (module quote "(func (result v128) (i8x16.mul (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))") "unknown operator")
@@ -0,0 +1,122 @@
+;; Tests for the i8x16 abs, min, max, avgr_u and popcnt instructions.
+
+(module
+  (func (export "i8x16.min_s") (param v128 v128) (result v128) (i8x16.min_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.min_u") (param v128 v128) (result v128) (i8x16.min_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.max_s") (param v128 v128) (result v128) (i8x16.max_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.max_u") (param v128 v128) (result v128) (i8x16.max_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.avgr_u") (param v128 v128) (result v128) (i8x16.avgr_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.abs") (param v128) (result v128) (i8x16.abs (local.get 0)))
+  (func (export "i8x16.popcnt") (param v128) (result v128) (i8x16.popcnt (local.get 0)))
+)
+
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -2 0 -128 0 -127 0 -64 0 -86 0 -100 0))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 1 -2 1 -128 1 -127 1 -64 1 -86 1 -100 1))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -2 -1 -128 -1 -127 -1 -64 -1 -86 -1 -100 -1))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 2 -128 2 -127 2 -64 2 -86 2 -100 2))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -2 -2 -2 -2 -2 -128 -2 -127 -2 -64 -2 -86 -2 -100 -2))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 126 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -127 -127 -127 -127 -127 -128 -127 -127 -127 -127 -127 -127 -127 -127 -127))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 64 -128 64 -127 64 -64 64 -86 64 -100 3))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -64 -64 -64 -64 -64 -128 -64 -127 -64 -64 -64 -86 -64 -100 -64))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 85 -128 85 -127 64 -64 85 -86 85 -100 3))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -86 -86 -86 -86 -86 -128 -86 -127 -86 -86 -86 -86 -86 -100 -86))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 100 -128 100 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -100 -100 -100 -100 -100 -128 -100 -127 -100 -100 -100 -100 -100 -100 -100))
+(assert_return (invoke "i8x16.min_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 3 -128 3 -127 3 -64 3 -86 3 -100 3))
+
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 2 2 2 2 2 2 2 2 2 2 2 2 2 2))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -2 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 127 2 127 127 127 126 127 64 127 85 127 100 127 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -128 2 -128 127 -128 126 -128 64 -128 85 -128 100 -128 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 126 2 126 126 126 126 126 64 126 85 126 100 126 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -127 2 -127 127 -128 126 -127 64 -127 85 -127 100 -127 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 64 2 64 64 64 64 64 64 64 64 64 64 64 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -64 2 -64 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 85 2 85 85 85 85 85 64 85 85 85 85 85 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -86 2 -86 127 -128 126 -127 64 -86 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 100 2 100 100 100 100 100 64 100 85 100 100 100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -100 2 -100 127 -128 126 -127 64 -100 85 -100 100 -100 3))
+(assert_return (invoke "i8x16.min_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 3 2 3 3 3 3 3 3 3 3 3 3 3 3))
+
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 0 2 0 127 0 126 0 64 0 85 0 100 0 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 1 1 2 1 127 1 126 1 64 1 85 1 100 1 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -1 127 -1 126 -1 64 -1 85 -1 100 -1 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 2 2 2 2 127 2 126 2 64 2 85 2 100 2 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -2 126 -2 64 -2 85 -2 100 -2 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 126 126 126 126 127 126 126 126 126 126 126 126 126 126 126))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -127 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 64 64 64 64 127 64 126 64 64 64 85 64 100 64 64))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -64 126 -64 64 -64 85 -64 100 -64 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 85 85 85 85 127 85 126 85 85 85 85 85 100 85 85))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -86 126 -86 64 -64 85 -86 100 -86 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 100 100 100 100 127 100 126 100 100 100 100 100 100 100 100))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -100 126 -100 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 3 3 3 3 127 3 126 3 64 3 85 3 100 3 3))
+
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 2 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -2 -1 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 127 -1 127 -2 127 -128 127 -127 127 -64 127 -86 127 -100 127))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -128 -1 -128 -2 -128 -128 -128 -127 -128 -64 -128 -86 -128 -100 -128))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 126 -1 126 -2 127 -128 126 -127 126 -64 126 -86 126 -100 126))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -127 -1 -127 -2 -127 -127 -127 -127 -127 -64 -127 -86 -127 -100 -127))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 64 -1 64 -2 127 -128 126 -127 64 -64 85 -86 100 -100 64))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -64 -1 -64 -2 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 85 -1 85 -2 127 -128 126 -127 85 -64 85 -86 100 -100 85))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -86 -1 -86 -2 -86 -86 -86 -86 -86 -64 -86 -86 -86 -86 -86))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 100 -1 100 -2 127 -128 126 -127 100 -64 100 -86 100 -100 100))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -100 -1 -100 -2 -100 -100 -100 -100 -100 -64 -100 -86 -100 -100 -100))
+(assert_return (invoke "i8x16.max_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 3 -1 3 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -128 1 127 64 64 63 65 32 96 43 85 50 78 2))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 1 -128 2 -128 64 65 64 65 33 97 43 86 51 79 2))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -128 -1 -127 -1 -65 -64 -65 -64 -96 -32 -86 -43 -78 -50 -127))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 2 -127 2 -128 65 65 64 66 33 97 44 86 51 79 3))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 -128 -1 -128 -2 -65 -65 -66 -64 -97 -33 -86 -44 -79 -51 -127))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 64 -65 65 -65 127 -128 127 -128 96 -96 106 -107 114 -114 65))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 65 -64 65 -65 -128 -128 127 -127 96 -96 107 -107 114 -114 66))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 63 64 -65 64 -66 127 127 126 -128 95 -97 106 -108 113 -115 65))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 65 65 -64 66 -64 -128 -127 -128 -127 97 -95 107 -106 115 -113 66))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 32 33 -96 33 -97 96 96 95 97 64 -128 75 117 82 110 34))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 96 97 -32 97 -33 -96 -96 -97 -95 -128 -64 -117 -75 -110 -82 98))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 43 43 -86 44 -86 106 107 106 107 75 -117 85 -128 93 121 44))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 86 -43 86 -44 -107 -107 -108 -106 117 -75 -128 -86 -121 -93 87))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 50 51 -78 51 -79 114 114 113 115 82 -110 93 -121 100 -128 52))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 78 79 -50 79 -51 -114 -114 -115 -113 110 -82 121 -93 -128 -100 80))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 2 -127 3 -127 65 66 65 66 34 98 44 87 52 80 3))
+
+(assert_return (invoke "i8x16.abs" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 1 2 2 127 -128 126 127 64 64 85 86 100 100 3))
+
+(assert_return (invoke "i8x16.popcnt" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 8 1 7 7 1 6 2 1 2 4 4 3 4 2))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.min_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.min_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.min_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.min_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.min_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.min_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.max_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.avgr_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.avgr_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.avgr_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.abs (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.abs ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.popcnt (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.popcnt ))) "type mismatch")
@@ -0,0 +1,217 @@
+;; Tests for the i8x16 comparison instructions.
+
+(module
+  (func (export "i8x16.eq") (param v128 v128) (result v128) (i8x16.eq (local.get 0) (local.get 1)))
+  (func (export "i8x16.ne") (param v128 v128) (result v128) (i8x16.ne (local.get 0) (local.get 1)))
+  (func (export "i8x16.lt_s") (param v128 v128) (result v128) (i8x16.lt_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.gt_s") (param v128 v128) (result v128) (i8x16.gt_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.le_s") (param v128 v128) (result v128) (i8x16.le_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.ge_s") (param v128 v128) (result v128) (i8x16.ge_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.lt_u") (param v128 v128) (result v128) (i8x16.lt_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.gt_u") (param v128 v128) (result v128) (i8x16.gt_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.le_u") (param v128 v128) (result v128) (i8x16.le_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.ge_u") (param v128 v128) (result v128) (i8x16.ge_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 -1 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 -1 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 -1 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 -1 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 -1 0 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 -1 0 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 -1 0))
+(assert_return (invoke "i8x16.eq" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 -1))
+
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i8x16.ne" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0))
+
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 -1 0 -1 0 0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i8x16.lt_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 0))
+
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 -1))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1 -1))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 0 0 -1 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 0))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 -1 0 -1 0 0))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 0 0 0 0 -1 0 0))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1 -1))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 -1 0 -1 0 0 0 0 0 -1 0))
+(assert_return (invoke "i8x16.ge_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 -1))
+
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 -1 0 0 0))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0))
+
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 0 0 0 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.gt_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0 0))
+
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 -1 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 0 0 0 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 -1 0 0 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 0 0 0 0 0 -1 0 -1 0 -1 0))
+(assert_return (invoke "i8x16.le_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 -1 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 0 0 0 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 -1 0 0 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 -1 0 -1 0 -1 0 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 -1 -1 -1 -1 -1 0 -1 0 -1 -1 -1))
+(assert_return (invoke "i8x16.ge_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 0 -1 0 0 0 0 0 0 0 0 0 0 0 -1))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.eq (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.eq (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.eq (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ne (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ne (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ne (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.lt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.gt_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.le_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.ge_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
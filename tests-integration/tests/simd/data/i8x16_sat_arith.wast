@@ -0,0 +1,91 @@
+;; Tests for the i8x16 saturating add and sub instructions.
+
+(module
+  (func (export "i8x16.add_sat_s") (param v128 v128) (result v128) (i8x16.add_sat_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.add_sat_u") (param v128 v128) (result v128) (i8x16.add_sat_u (local.get 0) (local.get 1)))
+  (func (export "i8x16.sub_sat_s") (param v128 v128) (result v128) (i8x16.sub_sat_s (local.get 0) (local.get 1)))
+  (func (export "i8x16.sub_sat_u") (param v128 v128) (result v128) (i8x16.sub_sat_u (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 2 0 3 -1 127 -127 127 -126 65 -63 86 -85 101 -99 4))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 0 -2 1 -3 126 -128 125 -128 63 -65 84 -87 99 -101 2))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 3 1 4 0 127 -126 127 -125 66 -62 87 -84 102 -98 5))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -1 -3 0 -4 125 -128 124 -128 62 -66 83 -88 98 -102 1))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 127 126 127 125 127 -1 127 0 127 63 127 41 127 27 127))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -127 -128 -126 -128 -1 -128 -2 -128 -64 -128 -43 -128 -28 -128 -125))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 127 125 127 124 127 -2 127 -1 127 62 127 40 127 26 127))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -126 -128 -125 -128 0 -128 -1 -128 -63 -128 -42 -128 -27 -128 -124))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 65 63 66 62 127 -64 127 -63 127 0 127 -22 127 -36 67))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -63 -65 -62 -66 63 -128 62 -128 0 -128 21 -128 36 -128 -61))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 86 84 87 83 127 -43 127 -42 127 21 127 -1 127 -15 88))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -85 -87 -84 -88 41 -128 40 -128 -22 -128 -1 -128 14 -128 -83))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 101 99 102 98 127 -28 127 -27 127 36 127 14 127 0 103))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -99 -101 -98 -102 27 -128 26 -128 -36 -128 -15 -128 0 -128 -97))
+(assert_return (invoke "i8x16.add_sat_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 4 2 5 1 127 -125 127 -124 67 -61 88 -83 103 -97 6))
+
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 2 -1 3 -1 -128 -127 127 -126 65 -63 86 -85 101 -99 4))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 3 -1 4 -1 -127 -126 -128 -125 66 -62 87 -84 102 -98 5))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 -128 -1 -127 -1 -2 -1 -3 -1 -65 -1 -44 -1 -29 -1 -126))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -127 -1 -126 -1 -1 -1 -2 -1 -64 -1 -43 -1 -28 -1 -125))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 127 -1 -128 -1 -3 -2 -4 -1 -66 -1 -45 -1 -30 -1 -127))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -126 -1 -125 -1 -1 -1 -1 -1 -63 -1 -42 -1 -27 -1 -124))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 65 -1 66 -1 -65 -64 -66 -63 -128 -1 -107 -22 -92 -36 67))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -63 -1 -62 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -61))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 86 -1 87 -1 -44 -43 -45 -42 -107 -1 -86 -1 -71 -15 88))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -85 -1 -84 -1 -1 -1 -1 -1 -22 -1 -1 -1 -1 -1 -83))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 101 -1 102 -1 -29 -28 -30 -27 -92 -1 -71 -1 -56 -1 103))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -99 -1 -98 -1 -1 -1 -1 -1 -36 -1 -15 -1 -1 -1 -97))
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 4 -1 5 -1 -126 -125 -127 -124 67 -61 88 -83 103 -97 6))
+
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 -1 1 -2 2 -127 127 -126 127 -64 64 -85 86 -100 100 -3))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 0 2 -1 3 -126 127 -125 127 -63 65 -84 87 -99 101 -2))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -2 0 -3 1 -128 127 -127 126 -65 63 -86 85 -101 99 -4))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 1 3 0 4 -125 127 -124 127 -62 66 -83 88 -98 102 -1))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -3 -1 -4 0 -128 126 -128 125 -66 62 -87 84 -102 98 -5))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 126 127 125 127 0 127 1 127 63 127 42 127 27 127 124))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 -128 -127 -128 -126 -128 0 -128 -1 -128 -64 -128 -42 -128 -28 -128))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 125 127 124 127 -1 127 0 127 62 127 41 127 26 127 123))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -128 -126 -128 -125 -128 1 -128 0 -128 -63 -128 -41 -128 -27 -128))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 63 65 62 66 -63 127 -62 127 0 127 -21 127 -36 127 61))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -65 -63 -66 -62 -128 64 -128 63 -128 0 -128 22 -128 36 -67))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 84 86 83 87 -42 127 -41 127 21 127 0 127 -15 127 82))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -87 -85 -88 -84 -128 42 -128 41 -128 -22 -128 0 -128 14 -89))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 99 101 98 102 -27 127 -26 127 36 127 15 127 0 127 97))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -101 -99 -102 -98 -128 28 -128 27 -128 -36 -128 -14 -128 0 -103))
+(assert_return (invoke "i8x16.sub_sat_s" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 2 4 1 5 -124 127 -123 127 -61 67 -82 89 -97 103 0))
+
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -1 -2 0 -3 1 -128 127 -127 126 -65 63 -86 85 -101 99 -4))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 2 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2 -2) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -2 -3 0 -4 0 127 126 -128 125 -66 62 -87 84 -102 98 -5))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 127 126 0 125 0 0 0 1 0 63 0 42 0 27 0 124))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -128 127 0 126 0 1 0 2 0 64 0 43 0 28 0 125))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126 126) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 126 125 0 124 0 0 0 0 0 62 0 41 0 26 0 123))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127 -127) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -127 -128 0 127 0 2 1 3 0 65 0 44 0 29 0 126))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64 64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 64 63 0 62 0 0 0 0 0 0 0 0 0 0 0 61))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64 -64) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -64 -65 0 -66 0 65 64 66 63 -128 0 107 22 92 36 -67))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85 85) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 85 84 0 83 0 0 0 0 0 21 0 0 0 0 0 82))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86 -86) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -86 -87 0 -88 0 43 42 44 41 106 0 85 0 70 14 -89))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100 100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 100 99 0 98 0 0 0 0 0 36 0 15 0 0 0 97))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100 -100) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 -100 -101 0 -102 0 29 28 30 27 92 0 71 0 56 0 -103))
+(assert_return (invoke "i8x16.sub_sat_u" (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3) (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i8x16 3 2 0 1 0 0 0 0 0 0 0 0 0 0 0 0))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.add_sat_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add_sat_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add_sat_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add_sat_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add_sat_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.add_sat_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_s (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_s (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_s (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_u (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_u (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.sub_sat_u (v128.const i32x4 0 0 0 0)))) "type mismatch")
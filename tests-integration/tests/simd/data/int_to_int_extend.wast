@@ -0,0 +1,79 @@
+;; Tests for the extend_low and extend_high instructions.
+
+(module
+  (func (export "i16x8.extend_low_i8x16_s") (param v128) (result v128) (i16x8.extend_low_i8x16_s (local.get 0)))
+  (func (export "i16x8.extend_low_i8x16_u") (param v128) (result v128) (i16x8.extend_low_i8x16_u (local.get 0)))
+  (func (export "i16x8.extend_high_i8x16_s") (param v128) (result v128) (i16x8.extend_high_i8x16_s (local.get 0)))
+  (func (export "i16x8.extend_high_i8x16_u") (param v128) (result v128) (i16x8.extend_high_i8x16_u (local.get 0)))
+  (func (export "i32x4.extend_low_i16x8_s") (param v128) (result v128) (i32x4.extend_low_i16x8_s (local.get 0)))
+  (func (export "i32x4.extend_low_i16x8_u") (param v128) (result v128) (i32x4.extend_low_i16x8_u (local.get 0)))
+  (func (export "i32x4.extend_high_i16x8_s") (param v128) (result v128) (i32x4.extend_high_i16x8_s (local.get 0)))
+  (func (export "i32x4.extend_high_i16x8_u") (param v128) (result v128) (i32x4.extend_high_i16x8_u (local.get 0)))
+  (func (export "i64x2.extend_low_i32x4_s") (param v128) (result v128) (i64x2.extend_low_i32x4_s (local.get 0)))
+  (func (export "i64x2.extend_low_i32x4_u") (param v128) (result v128) (i64x2.extend_low_i32x4_u (local.get 0)))
+  (func (export "i64x2.extend_high_i32x4_s") (param v128) (result v128) (i64x2.extend_high_i32x4_s (local.get 0)))
+  (func (export "i64x2.extend_high_i32x4_u") (param v128) (result v128) (i64x2.extend_high_i32x4_u (local.get 0)))
+)
+
+(assert_return (invoke "i16x8.extend_low_i8x16_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 0 1 -1 2 -2 127 -128 126))
+
+(assert_return (invoke "i16x8.extend_low_i8x16_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 0 1 255 2 254 127 128 126))
+
+(assert_return (invoke "i16x8.extend_high_i8x16_s" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 -127 64 -64 85 -86 100 -100 3))
+
+(assert_return (invoke "i16x8.extend_high_i8x16_u" (v128.const i8x16 0 1 -1 2 -2 127 -128 126 -127 64 -64 85 -86 100 -100 3)) (v128.const i16x8 129 64 192 85 170 100 156 3))
+
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 0 1 -1 2))
+(assert_return (invoke "i32x4.extend_low_i16x8_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 16384 -16384 21845 -21846))
+
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 0 1 65535 2))
+(assert_return (invoke "i32x4.extend_low_i16x8_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 16384 49152 21845 43690))
+
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 32767 -32768 32766 -32767))
+(assert_return (invoke "i32x4.extend_high_i16x8_s" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 255 256 12345 -12345))
+
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i16x8 0 1 -1 2 32767 -32768 32766 -32767)) (v128.const i32x4 32767 32768 32766 32769))
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i16x8 16384 -16384 21845 -21846 255 256 12345 -12345)) (v128.const i32x4 255 256 12345 53191))
+
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 0 1 -1 2)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i64x2 2147483647 -2147483648))
+(assert_return (invoke "i64x2.extend_low_i32x4_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i64x2 -1073741824 1431655765))
+
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 0 1 -1 2)) (v128.const i64x2 0 1))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i64x2 2147483647 2147483648))
+(assert_return (invoke "i64x2.extend_low_i32x4_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i64x2 3221225472 1431655765))
+
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 0 1 -1 2)) (v128.const i64x2 -1 2))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extend_high_i32x4_s" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i64x2 65535 123456789))
+
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 0 1 -1 2)) (v128.const i64x2 4294967295 2))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 2147483647 -2147483648 2147483646 1073741824)) (v128.const i64x2 2147483646 1073741824))
+(assert_return (invoke "i64x2.extend_high_i32x4_u" (v128.const i32x4 -1073741824 1431655765 65535 123456789)) (v128.const i64x2 65535 123456789))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i16x8.extend_low_i8x16_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_low_i8x16_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_low_i8x16_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_low_i8x16_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_high_i8x16_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_high_i8x16_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_high_i8x16_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.extend_high_i8x16_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_low_i16x8_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_low_i16x8_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_low_i16x8_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_low_i16x8_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_high_i16x8_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_high_i16x8_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_high_i16x8_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.extend_high_i16x8_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_low_i32x4_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_low_i32x4_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_low_i32x4_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_low_i32x4_u ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_high_i32x4_s (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_high_i32x4_s ))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_high_i32x4_u (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.extend_high_i32x4_u ))) "type mismatch")
@@ -0,0 +1,197 @@
+;; Tests for the extract_lane, replace_lane, shuffle and swizzle instructions.
+
+(module
+  (func (export "i8x16.extract_lane_s-0") (param v128) (result i32) (i8x16.extract_lane_s 0 (local.get 0)))
+  (func (export "i8x16.extract_lane_u-0") (param v128) (result i32) (i8x16.extract_lane_u 0 (local.get 0)))
+  (func (export "i8x16.replace_lane-0") (param v128 i32) (result v128) (i8x16.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "i8x16.extract_lane_s-1") (param v128) (result i32) (i8x16.extract_lane_s 1 (local.get 0)))
+  (func (export "i8x16.extract_lane_u-1") (param v128) (result i32) (i8x16.extract_lane_u 1 (local.get 0)))
+  (func (export "i8x16.replace_lane-1") (param v128 i32) (result v128) (i8x16.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "i8x16.extract_lane_s-8") (param v128) (result i32) (i8x16.extract_lane_s 8 (local.get 0)))
+  (func (export "i8x16.extract_lane_u-8") (param v128) (result i32) (i8x16.extract_lane_u 8 (local.get 0)))
+  (func (export "i8x16.replace_lane-8") (param v128 i32) (result v128) (i8x16.replace_lane 8 (local.get 0) (local.get 1)))
+  (func (export "i8x16.extract_lane_s-15") (param v128) (result i32) (i8x16.extract_lane_s 15 (local.get 0)))
+  (func (export "i8x16.extract_lane_u-15") (param v128) (result i32) (i8x16.extract_lane_u 15 (local.get 0)))
+  (func (export "i8x16.replace_lane-15") (param v128 i32) (result v128) (i8x16.replace_lane 15 (local.get 0) (local.get 1)))
+  (func (export "i16x8.extract_lane_s-0") (param v128) (result i32) (i16x8.extract_lane_s 0 (local.get 0)))
+  (func (export "i16x8.extract_lane_u-0") (param v128) (result i32) (i16x8.extract_lane_u 0 (local.get 0)))
+  (func (export "i16x8.replace_lane-0") (param v128 i32) (result v128) (i16x8.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "i16x8.extract_lane_s-1") (param v128) (result i32) (i16x8.extract_lane_s 1 (local.get 0)))
+  (func (export "i16x8.extract_lane_u-1") (param v128) (result i32) (i16x8.extract_lane_u 1 (local.get 0)))
+  (func (export "i16x8.replace_lane-1") (param v128 i32) (result v128) (i16x8.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "i16x8.extract_lane_s-4") (param v128) (result i32) (i16x8.extract_lane_s 4 (local.get 0)))
+  (func (export "i16x8.extract_lane_u-4") (param v128) (result i32) (i16x8.extract_lane_u 4 (local.get 0)))
+  (func (export "i16x8.replace_lane-4") (param v128 i32) (result v128) (i16x8.replace_lane 4 (local.get 0) (local.get 1)))
+  (func (export "i16x8.extract_lane_s-7") (param v128) (result i32) (i16x8.extract_lane_s 7 (local.get 0)))
+  (func (export "i16x8.extract_lane_u-7") (param v128) (result i32) (i16x8.extract_lane_u 7 (local.get 0)))
+  (func (export "i16x8.replace_lane-7") (param v128 i32) (result v128) (i16x8.replace_lane 7 (local.get 0) (local.get 1)))
+  (func (export "i32x4.extract_lane-0") (param v128) (result i32) (i32x4.extract_lane 0 (local.get 0)))
+  (func (export "i32x4.replace_lane-0") (param v128 i32) (result v128) (i32x4.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "i32x4.extract_lane-1") (param v128) (result i32) (i32x4.extract_lane 1 (local.get 0)))
+  (func (export "i32x4.replace_lane-1") (param v128 i32) (result v128) (i32x4.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "i32x4.extract_lane-2") (param v128) (result i32) (i32x4.extract_lane 2 (local.get 0)))
+  (func (export "i32x4.replace_lane-2") (param v128 i32) (result v128) (i32x4.replace_lane 2 (local.get 0) (local.get 1)))
+  (func (export "i32x4.extract_lane-3") (param v128) (result i32) (i32x4.extract_lane 3 (local.get 0)))
+  (func (export "i32x4.replace_lane-3") (param v128 i32) (result v128) (i32x4.replace_lane 3 (local.get 0) (local.get 1)))
+  (func (export "i64x2.extract_lane-0") (param v128) (result i64) (i64x2.extract_lane 0 (local.get 0)))
+  (func (export "i64x2.replace_lane-0") (param v128 i64) (result v128) (i64x2.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "i64x2.extract_lane-1") (param v128) (result i64) (i64x2.extract_lane 1 (local.get 0)))
+  (func (export "i64x2.replace_lane-1") (param v128 i64) (result v128) (i64x2.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "f32x4.extract_lane-0") (param v128) (result f32) (f32x4.extract_lane 0 (local.get 0)))
+  (func (export "f32x4.replace_lane-0") (param v128 f32) (result v128) (f32x4.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "f32x4.extract_lane-1") (param v128) (result f32) (f32x4.extract_lane 1 (local.get 0)))
+  (func (export "f32x4.replace_lane-1") (param v128 f32) (result v128) (f32x4.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "f32x4.extract_lane-2") (param v128) (result f32) (f32x4.extract_lane 2 (local.get 0)))
+  (func (export "f32x4.replace_lane-2") (param v128 f32) (result v128) (f32x4.replace_lane 2 (local.get 0) (local.get 1)))
+  (func (export "f32x4.extract_lane-3") (param v128) (result f32) (f32x4.extract_lane 3 (local.get 0)))
+  (func (export "f32x4.replace_lane-3") (param v128 f32) (result v128) (f32x4.replace_lane 3 (local.get 0) (local.get 1)))
+  (func (export "f64x2.extract_lane-0") (param v128) (result f64) (f64x2.extract_lane 0 (local.get 0)))
+  (func (export "f64x2.replace_lane-0") (param v128 f64) (result v128) (f64x2.replace_lane 0 (local.get 0) (local.get 1)))
+  (func (export "f64x2.extract_lane-1") (param v128) (result f64) (f64x2.extract_lane 1 (local.get 0)))
+  (func (export "f64x2.replace_lane-1") (param v128 f64) (result v128) (f64x2.replace_lane 1 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-identity") (param v128 v128) (result v128) (i8x16.shuffle 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-reverse") (param v128 v128) (result v128) (i8x16.shuffle 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-interleave") (param v128 v128) (result v128) (i8x16.shuffle 0 16 1 17 2 18 3 19 4 20 5 21 6 22 7 23 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-second") (param v128 v128) (result v128) (i8x16.shuffle 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-broadcast") (param v128 v128) (result v128) (i8x16.shuffle 31 31 31 31 31 31 31 31 31 31 31 31 31 31 31 31 (local.get 0) (local.get 1)))
+  (func (export "i8x16.shuffle-mixed") (param v128 v128) (result v128) (i8x16.shuffle 0 31 1 30 2 29 16 15 17 14 8 8 24 24 7 23 (local.get 0) (local.get 1)))
+  (func (export "i8x16.swizzle") (param v128 v128) (result v128) (i8x16.swizzle (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i8x16.extract_lane_s-0" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 2))
+(assert_return (invoke "i8x16.extract_lane_u-0" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 2))
+(assert_return (invoke "i8x16.replace_lane-0" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100) (i32.const 703489)) (v128.const i8x16 1 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100))
+(assert_return (invoke "i8x16.extract_lane_s-1" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const -127))
+(assert_return (invoke "i8x16.extract_lane_u-1" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 129))
+(assert_return (invoke "i8x16.replace_lane-1" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100) (i32.const 703617)) (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100))
+(assert_return (invoke "i8x16.extract_lane_s-8" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 85))
+(assert_return (invoke "i8x16.extract_lane_u-8" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 85))
+(assert_return (invoke "i8x16.replace_lane-8" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100) (i32.const 703552)) (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 64 0 127 -64 3 -2 64 -100))
+(assert_return (invoke "i8x16.extract_lane_s-15" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const -100))
+(assert_return (invoke "i8x16.extract_lane_u-15" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100)) (i32.const 156))
+(assert_return (invoke "i8x16.replace_lane-15" (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -100) (i32.const 703680)) (v128.const i8x16 2 -127 100 -1 126 -86 1 -128 85 0 127 -64 3 -2 64 -64))
+
+(assert_return (invoke "i16x8.extract_lane_s-0" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 2))
+(assert_return (invoke "i16x8.extract_lane_u-0" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 2))
+(assert_return (invoke "i16x8.replace_lane-0" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766) (i32.const 180092929)) (v128.const i16x8 1 16384 256 -1 -32767 255 1 32766))
+(assert_return (invoke "i16x8.extract_lane_s-1" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 16384))
+(assert_return (invoke "i16x8.extract_lane_u-1" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 16384))
+(assert_return (invoke "i16x8.replace_lane-1" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766) (i32.const 180109312)) (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766))
+(assert_return (invoke "i16x8.extract_lane_s-4" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const -32767))
+(assert_return (invoke "i16x8.extract_lane_u-4" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 32769))
+(assert_return (invoke "i16x8.replace_lane-4" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766) (i32.const 180093184)) (v128.const i16x8 2 16384 256 -1 256 255 1 32766))
+(assert_return (invoke "i16x8.extract_lane_s-7" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 32766))
+(assert_return (invoke "i16x8.extract_lane_u-7" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766)) (i32.const 32766))
+(assert_return (invoke "i16x8.replace_lane-7" (v128.const i16x8 2 16384 256 -1 -32767 255 1 32766) (i32.const 180158463)) (v128.const i16x8 2 16384 256 -1 -32767 255 1 -1))
+
+(assert_return (invoke "i32x4.extract_lane-0" (v128.const i32x4 2 -1073741824 1 2147483646)) (i32.const 2))
+(assert_return (invoke "i32x4.replace_lane-0" (v128.const i32x4 2 -1073741824 1 2147483646) (i32.const 1)) (v128.const i32x4 1 -1073741824 1 2147483646))
+(assert_return (invoke "i32x4.extract_lane-1" (v128.const i32x4 2 -1073741824 1 2147483646)) (i32.const -1073741824))
+(assert_return (invoke "i32x4.replace_lane-1" (v128.const i32x4 2 -1073741824 1 2147483646) (i32.const -1073741824)) (v128.const i32x4 2 -1073741824 1 2147483646))
+(assert_return (invoke "i32x4.extract_lane-2" (v128.const i32x4 2 -1073741824 1 2147483646)) (i32.const 1))
+(assert_return (invoke "i32x4.replace_lane-2" (v128.const i32x4 2 -1073741824 1 2147483646) (i32.const 2)) (v128.const i32x4 2 -1073741824 2 2147483646))
+(assert_return (invoke "i32x4.extract_lane-3" (v128.const i32x4 2 -1073741824 1 2147483646)) (i32.const 2147483646))
+(assert_return (invoke "i32x4.replace_lane-3" (v128.const i32x4 2 -1073741824 1 2147483646) (i32.const 65535)) (v128.const i32x4 2 -1073741824 1 65535))
+
+(assert_return (invoke "i64x2.extract_lane-0" (v128.const i64x2 2 4294967295)) (i64.const 2))
+(assert_return (invoke "i64x2.replace_lane-0" (v128.const i64x2 2 4294967295) (i64.const 1)) (v128.const i64x2 1 4294967295))
+(assert_return (invoke "i64x2.extract_lane-1" (v128.const i64x2 2 4294967295)) (i64.const 4294967295))
+(assert_return (invoke "i64x2.replace_lane-1" (v128.const i64x2 2 4294967295) (i64.const 4294967295)) (v128.const i64x2 2 4294967295))
+
+(assert_return (invoke "f32x4.extract_lane-0" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0)) (f32.const -0x1p+0))
+(assert_return (invoke "f32x4.replace_lane-0" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0) (f32.const -0x0p+0)) (v128.const f32x4 -0x0p+0 0x1p-126 -inf 0x0p+0))
+(assert_return (invoke "f32x4.extract_lane-1" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0)) (f32.const 0x1p-126))
+(assert_return (invoke "f32x4.replace_lane-1" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0) (f32.const 0x1p-126)) (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0))
+(assert_return (invoke "f32x4.extract_lane-2" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0)) (f32.const -inf))
+(assert_return (invoke "f32x4.replace_lane-2" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0) (f32.const -nan)) (v128.const f32x4 -0x1p+0 0x1p-126 -nan 0x0p+0))
+(assert_return (invoke "f32x4.extract_lane-3" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0)) (f32.const 0x0p+0))
+(assert_return (invoke "f32x4.replace_lane-3" (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x0p+0) (f32.const 0x1p-1)) (v128.const f32x4 -0x1p+0 0x1p-126 -inf 0x1p-1))
+
+(assert_return (invoke "f64x2.extract_lane-0" (v128.const f64x2 -0x1p+0 0x1p-1022)) (f64.const -0x1p+0))
+(assert_return (invoke "f64x2.replace_lane-0" (v128.const f64x2 -0x1p+0 0x1p-1022) (f64.const -0x0p+0)) (v128.const f64x2 -0x0p+0 0x1p-1022))
+(assert_return (invoke "f64x2.extract_lane-1" (v128.const f64x2 -0x1p+0 0x1p-1022)) (f64.const 0x1p-1022))
+(assert_return (invoke "f64x2.replace_lane-1" (v128.const f64x2 -0x1p+0 0x1p-1022) (f64.const 0x1p-1022)) (v128.const f64x2 -0x1p+0 0x1p-1022))
+
+(assert_return (invoke "i8x16.shuffle-identity" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31))
+(assert_return (invoke "i8x16.shuffle-reverse" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 31 30 29 28 27 26 25 24 23 22 21 20 19 18 17 16))
+(assert_return (invoke "i8x16.shuffle-interleave" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 16 -32 17 -31 18 -30 19 -29 20 -28 21 -27 22 -26 23 -25))
+(assert_return (invoke "i8x16.shuffle-second" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17))
+(assert_return (invoke "i8x16.shuffle-broadcast" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17 -17))
+(assert_return (invoke "i8x16.shuffle-mixed" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -32 -31 -30 -29 -28 -27 -26 -25 -24 -23 -22 -21 -20 -19 -18 -17)) (v128.const i8x16 16 -17 17 -18 18 -19 -32 31 -31 30 24 24 -24 -24 23 -25))
+
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 92 120 123 72 106 58 114 1 104 -38 -88 -74 66 60 -94 56) (v128.const i8x16 91 10 9 8 -122 73 82 105 13 9 9 76 95 -123 58 -127)) (v128.const i8x16 0 -88 -38 104 0 0 0 0 60 -38 -38 0 0 0 0 0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 3 -21 -41 121 -78 -53 31 116 -43 -100 20 127 -89 5 -9 36) (v128.const i8x16 -5 11 -86 15 7 2 18 1 -24 -10 7 7 3 115 119 -123)) (v128.const i8x16 0 127 0 36 116 -41 0 -21 0 0 116 116 121 0 0 0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 9 15 7 -75 50 109 -86 65 -20 -9 -128 98 26 -49 55 29) (v128.const i8x16 13 45 52 7 12 63 15 69 -116 9 -52 -104 -90 4 -55 1)) (v128.const i8x16 -49 0 0 65 26 0 29 0 0 -9 0 0 0 50 0 15))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 -50 127 -70 -63 13 -125 85 -120 43 25 -74 93 103 72 100 16) (v128.const i8x16 64 6 72 12 1 12 4 -112 0 -92 50 5 39 9 4 3)) (v128.const i8x16 0 85 0 103 127 103 13 0 -50 0 0 -125 0 25 13 -63))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 88 52 -111 111 23 46 -17 22 118 20 -30 8 -110 38 39 -83) (v128.const i8x16 5 26 -66 13 12 -50 11 11 114 1 -43 -108 11 76 5 26)) (v128.const i8x16 46 0 0 38 -110 0 8 8 0 52 0 0 8 0 46 0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 -93 -39 -65 114 -90 -18 9 36 -27 -9 0 42 -68 -30 -2 49) (v128.const i8x16 -83 0 48 -90 53 -96 2 92 -73 35 94 0 -13 -19 9 19)) (v128.const i8x16 0 -93 0 0 0 0 -65 0 0 0 0 -93 0 0 -9 0))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0)) (v128.const i8x16 31 30 29 28 27 26 25 24 23 22 21 20 19 18 17 16))
+(assert_return (invoke "i8x16.swizzle" (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31) (v128.const i8x16 -128 -1 16 17 -128 -1 16 17 -128 -1 16 17 -128 -1 16 17)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+
+;; Lane indices must be less than the number of lanes.
+
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_s 16 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_s 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_u 16 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_u 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i8x16.replace_lane 16 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_s 8 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_s 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_u 8 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_u 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i16x8.replace_lane 8 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i32x4.extract_lane 4 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i32) (i32x4.extract_lane 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i32x4.replace_lane 4 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result i64) (i64x2.extract_lane 2 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result i64) (i64x2.extract_lane 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i64x2.replace_lane 2 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result f32) (f32x4.extract_lane 4 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result f32) (f32x4.extract_lane 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (f32x4.replace_lane 4 (v128.const i32x4 0 0 0 0) (f32.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result f64) (f64x2.extract_lane 2 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result f64) (f64x2.extract_lane 255 (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (f64x2.replace_lane 2 (v128.const i32x4 0 0 0 0) (f64.const 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i8x16.shuffle 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 32 (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (func (result v128) (i8x16.shuffle 255 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+
+;; Lane indices are immediates.
+
+(assert_malformed (module quote "(func (result i32) (i8x16.extract_lane_s -1 (v128.const i32x4 0 0 0 0)))") "unexpected token")
+(assert_malformed (module quote "(func (result i32) (i8x16.extract_lane_u 256 (v128.const i32x4 0 0 0 0)))") "malformed lane index")
+(assert_malformed (module quote "(func (result i32) (i32x4.extract_lane (v128.const i32x4 0 0 0 0)))") "unexpected token")
+(assert_malformed (module quote "(func (result i32) (i32x4.extract_lane 1.5 (v128.const i32x4 0 0 0 0)))") "unexpected token")
+(assert_malformed (module quote "(func (result v128) (i8x16.shuffle 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))") "invalid lane length")
+(assert_malformed (module quote "(func (result v128) (i8x16.shuffle 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))") "invalid lane length")
+(assert_malformed (module quote "(func (result i32) (i8x16.extract_lane 0 (v128.const i32x4 0 0 0 0)))") "unknown operator")
+
+;; Type check
+
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_s 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i8x16.extract_lane_s 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.replace_lane 0 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.replace_lane 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_s 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i16x8.extract_lane_s 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.replace_lane 0 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.replace_lane 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.extract_lane 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (i32x4.extract_lane 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.replace_lane 0 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.replace_lane 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result i64) (i64x2.extract_lane 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result i64) (i64x2.extract_lane 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.replace_lane 0 (v128.const i32x4 0 0 0 0) (f32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.replace_lane 0 (i64.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result f32) (f32x4.extract_lane 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result f32) (f32x4.extract_lane 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.replace_lane 0 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.replace_lane 0 (f32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result f64) (f64x2.extract_lane 0 (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result f64) (f64x2.extract_lane 0))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.replace_lane 0 (v128.const i32x4 0 0 0 0) (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.replace_lane 0 (f64.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.shuffle 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.swizzle (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.swizzle (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.swizzle (v128.const i32x4 0 0 0 0)))) "type mismatch")
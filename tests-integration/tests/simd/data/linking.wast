@@ -0,0 +1,33 @@
+;; Tests for the v128 globals shared between modules.
+
+(module $M
+  (global (export "g-v128") v128 (v128.const i32x4 0 1 2 3))
+  (global (export "mg-v128") (mut v128) (v128.const f32x4 0.5 1.5 -0 nan))
+  (func (export "set") (param v128) (global.set 1 (local.get 0)))
+)
+(register "Mv128" $M)
+
+(module
+  (import "Mv128" "g-v128" (global $g v128))
+  (import "Mv128" "mg-v128" (global $mg (mut v128)))
+  (func (export "get") (result v128) (global.get $g))
+  (func (export "get-mut") (result v128) (global.get $mg))
+  (func (export "set-mut") (param v128) (global.set $mg (local.get 0)))
+)
+
+(assert_return (get $M "g-v128") (v128.const i32x4 0 1 2 3))
+(assert_return (invoke "get" ) (v128.const i32x4 0 1 2 3))
+(assert_return (invoke "get-mut" ) (v128.const f32x4 0x1p-1 0x1.8p+0 -0x0p+0 nan))
+(invoke "set-mut" (v128.const i64x2 1 2))
+(assert_return (get $M "mg-v128") (v128.const i64x2 1 2))
+(invoke $M "set" (v128.const i16x8 1 2 3 4 5 6 7 8))
+(assert_return (invoke "get-mut" ) (v128.const i16x8 1 2 3 4 5 6 7 8))
+
+(assert_unlinkable
+  (module (import "Mv128" "g-v128" (global i32)))
+  "incompatible import type"
+)
+(assert_unlinkable
+  (module (import "Mv128" "mg-v128" (global v128)))
+  "incompatible import type"
+)
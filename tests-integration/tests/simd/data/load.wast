@@ -0,0 +1,50 @@
+;; Tests for the v128.load instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "load") (param i32) (result v128) (v128.load (local.get 0)))
+  (func (export "load-offset") (param i32) (result v128) (v128.load offset=16 (local.get 0)))
+  (func (export "load-align-1") (param i32) (result v128) (v128.load align=1 (local.get 0)))
+  (func (export "load-align-16") (param i32) (result v128) (v128.load offset=1 align=16 (local.get 0)))
+  (func (export "as-i8x16-add") (param i32) (result v128) (i8x16.add (v128.load (local.get 0)) (v128.load offset=1 (local.get 0))))
+  (func (export "as-br") (param i32) (result v128) (block (result v128) (br 0 (v128.load (local.get 0)))))
+)
+
+(assert_return (invoke "load" (i32.const 0)) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+(assert_return (invoke "load" (i32.const 1)) (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "load" (i32.const 15)) (v128.const i8x16 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30))
+(assert_return (invoke "load" (i32.const 16)) (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31))
+(assert_return (invoke "load" (i32.const 48)) (v128.const i8x16 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63))
+(assert_return (invoke "load" (i32.const 63)) (v128.const i8x16 63 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "load" (i32.const 65520)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "load-offset" (i32.const 0)) (v128.const i8x16 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31))
+(assert_return (invoke "load-align-1" (i32.const 0)) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+(assert_return (invoke "load-align-16" (i32.const 0)) (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "as-i8x16-add" (i32.const 0)) (v128.const i8x16 1 3 5 7 9 11 13 15 17 19 21 23 25 27 29 31))
+(assert_return (invoke "as-br" (i32.const 0)) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+(assert_return (invoke "load-offset" (i32.const 3)) (v128.const i8x16 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34))
+(assert_return (invoke "load-align-1" (i32.const 3)) (v128.const i8x16 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18))
+(assert_return (invoke "load-align-16" (i32.const 3)) (v128.const i8x16 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19))
+(assert_return (invoke "as-i8x16-add" (i32.const 3)) (v128.const i8x16 7 9 11 13 15 17 19 21 23 25 27 29 31 33 35 37))
+(assert_return (invoke "as-br" (i32.const 3)) (v128.const i8x16 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18))
+(assert_return (invoke "load-offset" (i32.const 32)) (v128.const i8x16 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63))
+(assert_return (invoke "load-align-1" (i32.const 32)) (v128.const i8x16 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47))
+(assert_return (invoke "load-align-16" (i32.const 32)) (v128.const i8x16 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48))
+(assert_return (invoke "as-i8x16-add" (i32.const 32)) (v128.const i8x16 65 67 69 71 73 75 77 79 81 83 85 87 89 91 93 95))
+(assert_return (invoke "as-br" (i32.const 32)) (v128.const i8x16 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47))
+
+(assert_trap (invoke "load" (i32.const 65521)) "out of bounds memory access")
+(assert_trap (invoke "load" (i32.const 65535)) "out of bounds memory access")
+(assert_trap (invoke "load" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "load" (i32.const -1)) "out of bounds memory access")
+(assert_trap (invoke "load-offset" (i32.const 65505)) "out of bounds memory access")
+(assert_trap (invoke "load-offset" (i32.const -16)) "out of bounds memory access")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result i32) (v128.load (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (v128.load (i32.const 0)))) "unknown memory")
+(assert_malformed (module quote "(memory 1) (func (drop (v128.load8 (i32.const 0))))") "unknown operator")
@@ -0,0 +1,46 @@
+;; Tests for the v128.load16_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.load16_lane_0") (param i32 v128) (result v128) (v128.load16_lane 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_1") (param i32 v128) (result v128) (v128.load16_lane 1 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_2") (param i32 v128) (result v128) (v128.load16_lane 2 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_3") (param i32 v128) (result v128) (v128.load16_lane 3 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_4") (param i32 v128) (result v128) (v128.load16_lane 4 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_5") (param i32 v128) (result v128) (v128.load16_lane 5 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_6") (param i32 v128) (result v128) (v128.load16_lane 6 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_7") (param i32 v128) (result v128) (v128.load16_lane 7 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_0_offset") (param i32 v128) (result v128) (v128.load16_lane offset=1 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load16_lane_1_align") (param i32 v128) (result v128) (v128.load16_lane align=1 1 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "v128.load16_lane_0" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 256 -2 -3 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_0" (i32.const 1) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 513 -2 -3 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_1" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 256 -3 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_1" (i32.const 4) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 1284 -3 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_2" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 256 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_2" (i32.const 7) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 2055 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_3" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 256 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_3" (i32.const 10) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 2826 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_4" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 256 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_4" (i32.const 13) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 3597 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_5" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 256 -7 -8))
+(assert_return (invoke "v128.load16_lane_5" (i32.const 16) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 4368 -7 -8))
+(assert_return (invoke "v128.load16_lane_6" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 -6 256 -8))
+(assert_return (invoke "v128.load16_lane_6" (i32.const 19) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 -6 5139 -8))
+(assert_return (invoke "v128.load16_lane_7" (i32.const 0) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 256))
+(assert_return (invoke "v128.load16_lane_7" (i32.const 22) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 5910))
+(assert_return (invoke "v128.load16_lane_0_offset" (i32.const 4) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 1541 -2 -3 -4 -5 -6 -7 -8))
+(assert_return (invoke "v128.load16_lane_1_align" (i32.const 3) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) (v128.const i16x8 -1 1027 -3 -4 -5 -6 -7 -8))
+(assert_trap (invoke "v128.load16_lane_0" (i32.const 65535) (v128.const i16x8 -1 -2 -3 -4 -5 -6 -7 -8)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_lane 8 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_lane align=4 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_lane 0 (i32.const 0)))) "type mismatch")
@@ -0,0 +1,28 @@
+;; Tests for the v128.load64_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.load64_lane_0") (param i32 v128) (result v128) (v128.load64_lane 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load64_lane_1") (param i32 v128) (result v128) (v128.load64_lane 1 (local.get 0) (local.get 1)))
+  (func (export "v128.load64_lane_0_offset") (param i32 v128) (result v128) (v128.load64_lane offset=1 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load64_lane_1_align") (param i32 v128) (result v128) (v128.load64_lane align=1 1 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "v128.load64_lane_0" (i32.const 0) (v128.const i64x2 -1 -2)) (v128.const i64x2 506097522914230528 -2))
+(assert_return (invoke "v128.load64_lane_0" (i32.const 1) (v128.const i64x2 -1 -2)) (v128.const i64x2 578437695752307201 -2))
+(assert_return (invoke "v128.load64_lane_1" (i32.const 0) (v128.const i64x2 -1 -2)) (v128.const i64x2 -1 506097522914230528))
+(assert_return (invoke "v128.load64_lane_1" (i32.const 4) (v128.const i64x2 -1 -2)) (v128.const i64x2 -1 795458214266537220))
+(assert_return (invoke "v128.load64_lane_0_offset" (i32.const 4) (v128.const i64x2 -1 -2)) (v128.const i64x2 867798387104613893 -2))
+(assert_return (invoke "v128.load64_lane_1_align" (i32.const 3) (v128.const i64x2 -1 -2)) (v128.const i64x2 -1 723118041428460547))
+(assert_trap (invoke "v128.load64_lane_0" (i32.const 65529) (v128.const i64x2 -1 -2)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_lane 2 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_lane align=16 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_lane 0 (i32.const 0)))) "type mismatch")
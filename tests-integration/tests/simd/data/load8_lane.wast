@@ -0,0 +1,70 @@
+;; Tests for the v128.load8_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.load8_lane_0") (param i32 v128) (result v128) (v128.load8_lane 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_1") (param i32 v128) (result v128) (v128.load8_lane 1 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_2") (param i32 v128) (result v128) (v128.load8_lane 2 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_3") (param i32 v128) (result v128) (v128.load8_lane 3 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_4") (param i32 v128) (result v128) (v128.load8_lane 4 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_5") (param i32 v128) (result v128) (v128.load8_lane 5 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_6") (param i32 v128) (result v128) (v128.load8_lane 6 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_7") (param i32 v128) (result v128) (v128.load8_lane 7 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_8") (param i32 v128) (result v128) (v128.load8_lane 8 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_9") (param i32 v128) (result v128) (v128.load8_lane 9 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_10") (param i32 v128) (result v128) (v128.load8_lane 10 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_11") (param i32 v128) (result v128) (v128.load8_lane 11 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_12") (param i32 v128) (result v128) (v128.load8_lane 12 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_13") (param i32 v128) (result v128) (v128.load8_lane 13 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_14") (param i32 v128) (result v128) (v128.load8_lane 14 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_15") (param i32 v128) (result v128) (v128.load8_lane 15 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_0_offset") (param i32 v128) (result v128) (v128.load8_lane offset=1 0 (local.get 0) (local.get 1)))
+  (func (export "v128.load8_lane_1_align") (param i32 v128) (result v128) (v128.load8_lane align=1 1 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "v128.load8_lane_0" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 0 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_0" (i32.const 1) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_1" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 0 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_1" (i32.const 4) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 4 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_2" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 0 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_2" (i32.const 7) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 7 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_3" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 0 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_3" (i32.const 10) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 10 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_4" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 0 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_4" (i32.const 13) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 13 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_5" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 0 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_5" (i32.const 16) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 16 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_6" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 0 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_6" (i32.const 19) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 19 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_7" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 0 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_7" (i32.const 22) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 22 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_8" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 0 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_8" (i32.const 25) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 25 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_9" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 0 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_9" (i32.const 28) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 28 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_10" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 0 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_10" (i32.const 31) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 31 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_11" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 0 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_11" (i32.const 34) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 34 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_12" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 0 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_12" (i32.const 37) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 37 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_13" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 0 -15 -16))
+(assert_return (invoke "v128.load8_lane_13" (i32.const 40) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 40 -15 -16))
+(assert_return (invoke "v128.load8_lane_14" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 0 -16))
+(assert_return (invoke "v128.load8_lane_14" (i32.const 43) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 43 -16))
+(assert_return (invoke "v128.load8_lane_15" (i32.const 0) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 0))
+(assert_return (invoke "v128.load8_lane_15" (i32.const 46) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 46))
+(assert_return (invoke "v128.load8_lane_0_offset" (i32.const 4) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 5 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_return (invoke "v128.load8_lane_1_align" (i32.const 3) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) (v128.const i8x16 -1 3 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16))
+(assert_trap (invoke "v128.load8_lane_0" (i32.const 65536) (v128.const i8x16 -1 -2 -3 -4 -5 -6 -7 -8 -9 -10 -11 -12 -13 -14 -15 -16)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_lane 16 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_lane align=2 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_lane 0 (i32.const 0)))) "type mismatch")
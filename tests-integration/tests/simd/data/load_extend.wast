@@ -0,0 +1,76 @@
+;; Tests for the load and extend instructions.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\7f\80\fe\ff\55\aa\81\82\83\84\85\86\87\88\89\8a")
+  (data (i32.const 65528) "\f0\f1\f2\f3\f4\f5\f6\f7")
+  (func (export "v128.load8x8_s") (param i32) (result v128) (v128.load8x8_s (local.get 0)))
+  (func (export "v128.load8x8_s-offset") (param i32) (result v128) (v128.load8x8_s offset=7 align=1 (local.get 0)))
+  (func (export "v128.load8x8_u") (param i32) (result v128) (v128.load8x8_u (local.get 0)))
+  (func (export "v128.load8x8_u-offset") (param i32) (result v128) (v128.load8x8_u offset=7 align=1 (local.get 0)))
+  (func (export "v128.load16x4_s") (param i32) (result v128) (v128.load16x4_s (local.get 0)))
+  (func (export "v128.load16x4_s-offset") (param i32) (result v128) (v128.load16x4_s offset=7 align=1 (local.get 0)))
+  (func (export "v128.load16x4_u") (param i32) (result v128) (v128.load16x4_u (local.get 0)))
+  (func (export "v128.load16x4_u-offset") (param i32) (result v128) (v128.load16x4_u offset=7 align=1 (local.get 0)))
+  (func (export "v128.load32x2_s") (param i32) (result v128) (v128.load32x2_s (local.get 0)))
+  (func (export "v128.load32x2_s-offset") (param i32) (result v128) (v128.load32x2_s offset=7 align=1 (local.get 0)))
+  (func (export "v128.load32x2_u") (param i32) (result v128) (v128.load32x2_u (local.get 0)))
+  (func (export "v128.load32x2_u-offset") (param i32) (result v128) (v128.load32x2_u offset=7 align=1 (local.get 0)))
+)
+
+(assert_return (invoke "v128.load8x8_s" (i32.const 0)) (v128.const i16x8 0 1 127 -128 -2 -1 85 -86))
+(assert_return (invoke "v128.load8x8_s" (i32.const 3)) (v128.const i16x8 -128 -2 -1 85 -86 -127 -126 -125))
+(assert_return (invoke "v128.load8x8_s" (i32.const 10)) (v128.const i16x8 -125 -124 -123 -122 -121 -120 -119 -118))
+(assert_return (invoke "v128.load8x8_s" (i32.const 65528)) (v128.const i16x8 -16 -15 -14 -13 -12 -11 -10 -9))
+(assert_return (invoke "v128.load8x8_s-offset" (i32.const 1)) (v128.const i16x8 -127 -126 -125 -124 -123 -122 -121 -120))
+(assert_trap (invoke "v128.load8x8_s" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_s-offset" (i32.const 65522)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load8x8_u" (i32.const 0)) (v128.const i16x8 0 1 127 128 254 255 85 170))
+(assert_return (invoke "v128.load8x8_u" (i32.const 3)) (v128.const i16x8 128 254 255 85 170 129 130 131))
+(assert_return (invoke "v128.load8x8_u" (i32.const 10)) (v128.const i16x8 131 132 133 134 135 136 137 138))
+(assert_return (invoke "v128.load8x8_u" (i32.const 65528)) (v128.const i16x8 240 241 242 243 244 245 246 247))
+(assert_return (invoke "v128.load8x8_u-offset" (i32.const 1)) (v128.const i16x8 129 130 131 132 133 134 135 136))
+(assert_trap (invoke "v128.load8x8_u" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load8x8_u-offset" (i32.const 65522)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load16x4_s" (i32.const 0)) (v128.const i32x4 256 -32641 -2 -21931))
+(assert_return (invoke "v128.load16x4_s" (i32.const 3)) (v128.const i32x4 -384 22015 -32342 -31870))
+(assert_return (invoke "v128.load16x4_s" (i32.const 10)) (v128.const i32x4 -31613 -31099 -30585 -30071))
+(assert_return (invoke "v128.load16x4_s" (i32.const 65528)) (v128.const i32x4 -3600 -3086 -2572 -2058))
+(assert_return (invoke "v128.load16x4_s-offset" (i32.const 1)) (v128.const i32x4 -32127 -31613 -31099 -30585))
+(assert_trap (invoke "v128.load16x4_s" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_s-offset" (i32.const 65522)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load16x4_u" (i32.const 0)) (v128.const i32x4 256 32895 65534 43605))
+(assert_return (invoke "v128.load16x4_u" (i32.const 3)) (v128.const i32x4 65152 22015 33194 33666))
+(assert_return (invoke "v128.load16x4_u" (i32.const 10)) (v128.const i32x4 33923 34437 34951 35465))
+(assert_return (invoke "v128.load16x4_u" (i32.const 65528)) (v128.const i32x4 61936 62450 62964 63478))
+(assert_return (invoke "v128.load16x4_u-offset" (i32.const 1)) (v128.const i32x4 33409 33923 34437 34951))
+(assert_trap (invoke "v128.load16x4_u" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load16x4_u-offset" (i32.const 65522)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load32x2_s" (i32.const 0)) (v128.const i64x2 -2139160320 -1437204482))
+(assert_return (invoke "v128.load32x2_s" (i32.const 3)) (v128.const i64x2 1442840192 -2088599126))
+(assert_return (invoke "v128.load32x2_s" (i32.const 10)) (v128.const i64x2 -2038070141 -1970698105))
+(assert_return (invoke "v128.load32x2_s" (i32.const 65528)) (v128.const i64x2 -202182160 -134810124))
+(assert_return (invoke "v128.load32x2_s-offset" (i32.const 1)) (v128.const i64x2 -2071756159 -2004384123))
+(assert_trap (invoke "v128.load32x2_s" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_s-offset" (i32.const 65522)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load32x2_u" (i32.const 0)) (v128.const i64x2 2155806976 2857762814))
+(assert_return (invoke "v128.load32x2_u" (i32.const 3)) (v128.const i64x2 1442840192 2206368170))
+(assert_return (invoke "v128.load32x2_u" (i32.const 10)) (v128.const i64x2 2256897155 2324269191))
+(assert_return (invoke "v128.load32x2_u" (i32.const 65528)) (v128.const i64x2 4092785136 4160157172))
+(assert_return (invoke "v128.load32x2_u-offset" (i32.const 1)) (v128.const i64x2 2223211137 2290583173))
+(assert_trap (invoke "v128.load32x2_u" (i32.const 65529)) "out of bounds memory access")
+(assert_trap (invoke "v128.load32x2_u-offset" (i32.const 65522)) "out of bounds memory access")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8x8_s (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8x8_u (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16x4_s (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16x4_u (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load32x2_s (f32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load32x2_u (f32.const 0)))) "type mismatch")
@@ -0,0 +1,57 @@
+;; Tests for the load and splat instructions.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.load8_splat") (param i32) (result v128) (v128.load8_splat (local.get 0)))
+  (func (export "v128.load8_splat-offset") (param i32) (result v128) (v128.load8_splat offset=1 (local.get 0)))
+  (func (export "v128.load16_splat") (param i32) (result v128) (v128.load16_splat (local.get 0)))
+  (func (export "v128.load16_splat-offset") (param i32) (result v128) (v128.load16_splat offset=1 (local.get 0)))
+  (func (export "v128.load32_splat") (param i32) (result v128) (v128.load32_splat (local.get 0)))
+  (func (export "v128.load32_splat-offset") (param i32) (result v128) (v128.load32_splat offset=1 (local.get 0)))
+  (func (export "v128.load64_splat") (param i32) (result v128) (v128.load64_splat (local.get 0)))
+  (func (export "v128.load64_splat-offset") (param i32) (result v128) (v128.load64_splat offset=1 (local.get 0)))
+)
+
+(assert_return (invoke "v128.load8_splat" (i32.const 0)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "v128.load8_splat" (i32.const 1)) (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1))
+(assert_return (invoke "v128.load8_splat" (i32.const 5)) (v128.const i8x16 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5))
+(assert_return (invoke "v128.load8_splat" (i32.const 60)) (v128.const i8x16 60 60 60 60 60 60 60 60 60 60 60 60 60 60 60 60))
+(assert_return (invoke "v128.load8_splat" (i32.const 65535)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "v128.load8_splat-offset" (i32.const 2)) (v128.const i8x16 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3 3))
+(assert_trap (invoke "v128.load8_splat" (i32.const 65536)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load16_splat" (i32.const 0)) (v128.const i16x8 256 256 256 256 256 256 256 256))
+(assert_return (invoke "v128.load16_splat" (i32.const 1)) (v128.const i16x8 513 513 513 513 513 513 513 513))
+(assert_return (invoke "v128.load16_splat" (i32.const 5)) (v128.const i16x8 1541 1541 1541 1541 1541 1541 1541 1541))
+(assert_return (invoke "v128.load16_splat" (i32.const 60)) (v128.const i16x8 15676 15676 15676 15676 15676 15676 15676 15676))
+(assert_return (invoke "v128.load16_splat" (i32.const 65534)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "v128.load16_splat-offset" (i32.const 2)) (v128.const i16x8 1027 1027 1027 1027 1027 1027 1027 1027))
+(assert_trap (invoke "v128.load16_splat" (i32.const 65535)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load32_splat" (i32.const 0)) (v128.const i32x4 50462976 50462976 50462976 50462976))
+(assert_return (invoke "v128.load32_splat" (i32.const 1)) (v128.const i32x4 67305985 67305985 67305985 67305985))
+(assert_return (invoke "v128.load32_splat" (i32.const 5)) (v128.const i32x4 134678021 134678021 134678021 134678021))
+(assert_return (invoke "v128.load32_splat" (i32.const 60)) (v128.const i32x4 1061043516 1061043516 1061043516 1061043516))
+(assert_return (invoke "v128.load32_splat" (i32.const 65532)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.load32_splat-offset" (i32.const 2)) (v128.const i32x4 100992003 100992003 100992003 100992003))
+(assert_trap (invoke "v128.load32_splat" (i32.const 65533)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load64_splat" (i32.const 0)) (v128.const i64x2 506097522914230528 506097522914230528))
+(assert_return (invoke "v128.load64_splat" (i32.const 1)) (v128.const i64x2 578437695752307201 578437695752307201))
+(assert_return (invoke "v128.load64_splat" (i32.const 5)) (v128.const i64x2 867798387104613893 867798387104613893))
+(assert_return (invoke "v128.load64_splat" (i32.const 60)) (v128.const i64x2 1061043516 1061043516))
+(assert_return (invoke "v128.load64_splat" (i32.const 65528)) (v128.const i64x2 0 0))
+(assert_return (invoke "v128.load64_splat-offset" (i32.const 2)) (v128.const i64x2 723118041428460547 723118041428460547))
+(assert_trap (invoke "v128.load64_splat" (i32.const 65529)) "out of bounds memory access")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load8_splat))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load16_splat))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load32_splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load32_splat))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_splat))) "type mismatch")
@@ -0,0 +1,29 @@
+;; Tests for the load and zero-extend instructions.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.load32_zero") (param i32) (result v128) (v128.load32_zero (local.get 0)))
+  (func (export "v128.load32_zero-offset") (param i32) (result v128) (v128.load32_zero offset=2 align=1 (local.get 0)))
+  (func (export "v128.load64_zero") (param i32) (result v128) (v128.load64_zero (local.get 0)))
+  (func (export "v128.load64_zero-offset") (param i32) (result v128) (v128.load64_zero offset=2 align=1 (local.get 0)))
+)
+
+(assert_return (invoke "v128.load32_zero" (i32.const 0)) (v128.const i32x4 50462976 0 0 0))
+(assert_return (invoke "v128.load32_zero" (i32.const 1)) (v128.const i32x4 67305985 0 0 0))
+(assert_return (invoke "v128.load32_zero" (i32.const 9)) (v128.const i32x4 202050057 0 0 0))
+(assert_return (invoke "v128.load32_zero" (i32.const 65532)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "v128.load32_zero-offset" (i32.const 5)) (v128.const i32x4 168364039 0 0 0))
+(assert_trap (invoke "v128.load32_zero" (i32.const 65533)) "out of bounds memory access")
+
+(assert_return (invoke "v128.load64_zero" (i32.const 0)) (v128.const i64x2 506097522914230528 0))
+(assert_return (invoke "v128.load64_zero" (i32.const 1)) (v128.const i64x2 578437695752307201 0))
+(assert_return (invoke "v128.load64_zero" (i32.const 9)) (v128.const i64x2 1157159078456920585 0))
+(assert_return (invoke "v128.load64_zero" (i32.const 65528)) (v128.const i64x2 0 0))
+(assert_return (invoke "v128.load64_zero-offset" (i32.const 5)) (v128.const i64x2 1012478732780767239 0))
+(assert_trap (invoke "v128.load64_zero" (i32.const 65529)) "out of bounds memory access")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (result v128) (v128.load32_zero (i64.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.load64_zero (i64.const 0)))) "type mismatch")
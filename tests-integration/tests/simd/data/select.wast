@@ -0,0 +1,17 @@
+;; Tests for the select instruction with v128 operands.
+
+(module
+  (func (export "select") (param v128 v128 i32) (result v128) (select (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "select-typed") (param v128 v128 i32) (result v128) (select (result v128) (local.get 0) (local.get 1) (local.get 2)))
+)
+
+(assert_return (invoke "select" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const 1)) (v128.const i32x4 1 2 3 4))
+(assert_return (invoke "select" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const 0)) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf))
+(assert_return (invoke "select" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const -1)) (v128.const i32x4 1 2 3 4))
+(assert_return (invoke "select-typed" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const 1)) (v128.const i32x4 1 2 3 4))
+(assert_return (invoke "select-typed" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const 0)) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf))
+(assert_return (invoke "select-typed" (v128.const i32x4 1 2 3 4) (v128.const f32x4 0x1p+0 nan:0x400001 -0x0p+0 -inf) (i32.const -1)) (v128.const i32x4 1 2 3 4))
+
+(assert_invalid (module (func (result v128) (select (v128.const i32x4 0 0 0 0) (i32.const 0) (i32.const 1)))) "type mismatch")
+(assert_invalid (module (func (result v128) (select (result v128) (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result i32) (select (result v128) (v128.const i32x4 0 0 0 0) (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
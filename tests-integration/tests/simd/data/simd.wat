@@ -0,0 +1,80 @@
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f")
+
+  (func (export "const") (result v128)
+    v128.const i32x4 1 2 3 4)
+
+  (func (export "i32x4.add") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i32x4.add)
+
+  (func (export "i8x16.add_sat_u") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i8x16.add_sat_u)
+
+  (func (export "i16x8.splat_extract") (param i32) (result i32)
+    local.get 0
+    i16x8.splat
+    i16x8.extract_lane_s 7)
+
+  (func (export "f32x4.replace_sum") (param f32) (result f32)
+    v128.const f32x4 1.5 2.5 3.5 4.5
+    local.get 0
+    f32x4.replace_lane 2
+    f32x4.extract_lane 2)
+
+  (func (export "i8x16.shuffle") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i8x16.shuffle 0 16 1 17 2 18 3 19 4 20 5 21 6 22 7 23)
+
+  (func (export "i32x4.lt_s") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i32x4.lt_s)
+
+  (func (export "i8x16.bitmask") (param v128) (result i32)
+    local.get 0
+    i8x16.bitmask)
+
+  (func (export "i32x4.shl") (param v128 i32) (result v128)
+    local.get 0
+    local.get 1
+    i32x4.shl)
+
+  (func (export "f64x2.min") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    f64x2.min)
+
+  (func (export "v128.load") (param i32) (result v128)
+    local.get 0
+    v128.load)
+
+  (func (export "v128.store") (param i32 v128)
+    local.get 0
+    local.get 1
+    v128.store)
+
+  (func (export "v128.load16_lane") (param i32 v128) (result v128)
+    local.get 0
+    local.get 1
+    v128.load16_lane offset=2 3)
+
+  (func (export "v128.store32_lane") (param i32 v128)
+    local.get 0
+    local.get 1
+    v128.store32_lane 1)
+
+  (func (export "i16x8.extend_high_i8x16_s") (param v128) (result v128)
+    local.get 0
+    i16x8.extend_high_i8x16_s)
+
+  (func (export "i8x16.narrow_i16x8_u") (param v128 v128) (result v128)
+    local.get 0
+    local.get 1
+    i8x16.narrow_i16x8_u)
+)
@@ -0,0 +1,124 @@
+;; Tests for the splat instructions.
+
+(module
+  (func (export "i8x16.splat") (param i32) (result v128) (i8x16.splat (local.get 0)))
+  (func (export "i16x8.splat") (param i32) (result v128) (i16x8.splat (local.get 0)))
+  (func (export "i32x4.splat") (param i32) (result v128) (i32x4.splat (local.get 0)))
+  (func (export "i64x2.splat") (param i64) (result v128) (i64x2.splat (local.get 0)))
+  (func (export "f32x4.splat") (param f32) (result v128) (f32x4.splat (local.get 0)))
+  (func (export "f64x2.splat") (param f64) (result v128) (f64x2.splat (local.get 0)))
+)
+
+(assert_return (invoke "i8x16.splat" (i32.const 0)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.splat" (i32.const 1)) (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1))
+(assert_return (invoke "i8x16.splat" (i32.const -1)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.splat" (i32.const 127)) (v128.const i8x16 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127 127))
+(assert_return (invoke "i8x16.splat" (i32.const 128)) (v128.const i8x16 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128 -128))
+(assert_return (invoke "i8x16.splat" (i32.const 255)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.splat" (i32.const 256)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.splat" (i32.const 32767)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.splat" (i32.const 32768)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.splat" (i32.const 65535)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.splat" (i32.const 65536)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.splat" (i32.const 2147483647)) (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i8x16.splat" (i32.const -2147483648)) (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.splat" (i32.const 0)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.splat" (i32.const 1)) (v128.const i16x8 1 1 1 1 1 1 1 1))
+(assert_return (invoke "i16x8.splat" (i32.const -1)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.splat" (i32.const 127)) (v128.const i16x8 127 127 127 127 127 127 127 127))
+(assert_return (invoke "i16x8.splat" (i32.const 128)) (v128.const i16x8 128 128 128 128 128 128 128 128))
+(assert_return (invoke "i16x8.splat" (i32.const 255)) (v128.const i16x8 255 255 255 255 255 255 255 255))
+(assert_return (invoke "i16x8.splat" (i32.const 256)) (v128.const i16x8 256 256 256 256 256 256 256 256))
+(assert_return (invoke "i16x8.splat" (i32.const 32767)) (v128.const i16x8 32767 32767 32767 32767 32767 32767 32767 32767))
+(assert_return (invoke "i16x8.splat" (i32.const 32768)) (v128.const i16x8 -32768 -32768 -32768 -32768 -32768 -32768 -32768 -32768))
+(assert_return (invoke "i16x8.splat" (i32.const 65535)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.splat" (i32.const 65536)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.splat" (i32.const 2147483647)) (v128.const i16x8 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "i16x8.splat" (i32.const -2147483648)) (v128.const i16x8 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i32x4.splat" (i32.const 0)) (v128.const i32x4 0 0 0 0))
+(assert_return (invoke "i32x4.splat" (i32.const 1)) (v128.const i32x4 1 1 1 1))
+(assert_return (invoke "i32x4.splat" (i32.const -1)) (v128.const i32x4 -1 -1 -1 -1))
+(assert_return (invoke "i32x4.splat" (i32.const 127)) (v128.const i32x4 127 127 127 127))
+(assert_return (invoke "i32x4.splat" (i32.const 128)) (v128.const i32x4 128 128 128 128))
+(assert_return (invoke "i32x4.splat" (i32.const 255)) (v128.const i32x4 255 255 255 255))
+(assert_return (invoke "i32x4.splat" (i32.const 256)) (v128.const i32x4 256 256 256 256))
+(assert_return (invoke "i32x4.splat" (i32.const 32767)) (v128.const i32x4 32767 32767 32767 32767))
+(assert_return (invoke "i32x4.splat" (i32.const 32768)) (v128.const i32x4 32768 32768 32768 32768))
+(assert_return (invoke "i32x4.splat" (i32.const 65535)) (v128.const i32x4 65535 65535 65535 65535))
+(assert_return (invoke "i32x4.splat" (i32.const 65536)) (v128.const i32x4 65536 65536 65536 65536))
+(assert_return (invoke "i32x4.splat" (i32.const 2147483647)) (v128.const i32x4 2147483647 2147483647 2147483647 2147483647))
+(assert_return (invoke "i32x4.splat" (i32.const -2147483648)) (v128.const i32x4 -2147483648 -2147483648 -2147483648 -2147483648))
+(assert_return (invoke "i64x2.splat" (i64.const 0)) (v128.const i64x2 0 0))
+(assert_return (invoke "i64x2.splat" (i64.const 1)) (v128.const i64x2 1 1))
+(assert_return (invoke "i64x2.splat" (i64.const -1)) (v128.const i64x2 -1 -1))
+(assert_return (invoke "i64x2.splat" (i64.const 9223372036854775807)) (v128.const i64x2 9223372036854775807 9223372036854775807))
+(assert_return (invoke "i64x2.splat" (i64.const -9223372036854775808)) (v128.const i64x2 -9223372036854775808 -9223372036854775808))
+(assert_return (invoke "i64x2.splat" (i64.const 4294967296)) (v128.const i64x2 4294967296 4294967296))
+(assert_return (invoke "f32x4.splat" (f32.const 0x0p+0)) (v128.const f32x4 0x0p+0 0x0p+0 0x0p+0 0x0p+0))
+(assert_return (invoke "f32x4.splat" (f32.const -0x0p+0)) (v128.const f32x4 -0x0p+0 -0x0p+0 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p+0)) (v128.const f32x4 0x1p+0 0x1p+0 0x1p+0 0x1p+0))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1p+0)) (v128.const f32x4 -0x1p+0 -0x1p+0 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p-1)) (v128.const f32x4 0x1p-1 0x1p-1 0x1p-1 0x1p-1))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1p-1)) (v128.const f32x4 -0x1p-1 -0x1p-1 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p-149)) (v128.const f32x4 0x1p-149 0x1p-149 0x1p-149 0x1p-149))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1p-149)) (v128.const f32x4 -0x1p-149 -0x1p-149 -0x1p-149 -0x1p-149))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1p-126)) (v128.const f32x4 0x1p-126 0x1p-126 0x1p-126 0x1p-126))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1p-126)) (v128.const f32x4 -0x1p-126 -0x1p-126 -0x1p-126 -0x1p-126))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.fffffep+127)) (v128.const f32x4 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127 0x1.fffffep+127))
+(assert_return (invoke "f32x4.splat" (f32.const -0x1.fffffep+127)) (v128.const f32x4 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127 -0x1.fffffep+127))
+(assert_return (invoke "f32x4.splat" (f32.const inf)) (v128.const f32x4 inf inf inf inf))
+(assert_return (invoke "f32x4.splat" (f32.const -inf)) (v128.const f32x4 -inf -inf -inf -inf))
+(assert_return (invoke "f32x4.splat" (f32.const nan)) (v128.const f32x4 nan nan nan nan))
+(assert_return (invoke "f32x4.splat" (f32.const -nan)) (v128.const f32x4 -nan -nan -nan -nan))
+(assert_return (invoke "f32x4.splat" (f32.const nan:0x200000)) (v128.const f32x4 nan:0x200000 nan:0x200000 nan:0x200000 nan:0x200000))
+(assert_return (invoke "f32x4.splat" (f32.const 0x1.921fb6p+2)) (v128.const f32x4 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2 0x1.921fb6p+2))
+(assert_return (invoke "f64x2.splat" (f64.const 0x0p+0)) (v128.const f64x2 0x0p+0 0x0p+0))
+(assert_return (invoke "f64x2.splat" (f64.const -0x0p+0)) (v128.const f64x2 -0x0p+0 -0x0p+0))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1p+0)) (v128.const f64x2 0x1p+0 0x1p+0))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1p+0)) (v128.const f64x2 -0x1p+0 -0x1p+0))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1p-1)) (v128.const f64x2 0x1p-1 0x1p-1))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1p-1)) (v128.const f64x2 -0x1p-1 -0x1p-1))
+(assert_return (invoke "f64x2.splat" (f64.const 0x0.0000000000001p-1022)) (v128.const f64x2 0x0.0000000000001p-1022 0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.splat" (f64.const -0x0.0000000000001p-1022)) (v128.const f64x2 -0x0.0000000000001p-1022 -0x0.0000000000001p-1022))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1p-1022)) (v128.const f64x2 0x1p-1022 0x1p-1022))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1p-1022)) (v128.const f64x2 -0x1p-1022 -0x1p-1022))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.fffffffffffffp+1023)) (v128.const f64x2 0x1.fffffffffffffp+1023 0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.splat" (f64.const -0x1.fffffffffffffp+1023)) (v128.const f64x2 -0x1.fffffffffffffp+1023 -0x1.fffffffffffffp+1023))
+(assert_return (invoke "f64x2.splat" (f64.const inf)) (v128.const f64x2 inf inf))
+(assert_return (invoke "f64x2.splat" (f64.const -inf)) (v128.const f64x2 -inf -inf))
+(assert_return (invoke "f64x2.splat" (f64.const nan)) (v128.const f64x2 nan nan))
+(assert_return (invoke "f64x2.splat" (f64.const -nan)) (v128.const f64x2 -nan -nan))
+(assert_return (invoke "f64x2.splat" (f64.const nan:0x4000000000000)) (v128.const f64x2 nan:0x4000000000000 nan:0x4000000000000))
+(assert_return (invoke "f64x2.splat" (f64.const 0x1.921fb54442d18p+2)) (v128.const f64x2 0x1.921fb54442d18p+2 0x1.921fb54442d18p+2))
+
+;; Type check
+
+(assert_invalid (module (func (result v128) (i8x16.splat (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i8x16.splat))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.splat (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i16x8.splat))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.splat (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i32x4.splat))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.splat (i32.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (i64x2.splat))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.splat (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f32x4.splat))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.splat (i64.const 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.splat (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (func (result v128) (f64x2.splat))) "type mismatch")
+
+;; Splats of globals and in constant positions
+
+(module
+  (global $g f64 (f64.const 2))
+  (func (export "as-i32x4-add") (param i32 i32) (result v128) (i32x4.add (i32x4.splat (local.get 0)) (i32x4.splat (local.get 1))))
+  (func (export "as-f64x2-mul") (param f64) (result v128) (f64x2.mul (f64x2.splat (local.get 0)) (f64x2.splat (global.get $g))))
+)
+
+(assert_return (invoke "as-i32x4-add" (i32.const 1) (i32.const -3)) (v128.const i32x4 -2 -2 -2 -2))
+(assert_return (invoke "as-f64x2-mul" (f64.const 1.5)) (v128.const f64x2 0x1.8p+1 0x1.8p+1))
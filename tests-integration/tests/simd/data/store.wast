@@ -0,0 +1,32 @@
+;; Tests for the v128.store instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "store") (param i32 v128) (result v128) (v128.store (local.get 0) (local.get 1)) (v128.load (local.get 0)))
+  (func (export "store-offset") (param i32 v128) (result v128) (v128.store offset=1 align=1 (local.get 0) (local.get 1)) (v128.load offset=1 (local.get 0)))
+  (func (export "store-bytes") (param i32 v128) (result i64) (v128.store (local.get 0) (local.get 1)) (i64.load offset=8 (local.get 0)))
+  (func (export "neighbours") (result v128) (v128.store (i32.const 32) (v128.const i32x4 -1 -1 -1 -1)) (v128.load (i32.const 24)))
+)
+
+(assert_return (invoke "store" (i32.const 0) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81)) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81))
+(assert_return (invoke "store-offset" (i32.const 0) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf)) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf))
+(assert_return (invoke "store" (i32.const 7) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81)) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81))
+(assert_return (invoke "store-offset" (i32.const 6) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf)) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf))
+(assert_return (invoke "store" (i32.const 100) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81)) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81))
+(assert_return (invoke "store-offset" (i32.const 99) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf)) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf))
+(assert_return (invoke "store" (i32.const 65520) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81)) (v128.const i8x16 -96 -95 -94 -93 -92 -91 -90 -89 -88 -87 -86 -85 -84 -83 -82 -81))
+(assert_return (invoke "store-offset" (i32.const 65519) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf)) (v128.const f32x4 0x1p+0 -0x1p+1 nan:0x400001 -inf))
+(assert_return (invoke "store-bytes" (i32.const 0) (v128.const i64x2 1 72623859790382856)) (i64.const 0x0102030405060708))
+(assert_return (invoke "neighbours" ) (v128.const i8x16 24 25 26 27 28 29 30 31 -1 -1 -1 -1 -1 -1 -1 -1))
+
+(assert_trap (invoke "store" (i32.const 65521) (v128.const i32x4 0 0 0 0)) "out of bounds memory access")
+(assert_trap (invoke "store" (i32.const 65535) (v128.const i32x4 0 0 0 0)) "out of bounds memory access")
+(assert_trap (invoke "store" (i32.const -1) (v128.const i32x4 0 0 0 0)) "out of bounds memory access")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (v128.store (i32.const 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (v128.store (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (v128.store (v128.const i32x4 0 0 0 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (result v128) (v128.store (i32.const 0) (v128.const i32x4 0 0 0 0)))) "type mismatch")
@@ -0,0 +1,46 @@
+;; Tests for the v128.store16_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.store16_lane_0") (param i32 v128) (result i32) (v128.store16_lane 0 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_1") (param i32 v128) (result i32) (v128.store16_lane 1 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_2") (param i32 v128) (result i32) (v128.store16_lane 2 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_3") (param i32 v128) (result i32) (v128.store16_lane 3 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_4") (param i32 v128) (result i32) (v128.store16_lane 4 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_5") (param i32 v128) (result i32) (v128.store16_lane 5 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_6") (param i32 v128) (result i32) (v128.store16_lane 6 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_7") (param i32 v128) (result i32) (v128.store16_lane 7 (local.get 0) (local.get 1)) (i32.load16_u (local.get 0)))
+  (func (export "v128.store16_lane_0_offset") (param i32 v128) (result i32) (v128.store16_lane offset=1 0 (local.get 0) (local.get 1)) (i32.load16_u offset=1 (local.get 0)))
+  (func (export "v128.store16_lane_neighbours") (param v128) (result v128) (v128.store16_lane 0 (i32.const 8) (local.get 0)) (v128.load (i32.const 0)))
+)
+
+(assert_return (invoke "v128.store16_lane_0" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 8223))
+(assert_return (invoke "v128.store16_lane_0" (i32.const 200) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 8223))
+(assert_return (invoke "v128.store16_lane_1" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 16431))
+(assert_return (invoke "v128.store16_lane_1" (i32.const 201) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 16431))
+(assert_return (invoke "v128.store16_lane_2" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 24639))
+(assert_return (invoke "v128.store16_lane_2" (i32.const 202) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 24639))
+(assert_return (invoke "v128.store16_lane_3" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 32847))
+(assert_return (invoke "v128.store16_lane_3" (i32.const 203) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 32847))
+(assert_return (invoke "v128.store16_lane_4" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 41055))
+(assert_return (invoke "v128.store16_lane_4" (i32.const 204) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 41055))
+(assert_return (invoke "v128.store16_lane_5" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 49263))
+(assert_return (invoke "v128.store16_lane_5" (i32.const 205) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 49263))
+(assert_return (invoke "v128.store16_lane_6" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 57471))
+(assert_return (invoke "v128.store16_lane_6" (i32.const 206) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 57471))
+(assert_return (invoke "v128.store16_lane_7" (i32.const 100) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 143))
+(assert_return (invoke "v128.store16_lane_7" (i32.const 207) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 143))
+(assert_return (invoke "v128.store16_lane_0_offset" (i32.const 300) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (i32.const 8223))
+(assert_return (invoke "v128.store16_lane_neighbours" (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) (v128.const i8x16 0 1 2 3 4 5 6 7 31 32 10 11 12 13 14 15))
+(assert_trap (invoke "v128.store16_lane_0" (i32.const 65535) (v128.const i16x8 8223 16431 24639 -32689 -24481 -16273 -8065 143)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (v128.store16_lane 8 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (v128.store16_lane align=4 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (v128.store16_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (v128.store16_lane 0 (i32.const 0)))) "type mismatch")
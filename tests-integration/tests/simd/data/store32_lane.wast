@@ -0,0 +1,34 @@
+;; Tests for the v128.store32_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.store32_lane_0") (param i32 v128) (result i32) (v128.store32_lane 0 (local.get 0) (local.get 1)) (i32.load (local.get 0)))
+  (func (export "v128.store32_lane_1") (param i32 v128) (result i32) (v128.store32_lane 1 (local.get 0) (local.get 1)) (i32.load (local.get 0)))
+  (func (export "v128.store32_lane_2") (param i32 v128) (result i32) (v128.store32_lane 2 (local.get 0) (local.get 1)) (i32.load (local.get 0)))
+  (func (export "v128.store32_lane_3") (param i32 v128) (result i32) (v128.store32_lane 3 (local.get 0) (local.get 1)) (i32.load (local.get 0)))
+  (func (export "v128.store32_lane_0_offset") (param i32 v128) (result i32) (v128.store32_lane offset=1 0 (local.get 0) (local.get 1)) (i32.load offset=1 (local.get 0)))
+  (func (export "v128.store32_lane_neighbours") (param v128) (result v128) (v128.store32_lane 0 (i32.const 8) (local.get 0)) (v128.load (i32.const 0)))
+)
+
+(assert_return (invoke "v128.store32_lane_0" (i32.const 100) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const 1076895775))
+(assert_return (invoke "v128.store32_lane_0" (i32.const 200) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const 1076895775))
+(assert_return (invoke "v128.store32_lane_1" (i32.const 100) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const -2141175761))
+(assert_return (invoke "v128.store32_lane_1" (i32.const 201) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const -2141175761))
+(assert_return (invoke "v128.store32_lane_2" (i32.const 100) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const -1064280001))
+(assert_return (invoke "v128.store32_lane_2" (i32.const 202) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const -1064280001))
+(assert_return (invoke "v128.store32_lane_3" (i32.const 100) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const 12615759))
+(assert_return (invoke "v128.store32_lane_3" (i32.const 203) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const 12615759))
+(assert_return (invoke "v128.store32_lane_0_offset" (i32.const 300) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (i32.const 1076895775))
+(assert_return (invoke "v128.store32_lane_neighbours" (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) (v128.const i8x16 0 1 2 3 4 5 6 7 31 32 48 64 12 13 14 15))
+(assert_trap (invoke "v128.store32_lane_0" (i32.const 65533) (v128.const i32x4 1076895775 -2141175761 -1064280001 12615759)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (v128.store32_lane 4 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (v128.store32_lane align=8 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (v128.store32_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (v128.store32_lane 0 (i32.const 0)))) "type mismatch")
@@ -0,0 +1,28 @@
+;; Tests for the v128.store64_lane instruction.
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\00\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\11\12\13\14\15\16\17\18\19\1a\1b\1c\1d\1e\1f\20\21\22\23\24\25\26\27\28\29\2a\2b\2c\2d\2e\2f\30\31\32\33\34\35\36\37\38\39\3a\3b\3c\3d\3e\3f")
+  (func (export "v128.store64_lane_0") (param i32 v128) (result i64) (v128.store64_lane 0 (local.get 0) (local.get 1)) (i64.load (local.get 0)))
+  (func (export "v128.store64_lane_1") (param i32 v128) (result i64) (v128.store64_lane 1 (local.get 0) (local.get 1)) (i64.load (local.get 0)))
+  (func (export "v128.store64_lane_0_offset") (param i32 v128) (result i64) (v128.store64_lane offset=1 0 (local.get 0) (local.get 1)) (i64.load offset=1 (local.get 0)))
+  (func (export "v128.store64_lane_neighbours") (param v128) (result v128) (v128.store64_lane 0 (i32.const 8) (local.get 0)) (v128.load (i32.const 0)))
+)
+
+(assert_return (invoke "v128.store64_lane_0" (i32.const 100) (v128.const i64x2 -9191740941672636385 63262190364278831)) (i64.const -9191740941672636385))
+(assert_return (invoke "v128.store64_lane_0" (i32.const 200) (v128.const i64x2 -9191740941672636385 63262190364278831)) (i64.const -9191740941672636385))
+(assert_return (invoke "v128.store64_lane_1" (i32.const 100) (v128.const i64x2 -9191740941672636385 63262190364278831)) (i64.const 63262190364278831))
+(assert_return (invoke "v128.store64_lane_1" (i32.const 201) (v128.const i64x2 -9191740941672636385 63262190364278831)) (i64.const 63262190364278831))
+(assert_return (invoke "v128.store64_lane_0_offset" (i32.const 300) (v128.const i64x2 -9191740941672636385 63262190364278831)) (i64.const -9191740941672636385))
+(assert_return (invoke "v128.store64_lane_neighbours" (v128.const i64x2 -9191740941672636385 63262190364278831)) (v128.const i8x16 0 1 2 3 4 5 6 7 31 32 48 64 80 96 112 -128))
+(assert_trap (invoke "v128.store64_lane_0" (i32.const 65529) (v128.const i64x2 -9191740941672636385 63262190364278831)) "out of bounds memory access")
+
+;; Invalid lane indices and alignments
+
+(assert_invalid (module (memory 1) (func (v128.store64_lane 2 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "invalid lane index")
+(assert_invalid (module (memory 1) (func (v128.store64_lane align=16 0 (i32.const 0) (v128.const i32x4 0 0 0 0)))) "alignment must not be larger than natural")
+
+;; Type check
+
+(assert_invalid (module (memory 1) (func (v128.store64_lane 0 (v128.const i32x4 0 0 0 0) (i32.const 0)))) "type mismatch")
+(assert_invalid (module (memory 1) (func (v128.store64_lane 0 (i32.const 0)))) "type mismatch")
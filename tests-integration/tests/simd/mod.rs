@@ -0,0 +1,233 @@
+use {
+    tests::spec::{
+        loader::parse_and_run,
+        runner::{RunConfig, RunSet},
+    },
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{simd, Runtime},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn i32x4(lanes: [i32; 4]) -> u128 {
+    simd::from_lanes(lanes)
+}
+
+fn i8x16(lanes: [i8; 16]) -> u128 {
+    simd::from_lanes(lanes)
+}
+
+#[test]
+fn simd_arithmetic() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/simd/data/simd.wat")?;
+
+    let res = runtime.call(&mod_inst, "const", &[])?;
+    assert_eq!(res, vec![i32x4([1, 2, 3, 4]).into()]);
+
+    let res = runtime.call(&mod_inst, "i32x4.add", &[
+        i32x4([1, -2, i32::MAX, 4]).into(),
+        i32x4([10, 20, 1, -4]).into(),
+    ])?;
+    assert_eq!(res, vec![i32x4([11, 18, i32::MIN, 0]).into()]);
+
+    let res = runtime.call(&mod_inst, "i8x16.add_sat_u", &[
+        simd::splat::<u8, 16>(200).into(),
+        simd::from_lanes::<u8, 16>(std::array::from_fn(|i| i as u8 * 10)).into(),
+    ])?;
+    let expected =
+        simd::from_lanes::<u8, 16>(std::array::from_fn(|i| 200u8.saturating_add(i as u8 * 10)));
+    assert_eq!(res, vec![expected.into()]);
+
+    let res = runtime.call(&mod_inst, "i32x4.lt_s", &[
+        i32x4([1, -2, 3, 4]).into(),
+        i32x4([2, -3, 3, 5]).into(),
+    ])?;
+    assert_eq!(res, vec![i32x4([-1, 0, 0, -1]).into()]);
+
+    let res = runtime.call(&mod_inst, "i32x4.shl", &[
+        i32x4([1, 2, 3, -1]).into(),
+        33u32.into(),
+    ])?;
+    assert_eq!(res, vec![i32x4([2, 4, 6, -2]).into()]);
+
+    let mut lanes = [0i8; 16];
+    lanes[0] = -1;
+    lanes[3] = -128;
+    lanes[15] = -5;
+    let res = runtime.call(&mod_inst, "i8x16.bitmask", &[i8x16(lanes).into()])?;
+    assert_eq!(res, vec![0x8009u32.into()]);
+
+    let res = runtime.call(&mod_inst, "i16x8.extend_high_i8x16_s", &[i8x16([
+        0, 0, 0, 0, 0, 0, 0, 0, -1, 2, -3, 4, -5, 6, -7, 8,
+    ])
+    .into()])?;
+    let expected = simd::from_lanes::<i16, 8>([-1, 2, -3, 4, -5, 6, -7, 8]);
+    assert_eq!(res, vec![expected.into()]);
+
+    let res = runtime.call(&mod_inst, "i8x16.narrow_i16x8_u", &[
+        simd::from_lanes::<i16, 8>([-1, 0, 1, 255, 256, 1000, -1000, 7]).into(),
+        simd::splat::<i16, 8>(300).into(),
+    ])?;
+    let expected = simd::from_lanes::<u8, 16>([
+        0, 0, 1, 255, 255, 255, 0, 7, 255, 255, 255, 255, 255, 255, 255, 255,
+    ]);
+    assert_eq!(res, vec![expected.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn simd_lanes() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/simd/data/simd.wat")?;
+
+    let res = runtime.call(&mod_inst, "i16x8.splat_extract", &[0x1234_8001u32.into()])?;
+    assert_eq!(res, vec![0xFFFF_8001u32.into()]);
+
+    let res = runtime.call(&mod_inst, "f32x4.replace_sum", &[7.25f32.into()])?;
+    assert_eq!(res, vec![7.25f32.into()]);
+
+    let l: [u8; 16] = std::array::from_fn(|i| i as u8);
+    let r: [u8; 16] = std::array::from_fn(|i| 0x80 + i as u8);
+    let res = runtime.call(&mod_inst, "i8x16.shuffle", &[
+        simd::from_lanes(l).into(),
+        simd::from_lanes(r).into(),
+    ])?;
+    let expected: [u8; 16] = std::array::from_fn(|i| if i % 2 == 0 { l[i / 2] } else { r[i / 2] });
+    assert_eq!(res, vec![simd::from_lanes(expected).into()]);
+
+    Ok(())
+}
+
+#[test]
+fn simd_float_min() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/simd/data/simd.wat")?;
+
+    let res = runtime.call(&mod_inst, "f64x2.min", &[
+        simd::from_lanes::<f64, 2>([0.0, 1.0]).into(),
+        simd::from_lanes::<f64, 2>([-0.0, f64::NAN]).into(),
+    ])?;
+    let lanes = simd::lanes::<f64, 2>(res[0].try_into()?);
+    assert!(lanes[0] == 0.0 && lanes[0].is_sign_negative());
+    assert!(lanes[1].is_nan());
+
+    Ok(())
+}
+
+#[test]
+fn simd_memory() -> Result<()> {
+    let runtime = &mut Runtime::new();
+    let mod_inst = runtime.load_file("tests/simd/data/simd.wat")?;
+
+    let res = runtime.call(&mod_inst, "v128.load", &[0u32.into()])?;
+    let expected = simd::from_lanes::<u8, 16>(std::array::from_fn(|i| i as u8));
+    assert_eq!(res, vec![expected.into()]);
+
+    // Load the 16 bits at address 4 + 2 into lane 3.
+    let res = runtime.call(&mod_inst, "v128.load16_lane", &[4u32.into(), 0u128.into()])?;
+    assert_eq!(res, vec![(0x0706u128 << 48).into()]);
+
+    runtime.call(&mod_inst, "v128.store32_lane", &[
+        0u32.into(),
+        i32x4([1, 0x0B0A0908, 3, 4]).into(),
+    ])?;
+    runtime.call(&mod_inst, "v128.store", &[
+        32u32.into(),
+        i32x4([5, 6, 7, 8]).into(),
+    ])?;
+
+    let res = runtime.call(&mod_inst, "v128.load", &[0u32.into()])?;
+    let expected = simd::from_lanes::<u8, 16>(std::array::from_fn(|i| {
+        if i < 4 {
+            8 + i as u8
+        } else {
+            i as u8
+        }
+    }));
+    assert_eq!(res, vec![expected.into()]);
+
+    let res = runtime.call(&mod_inst, "v128.load", &[32u32.into()])?;
+    assert_eq!(res, vec![i32x4([5, 6, 7, 8]).into()]);
+
+    Ok(())
+}
+
+// Scripts for each group of SIMD instructions, in the format of the spec
+// tests. They were written for this project, and cover a subset of what the
+// upstream SIMD spec tests do.
+macro_rules! simdtest {
+    ($name:ident) => {
+        #[test]
+        fn $name() -> tests::spec::error::Result<()> {
+            parse_and_run(
+                format!("tests/simd/data/{}.wast", &stringify!($name)[2..]),
+                RunConfig {
+                    runset:             RunSet::All,
+                    failures_to_ignore: &[],
+                    binary_roundtrip:   false,
+                },
+            )
+        }
+    };
+}
+
+simdtest!(r#address);
+simdtest!(r#align);
+simdtest!(r#bit_shift);
+simdtest!(r#bitwise);
+simdtest!(r#boolean);
+simdtest!(r#const);
+simdtest!(r#conversions);
+simdtest!(r#f32x4);
+simdtest!(r#f32x4_arith);
+simdtest!(r#f32x4_cmp);
+simdtest!(r#f32x4_pmin_pmax);
+simdtest!(r#f32x4_rounding);
+simdtest!(r#f64x2);
+simdtest!(r#f64x2_arith);
+simdtest!(r#f64x2_cmp);
+simdtest!(r#f64x2_pmin_pmax);
+simdtest!(r#f64x2_rounding);
+simdtest!(r#i16x8_arith);
+simdtest!(r#i16x8_arith2);
+simdtest!(r#i16x8_cmp);
+simdtest!(r#i16x8_extadd_pairwise_i8x16);
+simdtest!(r#i16x8_extmul_i8x16);
+simdtest!(r#i16x8_q15mulr_sat_s);
+simdtest!(r#i16x8_sat_arith);
+simdtest!(r#i32x4_arith);
+simdtest!(r#i32x4_arith2);
+simdtest!(r#i32x4_cmp);
+simdtest!(r#i32x4_dot_i16x8);
+simdtest!(r#i32x4_extadd_pairwise_i16x8);
+simdtest!(r#i32x4_extmul_i16x8);
+simdtest!(r#i32x4_trunc_sat_f32x4);
+simdtest!(r#i32x4_trunc_sat_f64x2);
+simdtest!(r#i64x2_arith);
+simdtest!(r#i64x2_arith2);
+simdtest!(r#i64x2_cmp);
+simdtest!(r#i64x2_extmul_i32x4);
+simdtest!(r#i8x16_arith);
+simdtest!(r#i8x16_arith2);
+simdtest!(r#i8x16_cmp);
+simdtest!(r#i8x16_sat_arith);
+simdtest!(r#int_to_int_extend);
+simdtest!(r#lane);
+simdtest!(r#linking);
+simdtest!(r#load);
+simdtest!(r#load16_lane);
+simdtest!(r#load32_lane);
+simdtest!(r#load64_lane);
+simdtest!(r#load8_lane);
+simdtest!(r#load_extend);
+simdtest!(r#load_splat);
+simdtest!(r#load_zero);
+simdtest!(r#select);
+simdtest!(r#splat);
+simdtest!(r#store);
+simdtest!(r#store16_lane);
+simdtest!(r#store32_lane);
+simdtest!(r#store64_lane);
+simdtest!(r#store8_lane);
@@ -744,8 +744,7 @@
     "\1a"                             ;; drop
     "\0b"                             ;; end
   )
-  ;; TODO: This changes to "integer too large" with memory64.
-  "integer representation too long"
+  "integer too large"
 )
 (assert_malformed
   (module binary
@@ -764,8 +763,7 @@
     "\1a"                             ;; drop
     "\0b"                             ;; end
   )
-  ;; TODO: This changes to "integer too large" with memory64.
-  "integer representation too long"
+  "integer too large"
 )
 (assert_malformed
   (module binary
@@ -859,8 +857,7 @@
     "\82\80\80\80\80\80\80\80\80\10"  ;; offset 2 with unused bits set
     "\0b"                             ;; end
   )
-  ;; TODO: This changes to "integer too large" with memory64.
-  "integer representation too long"
+  "integer too large"
 )
 (assert_malformed
   (module binary
@@ -879,8 +876,7 @@
     "\82\80\80\80\80\80\80\80\80\40"  ;; offset 2 with some unused bits set
     "\0b"                             ;; end
   )
-  ;; TODO: This changes to "integer too large" with memory64.
-  "integer representation too long"
+  "integer too large"
 )
 
 ;; Signed LEB128s sign-extend
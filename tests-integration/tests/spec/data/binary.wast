@@ -118,11 +118,12 @@
     ;; Missing end marker here
     "\0a\04\01\02\00\0b"       ;; Code section: 1 function
   )
-  "illegal opcode"
+  ;; The code section id is read as throw_ref, followed by an unterminated if.
+  "unexpected end"
 )
 
-;; memory.grow reserved byte equal to zero.
-(assert_malformed
+;; memory.grow memory index must refer to a memory.
+(assert_invalid
   (module binary
     "\00asm" "\01\00\00\00"
     "\01\04\01\60\00\00"       ;; Type section
@@ -134,93 +135,81 @@
     "\07\00"
     "\41\00"                   ;; i32.const 0
     "\40"                      ;; memory.grow
-    "\01"                      ;; memory.grow reserved byte is not equal to zero!
+    "\01"                      ;; memory.grow memory index 1
     "\1a"                      ;; drop
     "\0b"                      ;; end
   )
-  "zero byte expected"
+  "unknown memory"
 )
 
-;; memory.grow reserved byte should not be a "long" LEB128 zero.
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0a\01"                ;; Code section
+;; memory.grow memory index may be a "long" LEB128 zero.
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0a\01"                  ;; Code section
 
-    ;; function 0
-    "\08\00"
-    "\41\00"                   ;; i32.const 0
-    "\40"                      ;; memory.grow
-    "\80\00"                   ;; memory.grow reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\08\00"
+  "\41\00"                     ;; i32.const 0
+  "\40"                        ;; memory.grow
+  "\80\00"                     ;; memory.grow memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
 ;; Same as above for 3, 4, and 5-byte zero encodings.
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0b\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0b\01"                  ;; Code section
 
-    ;; function 0
-    "\09\00"
-    "\41\00"                   ;; i32.const 0
-    "\40"                      ;; memory.grow
-    "\80\80\00"                ;; memory.grow reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\09\00"
+  "\41\00"                     ;; i32.const 0
+  "\40"                        ;; memory.grow
+  "\80\80\00"                  ;; memory.grow memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0c\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0c\01"                  ;; Code section
 
-    ;; function 0
-    "\0a\00"
-    "\41\00"                   ;; i32.const 0
-    "\40"                      ;; memory.grow
-    "\80\80\80\00"             ;; memory.grow reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\0a\00"
+  "\41\00"                     ;; i32.const 0
+  "\40"                        ;; memory.grow
+  "\80\80\80\00"               ;; memory.grow memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0d\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0d\01"                  ;; Code section
 
-    ;; function 0
-    "\0b\00"
-    "\41\00"                   ;; i32.const 0
-    "\40"                      ;; memory.grow
-    "\80\80\80\80\00"          ;; memory.grow reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\0b\00"
+  "\41\00"                     ;; i32.const 0
+  "\40"                        ;; memory.grow
+  "\80\80\80\80\00"            ;; memory.grow memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
-;; memory.size reserved byte equal to zero.
-(assert_malformed
+;; memory.size memory index must refer to a memory.
+(assert_invalid
   (module binary
     "\00asm" "\01\00\00\00"
     "\01\04\01\60\00\00"       ;; Type section
@@ -231,85 +220,73 @@
     ;; function 0
     "\05\00"
     "\3f"                      ;; memory.size
-    "\01"                      ;; memory.size reserved byte is not equal to zero!
+    "\01"                      ;; memory.size memory index 1
     "\1a"                      ;; drop
     "\0b"                      ;; end
   )
-  "zero byte expected"
+  "unknown memory"
 )
 
-;; memory.size reserved byte should not be a "long" LEB128 zero.
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\08\01"                ;; Code section
+;; memory.size memory index may be a "long" LEB128 zero.
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\08\01"                  ;; Code section
 
-    ;; function 0
-    "\06\00"
-    "\3f"                      ;; memory.size
-    "\80\00"                   ;; memory.size reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\06\00"
+  "\3f"                        ;; memory.size
+  "\80\00"                     ;; memory.size memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
 ;; Same as above for 3, 4, and 5-byte zero encodings.
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\09\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\09\01"                  ;; Code section
 
-    ;; function 0
-    "\07\00"
-    "\3f"                      ;; memory.size
-    "\80\80\00"                ;; memory.size reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\07\00"
+  "\3f"                        ;; memory.size
+  "\80\80\00"                  ;; memory.size memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0a\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0a\01"                  ;; Code section
 
-    ;; function 0
-    "\08\00"
-    "\3f"                      ;; memory.size
-    "\80\80\80\00"             ;; memory.size reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\08\00"
+  "\3f"                        ;; memory.size
+  "\80\80\80\00"               ;; memory.size memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
-(assert_malformed
-  (module binary
-    "\00asm" "\01\00\00\00"
-    "\01\04\01\60\00\00"       ;; Type section
-    "\03\02\01\00"             ;; Function section
-    "\05\03\01\00\00"          ;; Memory section
-    "\0a\0b\01"                ;; Code section
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\05\03\01\00\00"            ;; Memory section
+  "\0a\0b\01"                  ;; Code section
 
-    ;; function 0
-    "\09\00"
-    "\3f"                      ;; memory.size
-    "\80\80\80\80\00"          ;; memory.size reserved byte
-    "\1a"                      ;; drop
-    "\0b"                      ;; end
-  )
-  "zero byte expected"
+  ;; function 0
+  "\09\00"
+  "\3f"                        ;; memory.size
+  "\80\80\80\80\00"            ;; memory.size memory index 0
+  "\1a"                        ;; drop
+  "\0b"                        ;; end
 )
 
 ;; Local number is unsigned 32 bit
@@ -384,16 +361,16 @@
 ;; Local count can be 0.
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\04\01\60\00\00"     ;; Type section
-  "\03\02\01\00"           ;; Function section
-  "\0a\0a\01"              ;; Code section
+  "\01\04\01\60\00\00"       ;; Type section
+  "\03\02\01\00"             ;; Function section
+  "\0a\0a\01"                ;; Code section
 
   ;; function 0
   "\08\03"
-  "\00\7f"                 ;; 0 i32
-  "\00\7e"                 ;; 0 i64
-  "\02\7d"                 ;; 2 f32
-  "\0b"                    ;; end
+  "\00\7f"                   ;; 0 i32
+  "\00\7e"                   ;; 0 i64
+  "\02\7d"                   ;; 2 f32
+  "\0b"                      ;; end
 )
 
 ;; Function section has non-zero count, but code section is absent.
@@ -440,13 +417,13 @@
 ;; Function section has zero count, and code section is absent.
 (module binary
   "\00asm" "\01\00\00\00"
-  "\03\01\00"  ;; Function section with 0 functions
+  "\03\01\00"    ;; Function section with 0 functions
 )
 
 ;; Code section has zero count, and function section is absent.
 (module binary
   "\00asm" "\01\00\00\00"
-  "\0a\01\00"  ;; Code section with 0 functions
+  "\0a\01\00"    ;; Code section with 0 functions
 )
 
 ;; Fewer passive segments than datacount
@@ -566,55 +543,55 @@
 (module binary
   "\00asm" "\01\00\00\00"
 
-  "\01\04\01\60\00\00"       ;; Type section
+  "\01\04\01\60\00\00"         ;; Type section
 
-  "\03\02\01\00"             ;; Function section
+  "\03\02\01\00"               ;; Function section
 
-  "\04\04\01"                ;; Table section with 1 entry
-  "\70\00\00"                ;; no max, minimum 0, funcref
+  "\04\04\01"                  ;; Table section with 1 entry
+  "\70\00\00"                  ;; no max, minimum 0, funcref
 
-  "\05\03\01\00\00"          ;; Memory section
+  "\05\03\01\00\00"            ;; Memory section
 
-  "\09\07\01"                ;; Element section with one segment
-  "\05\70"                   ;; Passive, funcref
-  "\01"                      ;; 1 element
-  "\d2\00\0b"                ;; ref.func, index 0, end
+  "\09\07\01"                  ;; Element section with one segment
+  "\05\70"                     ;; Passive, funcref
+  "\01"                        ;; 1 element
+  "\d2\00\0b"                  ;; ref.func, index 0, end
 
-  "\0a\04\01"                ;; Code section
+  "\0a\04\01"                  ;; Code section
 
   ;; function 0
   "\02\00"
-  "\0b")                     ;; end
+  "\0b")                       ;; end
 
 ;; passive element segment containing opcode ref.null
 (module binary
   "\00asm" "\01\00\00\00"
 
-  "\01\04\01\60\00\00"       ;; Type section
+  "\01\04\01\60\00\00"         ;; Type section
 
-  "\03\02\01\00"             ;; Function section
+  "\03\02\01\00"               ;; Function section
 
-  "\04\04\01"                ;; Table section with 1 entry
-  "\70\00\00"                ;; no max, minimum 0, funcref
+  "\04\04\01"                  ;; Table section with 1 entry
+  "\70\00\00"                  ;; no max, minimum 0, funcref
 
-  "\05\03\01\00\00"          ;; Memory section
+  "\05\03\01\00\00"            ;; Memory section
 
-  "\09\07\01"                ;; Element section with one segment
-  "\05\70"                   ;; Passive, funcref
-  "\01"                      ;; 1 element
-  "\d0\70\0b"                ;; ref.null, end
+  "\09\07\01"                  ;; Element section with one segment
+  "\05\70"                     ;; Passive, funcref
+  "\01"                        ;; 1 element
+  "\d0\70\0b"                  ;; ref.null, end
 
-  "\0a\04\01"                ;; Code section
+  "\0a\04\01"                  ;; Code section
 
   ;; function 0
   "\02\00"
-  "\0b")                     ;; end
+  "\0b")                       ;; end
 
 
 ;; Type count can be zero
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\01\00"                               ;; type count can be zero
+  "\01\01\00"                                 ;; type count can be zero
 )
 
 ;; 2 type declared, 1 given
@@ -654,7 +631,7 @@
       "\02\04\01"                           ;; import section with single entry
       "\00"                                 ;; string length 0
       "\00"                                 ;; string length 0
-      "\04"                                 ;; malformed import kind
+      "\05"                                 ;; malformed import kind
   )
   "malformed import kind"
 )
@@ -664,7 +641,7 @@
       "\02\05\01"                           ;; import section with single entry
       "\00"                                 ;; string length 0
       "\00"                                 ;; string length 0
-      "\04"                                 ;; malformed import kind
+      "\05"                                 ;; malformed import kind
       "\00"                                 ;; dummy byte
   )
   "malformed import kind"
@@ -824,7 +801,7 @@
   (module binary
       "\00asm" "\01\00\00\00"
       "\05\02\01"                           ;; memory section with one entry
-      "\02"                                 ;; malformed memory limits flag
+      "\08"                                 ;; malformed memory limits flag
   )
   "integer too large"
 )
@@ -832,7 +809,7 @@
   (module binary
       "\00asm" "\01\00\00\00"
       "\05\03\01"                           ;; memory section with one entry
-      "\02"                                 ;; malformed memory limits flag
+      "\08"                                 ;; malformed memory limits flag
       "\00"                                 ;; dummy byte
   )
   "integer too large"
@@ -859,7 +836,7 @@
 ;; Global count can be zero
 (module binary
   "\00asm" "\01\00\00\00"
-  "\06\01\00"                               ;; global count can be zero
+  "\06\01\00"                                 ;; global count can be zero
 )
 
 ;; 2 global declared, 1 given
@@ -887,13 +864,13 @@
 ;; Export count can be 0
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\04\01"                               ;; type section
-  "\60\00\00"                               ;; type 0
-  "\03\03\02\00\00"                         ;; func section
-  "\07\01\00"                               ;; export count can be zero
-  "\0a\07\02"                               ;; code section
-  "\02\00\0b"                               ;; function body 0
-  "\02\00\0b"                               ;; function body 1
+  "\01\04\01"                                 ;; type section
+  "\60\00\00"                                 ;; type 0
+  "\03\03\02\00\00"                           ;; func section
+  "\07\01\00"                                 ;; export count can be zero
+  "\0a\07\02"                                 ;; code section
+  "\02\00\0b"                                 ;; function body 0
+  "\02\00\0b"                                 ;; function body 1
 )
 
 ;; 2 export declared, 1 given
@@ -941,14 +918,14 @@
 ;; elem segment count can be zero
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\04\01"                               ;; type section
-  "\60\00\00"                               ;; type 0
-  "\03\02\01\00"                            ;; func section
-  "\04\04\01"                               ;; table section
-  "\70\00\01"                               ;; table 0
-  "\09\01\00"                               ;; elem segment count can be zero
-  "\0a\04\01"                               ;; code section
-  "\02\00\0b"                               ;; function body
+  "\01\04\01"                                 ;; type section
+  "\60\00\00"                                 ;; type 0
+  "\03\02\01\00"                              ;; func section
+  "\04\04\01"                                 ;; table section
+  "\70\00\01"                                 ;; table 0
+  "\09\01\00"                                 ;; elem segment count can be zero
+  "\0a\04\01"                                 ;; code section
+  "\02\00\0b"                                 ;; function body
 )
 
 ;; 2 elem segment declared, 1 given
@@ -1005,9 +982,9 @@
 ;; data segment count can be zero
 (module binary
   "\00asm" "\01\00\00\00"
-  "\05\03\01"                               ;; memory section
-  "\00\01"                                  ;; memory 0
-  "\0b\01\00"                               ;; data segment count can be zero
+  "\05\03\01"                                 ;; memory section
+  "\00\01"                                    ;; memory 0
+  "\0b\01\00"                                 ;; data segment count can be zero
 )
 
 ;; 2 data segment declared, 1 given
@@ -1067,18 +1044,18 @@
 ;; br_table target count can be zero
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\04\01"                               ;; type section
-  "\60\00\00"                               ;; type 0
-  "\03\02\01\00"                            ;; func section
-  "\0a\11\01"                               ;; code section
-  "\0f\00"                                  ;; func 0
-  "\02\40"                                  ;; block 0
-  "\41\01"                                  ;; condition of if 0
-  "\04\40"                                  ;; if 0
-  "\41\01"                                  ;; index of br_table element
-  "\0e\00"                                  ;; br_table target count can be zero
-  "\02"                                     ;; break depth for default
-  "\0b\0b\0b"                               ;; end
+  "\01\04\01"                                 ;; type section
+  "\60\00\00"                                 ;; type 0
+  "\03\02\01\00"                              ;; func section
+  "\0a\11\01"                                 ;; code section
+  "\0f\00"                                    ;; func 0
+  "\02\40"                                    ;; block 0
+  "\41\01"                                    ;; condition of if 0
+  "\04\40"                                    ;; if 0
+  "\41\01"                                    ;; index of br_table element
+  "\0e\00"                                    ;; br_table target count can be zero
+  "\02"                                       ;; break depth for default
+  "\0b\0b\0b"                                 ;; end
 )
 
 ;; 1 br_table target declared, 2 given
@@ -1106,14 +1083,14 @@
 ;; Start section
 (module binary
   "\00asm" "\01\00\00\00"
-  "\01\04\01\60\00\00"       ;; Type section
-  "\03\02\01\00"             ;; Function section
-  "\08\01\00"                ;; Start section: function 0
+  "\01\04\01\60\00\00"         ;; Type section
+  "\03\02\01\00"               ;; Function section
+  "\08\01\00"                  ;; Start section: function 0
 
-  "\0a\04\01"                ;; Code section
+  "\0a\04\01"                  ;; Code section
   ;; function 0
   "\02\00"
-  "\0b"                      ;; end
+  "\0b"                        ;; end
 )
 
 ;; Multiple start sections
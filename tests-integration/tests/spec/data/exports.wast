@@ -185,8 +185,7 @@
 
 (module (memory 0) (export "a" (memory 0)))
 (module (memory 0) (export "a" (memory 0)) (export "b" (memory 0)))
-;; No multiple memories yet.
-;; (module (memory 0) (memory 0) (export "a" (memory 0)) (export "b" (memory 1)))
+(module (memory 0) (memory 0) (export "a" (memory 0)) (export "b" (memory 1)))
 
 (module (memory (export "a") 0))
 (module (memory (export "a") 0 1))
@@ -219,11 +218,10 @@
   (module (memory 0) (export "a" (memory 0)) (export "a" (memory 0)))
   "duplicate export name"
 )
-;; No multiple memories yet.
-;; (assert_invalid
-;;   (module (memory 0) (memory 0) (export "a" (memory 0)) (export "a" (memory 1)))
-;;   "duplicate export name"
-;; )
+(assert_invalid
+  (module (memory 0) (memory 0) (export "a" (memory 0)) (export "a" (memory 1)))
+  "duplicate export name"
+)
 (assert_invalid
   (module (memory 0) (func) (export "a" (memory 0)) (export "a" (func 0)))
   "duplicate export name"
@@ -7,8 +7,8 @@
 (module (memory 1 256))
 (module (memory 0 65536))
 
-(assert_invalid (module (memory 0) (memory 0)) "multiple memories")
-(assert_invalid (module (memory (import "spectest" "memory") 0) (memory 0)) "multiple memories")
+(module (memory 0) (memory 0))
+(module (memory (import "spectest" "memory") 0) (memory 0))
 
 (module (memory (data)) (func (export "memsize") (result i32) (memory.size)))
 (assert_return (invoke "memsize") (i32.const 0))
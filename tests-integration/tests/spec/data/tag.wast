@@ -0,0 +1,47 @@
+;; Test tag section
+
+(module
+  (tag)
+  (tag (param i32))
+  (tag (export "t2") (param i32))
+  (tag $t3 (param i32 f32))
+  (export "t3" (tag 3))
+)
+
+(register "test")
+
+(module
+  (tag $t0 (import "test" "t2") (param i32))
+  (import "test" "t3" (tag $t1 (param i32 f32)))
+)
+
+(module
+  (type $t (func (param i64 f64)))
+  (tag (type $t))
+  (tag $t4 (type $t) (param i64 f64))
+)
+
+(assert_invalid
+  (module (tag (result i32)))
+  "non-empty tag result type"
+)
+
+(assert_invalid
+  (module (type $t (func (result i32))) (tag (type $t)))
+  "non-empty tag result type"
+)
+
+(assert_unlinkable
+  (module (import "test" "t2" (tag (param f32))))
+  "incompatible import type"
+)
+
+(assert_unlinkable
+  (module (import "test" "t3" (tag (param i32))))
+  "incompatible import type"
+)
+
+(assert_unlinkable
+  (module (import "test" "t2" (func (param i32))))
+  "incompatible import type"
+)
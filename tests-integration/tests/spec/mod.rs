@@ -18,6 +18,7 @@ macro_rules! spectest {
                 RunConfig {
                     runset: $runset,
                     failures_to_ignore: GLOBAL_FAILURES_TO_IGNORE,
+                    binary_roundtrip: false,
                 }
             )
         }
@@ -96,6 +97,8 @@ spectest!(r#ref_func);
 spectest!(r#ref_is_null);
 spectest!(r#ref_null);
 spectest!(r#return);
+spectest!(r#return_call);
+spectest!(r#return_call_indirect);
 spectest!(r#select);
 spectest!(r#skip_x_stack_x_guard_x_page);
 spectest!(r#stack);
@@ -111,8 +114,12 @@ spectest!(r#table_grow);
 spectest!(r#table_init);
 spectest!(r#table_set);
 spectest!(r#table_size);
+spectest!(r#tag);
+spectest!(r#throw);
+spectest!(r#throw_ref);
 spectest!(r#token);
 spectest!(r#traps);
+spectest!(r#try_table);
 spectest!(r#type);
 spectest!(r#unreachable);
 spectest!(r#unreached_x_invalid);
@@ -0,0 +1,90 @@
+(assert_invalid
+  (module (memory 1 shared))
+  "shared memory must have maximum"
+)
+(assert_invalid
+  (module (memory i64 1 shared))
+  "shared memory must have maximum"
+)
+
+;; The alignment of atomic accesses defaults to, and must be, their natural
+;; alignment.
+(module
+  (memory 1 1 shared)
+  (func (drop (i32.atomic.load align=4 (i32.const 0))))
+  (func (drop (i64.atomic.rmw16.add_u align=2 (i32.const 0) (i64.const 0))))
+  (func (drop (memory.atomic.wait64 align=8 (i32.const 0) (i64.const 0) (i64.const 0))))
+)
+(assert_invalid
+  (module (memory 1 1 shared) (func (drop (i32.atomic.load align=2 (i32.const 0)))))
+  "alignment must be equal to natural"
+)
+(assert_invalid
+  (module (memory 1 1 shared) (func (drop (i64.atomic.load align=16 (i32.const 0)))))
+  "alignment must be equal to natural"
+)
+(assert_invalid
+  (module (memory 1 1 shared) (func (i32.atomic.store8 align=2 (i32.const 0) (i32.const 0))))
+  "alignment must be equal to natural"
+)
+(assert_invalid
+  (module
+    (memory 1 1 shared)
+    (func (drop (memory.atomic.notify align=8 (i32.const 0) (i32.const 0))))
+  )
+  "alignment must be equal to natural"
+)
+
+(assert_invalid
+  (module (func (drop (i32.atomic.load (i32.const 0)))))
+  "unknown memory"
+)
+(assert_invalid
+  (module (memory 1 1 shared) (func (drop (i32.atomic.load (i64.const 0)))))
+  "type mismatch"
+)
+(assert_invalid
+  (module (memory 1 1 shared) (func (drop (i32.atomic.rmw.add (i32.const 0) (i64.const 0)))))
+  "type mismatch"
+)
+(assert_invalid
+  (module
+    (memory 1 1 shared)
+    (func (drop (memory.atomic.wait32 (i32.const 0) (i32.const 0) (i32.const 0))))
+  )
+  "type mismatch"
+)
+(assert_invalid
+  (module (memory i64 1 1 shared) (func (drop (i64.atomic.load (i32.const 0)))))
+  "type mismatch"
+)
+
+;; atomic.fence is followed by a reserved zero byte.
+(module binary
+  "\00asm" "\01\00\00\00"
+  "\01\04\01\60\00\00"        ;; type section: one type, [] -> []
+  "\03\02\01\00"              ;; function section: one function of type 0
+  "\0a\07\01\05\00\fe\03\00\0b" ;; code section: atomic.fence
+)
+(assert_malformed
+  (module binary
+    "\00asm" "\01\00\00\00"
+    "\01\04\01\60\00\00"        ;; type section: one type, [] -> []
+    "\03\02\01\00"              ;; function section: one function of type 0
+    "\0a\07\01\05\00\fe\03\01\0b" ;; code section: atomic.fence with a non-zero byte
+  )
+  "zero byte expected"
+)
+
+;; Shared memory imports must match the sharedness of the export.
+(module (memory (export "shared") 1 1 shared) (memory (export "unshared") 1 1))
+(register "threads")
+(module (import "threads" "shared" (memory 1 1 shared)))
+(assert_unlinkable
+  (module (import "threads" "shared" (memory 1 1)))
+  "incompatible import type"
+)
+(assert_unlinkable
+  (module (import "threads" "unshared" (memory 1 1 shared)))
+  "incompatible import type"
+)
@@ -0,0 +1,64 @@
+;; With a single thread, nothing can notify a waiter, so waits that don't fail
+;; the comparison time out immediately, and notify never wakes anyone.
+(module
+  (memory 1 1 shared)
+
+  (func (export "init") (param $value i64) (i64.store (i32.const 0) (local.get $value)))
+
+  (func (export "memory.atomic.notify") (param $addr i32) (param $count i32) (result i32)
+    (memory.atomic.notify (local.get $addr) (local.get $count)))
+  (func (export "memory.atomic.wait32")
+    (param $addr i32) (param $expected i32) (param $timeout i64) (result i32)
+    (memory.atomic.wait32 (local.get $addr) (local.get $expected) (local.get $timeout)))
+  (func (export "memory.atomic.wait64")
+    (param $addr i32) (param $expected i64) (param $timeout i64) (result i32)
+    (memory.atomic.wait64 (local.get $addr) (local.get $expected) (local.get $timeout)))
+)
+
+(invoke "init" (i64.const 0xffffffff))
+(assert_return (invoke "memory.atomic.notify" (i32.const 0) (i32.const 10)) (i32.const 0))
+(assert_return (invoke "memory.atomic.wait32" (i32.const 0) (i32.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "memory.atomic.wait32" (i32.const 0) (i32.const -1) (i64.const 0)) (i32.const 2))
+(assert_return (invoke "memory.atomic.wait32" (i32.const 4) (i32.const 0) (i64.const -1)) (i32.const 2))
+(assert_return (invoke "memory.atomic.wait64" (i32.const 0) (i64.const -1) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "memory.atomic.wait64" (i32.const 0) (i64.const 0xffffffff) (i64.const 10)) (i32.const 2))
+
+(assert_trap (invoke "memory.atomic.notify" (i32.const 2) (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.wait32" (i32.const 1) (i32.const 0) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.wait64" (i32.const 4) (i64.const 0) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "memory.atomic.notify" (i32.const 65536) (i32.const 1)) "out of bounds memory access")
+(assert_trap (invoke "memory.atomic.wait64" (i32.const 65536) (i64.const 0) (i64.const 0)) "out of bounds memory access")
+
+;; Only shared memories can be waited on, but notify and the other atomic
+;; accesses work with unshared memories too.
+(module
+  (memory 1 1)
+
+  (func (export "memory.atomic.notify") (param $addr i32) (param $count i32) (result i32)
+    (memory.atomic.notify (local.get $addr) (local.get $count)))
+  (func (export "memory.atomic.wait32")
+    (param $addr i32) (param $expected i32) (param $timeout i64) (result i32)
+    (memory.atomic.wait32 (local.get $addr) (local.get $expected) (local.get $timeout)))
+  (func (export "i32.atomic.rmw.add") (param $addr i32) (param $value i32) (result i32)
+    (i32.atomic.rmw.add (local.get $addr) (local.get $value)))
+)
+
+(assert_return (invoke "memory.atomic.notify" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_trap (invoke "memory.atomic.wait32" (i32.const 0) (i32.const 0) (i64.const 0)) "expected shared memory")
+(assert_return (invoke "i32.atomic.rmw.add" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.atomic.rmw.add" (i32.const 0) (i32.const 1)) (i32.const 1))
+
+;; Addresses of 64-bit memories are i64s.
+(module
+  (memory i64 1 1 shared)
+
+  (func (export "i64.atomic.rmw.add") (param $addr i64) (param $value i64) (result i64)
+    (i64.atomic.rmw.add (local.get $addr) (local.get $value)))
+  (func (export "memory.atomic.wait64")
+    (param $addr i64) (param $expected i64) (param $timeout i64) (result i32)
+    (memory.atomic.wait64 (local.get $addr) (local.get $expected) (local.get $timeout)))
+)
+
+(assert_return (invoke "i64.atomic.rmw.add" (i64.const 8) (i64.const 5)) (i64.const 0))
+(assert_return (invoke "memory.atomic.wait64" (i64.const 8) (i64.const 5) (i64.const 0)) (i32.const 2))
+(assert_trap (invoke "i64.atomic.rmw.add" (i64.const 0x1_0000_0000) (i64.const 0)) "out of bounds memory access")
@@ -0,0 +1,40 @@
+use tests::spec::{
+    error::Result,
+    loader::parse_and_run,
+    runner::{RunConfig, RunSet},
+};
+
+fn run(path: &str, binary_roundtrip: bool) -> Result<()> {
+    parse_and_run(path, RunConfig {
+        runset: RunSet::All,
+        failures_to_ignore: &[],
+        binary_roundtrip,
+    })
+}
+
+#[test]
+fn atomics() -> Result<()> {
+    run("tests/threads/data/atomics.wast", false)
+}
+
+#[test]
+fn wait_notify() -> Result<()> {
+    run("tests/threads/data/wait_notify.wast", false)
+}
+
+#[test]
+fn validation() -> Result<()> {
+    run("tests/threads/data/validation.wast", false)
+}
+
+/// Shared memories and atomic instructions survive the binary encoder and
+/// parser.
+#[test]
+fn atomics_binary_roundtrip() -> Result<()> {
+    run("tests/threads/data/atomics.wast", true)
+}
+
+#[test]
+fn wait_notify_binary_roundtrip() -> Result<()> {
+    run("tests/threads/data/wait_notify.wast", true)
+}
@@ -7,6 +7,7 @@ use tests::spec::{
 const RUN_CONFIG: RunConfig = RunConfig {
     runset:             RunSet::All,
     failures_to_ignore: &[],
+    binary_roundtrip:   false,
 };
 
 #[test]
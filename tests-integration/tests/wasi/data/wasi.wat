@@ -0,0 +1,155 @@
+(module
+  (import "wasi_snapshot_preview1" "fd_write"
+    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_read"
+    (func $fd_read (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_close"
+    (func $fd_close (param i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_seek"
+    (func $fd_seek (param i32 i64 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_prestat_get"
+    (func $fd_prestat_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_prestat_dir_name"
+    (func $fd_prestat_dir_name (param i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "path_open"
+    (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "args_sizes_get"
+    (func $args_sizes_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "args_get"
+    (func $args_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "environ_sizes_get"
+    (func $environ_sizes_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "environ_get"
+    (func $environ_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "clock_time_get"
+    (func $clock_time_get (param i32 i64 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "random_get"
+    (func $random_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "proc_exit"
+    (func $proc_exit (param i32)))
+
+  ;; 0-63: iovecs and results.
+  ;; 1024: pointer arrays.
+  ;; 2048: string buffers.
+  (memory (export "memory") 1)
+  (data (i32.const 100) "hello, world\n")
+  (data (i32.const 200) "in.txt")
+  (data (i32.const 220) "out.txt")
+  (data (i32.const 240) "../in.txt")
+  (data (i32.const 260) "link.txt")
+
+  ;; Write len bytes at ptr to fd, returning the errno.
+  (func $write (param $fd i32) (param $ptr i32) (param $len i32) (result i32)
+    (i32.store (i32.const 0) (local.get $ptr))
+    (i32.store (i32.const 4) (local.get $len))
+    (call $fd_write (local.get $fd) (i32.const 0) (i32.const 1) (i32.const 8)))
+
+  ;; Open a path in the preopened directory, storing the fd at 24.
+  (func $open (param $path i32) (param $len i32) (param $oflags i32)
+      (param $rights i64) (result i32)
+    (call $path_open
+      (i32.const 3) (i32.const 0) (local.get $path) (local.get $len)
+      (local.get $oflags) (local.get $rights) (i64.const 0) (i32.const 0)
+      (i32.const 24)))
+
+  (func (export "_start")
+    (drop (call $write (i32.const 1) (i32.const 100) (i32.const 13))))
+
+  (func (export "hello_stderr") (result i32)
+    (call $write (i32.const 2) (i32.const 100) (i32.const 13)))
+
+  ;; Write the nul-separated arguments to stdout.
+  (func (export "args") (result i32)
+    (drop (call $args_sizes_get (i32.const 16) (i32.const 20)))
+    (drop (call $args_get (i32.const 1024) (i32.const 2048)))
+    (drop (call $write (i32.const 1) (i32.const 2048) (i32.load (i32.const 20))))
+    (i32.load (i32.const 16)))
+
+  ;; Write the nul-separated environment to stdout.
+  (func (export "environ") (result i32)
+    (drop (call $environ_sizes_get (i32.const 16) (i32.const 20)))
+    (drop (call $environ_get (i32.const 1024) (i32.const 2048)))
+    (drop (call $write (i32.const 1) (i32.const 2048) (i32.load (i32.const 20))))
+    (i32.load (i32.const 16)))
+
+  ;; Write the name of the preopened directory to stdout.
+  (func (export "prestat") (result i32)
+    (drop (call $fd_prestat_get (i32.const 3) (i32.const 40)))
+    (drop (call $fd_prestat_dir_name (i32.const 3) (i32.const 2048) (i32.load (i32.const 44))))
+    (drop (call $write (i32.const 1) (i32.const 2048) (i32.load (i32.const 44))))
+    ;; The next descriptor isn't a preopen.
+    (call $fd_prestat_get (i32.const 4) (i32.const 40)))
+
+  ;; Copy the contents of in.txt to stdout.
+  (func (export "cat") (result i32)
+    (local $errno i32)
+    (local.set $errno (call $open (i32.const 200) (i32.const 6) (i32.const 0) (i64.const 2)))
+    (if (local.get $errno) (then (return (local.get $errno))))
+    (i32.store (i32.const 0) (i32.const 2048))
+    (i32.store (i32.const 4) (i32.const 1000))
+    (drop (call $fd_read (i32.load (i32.const 24)) (i32.const 0) (i32.const 1) (i32.const 28)))
+    (drop (call $write (i32.const 1) (i32.const 2048) (i32.load (i32.const 28))))
+    (call $fd_close (i32.load (i32.const 24))))
+
+  ;; Create out.txt containing the greeting.
+  (func (export "create") (result i32)
+    (local $errno i32)
+    ;; CREAT | TRUNC, FD_WRITE
+    (local.set $errno (call $open (i32.const 220) (i32.const 7) (i32.const 9) (i64.const 64)))
+    (if (local.get $errno) (then (return (local.get $errno))))
+    (drop (call $write (i32.load (i32.const 24)) (i32.const 100) (i32.const 13)))
+    (call $fd_close (i32.load (i32.const 24))))
+
+  (func (export "escape") (result i32)
+    (call $open (i32.const 240) (i32.const 9) (i32.const 0) (i64.const 2)))
+
+  ;; Open link.txt with the given oflags, for reading.
+  (func (export "open_link") (param $oflags i32) (result i32)
+    (call $open (i32.const 260) (i32.const 8) (local.get $oflags) (i64.const 2)))
+
+  ;; The size of in.txt, found by seeking to its end.
+  (func (export "size") (result i64)
+    (drop (call $open (i32.const 200) (i32.const 6) (i32.const 0) (i64.const 2)))
+    (drop (call $fd_seek (i32.load (i32.const 24)) (i64.const 0) (i32.const 2) (i32.const 32)))
+    (drop (call $fd_close (i32.load (i32.const 24))))
+    (i64.load (i32.const 32)))
+
+  (func (export "seek_stdout") (result i32)
+    (call $fd_seek (i32.const 1) (i64.const 0) (i32.const 0) (i32.const 32)))
+
+  (func (export "close_twice") (result i32)
+    (drop (call $fd_close (i32.const 3)))
+    (call $fd_close (i32.const 3)))
+
+  (func (export "realtime") (result i64)
+    (drop (call $clock_time_get (i32.const 0) (i64.const 0) (i32.const 56)))
+    (i64.load (i32.const 56)))
+
+  (func (export "random") (result i32)
+    (call $random_get (i32.const 2048) (i32.const 64)))
+
+  ;; Buffers that are much larger than memory.
+  (func (export "random_huge") (result i32)
+    (call $random_get (i32.const 2048) (i32.const -1)))
+
+  (func (export "read_huge") (result i32)
+    (i32.store (i32.const 0) (i32.const 2048))
+    (i32.store (i32.const 4) (i32.const -1))
+    (call $fd_read (i32.const 0) (i32.const 0) (i32.const 1) (i32.const 28)))
+
+  ;; Write the greeting with 1000 iovecs that all refer to it, returning the
+  ;; number of bytes written.
+  (func (export "write_repeated") (result i32)
+    (local $i i32)
+    (loop $fill
+      (i32.store (i32.add (i32.const 4096) (i32.mul (local.get $i) (i32.const 8))) (i32.const 100))
+      (i32.store (i32.add (i32.const 4100) (i32.mul (local.get $i) (i32.const 8))) (i32.const 13))
+      (local.set $i (i32.add (local.get $i) (i32.const 1)))
+      (br_if $fill (i32.lt_u (local.get $i) (i32.const 1000))))
+    (drop (call $fd_write (i32.const 1) (i32.const 4096) (i32.const 1000) (i32.const 8)))
+    (i32.load (i32.const 8)))
+
+  (func (export "exit")
+    (call $proc_exit (i32.const 7))
+    unreachable)
+)
@@ -0,0 +1,232 @@
+use {
+    std::{cell::RefCell, io, path::PathBuf, rc::Rc, time::SystemTime},
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_runtime::runtime::{
+        error::{RuntimeErrorKind, TrapKind},
+        Runtime,
+    },
+    wrausmt_wasi::{Errno, WasiCtx},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// A writer whose output can be inspected after it's given to the program.
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Output {
+    fn take(&self) -> String {
+        String::from_utf8(self.0.take()).unwrap()
+    }
+}
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A directory for a test to share with its program, containing `in.txt`.
+fn test_dir(name: &str) -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("wrausmt-wasi-{}-{name}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("in.txt"), "file contents\n")?;
+    Ok(dir)
+}
+
+fn errno(e: Errno) -> wrausmt_runtime::runtime::values::Value {
+    (e as u32).into()
+}
+
+#[test]
+fn stdio() -> Result<()> {
+    let (stdout, stderr) = (Output::default(), Output::default());
+    let mut runtime = Runtime::new();
+    WasiCtx::new()
+        .stdout(stdout.clone())
+        .stderr(stderr.clone())
+        .register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    runtime.call(&module, "_start", &[])?;
+    assert_eq!(stdout.take(), "hello, world\n");
+
+    let res = runtime.call(&module, "hello_stderr", &[])?;
+    assert_eq!(res, [errno(Errno::Success)]);
+    assert_eq!(stderr.take(), "hello, world\n");
+
+    let res = runtime.call(&module, "seek_stdout", &[])?;
+    assert_eq!(res, [errno(Errno::Spipe)]);
+    Ok(())
+}
+
+#[test]
+fn args_and_environ() -> Result<()> {
+    let stdout = Output::default();
+    let mut runtime = Runtime::new();
+    WasiCtx::new()
+        .args(["prog", "one", "two"])
+        .env("A", "1")
+        .env("B", "two")
+        .stdout(stdout.clone())
+        .register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    let res = runtime.call(&module, "args", &[])?;
+    assert_eq!(res, [3u32.into()]);
+    assert_eq!(stdout.take(), "prog\0one\0two\0");
+
+    let res = runtime.call(&module, "environ", &[])?;
+    assert_eq!(res, [2u32.into()]);
+    assert_eq!(stdout.take(), "A=1\0B=two\0");
+    Ok(())
+}
+
+#[test]
+fn preopened_dir() -> Result<()> {
+    let dir = test_dir("preopen")?;
+    let stdout = Output::default();
+    let mut runtime = Runtime::new();
+    WasiCtx::new()
+        .preopen_dir("/sandbox", &dir)
+        .stdout(stdout.clone())
+        .register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    let res = runtime.call(&module, "prestat", &[])?;
+    assert_eq!(res, [errno(Errno::Badf)]);
+    assert_eq!(stdout.take(), "/sandbox");
+
+    let res = runtime.call(&module, "cat", &[])?;
+    assert_eq!(res, [errno(Errno::Success)]);
+    assert_eq!(stdout.take(), "file contents\n");
+
+    let res = runtime.call(&module, "size", &[])?;
+    assert_eq!(res, [14u64.into()]);
+
+    let res = runtime.call(&module, "create", &[])?;
+    assert_eq!(res, [errno(Errno::Success)]);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("out.txt"))?,
+        "hello, world\n"
+    );
+
+    let res = runtime.call(&module, "escape", &[])?;
+    assert_eq!(res, [errno(Errno::Notcapable)]);
+
+    let res = runtime.call(&module, "close_twice", &[])?;
+    assert_eq!(res, [errno(Errno::Badf)]);
+
+    // Without the preopen, the file can't be reached.
+    let res = runtime.call(&module, "cat", &[])?;
+    assert_eq!(res, [errno(Errno::Badf)]);
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn symlinks() -> Result<()> {
+    let dir = test_dir("symlinks")?;
+    let outside = test_dir("outside")?;
+    let link = dir.join("link.txt");
+    let mut runtime = Runtime::new();
+    WasiCtx::new()
+        .preopen_dir("/sandbox", &dir)
+        .register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+    let open_link =
+        |runtime: &mut Runtime, oflags: u32| runtime.call(&module, "open_link", &[oflags.into()]);
+
+    // A link to a file outside of the directory can't be followed.
+    std::os::unix::fs::symlink(outside.join("in.txt"), &link)?;
+    assert_eq!(open_link(&mut runtime, 0)?, [errno(Errno::Notcapable)]);
+
+    // A link to a file inside of it can.
+    std::fs::remove_file(&link)?;
+    std::os::unix::fs::symlink(dir.join("in.txt"), &link)?;
+    assert_eq!(open_link(&mut runtime, 0)?, [errno(Errno::Success)]);
+
+    // A dangling link can't be used to create a file outside of it.
+    std::fs::remove_file(&link)?;
+    std::os::unix::fs::symlink(outside.join("new.txt"), &link)?;
+    // CREAT
+    assert_eq!(open_link(&mut runtime, 1)?, [errno(Errno::Notcapable)]);
+    assert!(!outside.join("new.txt").exists());
+
+    std::fs::remove_dir_all(dir)?;
+    std::fs::remove_dir_all(outside)?;
+    Ok(())
+}
+
+#[test]
+fn clock_and_random() -> Result<()> {
+    let mut runtime = Runtime::new();
+    WasiCtx::new().register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    let before = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+    let res: u64 = runtime.call(&module, "realtime", &[])?[0].try_into()?;
+    let after = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+    assert!((before.as_nanos()..=after.as_nanos()).contains(&(res as u128)));
+
+    let res = runtime.call(&module, "random", &[])?;
+    assert_eq!(res, [errno(Errno::Success)]);
+    Ok(())
+}
+
+/// Buffers are checked against the program's memory before anything is
+/// read into them.
+#[test]
+fn huge_buffers() -> Result<()> {
+    let mut runtime = Runtime::new();
+    WasiCtx::new().stdin(io::empty()).register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    for name in ["random_huge", "read_huge"] {
+        let err = runtime.call(&module, name, &[]).unwrap_err();
+        assert!(
+            matches!(
+                err.kind,
+                RuntimeErrorKind::Trap(TrapKind::OutOfBoundsMemoryAccess(..))
+            ),
+            "{name}: {err}"
+        );
+    }
+    Ok(())
+}
+
+/// Buffers are written straight from memory, however many iovecs refer to
+/// them.
+#[test]
+fn repeated_iovecs() -> Result<()> {
+    let stdout = Output::default();
+    let mut runtime = Runtime::new();
+    WasiCtx::new().stdout(stdout.clone()).register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    let res = runtime.call(&module, "write_repeated", &[])?;
+    assert_eq!(res, [13000u32.into()]);
+    assert_eq!(stdout.take(), "hello, world\n".repeat(1000));
+    Ok(())
+}
+
+#[test]
+fn proc_exit() -> Result<()> {
+    let stdout = Output::default();
+    let mut runtime = Runtime::new();
+    WasiCtx::new().stdout(stdout.clone()).register(&mut runtime);
+    let module = runtime.load_file("tests/wasi/data/wasi.wat")?;
+
+    let err = runtime.call(&module, "exit", &[]).unwrap_err();
+    assert!(matches!(err.kind, RuntimeErrorKind::Exit(7)));
+
+    // The runtime can still be used after the program exits.
+    runtime.call(&module, "_start", &[])?;
+    assert_eq!(stdout.take(), "hello, world\n");
+    Ok(())
+}
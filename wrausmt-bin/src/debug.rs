@@ -0,0 +1,222 @@
+//! An interactive debugger for the command line, enabled with `--debug`.
+//! Commands are read from stdin, and everything is written to stderr, so that
+//! the program's own output can still be told apart.
+
+use {
+    crate::values::format_value,
+    std::io::{BufRead, Write},
+    wrausmt_runtime::runtime::{
+        debug::{Breakpoint, DebugContext, Debugger, PauseReason, Resume, Watchpoint},
+        error::Result,
+        instance::addr::{self, Address},
+    },
+};
+
+const HELP: &str = r"Commands:
+  s, step                Execute one instruction, stepping into calls.
+  n, next                Execute one instruction, stepping over calls.
+  finish                 Run until the current function returns.
+  c, continue            Run until a breakpoint or watchpoint.
+  b, break <f> [off]     Pause at offset <off> (default 0) of function <f>,
+                         given as a $name or an index in the current module.
+  d, delete <f> [off]    Remove a breakpoint.
+  watch <addr> [len]     Pause when any of the <len> (default 4) bytes at
+                         <addr> in memory 0 change.
+  unwatch <addr> [len]   Remove a watchpoint.
+  bt, backtrace          Show the current function and its callers.
+  locals                 Show the locals of the current function.
+  stack                  Show the operands of the current function.
+  labels                 Show the labels of the current function.
+  mem <addr> [len]       Show <len> (default 16) bytes of memory 0.
+  q, quit                Stop the program.";
+
+pub struct CliDebugger;
+
+impl Debugger for CliDebugger {
+    fn pause(&mut self, ctx: &mut DebugContext) -> Result<Resume> {
+        match ctx.reason() {
+            PauseReason::Breakpoint(_) => eprintln!("breakpoint"),
+            PauseReason::Step => (),
+            PauseReason::Watchpoint {
+                watchpoint,
+                old,
+                new,
+            } => eprintln!(
+                "watchpoint {:#x}..{:#x}: {} -> {}",
+                watchpoint.range.start,
+                watchpoint.range.end,
+                format_bytes(Some(old)),
+                format_bytes(Some(new)),
+            ),
+        }
+        let executed = match ctx.reason() {
+            PauseReason::Watchpoint { .. } => "after",
+            _ => "before",
+        };
+        eprintln!("  {executed} {} in {}", ctx.instruction().name, ctx.frame());
+
+        let mut line = String::new();
+        loop {
+            eprint!("(debug) ");
+            let _ = std::io::stderr().flush();
+            line.clear();
+            if std::io::stdin().lock().read_line(&mut line).unwrap_or(0) == 0 {
+                // Without any more commands, run the program to the end.
+                eprintln!();
+                return Ok(Resume::Continue);
+            }
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+            let args: Vec<&str> = words.collect();
+            match command {
+                "s" | "step" => return Ok(Resume::Step),
+                "n" | "next" => return Ok(Resume::StepOver),
+                "finish" => return Ok(Resume::StepOut),
+                "c" | "continue" => return Ok(Resume::Continue),
+                "q" | "quit" => std::process::exit(1),
+                "h" | "help" => eprintln!("{HELP}"),
+                "b" | "break" | "d" | "delete" => match breakpoint(ctx, &args) {
+                    Ok(bp) if command.starts_with('b') => ctx.add_breakpoint(bp),
+                    Ok(bp) if ctx.remove_breakpoint(bp) => (),
+                    Ok(_) => eprintln!("no such breakpoint"),
+                    Err(e) => eprintln!("{e}"),
+                },
+                "watch" | "unwatch" => match watchpoint(ctx, &args) {
+                    Ok(wp) if command == "watch" => {
+                        if let Err(e) = ctx.add_watchpoint(wp) {
+                            eprintln!("{e}");
+                        }
+                    }
+                    Ok(wp) if ctx.remove_watchpoint(&wp) => (),
+                    Ok(_) => eprintln!("no such watchpoint"),
+                    Err(e) => eprintln!("{e}"),
+                },
+                "bt" | "backtrace" => {
+                    for (i, frame) in ctx.backtrace().iter().enumerate() {
+                        eprintln!("  {i}: {frame}");
+                    }
+                }
+                "locals" => match ctx.locals() {
+                    Ok(locals) => {
+                        for (i, val) in locals.iter().enumerate() {
+                            eprintln!("  {i}: {}", format_value(ctx.runtime(), val));
+                        }
+                    }
+                    Err(e) => eprintln!("{e}"),
+                },
+                "stack" => match ctx.operands() {
+                    Ok(operands) => {
+                        for val in operands.iter().rev() {
+                            eprintln!("  {}", format_value(ctx.runtime(), val));
+                        }
+                    }
+                    Err(e) => eprintln!("{e}"),
+                },
+                "labels" => match ctx.labels() {
+                    Ok(labels) => {
+                        for (i, label) in labels.iter().rev().enumerate() {
+                            eprintln!(
+                                "  {i}: arity {} continuation {:#x}",
+                                label.arity, label.continuation
+                            );
+                        }
+                    }
+                    Err(e) => eprintln!("{e}"),
+                },
+                "mem" => match memory_range(&args, 16) {
+                    Ok((start, len)) => match ctx.memory(0) {
+                        Ok(mem) => {
+                            let end = start.saturating_add(len).min(mem.data.len());
+                            let bytes = mem.data.get(start..end);
+                            eprintln!("  {start:#x}: {}", format_bytes(bytes));
+                        }
+                        Err(e) => eprintln!("{e}"),
+                    },
+                    Err(e) => eprintln!("{e}"),
+                },
+                _ => eprintln!("unknown command {command}, try help"),
+            }
+        }
+    }
+}
+
+/// The breakpoint described by the arguments of `break` or `delete`.
+fn breakpoint(ctx: &DebugContext, args: &[&str]) -> std::result::Result<Breakpoint, String> {
+    let func = match args.first() {
+        Some(func) => find_func(ctx, func)?,
+        None => return Err("which function?".to_owned()),
+    };
+    let offset = match args.get(1) {
+        Some(offset) => parse_number(offset)?,
+        None => 0,
+    };
+    Ok(Breakpoint { func, offset })
+}
+
+/// A function of the paused module, by its identifier or index.
+fn find_func(
+    ctx: &DebugContext,
+    name: &str,
+) -> std::result::Result<Address<addr::Function>, String> {
+    let funcs = ctx.frame().module.funcs();
+    let found = match name.starts_with('$') {
+        true => funcs.iter().copied().find(|addr| {
+            ctx.runtime()
+                .func_name(*addr)
+                .is_ok_and(|id| id.is_some_and(|id| id.as_str() == name))
+        }),
+        false => funcs.get(parse_number(name)?).copied(),
+    };
+    found.ok_or_else(|| format!("no function {name}"))
+}
+
+/// The watchpoint described by the arguments of `watch` or `unwatch`.
+fn watchpoint(ctx: &DebugContext, args: &[&str]) -> std::result::Result<Watchpoint, String> {
+    let (start, len) = memory_range(args, 4)?;
+    let mem = ctx
+        .frame()
+        .module
+        .mems()
+        .first()
+        .copied()
+        .ok_or("no memory")?;
+    Ok(Watchpoint {
+        mem,
+        range: start..start.saturating_add(len),
+    })
+}
+
+/// An address and a length, which defaults to `default_len`.
+fn memory_range(args: &[&str], default_len: usize) -> std::result::Result<(usize, usize), String> {
+    let start = match args.first() {
+        Some(start) => parse_number(start)?,
+        None => return Err("which address?".to_owned()),
+    };
+    let len = match args.get(1) {
+        Some(len) => parse_number(len)?,
+        None => default_len,
+    };
+    Ok((start, len))
+}
+
+/// A number, in decimal or prefixed with `0x` in hex.
+fn parse_number(s: &str) -> std::result::Result<usize, String> {
+    match s.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+    .map_err(|e| format!("{s}: {e}"))
+}
+
+fn format_bytes(bytes: Option<&[u8]>) -> String {
+    match bytes {
+        Some(bytes) => bytes
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<Vec<_>>()
+            .join(" "),
+        None => "<out of bounds>".to_owned(),
+    }
+}
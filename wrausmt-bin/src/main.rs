@@ -1,4 +1,22 @@
-use {wrausmt_format::file_loader::FileLoader, wrausmt_runtime::runtime::Runtime};
+mod debug;
+mod values;
+
+use {
+    debug::CliDebugger,
+    std::{net::TcpListener, rc::Rc},
+    values::{format_functype, format_value, parse_value},
+    wrausmt_format::file_loader::FileLoader,
+    wrausmt_gdb::GdbStub,
+    wrausmt_runtime::{
+        runtime::{
+            error::{RuntimeError, RuntimeErrorKind},
+            instance::{ExportInstance, ExternalVal, ModuleInstance},
+            Runtime,
+        },
+        syntax::types::ExternType,
+    },
+    wrausmt_wasi::WasiCtx,
+};
 
 #[derive(Debug)]
 struct FlagsAndArgs {
@@ -7,12 +25,23 @@ struct FlagsAndArgs {
 }
 
 impl FlagsAndArgs {
-    #[allow(dead_code)]
     fn has_flag(&self, name: &str) -> bool {
         self.flags.iter().any(|(f, _)| f == name)
     }
+
+    /// The values of every occurrence of the flag.
+    fn flag_values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.flags
+            .iter()
+            .filter(move |(f, _)| f == name)
+            .filter_map(|(_, v)| v.as_deref())
+    }
 }
 
+/// Flags starting with `--` take the following argument as their value,
+/// except for these.
+const SWITCHES: &[&str] = &["--debug", "--list-exports"];
+
 impl FlagsAndArgs {
     fn new() -> Self {
         let mut flags: Vec<(String, Option<String>)> = Vec::new();
@@ -20,10 +49,14 @@ impl FlagsAndArgs {
         let mut arg_iter = std::env::args();
         while let Some(arg) = arg_iter.next() {
             match arg {
+                arg if SWITCHES.contains(&arg.as_str()) => flags.push((arg, None)),
                 arg if arg.starts_with("--") => {
                     flags.push((arg, arg_iter.next()));
                 }
-                arg if arg.starts_with('-') => flags.push((arg[1..].to_owned(), None)),
+                // Negative numbers are arguments for the invoked function.
+                arg if arg.starts_with('-') && !is_negative_number(&arg) => {
+                    flags.push((arg[1..].to_owned(), None))
+                }
                 _ => args.push(arg),
             }
         }
@@ -31,23 +64,165 @@ impl FlagsAndArgs {
     }
 }
 
+fn is_negative_number(arg: &str) -> bool {
+    arg.strip_prefix('-').is_some_and(|rest| {
+        rest.starts_with(|c: char| c.is_ascii_digit()) || rest == "inf" || rest == "nan"
+    })
+}
+
+/// Report a failure, and exit with a non-zero status. A program that exits
+/// through WASI uses its own exit code.
+fn fail(e: RuntimeError) -> ! {
+    match &e.kind {
+        RuntimeErrorKind::Exit(code) => std::process::exit(*code as i32),
+        RuntimeErrorKind::Trap(trap) => {
+            eprintln!("error: wasm trap: {trap}");
+            for (i, frame) in e.backtrace().iter().enumerate() {
+                eprintln!("  {i}: {frame}");
+            }
+        }
+        _ => eprintln!("error: {e}"),
+    }
+    std::process::exit(1)
+}
+
+fn list_exports(module: &ModuleInstance) {
+    for export in module.exports() {
+        match &export.externtype {
+            ExternType::Func(functype) => {
+                println!("func {}{}", export.name, format_functype(functype))
+            }
+            ExternType::Table(_) => println!("table {}", export.name),
+            ExternType::Memory(_) => println!("memory {}", export.name),
+            ExternType::Global(_) => println!("global {}", export.name),
+            ExternType::Tag(_) => println!("tag {}", export.name),
+        }
+    }
+}
+
+/// Call the exported function, with the provided arguments parsed according
+/// to its parameter types, and print the results.
+fn invoke(
+    runtime: &mut Runtime,
+    module: &Rc<ModuleInstance>,
+    name: &str,
+    args: &[String],
+) -> Result<(), RuntimeError> {
+    let functype = match module.resolve(name) {
+        Some(ExportInstance {
+            addr: ExternalVal::Func(addr),
+            ..
+        }) => runtime.func_type(*addr).unwrap_or_else(|e| fail(e)),
+        _ => {
+            eprintln!("error: no exported function named {name}");
+            std::process::exit(1);
+        }
+    };
+    if args.len() != functype.params.len() {
+        eprintln!(
+            "error: {name}{} takes {} arguments, but {} were provided",
+            format_functype(&functype),
+            functype.params.len(),
+            args.len()
+        );
+        std::process::exit(1);
+    }
+    let vals = args
+        .iter()
+        .zip(functype.params.iter())
+        .map(|(arg, valtype)| parse_value(runtime, arg, *valtype))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|e| {
+            eprintln!("error: invalid argument {e}");
+            std::process::exit(1);
+        });
+
+    let results = runtime.call(module, name, &vals)?;
+    // The results are popped from the stack, so the last one comes first.
+    for result in results.iter().rev() {
+        println!("{}", format_value(runtime, result));
+    }
+    Ok(())
+}
+
+/// Wait for a debugger to connect on the local port.
+fn accept_gdb(port: &str) -> GdbStub {
+    let addr = format!("127.0.0.1:{port}");
+    let stub = TcpListener::bind(&addr).and_then(|listener| {
+        eprintln!("waiting for a debugger on {addr}");
+        GdbStub::accept(&listener)
+    });
+    stub.unwrap_or_else(|e| {
+        eprintln!("error: debugger connection failed: {e}");
+        std::process::exit(1);
+    })
+}
+
 fn main() {
     let flags_and_args = FlagsAndArgs::new();
     if let Some(filename) = flags_and_args.args.get(1) {
         let mut runtime = Runtime::new();
-        let module = runtime.load_file(filename);
-        match module {
-            Ok(_) => {}
-            Err(e) => println!("Load failed: {}", e),
+        let mut wasi = WasiCtx::new().args(&flags_and_args.args[1..]).inherit_env();
+        for dir in flags_and_args.flag_values("--dir") {
+            wasi = wasi.preopen_dir(dir, dir);
+        }
+        wasi.register(&mut runtime);
+
+        let module = match runtime.load_file(filename) {
+            Ok(module) => module,
+            Err(e) => {
+                eprintln!("error: load failed: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if flags_and_args.has_flag("--debug") {
+            runtime.attach_debugger(CliDebugger);
+            runtime.break_next();
+        }
+        let gdb = flags_and_args.flag_values("--gdb").next().map(accept_gdb);
+        if let Some(stub) = &gdb {
+            runtime.attach_debugger(stub.clone());
+            runtime.break_next();
+        }
+
+        let result = if flags_and_args.has_flag("--list-exports") {
+            list_exports(&module);
+            Ok(())
+        } else if let Some(name) = flags_and_args.flag_values("--invoke").next() {
+            invoke(&mut runtime, &module, name, &flags_and_args.args[2..])
+        } else if module.resolve("_start").is_some() {
+            runtime.call(&module, "_start", &[]).map(|_| ())
+        } else {
+            Ok(())
+        };
+        if let Some(stub) = &gdb {
+            stub.finished(&result);
+        }
+        if let Err(e) = result {
+            fail(e);
         }
     } else {
         println!(
             r"Wrausmt Runner:
 
-Provide a single filename.
+Provide a filename, followed by any arguments.
 
 It will be loaded as a binary file if it starts with the magic header, otherwise
-it will be loaded as a text file."
+it will be loaded as a text file. If the module exports a `_start` function, it
+is run as a WASI program, with the arguments passed to it.
+
+Flags:
+  --debug            Pause before the first instruction, and debug the program
+                     interactively. Type help at the prompt for the commands.
+  --dir <path>       Give the program access to the directory at <path>.
+  --gdb <port>       Wait for a debugger to connect to 127.0.0.1:<port> with
+                     the GDB remote protocol, and pause before the first
+                     instruction.
+  --invoke <export>  Call the exported function instead of `_start`. The
+                     arguments are parsed according to its parameter types,
+                     and its results are printed, like `i32:42`.
+  --list-exports     Print the module's exports, instead of running it."
         );
     }
 }
@@ -0,0 +1,104 @@
+//! Conversion between command line text and WebAssembly values.
+
+use {
+    std::rc::Rc,
+    wrausmt_runtime::{
+        runtime::{
+            values::{Num, Ref, Value},
+            Runtime,
+        },
+        syntax::types::{FunctionType, NumType, RefType, ValueType},
+    },
+};
+
+/// Parse an integer given in decimal, or in hex with a `0x` prefix. Negative
+/// values are stored as their two's complement bits.
+macro_rules! parse_int {
+    ( $s:expr, $signed:ty, $unsigned:ty ) => {{
+        let s: &str = $s;
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        let magnitude = match digits.strip_prefix("0x") {
+            Some(hex) => <$unsigned>::from_str_radix(hex, 16),
+            None => digits.parse::<$unsigned>(),
+        }
+        .map_err(|e| format!("{s}: {e}"))?;
+        if neg {
+            if magnitude > <$signed>::MIN.unsigned_abs() {
+                return Err(format!("{s}: number too small"));
+            }
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        }
+    }};
+}
+
+/// Parse a command line argument as a value of the provided type. References
+/// may be `null`; non-null external references are given as numbers, which
+/// become host objects in the runtime's store.
+pub fn parse_value(runtime: &mut Runtime, s: &str, valtype: ValueType) -> Result<Value, String> {
+    let float_err = |e: std::num::ParseFloatError| format!("{s}: {e}");
+    Ok(match valtype {
+        ValueType::Num(NumType::I32) => parse_int!(s, i32, u32).into(),
+        ValueType::Num(NumType::I64) => parse_int!(s, i64, u64).into(),
+        ValueType::Num(NumType::F32) => s.parse::<f32>().map_err(float_err)?.into(),
+        ValueType::Num(NumType::F64) => s.parse::<f64>().map_err(float_err)?.into(),
+        ValueType::Num(NumType::V128) => parse_int!(s, i128, u128).into(),
+        ValueType::Ref(reftype) if s == "null" => Value::Ref(Ref::Null(reftype)),
+        ValueType::Ref(RefType::Extern) => runtime
+            .alloc_extern(Rc::new(parse_int!(s, i32, u32)))
+            .into(),
+        ValueType::Ref(RefType::Func) => Err(format!("{s}: funcref arguments must be null"))?,
+        ValueType::Ref(RefType::Exn) => Err(format!("{s}: exnref arguments must be null"))?,
+    })
+}
+
+/// Format a value the way that the spec test scripts write them, prefixed with
+/// its type: `i32:42`. Integers are shown signed. External references to
+/// numbers given as arguments are shown as that number.
+pub fn format_value(runtime: &Runtime, val: &Value) -> String {
+    match val {
+        Value::Num(Num::I32(v)) => format!("i32:{}", *v as i32),
+        Value::Num(Num::I64(v)) => format!("i64:{}", *v as i64),
+        Value::Num(Num::F32(v)) => format!("f32:{v}"),
+        Value::Num(Num::F64(v)) => format!("f64:{v}"),
+        Value::Num(Num::V128(v)) => format!("v128:0x{v:032x}"),
+        Value::Ref(Ref::Func(a)) => format!("funcref:{}", a.0),
+        Value::Ref(r @ Ref::Extern(e)) => match runtime.extern_object::<u32>(*r) {
+            Ok(Some(n)) => format!("externref:{n}"),
+            _ => format!("externref:@{}", e.0),
+        },
+        Value::Ref(Ref::Exn(a)) => format!("exnref:{}", a.0),
+        Value::Ref(Ref::Null(RefType::Func)) => "funcref:null".into(),
+        Value::Ref(Ref::Null(RefType::Extern)) => "externref:null".into(),
+        Value::Ref(Ref::Null(RefType::Exn)) => "exnref:null".into(),
+    }
+}
+
+fn valtype_name(valtype: &ValueType) -> &'static str {
+    match valtype {
+        ValueType::Num(NumType::I32) => "i32",
+        ValueType::Num(NumType::I64) => "i64",
+        ValueType::Num(NumType::F32) => "f32",
+        ValueType::Num(NumType::F64) => "f64",
+        ValueType::Num(NumType::V128) => "v128",
+        ValueType::Ref(RefType::Func) => "funcref",
+        ValueType::Ref(RefType::Extern) => "externref",
+        ValueType::Ref(RefType::Exn) => "exnref",
+    }
+}
+
+/// Format a function type as it would appear in the text format.
+pub fn format_functype(functype: &FunctionType) -> String {
+    let mut out = String::new();
+    for (kind, types) in [("param", &functype.params), ("result", &functype.result)] {
+        if !types.is_empty() {
+            let names: Vec<_> = types.iter().map(valtype_name).collect();
+            out += &format!(" ({kind} {})", names.join(" "));
+        }
+    }
+    out
+}
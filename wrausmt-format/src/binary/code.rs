@@ -11,10 +11,10 @@ use {
     std::io::Read,
     wrausmt_common::true_or::TrueOr,
     wrausmt_runtime::{
-        instructions::{instruction_data, op_consts, Operands, BAD_INSTRUCTION},
+        instructions::{instruction_data, op_consts, opcodes, Operands, BAD_INSTRUCTION},
         syntax::{
-            self, types::ValueType, Continuation, FResult, FuncField, Id, Index, Instruction,
-            Local, Opcode, Resolved, TypeIndex, TypeUse, UncompiledExpr,
+            self, types::ValueType, Catch, Continuation, FResult, FuncField, Id, Index,
+            Instruction, Local, MemoryIndex, Opcode, Resolved, TypeIndex, TypeUse, UncompiledExpr,
         },
     },
 };
@@ -39,6 +39,9 @@ pub struct ExpressionWithEnd {
 
 const MAX_LOCALS_PER_FUNC: usize = (u32::MAX - 1) as usize;
 
+/// Set in the alignment of a memarg when it's followed by a memory index.
+pub(in crate::binary) const MEMARG_MEMIDX_FLAG: u32 = 1 << 6;
+
 type LocalCount = u32;
 
 /// Read the Code section of a binary module.
@@ -170,9 +173,35 @@ impl<R: ParserReader> BinaryParser<R> {
             .map_err(|_| self.err(BinaryParseErrorKind::InvalidSecondaryOpcode(secondary)))
     }
 
-    fn read_zero_byte(&mut self) -> Result<()> {
-        let byte = self.read_byte()?;
-        (byte == 0).true_or_else(|| self.err(BinaryParseErrorKind::ZeroByteExpected))
+    /// Read the alignment and offset of a memory access. When bit 6 of the
+    /// alignment is set, a memory index follows it; otherwise the access uses
+    /// memory 0.
+    fn read_memargs(&mut self) -> Result<(Index<Resolved, MemoryIndex>, u32, u64)> {
+        let flags = self.read_u32_leb_128().result(self)?;
+        let memidx = if flags & MEMARG_MEMIDX_FLAG != 0 {
+            self.read_index_use()?
+        } else {
+            Index::unnamed(0)
+        };
+        let offset = self.read_u64_leb_128().result(self)?;
+        Ok((memidx, flags & !MEMARG_MEMIDX_FLAG, offset))
+    }
+
+    /// Read one handler clause of a `try_table` instruction.
+    /// catch := 0x00 x:tagidx l:labelidx (catch)
+    ///        | 0x01 x:tagidx l:labelidx (catch_ref)
+    ///        | 0x02 l:labelidx (catch_all)
+    ///        | 0x03 l:labelidx (catch_all_ref)
+    fn read_catch(&mut self) -> Result<Catch<Resolved>> {
+        pctx!(self, "read catch");
+        let kind = self.read_byte()?;
+        Ok(match kind {
+            0 => Catch::Catch(self.read_index_use()?, self.read_index_use()?),
+            1 => Catch::CatchRef(self.read_index_use()?, self.read_index_use()?),
+            2 => Catch::CatchAll(self.read_index_use()?),
+            3 => Catch::CatchAllRef(self.read_index_use()?),
+            _ => Err(self.err(BinaryParseErrorKind::MalformedCatchKind(kind)))?,
+        })
     }
 
     /// Returns -1 if EOF or end instruction was reached while parsing an
@@ -189,6 +218,7 @@ impl<R: ParserReader> BinaryParser<R> {
         let opcode = match opcode_buf[0] {
             op_consts::EXTENDED_PREFIX => Opcode::Extended(self.read_secondary_opcode()?),
             op_consts::SIMD_PREFIX => Opcode::Simd(self.read_secondary_opcode()?),
+            op_consts::ATOMIC_PREFIX => Opcode::Atomic(self.read_secondary_opcode()?),
             _ => Opcode::Normal(opcode_buf[0]),
         };
 
@@ -201,6 +231,12 @@ impl<R: ParserReader> BinaryParser<R> {
         (instruction_data != &BAD_INSTRUCTION)
             .true_or_else(|| self.err(BinaryParseErrorKind::InvalidOpcode(opcode)))?;
 
+        // atomic.fence is followed by a reserved byte, which must be zero.
+        if opcode == opcodes::ATOMIC_FENCE {
+            (self.read_byte()? == 0x00)
+                .true_or_else(|| self.err(BinaryParseErrorKind::ZeroByteExpected))?;
+        }
+
         // Handle any additional behavior
         let operands = match instruction_data.operands {
             Operands::Select | Operands::None => syntax::Operands::None,
@@ -215,6 +251,7 @@ impl<R: ParserReader> BinaryParser<R> {
                 syntax::Operands::DataIndex(self.read_index_use()?)
             }
             Operands::MemoryIndex => syntax::Operands::MemoryIndex(self.read_index_use()?),
+            Operands::TagIndex => syntax::Operands::TagIndex(self.read_index_use()?),
             Operands::TableCopy => {
                 let dsttabidx = self.read_index_use()?;
                 let srctabidx = self.read_index_use()?;
@@ -245,24 +282,39 @@ impl<R: ParserReader> BinaryParser<R> {
                 let val = f64::from_bits(u64::from_le_bytes(buf));
                 syntax::Operands::F64(val)
             }
-            Operands::Memargs => syntax::Operands::Memargs(
-                self.read_u32_leb_128().result(self)?,
-                self.read_u32_leb_128().result(self)?,
-            ),
+            Operands::Memargs => {
+                let (memidx, align, offset) = self.read_memargs()?;
+                syntax::Operands::Memargs(memidx, align, offset)
+            }
+            Operands::MemargsLane => {
+                let (memidx, align, offset) = self.read_memargs()?;
+                syntax::Operands::MemargsLane(memidx, align, offset, self.read_byte()?)
+            }
+            Operands::V128 => {
+                let mut buf = [0u8; 16];
+                self.read_exact(&mut buf).result(self)?;
+                syntax::Operands::V128(u128::from_le_bytes(buf))
+            }
+            Operands::Shuffle => {
+                let mut buf = [0u8; 16];
+                self.read_exact(&mut buf).result(self)?;
+                syntax::Operands::Shuffle(buf)
+            }
+            Operands::LaneIndex => syntax::Operands::LaneIndex(self.read_byte()?),
             Operands::MemoryInit => {
                 data_indices_ok
                     .true_or_else(|| self.err(BinaryParseErrorKind::DataCountMissing))?;
-                self.read_zero_byte()?;
-                syntax::Operands::None
+                let dataidx = self.read_index_use()?;
+                let memidx = self.read_index_use()?;
+                syntax::Operands::MemoryInit(memidx, dataidx)
             }
             Operands::MemorySize | Operands::MemoryGrow | Operands::MemoryFill => {
-                self.read_zero_byte()?;
-                syntax::Operands::None
+                syntax::Operands::MemoryIndex(self.read_index_use()?)
             }
             Operands::MemoryCopy => {
-                self.read_zero_byte()?;
-                self.read_zero_byte()?;
-                syntax::Operands::None
+                let dstidx = self.read_index_use()?;
+                let srcidx = self.read_index_use()?;
+                syntax::Operands::MemoryCopy(dstidx, srcidx)
             }
             Operands::Block => {
                 let bt = self.read_blocktype()?;
@@ -284,6 +336,12 @@ impl<R: ParserReader> BinaryParser<R> {
                 };
                 syntax::Operands::If(None, bt, th.expr, el)
             }
+            Operands::TryTable => {
+                let bt = self.read_blocktype()?;
+                let catches = self.read_vec(|_, s| s.read_catch())?;
+                let expr = self.read_expr(data_indices_ok)?;
+                syntax::Operands::TryTable(None, bt, catches, expr)
+            }
             Operands::HeapType => {
                 let ht = self.read_ref_type()?;
                 syntax::Operands::HeapType(ht)
@@ -303,6 +361,13 @@ impl<R: ParserReader> BinaryParser<R> {
             }
         };
 
+        // The two forms of select share the 0x1b opcode, as they do in the text
+        // format.
+        let opcode = match operands {
+            syntax::Operands::SelectT(_) => opcodes::SELECT,
+            _ => opcode,
+        };
+
         Ok(InstructionOrEnd::Instruction(Instruction {
             name: Id::default(),
             opcode,
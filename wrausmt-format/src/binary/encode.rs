@@ -0,0 +1,571 @@
+//! Serialization of a [Module] into the binary format. [Spec][Spec]
+//!
+//! The encoder is the inverse of the [parser][super::parse_wasm_data]: every
+//! section that the parser reads is written back out in the order required by
+//! the specification, with all integers LEB128 encoded. Custom sections are
+//! written after all of the standard sections, since the module does not
+//! record where they originally appeared.
+//!
+//! [Spec]: https://webassembly.github.io/spec/core/binary/modules.html
+
+use {
+    super::{code::MEMARG_MEMIDX_FLAG, leb128::WriteLeb128},
+    std::io::Write,
+    wrausmt_runtime::{
+        instructions::{op_consts, opcodes},
+        syntax::{
+            self,
+            types::{
+                GlobalType, IndexType, Limits, MemType, NumType, RefType, TableType, ValueType,
+            },
+            BlockType, Catch, DataField, ElemField, ExportDesc, FunctionType, ImportDesc, Index,
+            IndexSpace, Instruction, Local, MemoryIndex, ModeEntry, Module, Opcode, Resolved,
+            TypeUse, UncompiledExpr, ValidatedState,
+        },
+    },
+};
+
+type Result<T> = std::io::Result<T>;
+
+const MAGIC: &[u8] = b"\0asm";
+const VERSION: &[u8] = b"\x01\0\0\0";
+
+/// Write the provided module to `out` using the WebAssembly binary format.
+pub fn encode_wasm_data<V: ValidatedState>(
+    module: &Module<Resolved, V, UncompiledExpr<Resolved>>,
+    out: &mut impl Write,
+) -> Result<()> {
+    BinaryEncoder { writer: out }.write_module(module)
+}
+
+struct BinaryEncoder<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> BinaryEncoder<W> {
+    fn write_module<V: ValidatedState>(
+        &mut self,
+        module: &Module<Resolved, V, UncompiledExpr<Resolved>>,
+    ) -> Result<()> {
+        self.write_all(MAGIC)?;
+        self.write_all(VERSION)?;
+
+        if !module.types.is_empty() {
+            self.write_section(1, |s| {
+                s.write_vec(&module.types, |s, t| s.write_function_type(&t.functiontype))
+            })?;
+        }
+        if !module.imports.is_empty() {
+            self.write_section(2, |s| {
+                s.write_vec(&module.imports, |s, i| {
+                    s.write_name(&i.modname)?;
+                    s.write_name(&i.name)?;
+                    s.write_import_desc(&i.desc)
+                })
+            })?;
+        }
+        if !module.funcs.is_empty() {
+            self.write_section(3, |s| {
+                s.write_vec(&module.funcs, |s, f| s.write_type_use(&f.typeuse))
+            })?;
+        }
+        if !module.tables.is_empty() {
+            self.write_section(4, |s| {
+                s.write_vec(&module.tables, |s, t| s.write_table_type(&t.tabletype))
+            })?;
+        }
+        if !module.memories.is_empty() {
+            self.write_section(5, |s| {
+                s.write_vec(&module.memories, |s, m| s.write_mem_type(&m.memtype))
+            })?;
+        }
+        if !module.tags.is_empty() {
+            self.write_section(13, |s| {
+                s.write_vec(&module.tags, |s, t| s.write_tag_type(&t.typeuse))
+            })?;
+        }
+        if !module.globals.is_empty() {
+            self.write_section(6, |s| {
+                s.write_vec(&module.globals, |s, g| {
+                    s.write_global_type(&g.globaltype)?;
+                    s.write_expr(&g.init)
+                })
+            })?;
+        }
+        if !module.exports.is_empty() {
+            self.write_section(7, |s| {
+                s.write_vec(&module.exports, |s, e| {
+                    s.write_name(&e.name)?;
+                    s.write_export_desc(&e.exportdesc)
+                })
+            })?;
+        }
+        if let Some(start) = &module.start {
+            self.write_section(8, |s| s.write_index(&start.idx))?;
+        }
+        if !module.elems.is_empty() {
+            self.write_section(9, |s| s.write_vec(&module.elems, |s, e| s.write_elem(e)))?;
+        }
+        // The data count section is always written when there is code or data,
+        // so that data indices in function bodies are always permitted.
+        if !module.funcs.is_empty() || !module.data.is_empty() {
+            self.write_section(12, |s| s.write_u32_leb_128(module.data.len() as u32))?;
+        }
+        if !module.funcs.is_empty() {
+            self.write_section(10, |s| {
+                s.write_vec(&module.funcs, |s, f| {
+                    s.write_sized(|s| {
+                        s.write_locals(&f.locals)?;
+                        s.write_expr(&f.body)
+                    })
+                })
+            })?;
+        }
+        if !module.data.is_empty() {
+            self.write_section(11, |s| s.write_vec(&module.data, |s, d| s.write_data(d)))?;
+        }
+        for custom in &module.customs {
+            self.write_section(0, |s| {
+                s.write_name(&custom.name)?;
+                s.write_all(&custom.content)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Write a section with the provided ID. The content is written to a
+    /// buffer first, so that it can be preceded by its size.
+    fn write_section(
+        &mut self,
+        id: u8,
+        f: impl FnOnce(&mut BinaryEncoder<Vec<u8>>) -> Result<()>,
+    ) -> Result<()> {
+        self.write_byte(id)?;
+        self.write_sized(f)
+    }
+
+    /// Write the content produced by `f`, preceded by its size in bytes.
+    fn write_sized(
+        &mut self,
+        f: impl FnOnce(&mut BinaryEncoder<Vec<u8>>) -> Result<()>,
+    ) -> Result<()> {
+        let mut content = BinaryEncoder { writer: vec![] };
+        f(&mut content)?;
+        self.write_u32_leb_128(content.writer.len() as u32)?;
+        self.write_all(&content.writer)
+    }
+
+    fn write_byte(&mut self, b: u8) -> Result<()> {
+        self.write_all(&[b])
+    }
+
+    fn write_vec<T>(&mut self, items: &[T], f: impl Fn(&mut Self, &T) -> Result<()>) -> Result<()> {
+        self.write_u32_leb_128(items.len() as u32)?;
+        items.iter().try_for_each(|i| f(self, i))
+    }
+
+    fn write_name(&mut self, name: &str) -> Result<()> {
+        self.write_bytes(name.as_bytes())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.write_u32_leb_128(bytes.len() as u32)?;
+        self.write_all(bytes)
+    }
+
+    fn write_index<S: IndexSpace>(&mut self, idx: &Index<Resolved, S>) -> Result<()> {
+        self.write_u32_leb_128(idx.value())
+    }
+
+    fn write_type_use(&mut self, typeuse: &TypeUse<Resolved>) -> Result<()> {
+        self.write_index(typeuse.index())
+    }
+
+    fn write_function_type(&mut self, functiontype: &FunctionType) -> Result<()> {
+        self.write_byte(0x60)?;
+        self.write_vec(&functiontype.params, |s, p| {
+            s.write_value_type(&p.valuetype)
+        })?;
+        self.write_vec(&functiontype.results, |s, r| {
+            s.write_value_type(&r.valuetype)
+        })
+    }
+
+    fn write_value_type(&mut self, valtype: &ValueType) -> Result<()> {
+        self.write_byte(match valtype {
+            ValueType::Num(NumType::I32) => 0x7F,
+            ValueType::Num(NumType::I64) => 0x7E,
+            ValueType::Num(NumType::F32) => 0x7D,
+            ValueType::Num(NumType::F64) => 0x7C,
+            ValueType::Num(NumType::V128) => 0x7B,
+            ValueType::Ref(r) => return self.write_ref_type(r),
+        })
+    }
+
+    fn write_ref_type(&mut self, reftype: &RefType) -> Result<()> {
+        self.write_byte(match reftype {
+            RefType::Func => 0x70,
+            RefType::Extern => 0x6F,
+            RefType::Exn => 0x69,
+        })
+    }
+
+    /// A tag type is its function type, preceded by a reserved attribute byte.
+    fn write_tag_type(&mut self, typeuse: &TypeUse<Resolved>) -> Result<()> {
+        self.write_byte(0x00)?;
+        self.write_type_use(typeuse)
+    }
+
+    fn write_limits(&mut self, limits: &Limits) -> Result<()> {
+        match limits.upper {
+            Some(upper) => {
+                self.write_byte(0x01)?;
+                self.write_u32_leb_128(limits.lower as u32)?;
+                self.write_u32_leb_128(upper as u32)
+            }
+            None => {
+                self.write_byte(0x00)?;
+                self.write_u32_leb_128(limits.lower as u32)
+            }
+        }
+    }
+
+    /// Shared memories set bit 1 of the limits flags. Memories with 64-bit
+    /// indices set bit 2, and write their limits as 64-bit values.
+    fn write_mem_type<V: ValidatedState>(&mut self, memtype: &MemType<V>) -> Result<()> {
+        let limits = &memtype.limits;
+        let is64 = memtype.idxtype == IndexType::I64;
+        let flags = limits.upper.is_some() as u8 | (memtype.shared as u8) << 1 | (is64 as u8) << 2;
+        self.write_byte(flags)?;
+        let write_value = |s: &mut Self, v: u64| match memtype.idxtype {
+            IndexType::I32 => s.write_u32_leb_128(v as u32),
+            IndexType::I64 => s.write_u64_leb_128(v),
+        };
+        write_value(self, limits.lower)?;
+        if let Some(upper) = limits.upper {
+            write_value(self, upper)?;
+        }
+        Ok(())
+    }
+
+    fn write_table_type<V: ValidatedState>(&mut self, tabletype: &TableType<V>) -> Result<()> {
+        self.write_ref_type(&tabletype.reftype)?;
+        self.write_limits(&tabletype.limits)
+    }
+
+    fn write_global_type(&mut self, globaltype: &GlobalType) -> Result<()> {
+        self.write_value_type(&globaltype.valtype)?;
+        self.write_byte(globaltype.mutable as u8)
+    }
+
+    fn write_import_desc<V: ValidatedState>(
+        &mut self,
+        desc: &ImportDesc<Resolved, V>,
+    ) -> Result<()> {
+        match desc {
+            ImportDesc::Func(typeuse) => {
+                self.write_byte(0x00)?;
+                self.write_type_use(typeuse)
+            }
+            ImportDesc::Table(tabletype) => {
+                self.write_byte(0x01)?;
+                self.write_table_type(tabletype)
+            }
+            ImportDesc::Mem(memtype) => {
+                self.write_byte(0x02)?;
+                self.write_mem_type(memtype)
+            }
+            ImportDesc::Global(globaltype) => {
+                self.write_byte(0x03)?;
+                self.write_global_type(globaltype)
+            }
+            ImportDesc::Tag(typeuse) => {
+                self.write_byte(0x04)?;
+                self.write_tag_type(typeuse)
+            }
+        }
+    }
+
+    fn write_export_desc(&mut self, desc: &ExportDesc<Resolved>) -> Result<()> {
+        match desc {
+            ExportDesc::Func(idx) => {
+                self.write_byte(0x00)?;
+                self.write_index(idx)
+            }
+            ExportDesc::Table(idx) => {
+                self.write_byte(0x01)?;
+                self.write_index(idx)
+            }
+            ExportDesc::Mem(idx) => {
+                self.write_byte(0x02)?;
+                self.write_index(idx)
+            }
+            ExportDesc::Global(idx) => {
+                self.write_byte(0x03)?;
+                self.write_index(idx)
+            }
+            ExportDesc::Tag(idx) => {
+                self.write_byte(0x04)?;
+                self.write_index(idx)
+            }
+        }
+    }
+
+    /// Write the element segment using the most compact of the eight
+    /// encodings. Segments whose items are all `ref.func` use the function
+    /// index forms, and all others use the expression forms.
+    fn write_elem(&mut self, elem: &ElemField<Resolved, UncompiledExpr<Resolved>>) -> Result<()> {
+        let funcs: Option<Vec<_>> = elem.elemlist.items.iter().map(ref_func_index).collect();
+        let funcs = funcs.filter(|_| elem.elemlist.reftype == RefType::Func);
+        let exprs_bit = if funcs.is_some() { 0 } else { 4 };
+
+        match &elem.mode {
+            ModeEntry::Active(tp) => {
+                if tp.tableuse.tableidx.value() == 0 && elem.elemlist.reftype == RefType::Func {
+                    self.write_u32_leb_128(exprs_bit)?;
+                    self.write_expr(&tp.offset)?;
+                    return self.write_elem_items(elem, funcs, false);
+                }
+                self.write_u32_leb_128(exprs_bit | 2)?;
+                self.write_index(&tp.tableuse.tableidx)?;
+                self.write_expr(&tp.offset)?;
+            }
+            ModeEntry::Passive => self.write_u32_leb_128(exprs_bit | 1)?,
+            ModeEntry::Declarative => self.write_u32_leb_128(exprs_bit | 3)?,
+        }
+        self.write_elem_items(elem, funcs, true)
+    }
+
+    fn write_elem_items(
+        &mut self,
+        elem: &ElemField<Resolved, UncompiledExpr<Resolved>>,
+        funcs: Option<Vec<u32>>,
+        with_kind: bool,
+    ) -> Result<()> {
+        match funcs {
+            Some(funcs) => {
+                if with_kind {
+                    // elemkind: always 0x00 for funcref
+                    self.write_byte(0x00)?;
+                }
+                self.write_vec(&funcs, |s, f| s.write_u32_leb_128(*f))
+            }
+            None => {
+                if with_kind {
+                    self.write_ref_type(&elem.elemlist.reftype)?;
+                }
+                self.write_vec(&elem.elemlist.items, Self::write_expr)
+            }
+        }
+    }
+
+    fn write_data(&mut self, data: &DataField<Resolved, UncompiledExpr<Resolved>>) -> Result<()> {
+        match &data.init {
+            Some(init) if init.memidx.value() == 0 => {
+                self.write_u32_leb_128(0)?;
+                self.write_expr(&init.offset)?;
+            }
+            Some(init) => {
+                self.write_u32_leb_128(2)?;
+                self.write_index(&init.memidx)?;
+                self.write_expr(&init.offset)?;
+            }
+            None => self.write_u32_leb_128(1)?,
+        }
+        self.write_bytes(&data.data)
+    }
+
+    /// Write the locals as runs of the same type.
+    fn write_locals(&mut self, locals: &[Local]) -> Result<()> {
+        let runs = locals
+            .iter()
+            .fold(vec![], |mut runs: Vec<(u32, ValueType)>, l| {
+                match runs.last_mut() {
+                    Some((count, valtype)) if *valtype == l.valtype => *count += 1,
+                    _ => runs.push((1, l.valtype)),
+                }
+                runs
+            });
+        self.write_vec(&runs, |s, (count, valtype)| {
+            s.write_u32_leb_128(*count)?;
+            s.write_value_type(valtype)
+        })
+    }
+
+    /// Write an expression, including the terminating `end`.
+    fn write_expr(&mut self, expr: &UncompiledExpr<Resolved>) -> Result<()> {
+        expr.instr.iter().try_for_each(|i| self.write_inst(i))?;
+        self.write_opcode(opcodes::END)
+    }
+
+    fn write_opcode(&mut self, opcode: Opcode) -> Result<()> {
+        match opcode {
+            Opcode::Normal(o) => self.write_byte(o),
+            Opcode::Extended(o) => {
+                self.write_byte(op_consts::EXTENDED_PREFIX)?;
+                self.write_u32_leb_128(o as u32)
+            }
+            Opcode::Simd(o) => {
+                self.write_byte(op_consts::SIMD_PREFIX)?;
+                self.write_u32_leb_128(o as u32)
+            }
+            Opcode::Atomic(o) => {
+                self.write_byte(op_consts::ATOMIC_PREFIX)?;
+                self.write_u32_leb_128(o as u32)
+            }
+        }
+    }
+
+    /// The memory index is only written when it isn't 0, since memory 0 is
+    /// implied when the flag in the alignment isn't set.
+    fn write_memargs(
+        &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
+        align: u32,
+        offset: u64,
+    ) -> Result<()> {
+        if memidx.value() == 0 {
+            self.write_u32_leb_128(align)?;
+        } else {
+            self.write_u32_leb_128(align | MEMARG_MEMIDX_FLAG)?;
+            self.write_index(memidx)?;
+        }
+        self.write_u64_leb_128(offset)
+    }
+
+    fn write_blocktype(&mut self, blocktype: &BlockType<Resolved>) -> Result<()> {
+        match blocktype {
+            BlockType::Void => self.write_byte(0x40),
+            BlockType::SingleResult(valtype) => self.write_value_type(valtype),
+            BlockType::TypeUse(typeuse) => self.write_i64_leb_128(typeuse.index().value() as i64),
+        }
+    }
+
+    fn write_catch(&mut self, catch: &Catch<Resolved>) -> Result<()> {
+        match catch {
+            Catch::Catch(tagidx, labelidx) => {
+                self.write_byte(0x00)?;
+                self.write_index(tagidx)?;
+                self.write_index(labelidx)
+            }
+            Catch::CatchRef(tagidx, labelidx) => {
+                self.write_byte(0x01)?;
+                self.write_index(tagidx)?;
+                self.write_index(labelidx)
+            }
+            Catch::CatchAll(labelidx) => {
+                self.write_byte(0x02)?;
+                self.write_index(labelidx)
+            }
+            Catch::CatchAllRef(labelidx) => {
+                self.write_byte(0x03)?;
+                self.write_index(labelidx)
+            }
+        }
+    }
+
+    fn write_inst(&mut self, instr: &Instruction<Resolved>) -> Result<()> {
+        // The text format uses the same opcode for both forms of select.
+        let opcode = match &instr.operands {
+            syntax::Operands::SelectT(_) => opcodes::SELECTT,
+            _ => instr.opcode,
+        };
+        self.write_opcode(opcode)?;
+
+        match &instr.operands {
+            // atomic.fence is followed by a reserved zero byte.
+            syntax::Operands::None if opcode == opcodes::ATOMIC_FENCE => self.write_byte(0x00),
+            syntax::Operands::None => Ok(()),
+            syntax::Operands::Block(_, blocktype, expr, _) => {
+                self.write_blocktype(blocktype)?;
+                self.write_expr(expr)
+            }
+            syntax::Operands::If(_, blocktype, th, el) => {
+                self.write_blocktype(blocktype)?;
+                th.instr.iter().try_for_each(|i| self.write_inst(i))?;
+                if !el.instr.is_empty() {
+                    self.write_opcode(opcodes::ELSE)?;
+                }
+                self.write_expr(el)
+            }
+            syntax::Operands::TryTable(_, blocktype, catches, expr) => {
+                self.write_blocktype(blocktype)?;
+                self.write_vec(catches, Self::write_catch)?;
+                self.write_expr(expr)
+            }
+            syntax::Operands::BrTable(idxs, last) => {
+                self.write_vec(idxs, |s, i| s.write_index(i))?;
+                self.write_index(last)
+            }
+            syntax::Operands::SelectT(results) => {
+                self.write_vec(results, |s, r| s.write_value_type(&r.valuetype))
+            }
+            syntax::Operands::CallIndirect(tabidx, typeuse) => {
+                self.write_type_use(typeuse)?;
+                self.write_index(tabidx)
+            }
+            syntax::Operands::FuncIndex(idx) => self.write_index(idx),
+            syntax::Operands::TableIndex(idx) => self.write_index(idx),
+            syntax::Operands::GlobalIndex(idx) => self.write_index(idx),
+            syntax::Operands::ElemIndex(idx) => self.write_index(idx),
+            syntax::Operands::DataIndex(idx) => self.write_index(idx),
+            syntax::Operands::LocalIndex(idx) => self.write_index(idx),
+            syntax::Operands::LabelIndex(idx) => self.write_index(idx),
+            syntax::Operands::MemoryIndex(idx) => self.write_index(idx),
+            syntax::Operands::TagIndex(idx) => self.write_index(idx),
+            syntax::Operands::Memargs(memidx, align, offset) => {
+                self.write_memargs(memidx, *align, *offset)
+            }
+            syntax::Operands::MemargsLane(memidx, align, offset, lane) => {
+                self.write_memargs(memidx, *align, *offset)?;
+                self.write_byte(*lane)
+            }
+            syntax::Operands::HeapType(reftype) => self.write_ref_type(reftype),
+            syntax::Operands::TableInit(tabidx, elemidx) => {
+                self.write_index(elemidx)?;
+                self.write_index(tabidx)
+            }
+            syntax::Operands::TableCopy(dst, src) => {
+                self.write_index(dst)?;
+                self.write_index(src)
+            }
+            syntax::Operands::MemoryInit(memidx, dataidx) => {
+                self.write_index(dataidx)?;
+                self.write_index(memidx)
+            }
+            syntax::Operands::MemoryCopy(dst, src) => {
+                self.write_index(dst)?;
+                self.write_index(src)
+            }
+            syntax::Operands::I32(n) => self.write_i32_leb_128(*n as i32),
+            syntax::Operands::I64(n) => self.write_i64_leb_128(*n as i64),
+            syntax::Operands::F32(n) => self.write_all(&n.to_bits().to_le_bytes()),
+            syntax::Operands::F64(n) => self.write_all(&n.to_bits().to_le_bytes()),
+            syntax::Operands::V128(v) => self.write_all(&v.to_le_bytes()),
+            syntax::Operands::Shuffle(lanes) => self.write_all(lanes),
+            syntax::Operands::LaneIndex(lane) => self.write_byte(*lane),
+        }
+    }
+}
+
+/// If the expression is a single `ref.func`, return its function index.
+fn ref_func_index(expr: &UncompiledExpr<Resolved>) -> Option<u32> {
+    match expr.instr.as_slice() {
+        [Instruction {
+            opcode: opcodes::REF_FUNC,
+            operands: syntax::Operands::FuncIndex(idx),
+            ..
+        }] => Some(idx.value()),
+        _ => None,
+    }
+}
+
+impl<W: Write> Write for BinaryEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
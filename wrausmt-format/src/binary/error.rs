@@ -22,7 +22,6 @@ pub enum BinaryParseErrorKind {
     InvalidExportType(u8),
     InvalidFuncType(u8),
     InvalidBlockType(i64),
-    ZeroByteExpected,
     CodeTooShort,
     CodeTooLong,
     SectionTooShort,
@@ -30,6 +29,10 @@ pub enum BinaryParseErrorKind {
     MalformedRefType(u8),
     MalformedSectionId(u8),
     MalformedImportKind(u8),
+    MalformedLimitsFlags(u8),
+    MalformedTagAttribute(u8),
+    MalformedCatchKind(u8),
+    ZeroByteExpected,
     UnxpectedEndOfSectionOrFunction,
     UnexpectedContentAfterEnd,
     UnexpectedEnd,
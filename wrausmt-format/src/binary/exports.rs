@@ -18,6 +18,7 @@ impl<R: ParserReader> BinaryParser<R> {
     /// 0x01 Table
     /// 0x02 Memory
     /// 0x03 Global
+    /// 0x04 Tag
     pub(in crate::binary) fn read_exports_section(
         &mut self,
     ) -> Result<Vec<ExportField<Resolved, Unvalidated>>> {
@@ -32,6 +33,7 @@ impl<R: ParserReader> BinaryParser<R> {
             1 => Ok(ExportDesc::Table(self.read_index_use()?)),
             2 => Ok(ExportDesc::Mem(self.read_index_use()?)),
             3 => Ok(ExportDesc::Global(self.read_index_use()?)),
+            4 => Ok(ExportDesc::Tag(self.read_index_use()?)),
             _ => Err(self.err(BinaryParseErrorKind::InvalidExportType(kind))),
         }
     }
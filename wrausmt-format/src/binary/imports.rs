@@ -18,6 +18,7 @@ impl<R: ParserReader> BinaryParser<R> {
     /// 0x01 (table) tt:tabletype
     /// 0x02 (memory) mt:memorytype
     /// 0x03 (global) gt:globaltype
+    /// 0x04 (tag) tt:tagtype
     pub(in crate::binary) fn read_imports_section(
         &mut self,
     ) -> Result<Vec<ImportField<Resolved, Unvalidated>>> {
@@ -36,6 +37,7 @@ impl<R: ParserReader> BinaryParser<R> {
                         1 => ImportDesc::Table(s.read_table_type()?),
                         2 => ImportDesc::Mem(s.read_memory_type()?),
                         3 => ImportDesc::Global(s.read_global_type()?),
+                        4 => ImportDesc::Tag(s.read_tag_type()?),
                         _ => return Err(s.err(BinaryParseErrorKind::MalformedImportKind(kind))),
                     }
                 },
@@ -1,4 +1,4 @@
-use std::io::{Error as IOError, ErrorKind as IOErrorKind, Read};
+use std::io::{Error as IOError, ErrorKind as IOErrorKind, Read, Write};
 
 #[derive(Debug)]
 pub enum LEB128Error {
@@ -143,6 +143,46 @@ pub trait ReadLeb128: Read + Sized {
 }
 
 impl<R: Read + Sized> ReadLeb128 for R {}
+
+// Generalized encoder for both signed & unsigned LEB128 of any size. Signed
+// values are emitted until the remaining bits are all copies of the sign bit
+// of the last emitted byte.
+fn write_leb_128(w: &mut impl Write, value: i128, signed: bool) -> std::io::Result<()> {
+    let mut value = value;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = if signed {
+            (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0)
+        } else {
+            value == 0
+        };
+        if done {
+            return w.write_all(&[byte]);
+        }
+        w.write_all(&[byte | 0x80])?;
+    }
+}
+
+pub trait WriteLeb128: Write + Sized {
+    fn write_u32_leb_128(&mut self, v: u32) -> std::io::Result<()> {
+        write_leb_128(self, v as i128, false)
+    }
+
+    fn write_i32_leb_128(&mut self, v: i32) -> std::io::Result<()> {
+        write_leb_128(self, v as i128, true)
+    }
+
+    fn write_u64_leb_128(&mut self, v: u64) -> std::io::Result<()> {
+        write_leb_128(self, v as i128, false)
+    }
+
+    fn write_i64_leb_128(&mut self, v: i64) -> std::io::Result<()> {
+        write_leb_128(self, v as i128, true)
+    }
+}
+
+impl<W: Write + Sized> WriteLeb128 for W {}
 #[cfg(test)]
 mod test {
     use super::parse_leb_128;
@@ -18,6 +18,7 @@ mod code;
 mod custom;
 mod data;
 mod elems;
+pub mod encode;
 mod exports;
 mod funcs;
 mod globals;
@@ -33,10 +34,12 @@ mod imports;
 /// binary parsing task as traits on [std::io::Read].
 pub mod leb128;
 mod mems;
+mod names;
 mod read_with_location;
 mod section;
 mod start;
 mod tables;
+mod tags;
 mod types;
 mod values;
 
@@ -89,6 +92,11 @@ impl<R: ParserReader> BinaryParser<R> {
             module.memories = self.read_section(Self::read_mems_section)?;
             id = self.read_next_section_id(&mut module.customs)?;
         }
+        if id == Some(13) {
+            pctx!(self, "tags section");
+            module.tags = self.read_section(Self::read_tags_section)?;
+            id = self.read_next_section_id(&mut module.customs)?;
+        }
         if id == Some(6) {
             pctx!(self, "globals section");
             module.globals = self.read_section(Self::read_globals_section)?;
@@ -139,6 +147,7 @@ impl<R: ParserReader> BinaryParser<R> {
         }
 
         self.resolve_functypes(&mut module.funcs, &functypes)?;
+        names::apply_names(&mut module);
 
         Ok(module)
     }
@@ -0,0 +1,227 @@
+use {
+    super::{error::Result, read_with_location::ReadWithLocation, BinaryParser, ParserReader},
+    crate::{
+        binary::{error::ParseResult, leb128::ReadLeb128, read_with_location::Locate},
+        pctx,
+    },
+    std::collections::HashSet,
+    wrausmt_runtime::syntax::{
+        Id, ImportDesc, Instruction, Module, Operands, Resolved, TypeUse, UncompiledExpr,
+        Unvalidated,
+    },
+};
+
+type NameMap = Vec<(u32, String)>;
+type IndirectNameMap = Vec<(u32, NameMap)>;
+
+/// The contents of the `name` custom section. [Spec]
+///
+/// Besides the module, function and local subsections of the spec, the
+/// subsections of the extended name section proposal are read too. Unknown
+/// subsections are skipped.
+///
+/// [Spec]: https://webassembly.github.io/spec/core/appendix/custom.html#name-section
+#[derive(Debug, Default)]
+struct Names {
+    module:   Option<String>,
+    funcs:    NameMap,
+    locals:   IndirectNameMap,
+    labels:   IndirectNameMap,
+    types:    NameMap,
+    tables:   NameMap,
+    memories: NameMap,
+    globals:  NameMap,
+    elems:    NameMap,
+    data:     NameMap,
+    tags:     NameMap,
+}
+
+impl<R: ParserReader> BinaryParser<R> {
+    /// Read a name section from the contents of the custom section.
+    /// namesec := section_0(namedata)
+    /// namedata := n:name (if n = 'name') namesubsection*
+    /// namesubsection := N:byte size:u32 B:namemap
+    fn read_names(&mut self, size: usize) -> Result<Names> {
+        pctx!(self, "read names");
+        let mut names = Names::default();
+        while (self.location().pos as usize) < size {
+            let id = self.read_byte()?;
+            let content = self.read_bytes()?;
+            let mut sub = BinaryParser::new(ReadWithLocation::new(&content[..]));
+            match id {
+                0 => names.module = Some(sub.read_name()?),
+                1 => names.funcs = sub.read_name_map()?,
+                2 => names.locals = sub.read_indirect_name_map()?,
+                3 => names.labels = sub.read_indirect_name_map()?,
+                4 => names.types = sub.read_name_map()?,
+                5 => names.tables = sub.read_name_map()?,
+                6 => names.memories = sub.read_name_map()?,
+                7 => names.globals = sub.read_name_map()?,
+                8 => names.elems = sub.read_name_map()?,
+                9 => names.data = sub.read_name_map()?,
+                11 => names.tags = sub.read_name_map()?,
+                _ => (),
+            }
+        }
+        Ok(names)
+    }
+
+    /// namemap := vec(nameassoc)
+    /// nameassoc := idx name
+    fn read_name_map(&mut self) -> Result<NameMap> {
+        pctx!(self, "read name map");
+        self.read_vec(|_, s| Ok((s.read_u32_leb_128().result(s)?, s.read_name()?)))
+    }
+
+    /// indirectnamemap := vec(indirectnameassoc)
+    /// indirectnameassoc := idx namemap
+    fn read_indirect_name_map(&mut self) -> Result<IndirectNameMap> {
+        pctx!(self, "read indirect name map");
+        self.read_vec(|_, s| Ok((s.read_u32_leb_128().result(s)?, s.read_name_map()?)))
+    }
+}
+
+/// Apply the names in the `name` custom section of the module, if it has one,
+/// to the identifiers of the module and of the items that they name.
+///
+/// Errors in the name section don't invalidate the module, so a malformed
+/// name section is ignored. Names that aren't valid identifiers, or that
+/// repeat a name already used in the same index space, are skipped, so that
+/// the module can still be written in the text format.
+pub(in crate::binary) fn apply_names(
+    module: &mut Module<Resolved, Unvalidated, UncompiledExpr<Resolved>>,
+) {
+    let Some(custom) = module.customs.iter().find(|c| c.name == "name") else {
+        return;
+    };
+    let mut parser = BinaryParser::new(ReadWithLocation::new(&custom.content[..]));
+    let Ok(names) = parser.read_names(custom.content.len()) else {
+        return;
+    };
+
+    if let Some(name) = names.module {
+        module.id = to_id(&name);
+    }
+
+    let imported_funcs = module
+        .imports
+        .iter()
+        .filter(|i| matches!(i.desc, ImportDesc::Func(_)))
+        .count();
+
+    macro_rules! apply_with_imports {
+        ( $names:expr, $desc:ident, $items:expr ) => {
+            apply(
+                module
+                    .imports
+                    .iter_mut()
+                    .filter(|i| matches!(i.desc, ImportDesc::$desc(..)))
+                    .map(|i| &mut i.id)
+                    .chain($items.iter_mut().map(|item| &mut item.id))
+                    .collect(),
+                $names,
+            )
+        };
+    }
+    apply_with_imports!(names.funcs, Func, module.funcs);
+    apply_with_imports!(names.tables, Table, module.tables);
+    apply_with_imports!(names.memories, Mem, module.memories);
+    apply_with_imports!(names.globals, Global, module.globals);
+    apply_with_imports!(names.tags, Tag, module.tags);
+    apply(
+        module.types.iter_mut().map(|t| &mut t.id).collect(),
+        names.types,
+    );
+    apply(
+        module.elems.iter_mut().map(|e| &mut e.id).collect(),
+        names.elems,
+    );
+    apply(
+        module.data.iter_mut().map(|d| &mut d.id).collect(),
+        names.data,
+    );
+
+    // Imported functions don't have locals or labels.
+    for (funcidx, localnames) in names.locals {
+        let Some(func) = (funcidx as usize)
+            .checked_sub(imported_funcs)
+            .and_then(|i| module.funcs.get_mut(i))
+        else {
+            continue;
+        };
+        let TypeUse::ByIndex(index) = &func.typeuse else {
+            continue;
+        };
+        let Some(typefield) = module.types.get(index.value() as usize) else {
+            continue;
+        };
+
+        // Parameter names are written inline in the type use, which is how
+        // the text format declares them for a function.
+        let mut functiontype = typefield.functiontype.clone();
+        let params = functiontype.params.iter_mut().map(|p| &mut p.id);
+        let locals = func.locals.iter_mut().map(|l| &mut l.id);
+        apply(params.chain(locals).collect(), localnames);
+        if functiontype.params.iter().any(|p| p.id.is_some()) {
+            func.typeuse = TypeUse::NamedInline {
+                functiontype,
+                index: index.clone(),
+            };
+        }
+    }
+
+    for (funcidx, labelnames) in names.labels {
+        let Some(func) = (funcidx as usize)
+            .checked_sub(imported_funcs)
+            .and_then(|i| module.funcs.get_mut(i))
+        else {
+            continue;
+        };
+        let mut labels = vec![];
+        collect_labels(&mut func.body.instr, &mut labels);
+        apply(labels, labelnames);
+    }
+}
+
+/// The identifier for a name, if the name is a valid identifier.
+fn to_id(name: &str) -> Option<Id> {
+    match name {
+        "" => None,
+        _ => Id::try_from(format!("${name}").as_str()).ok(),
+    }
+}
+
+/// Set the identifiers in `slots`, which are ordered by index, to their names.
+fn apply(mut slots: Vec<&mut Option<Id>>, names: NameMap) {
+    let mut used = HashSet::new();
+    for (idx, name) in names {
+        let (Some(slot), Some(id)) = (slots.get_mut(idx as usize), to_id(&name)) else {
+            continue;
+        };
+        if used.insert(id.clone()) {
+            **slot = Some(id);
+        }
+    }
+}
+
+/// Collect the label identifiers of the structured instructions in `instrs`,
+/// in label index order: the order in which the instructions begin.
+fn collect_labels<'a>(
+    instrs: &'a mut [Instruction<Resolved>],
+    labels: &mut Vec<&'a mut Option<Id>>,
+) {
+    for instr in instrs {
+        match &mut instr.operands {
+            Operands::Block(id, _, expr, _) | Operands::TryTable(id, _, _, expr) => {
+                labels.push(id);
+                collect_labels(&mut expr.instr, labels);
+            }
+            Operands::If(id, _, th, el) => {
+                labels.push(id);
+                collect_labels(&mut th.instr, labels);
+                collect_labels(&mut el.instr, labels);
+            }
+            _ => (),
+        }
+    }
+}
@@ -29,7 +29,7 @@ impl<R: ParserReader> BinaryParser<R> {
                             .eof_as_kind(BinaryParseErrorKind::UnexpectedEnd)?,
                     );
                 }
-                Some(Ok(v @ 1..=12)) => {
+                Some(Ok(v @ 1..=13)) => {
                     return Ok(Some(v));
                 }
                 Some(Ok(v)) => Err(self.err(BinaryParseErrorKind::MalformedSectionId(v)))?,
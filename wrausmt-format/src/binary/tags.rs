@@ -0,0 +1,39 @@
+use {
+    super::{
+        error::{BinaryParseErrorKind, Result},
+        BinaryParser, ParserReader,
+    },
+    crate::{binary::read_with_location::Locate, pctx},
+    wrausmt_runtime::syntax::{Resolved, TagField, TypeUse},
+};
+
+/// Read the tags section of a binary module from a std::io::Read.
+impl<R: ParserReader> BinaryParser<R> {
+    /// Read the tags section of a module.
+    /// tagsec := section vec(tag)
+    pub(in crate::binary) fn read_tags_section(&mut self) -> Result<Vec<TagField<Resolved>>> {
+        pctx!(self, "read tags section");
+        self.read_vec(|_, s| s.read_tag_field())
+    }
+
+    fn read_tag_field(&mut self) -> Result<TagField<Resolved>> {
+        pctx!(self, "read tag field");
+        let location = self.location();
+        Ok(TagField {
+            id: None,
+            exports: vec![],
+            typeuse: self.read_tag_type()?,
+            location,
+        })
+    }
+
+    /// tag := 0x00 x:typeidx
+    /// The leading attribute byte is reserved, and must be zero.
+    pub(in crate::binary) fn read_tag_type(&mut self) -> Result<TypeUse<Resolved>> {
+        pctx!(self, "read tag type");
+        match self.read_byte()? {
+            0 => self.read_type_use(),
+            b => Err(self.err(BinaryParseErrorKind::MalformedTagAttribute(b))),
+        }
+    }
+}
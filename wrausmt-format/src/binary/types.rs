@@ -5,7 +5,7 @@ use {
         pctx,
     },
     wrausmt_runtime::syntax::{
-        types::{GlobalType, Limits, MemType, NumType, RefType, TableType, ValueType},
+        types::{GlobalType, IndexType, Limits, MemType, NumType, RefType, TableType, ValueType},
         BlockType, FParam, FResult, FunctionType, Index, Resolved, TypeField, TypeUse, Unvalidated,
     },
 };
@@ -59,9 +59,28 @@ impl<R: ParserReader> BinaryParser<R> {
         Ok(TypeUse::ByIndex(self.read_index_use()?))
     }
 
+    /// The limits of a memory are preceded by flags: bit 0 is set when an
+    /// upper limit is present, bit 1 when the memory is shared, and bit 2 when
+    /// the memory has 64-bit indices.
     pub(in crate::binary) fn read_memory_type(&mut self) -> Result<MemType<Unvalidated>> {
         pctx!(self, "read memory type");
-        Ok(MemType::new(self.read_limits()?))
+        let flags = self.read_byte()?;
+        let idxtype = match flags & !0x03 {
+            0x00 => IndexType::I32,
+            0x04 => IndexType::I64,
+            _ => Err(self.err(BinaryParseErrorKind::MalformedLimitsFlags(flags)))?,
+        };
+        let has_upper = flags & 0x01 != 0;
+        let shared = flags & 0x02 != 0;
+        let limits = match idxtype {
+            IndexType::I32 => {
+                self.read_limit_values(has_upper, |s| Ok(s.read_u32_leb_128().result(s)?.into()))?
+            }
+            IndexType::I64 => {
+                self.read_limit_values(has_upper, |s| s.read_u64_leb_128().result(s))?
+            }
+        };
+        Ok(MemType::new(idxtype, limits, shared))
     }
 
     pub(in crate::binary) fn read_table_type(&mut self) -> Result<TableType<Unvalidated>> {
@@ -82,10 +101,18 @@ impl<R: ParserReader> BinaryParser<R> {
     fn read_limits(&mut self) -> Result<Limits> {
         pctx!(self, "read limits");
         let has_upper = self.read_bool()?;
+        self.read_limit_values(has_upper, |s| Ok(s.read_u32_leb_128().result(s)?.into()))
+    }
+
+    fn read_limit_values(
+        &mut self,
+        has_upper: bool,
+        read_value: impl Fn(&mut Self) -> Result<u64>,
+    ) -> Result<Limits> {
         Ok(Limits {
-            lower: self.read_u32_leb_128().result(self)?,
+            lower: read_value(self)?,
             upper: if has_upper {
-                Some(self.read_u32_leb_128().result(self)?)
+                Some(read_value(self)?)
             } else {
                 None
             },
@@ -100,10 +127,12 @@ impl<R: ParserReader> BinaryParser<R> {
             -0x02 => BlockType::SingleResult(NumType::I64.into()),
             -0x03 => BlockType::SingleResult(NumType::F32.into()),
             -0x04 => BlockType::SingleResult(NumType::F64.into()),
+            -0x05 => BlockType::SingleResult(NumType::V128.into()),
             -0x10 => BlockType::SingleResult(RefType::Func.into()),
             -0x11 => BlockType::SingleResult(RefType::Extern.into()),
+            -0x17 => BlockType::SingleResult(RefType::Exn.into()),
             -0x40 => BlockType::Void,
-            x if x > 0 && x <= u32::MAX as i64 => {
+            x if x >= 0 && x <= u32::MAX as i64 => {
                 BlockType::TypeUse(TypeUse::ByIndex(Index::unnamed(x as u32)))
             }
             // TODO: This is not the right error.
@@ -73,8 +73,10 @@ impl<R: ParserReader> BinaryParser<R> {
             -0x02 => Ok(NumType::I64.into()),
             -0x03 => Ok(NumType::F32.into()),
             -0x04 => Ok(NumType::F64.into()),
+            -0x05 => Ok(NumType::V128.into()),
             -0x10 => Ok(RefType::Func.into()),
             -0x11 => Ok(RefType::Extern.into()),
+            -0x17 => Ok(RefType::Exn.into()),
             b => Err(self.err(BinaryParseErrorKind::InvalidValueType(b as u8))),
         }
     }
@@ -84,6 +86,7 @@ impl<R: ParserReader> BinaryParser<R> {
         match self.read_i64_leb_128().result(self)? {
             -0x10 => Ok(RefType::Func),
             -0x11 => Ok(RefType::Extern),
+            -0x17 => Ok(RefType::Exn),
             b => Err(self.err(BinaryParseErrorKind::MalformedRefType(b as u8))),
         }
     }
@@ -5,6 +5,7 @@ use {
     wrausmt_runtime::{
         instructions::opcodes,
         syntax::{
+            location::SourceMap,
             types::{NumType, RefType, ValueType},
             CompiledExpr, FuncIndex, Index, Instruction, Operands, Resolved, UncompiledExpr,
         },
@@ -50,7 +51,8 @@ pub fn compile_const_expr(
     })?;
 
     Ok(CompiledExpr {
-        instr: out.into_boxed_slice(),
+        instr:     out.into_boxed_slice(),
+        locations: SourceMap::default(),
     })
 }
 
@@ -87,12 +89,19 @@ fn validate_and_emit_instr(
             let bytes = &v.to_bits().to_le_bytes()[..];
             out.extend(bytes);
         }
+        instr!(opcodes::V128_CONST => Operands::V128(v)) => {
+            stack.push(NumType::V128.into());
+
+            let bytes = &v.to_le_bytes()[..];
+            out.extend(bytes);
+        }
         instr!(opcodes::REF_NULL => Operands::HeapType(ht)) => {
             stack.push((*ht).into());
 
             let htbyte = match ht {
                 RefType::Func => 0x70,
                 RefType::Extern => 0x6F,
+                RefType::Exn => 0x69,
             };
             out.push(htbyte);
         }
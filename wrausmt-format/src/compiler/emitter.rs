@@ -8,9 +8,9 @@ use {
         instructions::opcodes,
         syntax::{
             self,
-            location::Location,
+            location::{Location, SourceMap},
             types::{RefType, ValueType},
-            BlockType, CompiledExpr, FuncField, Id, Instruction, Opcode, Operands, Resolved,
+            BlockType, Catch, CompiledExpr, FuncField, Id, Instruction, Opcode, Operands, Resolved,
             TypeUse, UncompiledExpr,
         },
     },
@@ -23,10 +23,14 @@ pub trait Emitter {
     fn emit8(&mut self, v: u8);
     fn emit32(&mut self, v: u32);
     fn emit64(&mut self, v: u64);
+    fn emit128(&mut self, v: u128);
     fn splice8(&mut self, idx: usize, v: u8);
     fn splice32(&mut self, idx: usize, v: u32);
     fn len(&self) -> usize;
     fn emit_opcode(&mut self, opcode: Opcode);
+    /// Record that the code emitted next was compiled from the instruction
+    /// at `location`.
+    fn mark_location(&mut self, location: Location);
     fn func_arity(&self, typeuse: &TypeUse<Resolved>, location: &Location) -> Result<(u32, u32)>;
 
     fn is_empty(&self) -> bool;
@@ -132,6 +136,43 @@ pub trait Emitter {
         Ok(())
     }
 
+    /// Emit a `try_table` block. It's laid out like a block whose
+    /// continuation is followed by its catch clauses, so that the executor can
+    /// find them when an exception is thrown inside of the block.
+    fn emit_try_table(
+        &mut self,
+        blocktype: &BlockType<Resolved>,
+        catches: &[Catch<Resolved>],
+        expr: &UncompiledExpr<Resolved>,
+        location: &Location,
+    ) -> Result<()> {
+        self.emit_block_type(blocktype, location)?;
+
+        let continuation_location = self.len();
+        self.emit32(0x00);
+
+        self.emit32(catches.len() as u32);
+        for catch in catches {
+            // Use the binary format encoding of the clause kinds. The tag
+            // index of the catch_all clauses is unused.
+            let (kind, tagidx, labelidx) = match catch {
+                Catch::Catch(t, l) => (0x00, t.value(), l),
+                Catch::CatchRef(t, l) => (0x01, t.value(), l),
+                Catch::CatchAll(l) => (0x02, 0, l),
+                Catch::CatchAllRef(l) => (0x03, 0, l),
+            };
+            self.emit8(kind);
+            self.emit32(tagidx);
+            self.emit32(labelidx.value());
+        }
+
+        self.emit_expr(expr)?;
+        self.emit_end(location)?;
+
+        self.splice32(continuation_location, self.len() as u32);
+        Ok(())
+    }
+
     fn emit_expr(&mut self, expr: &syntax::UncompiledExpr<Resolved>) -> Result<()> {
         expr.instr.iter().try_for_each(|i| self.emit_instr(i))
     }
@@ -140,6 +181,7 @@ pub trait Emitter {
         self.validate_instr(instr)?;
 
         // Emit opcode
+        self.mark_location(instr.location);
         self.emit_opcode(instr.opcode);
 
         // Emit operands
@@ -158,6 +200,9 @@ pub trait Emitter {
             syntax::Operands::If(_, blocktype, th, el) => {
                 self.emit_if(blocktype, th, el, &instr.location)?
             }
+            syntax::Operands::TryTable(_, blocktype, catches, e) => {
+                self.emit_try_table(blocktype, catches, e, &instr.location)?
+            }
             syntax::Operands::I32(n) => self.emit32(*n),
             syntax::Operands::I64(n) => self.emit64(*n),
             syntax::Operands::F32(n) => self.emit32(n.to_bits()),
@@ -170,10 +215,23 @@ pub trait Emitter {
             syntax::Operands::LocalIndex(idx) => self.emit32(idx.value()),
             syntax::Operands::LabelIndex(idx) => self.emit32(idx.value()),
             syntax::Operands::MemoryIndex(idx) => self.emit32(idx.value()),
-            syntax::Operands::Memargs(o, a) => {
-                self.emit32(*o);
-                self.emit32(*a)
+            syntax::Operands::TagIndex(idx) => self.emit32(idx.value()),
+            syntax::Operands::Memargs(m, a, o) => {
+                self.emit32(m.value());
+                self.emit32(*a);
+                self.emit64(*o)
             }
+            // The lane is emitted first, so that the lane instructions can read
+            // it before the memory access reads the memargs.
+            syntax::Operands::MemargsLane(m, a, o, l) => {
+                self.emit8(*l);
+                self.emit32(m.value());
+                self.emit32(*a);
+                self.emit64(*o)
+            }
+            syntax::Operands::V128(v) => self.emit128(*v),
+            syntax::Operands::Shuffle(lanes) => lanes.iter().for_each(|l| self.emit8(*l)),
+            syntax::Operands::LaneIndex(l) => self.emit8(*l),
             syntax::Operands::TableInit(ti, ei) => {
                 self.emit32(ti.value());
                 self.emit32(ei.value());
@@ -182,11 +240,20 @@ pub trait Emitter {
                 self.emit32(ti.value());
                 self.emit32(t2i.value());
             }
+            syntax::Operands::MemoryInit(mi, di) => {
+                self.emit32(mi.value());
+                self.emit32(di.value());
+            }
+            syntax::Operands::MemoryCopy(mi, m2i) => {
+                self.emit32(mi.value());
+                self.emit32(m2i.value());
+            }
             syntax::Operands::HeapType(ht) => {
                 // Use the binary format encoding of ref type.
                 let htbyte = match ht {
                     RefType::Func => 0x70,
                     RefType::Extern => 0x6F,
+                    RefType::Exn => 0x69,
                 };
                 self.emit8(htbyte);
             }
@@ -215,6 +282,7 @@ pub trait Emitter {
 
 pub struct ValidatingEmitter<'a> {
     output:     Vec<u8>,
+    locations:  Vec<(u32, Location)>,
     validation: Validation<'a>,
 }
 
@@ -238,9 +306,7 @@ impl<'a> ValidatingEmitter<'a> {
         out.emit_expr(&func.body)?;
         out.emit_end(&func.location)?;
 
-        Ok(CompiledExpr {
-            instr: out.finish()?,
-        })
+        out.finish()
     }
 
     fn new(
@@ -250,12 +316,16 @@ impl<'a> ValidatingEmitter<'a> {
     ) -> ValidatingEmitter {
         ValidatingEmitter {
             output:     Vec::new(),
+            locations:  Vec::new(),
             validation: Validation::new(module, localtypes, resulttypes),
         }
     }
 
-    fn finish(self) -> Result<Box<[u8]>> {
-        Ok(self.output.into_boxed_slice())
+    fn finish(self) -> Result<CompiledExpr> {
+        Ok(CompiledExpr {
+            instr:     self.output.into_boxed_slice(),
+            locations: SourceMap::new(self.locations),
+        })
     }
 }
 
@@ -308,10 +378,19 @@ impl<'a> Emitter for ValidatingEmitter<'a> {
         self.output.extend(bytes);
     }
 
+    fn emit128(&mut self, v: u128) {
+        let bytes = &v.to_le_bytes()[..];
+        self.output.extend(bytes);
+    }
+
     fn emit_opcode(&mut self, opcode: Opcode) {
         self.output.extend(opcode.bytes());
     }
 
+    fn mark_location(&mut self, location: Location) {
+        self.locations.push((self.output.len() as u32, location));
+    }
+
     fn len(&self) -> usize {
         self.output.len()
     }
@@ -109,6 +109,7 @@ pub fn compile_module(
         funcs,
         tables,
         memories,
+        tags: module.tags,
         imports,
         exports,
         globals,
@@ -249,9 +250,15 @@ fn validate_memtype(memtype: MemType<Unvalidated>) -> KindResult<MemType<Validat
     if let Some(upper) = memtype.limits.upper {
         (memtype.limits.lower <= upper).true_or(ValidationErrorKind::InvalidLimits)?;
     }
-    (memtype.limits.upper.unwrap_or(memtype.limits.lower) <= 65536)
+    (memtype.limits.upper.unwrap_or(memtype.limits.lower) <= memtype.idxtype.max_pages())
         .true_or(ValidationErrorKind::MemoryTooLarge)?;
-    Ok(MemType::new(memtype.limits))
+    (!memtype.shared || memtype.limits.upper.is_some())
+        .true_or(ValidationErrorKind::SharedMemoryWithoutMax)?;
+    Ok(MemType::new(
+        memtype.idxtype,
+        memtype.limits,
+        memtype.shared,
+    ))
 }
 
 fn validate_tabletype(tabletype: TableType<Unvalidated>) -> KindResult<TableType<Validated>> {
@@ -282,6 +289,7 @@ fn validate_import_desc(
         ImportDesc::Global(g) => ImportDesc::Global(g),
         ImportDesc::Mem(m) => ImportDesc::Mem(validate_memtype(m)?),
         ImportDesc::Table(t) => ImportDesc::Table(validate_tabletype(t)?),
+        ImportDesc::Tag(t) => ImportDesc::Tag(t),
     })
 }
 
@@ -311,6 +319,10 @@ fn compile_export_desc(
                 .true_or(ValidationErrorKind::UnknownTable)?;
             Ok(ExportDesc::Table(ti))
         }
+        ExportDesc::Tag(ti) => {
+            (module.tags.len() > ti.value() as usize).true_or(ValidationErrorKind::UnknownTag)?;
+            Ok(ExportDesc::Tag(ti))
+        }
     }
 }
 
@@ -413,16 +425,19 @@ fn compile_data_init(
         .true_or(ValidationErrorKind::UnknownMemory)
         .validation_error(*location)?;
 
-    let mut offset = compile_const_expr(&data_init.offset, module, funcrefs, NumType::I32.into())
+    let idxtype = module.mems[data_init.memidx.value() as usize].idxtype;
+    let mut offset = compile_const_expr(&data_init.offset, module, funcrefs, idxtype.into())
         .validation_error(*location)?;
     let mut offset_expr_instrs = offset.instr.to_vec();
 
-    // "(i32.const 0) (i32.const {cnt}) (memory.init {di}) (data.drop {di})"
+    // "(i32.const 0) (i32.const {cnt}) (memory.init {memidx} {di}) (data.drop
+    // {di})"
     offset_expr_instrs.push(0x41);
     offset_expr_instrs.extend(0u32.to_le_bytes());
     offset_expr_instrs.push(0x41);
     offset_expr_instrs.extend((cnt as u32).to_le_bytes());
     offset_expr_instrs.extend(&[0xFC, 0x08]);
+    offset_expr_instrs.extend(data_init.memidx.value().to_le_bytes());
     offset_expr_instrs.extend((di as u32).to_le_bytes());
     offset_expr_instrs.extend(&[0xFC, 0x09]);
     offset_expr_instrs.extend((di as u32).to_le_bytes());
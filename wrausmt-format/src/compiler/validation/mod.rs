@@ -12,7 +12,7 @@ use {
             self,
             location::Location,
             types::{GlobalType, MemType, RefType, TableType, ValueType},
-            FuncIndex, ImportDesc, Index, Instruction, LocalIndex, Module, Resolved,
+            FuncIndex, ImportDesc, Index, Instruction, LocalIndex, Module, Resolved, TypeUse,
             UncompiledExpr, Unvalidated,
         },
     },
@@ -24,6 +24,7 @@ mod stacks;
 #[derive(Debug)]
 pub enum ValidationErrorKind {
     AlignmentTooLarge(u32),
+    AlignmentNotNatural(u32),
     BreakTypeMismatch,
     CtrlStackUnderflow,
 
@@ -37,11 +38,15 @@ pub enum ValidationErrorKind {
     ImmutableGlobal,
     InvalidConstantGlobal,
     InvalidConstantInstruction,
+    InvalidLaneIndex(u8),
     InvalidLimits,
     MemoryTooLarge,
-    MultipleMemories,
+    NonEmptyTagResult,
+    OffsetOutOfRange,
     OpcodeMismatch,
     OperandsMismatch,
+    ReturnTypeMismatch,
+    SharedMemoryWithoutMax,
     TableTooLarge,
     TypeMismatch {
         actual: ValidationType,
@@ -57,6 +62,7 @@ pub enum ValidationErrorKind {
     UnknownLabel,
     UnknownMemory,
     UnknownTable,
+    UnknownTag,
     UnknownType,
     UnusedValues,
     UnsupportedSelect,
@@ -147,6 +153,7 @@ pub struct ModuleContext {
     pub tables:   Vec<TableType<Unvalidated>>,
     pub mems:     Vec<MemType<Unvalidated>>,
     pub globals:  Vec<GlobalValidationType>,
+    pub tags:     Vec<FunctionType>,
     pub elems:    Vec<RefType>,
     pub datas:    usize,
     pub funcrefs: Vec<Index<Resolved, FuncIndex>>,
@@ -161,6 +168,7 @@ impl ModuleContext {
         let mut tables: Vec<TableType<Unvalidated>> = Vec::new();
         let mut mems: Vec<MemType<Unvalidated>> = Vec::new();
         let mut globals: Vec<GlobalValidationType> = Vec::new();
+        let mut tags: Vec<FunctionType> = Vec::new();
 
         for import in module.imports.iter() {
             match &import.desc {
@@ -175,16 +183,14 @@ impl ModuleContext {
                         .into(),
                 ),
                 ImportDesc::Table(tt) => tables.push(tt.clone()),
-                ImportDesc::Mem(mt) => {
-                    (mems.is_empty())
-                        .true_or(ValidationErrorKind::MultipleMemories)
-                        .validation_error(import.location)?;
-                    mems.push(mt.clone())
-                }
+                ImportDesc::Mem(mt) => mems.push(mt.clone()),
                 ImportDesc::Global(gt) => globals.push(GlobalValidationType {
                     globaltype: gt.clone(),
                     imported:   true,
                 }),
+                ImportDesc::Tag(tu) => {
+                    tags.push(tag_type(module, tu).validation_error(import.location)?)
+                }
             }
         }
 
@@ -202,16 +208,14 @@ impl ModuleContext {
         }
 
         tables.extend(module.tables.iter().map(|t| t.tabletype.clone()));
-        for mem in &module.memories {
-            (mems.is_empty())
-                .true_or(ValidationErrorKind::MultipleMemories)
-                .validation_error(mem.location)?;
-            mems.push(mem.memtype.clone())
-        }
+        mems.extend(module.memories.iter().map(|m| m.memtype.clone()));
         globals.extend(module.globals.iter().map(|g| GlobalValidationType {
             globaltype: g.globaltype.clone(),
             imported:   false,
         }));
+        for t in &module.tags {
+            tags.push(tag_type(module, &t.typeuse).validation_error(t.location)?);
+        }
 
         Ok(ModuleContext {
             types: module
@@ -226,6 +230,7 @@ impl ModuleContext {
             tables,
             mems,
             globals,
+            tags,
             elems: module.elems.iter().map(|e| e.elemlist.reftype).collect(),
             datas: module.data.len(),
             funcrefs: Vec::new(),
@@ -238,6 +243,25 @@ impl ModuleContext {
     }
 }
 
+/// The type of a tag is a function type whose results are empty.
+fn tag_type(
+    module: &Module<Resolved, Unvalidated, UncompiledExpr<Resolved>>,
+    typeuse: &TypeUse<Resolved>,
+) -> KindResult<FunctionType> {
+    let functype: FunctionType = module
+        .types
+        .get(typeuse.index().value() as usize)
+        .ok_or(ValidationErrorKind::UnknownType)?
+        .functiontype
+        .clone()
+        .into();
+    functype
+        .results
+        .is_empty()
+        .true_or(ValidationErrorKind::NonEmptyTagResult)?;
+    Ok(functype)
+}
+
 /// The Validation context and implementation.
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/appendix/algorithm.html
@@ -6,7 +6,8 @@ use {
         instructions::opcodes,
         syntax::{
             types::{NumType, RefType, ValueType},
-            BlockType, Index, Instruction, LocalIndex, Operands, Resolved,
+            BlockType, Catch, Index, Instruction, LocalIndex, MemoryIndex, Operands, Resolved,
+            TagIndex,
         },
     },
 };
@@ -16,7 +17,9 @@ const I32: ValueType = ValueType::Num(NumType::I32);
 const I64: ValueType = ValueType::Num(NumType::I64);
 const F32: ValueType = ValueType::Num(NumType::F32);
 const F64: ValueType = ValueType::Num(NumType::F64);
+const V128: ValueType = ValueType::Num(NumType::V128);
 const FUNC: ValueType = ValueType::Ref(RefType::Func);
+const EXN: ValueType = ValueType::Ref(RefType::Exn);
 
 macro_rules! instr {
     ($opcode:pat) => {
@@ -36,8 +39,8 @@ macro_rules! instr {
 }
 
 macro_rules! meminstr {
-    ($opcode:pat, align: $a:ident) => {
-        instr!($opcode => Operands::Memargs($a, _))
+    ($opcode:pat, mem: $m:ident, align: $a:ident) => {
+        instr!($opcode => Operands::Memargs($m, $a, _))
     }
 }
 
@@ -60,36 +63,104 @@ impl<'a> Validation<'a> {
         Ok(())
     }
 
+    /// The index type of the memory, which is the type of the addresses used
+    /// to access it.
+    fn mem(&self, memidx: &Index<Resolved, MemoryIndex>) -> Result<ValueType> {
+        self.module
+            .mems
+            .get(memidx.value() as usize)
+            .map(|m| m.idxtype.into())
+            .ok_or(ValidationErrorKind::UnknownMemory)
+    }
+
+    /// The offset of a memory access must be representable in the memory's
+    /// index type.
+    fn offset(&self, memidx: &Index<Resolved, MemoryIndex>, offset: u64) -> Result<()> {
+        (self.mem(memidx)? == I64 || offset <= u32::MAX as u64)
+            .true_or(ValidationErrorKind::OffsetOutOfRange)
+    }
+
     fn loadop(
         &mut self,
-        i: ValueType,
+        memidx: &Index<Resolved, MemoryIndex>,
         o: ValueType,
         alignment: u32,
         natural_alignment: u32,
     ) -> Result<()> {
-        (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-        (alignment <= natural_alignment)
+        let a = self.mem(memidx)?;
+        (alignment <= natural_alignment.trailing_zeros())
             .true_or(ValidationErrorKind::AlignmentTooLarge(alignment))?;
-        self.stacks.pop_val(i)?;
+        self.stacks.pop_val(a)?;
         self.stacks.push_val(o);
         Ok(())
     }
 
     fn storeop(
         &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
         v: ValueType,
-        a: ValueType,
         alignment: u32,
         natural_alignment: u32,
     ) -> Result<()> {
-        (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-        (alignment <= natural_alignment)
+        let a = self.mem(memidx)?;
+        (alignment <= natural_alignment.trailing_zeros())
             .true_or(ValidationErrorKind::AlignmentTooLarge(alignment))?;
         self.stacks.pop_val(v)?;
         self.stacks.pop_val(a)?;
         Ok(())
     }
 
+    /// Atomic accesses must be naturally aligned. The operands in `params`
+    /// follow the address on the stack.
+    fn atomicop(
+        &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
+        alignment: u32,
+        natural_alignment: u32,
+        params: &[ValueType],
+        result: Option<ValueType>,
+    ) -> Result<()> {
+        let a = self.mem(memidx)?;
+        (alignment == natural_alignment.trailing_zeros())
+            .true_or(ValidationErrorKind::AlignmentNotNatural(alignment))?;
+        for p in params.iter().rev() {
+            self.stacks.pop_val(*p)?;
+        }
+        self.stacks.pop_val(a)?;
+        if let Some(r) = result {
+            self.stacks.push_val(r);
+        }
+        Ok(())
+    }
+
+    fn loadlaneop(
+        &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
+        alignment: u32,
+        lane: u8,
+        size: u32,
+    ) -> Result<()> {
+        Self::lane(lane, 16 / size as u8)?;
+        self.storeop(memidx, V128, alignment, size)?;
+        self.stacks.push_val(V128);
+        Ok(())
+    }
+
+    fn storelaneop(
+        &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
+        alignment: u32,
+        lane: u8,
+        size: u32,
+    ) -> Result<()> {
+        Self::lane(lane, 16 / size as u8)?;
+        self.storeop(memidx, V128, alignment, size)
+    }
+
+    fn lane(lane: u8, lanes: u8) -> Result<()> {
+        (lane < lanes).true_or(ValidationErrorKind::InvalidLaneIndex(lane))
+    }
+
     fn function_type_for_blocktype(&self, blocktype: &BlockType<Resolved>) -> FunctionType {
         match blocktype {
             BlockType::Void => FunctionType::default(),
@@ -101,6 +172,26 @@ impl<'a> Validation<'a> {
         }
     }
 
+    fn tag_type(&self, idx: &Index<Resolved, TagIndex>) -> Result<&FunctionType> {
+        self.module
+            .tags
+            .get(idx.value() as usize)
+            .ok_or(ValidationErrorKind::UnknownTag)
+    }
+
+    /// The values that a catch clause passes to its label must match the
+    /// label's types.
+    fn validate_catch(&self, catch: &Catch<Resolved>) -> Result<()> {
+        let (mut passed, label) = match catch {
+            Catch::Catch(t, l) | Catch::CatchRef(t, l) => (self.tag_type(t)?.params.clone(), l),
+            Catch::CatchAll(l) | Catch::CatchAllRef(l) => (vec![], l),
+        };
+        if matches!(catch, Catch::CatchRef(..) | Catch::CatchAllRef(_)) {
+            passed.push(EXN);
+        }
+        (self.stacks.label_types(label)? == passed).true_or(ValidationErrorKind::BreakTypeMismatch)
+    }
+
     fn local_type(&self, idx: &Index<Resolved, LocalIndex>) -> Result<ValueType> {
         self.localtypes
             .get(idx.value() as usize)
@@ -125,6 +216,10 @@ impl<'a> Validation<'a> {
     pub fn validate_instr(&mut self, instr: &Instruction<Resolved>) -> Result<()> {
         println!("VALIDATION {instr:?}");
 
+        if let Operands::Memargs(m, _, o) | Operands::MemargsLane(m, _, o, _) = &instr.operands {
+            self.offset(m, *o)?;
+        }
+
         match instr {
             instr!(opcodes::UNREACHABLE) => self.stacks.unreachable(),
             instr!(opcodes::NOP) => Ok(()),
@@ -151,6 +246,28 @@ impl<'a> Validation<'a> {
                 Ok(())
             }
 
+            instr!(opcodes::THROW => Operands::TagIndex(idx)) => {
+                let params = self.tag_type(idx)?.params.clone();
+                self.stacks.pop_vals(&params)?;
+                self.stacks.unreachable()?;
+                Ok(())
+            }
+
+            instr!(opcodes::THROW_REF) => {
+                self.stacks.pop_val(EXN)?;
+                self.stacks.unreachable()?;
+                Ok(())
+            }
+
+            instr!(opcodes::TRY_TABLE => Operands::TryTable(_, blocktype, catches, _)) => {
+                let ft = self.function_type_for_blocktype(blocktype);
+                self.stacks.pop_vals(&ft.params)?;
+                catches.iter().try_for_each(|c| self.validate_catch(c))?;
+                self.stacks
+                    .push_ctrl(opcodes::TRY_TABLE, ft.params, ft.results);
+                Ok(())
+            }
+
             instr!(opcodes::ELSE) => self.validate_else(),
 
             instr!(opcodes::END) => self.validate_end(),
@@ -229,6 +346,41 @@ impl<'a> Validation<'a> {
                 Ok(())
             }
 
+            // A tail call returns the callee's results in place of the
+            // current function's, so their result types must be the same.
+            instr!(opcodes::RETURN_CALL => Operands::FuncIndex(idx)) => {
+                let ft = &self
+                    .module
+                    .funcs
+                    .get(idx.value() as usize)
+                    .ok_or(ValidationErrorKind::UnknownFunc)?;
+                (ft.results == self.stacks.return_types()?)
+                    .true_or(ValidationErrorKind::ReturnTypeMismatch)?;
+                self.stacks.pop_vals(&ft.params)?;
+                self.stacks.unreachable()?;
+                Ok(())
+            }
+
+            instr!(opcodes::RETURN_CALL_INDIRECT => Operands::CallIndirect(tabidx, typeuse)) => {
+                self.module
+                    .tables
+                    .get(tabidx.value() as usize)
+                    .map(|t| t.reftype == RefType::Func)
+                    .ok_or(ValidationErrorKind::UnknownTable)?
+                    .true_or(ValidationErrorKind::WrongTableType)?;
+                let ft = self
+                    .module
+                    .types
+                    .get(typeuse.index().value() as usize)
+                    .ok_or(ValidationErrorKind::UnknownType)?;
+                (ft.results == self.stacks.return_types()?)
+                    .true_or(ValidationErrorKind::ReturnTypeMismatch)?;
+                self.stacks.pop_val(I32)?;
+                self.stacks.pop_vals(&ft.params)?;
+                self.stacks.unreachable()?;
+                Ok(())
+            }
+
             // Note: the binary 0x1b and 0x1c both turn into SELECT,
             // with opcode 0x1b, but different operand types.
             instr!(opcodes::SELECT) => {
@@ -326,41 +478,41 @@ impl<'a> Validation<'a> {
                 Ok(())
             }
             // 0x28
-            meminstr!(opcodes::I32_LOAD, align: a) => self.loadop(I32, I32, *a, 4),
-            meminstr!(opcodes::I64_LOAD, align: a) => self.loadop(I32, I64, *a, 8),
-            meminstr!(opcodes::F32_LOAD, align: a) => self.loadop(I32, F32, *a, 4),
-            meminstr!(opcodes::F64_LOAD, align: a) => self.loadop(I32, F64, *a, 8),
-            meminstr!(opcodes::I32_LOAD8_S, align: a) => self.loadop(I32, I32, *a, 1),
-            meminstr!(opcodes::I32_LOAD8_U, align: a) => self.loadop(I32, I32, *a, 1),
-            meminstr!(opcodes::I32_LOAD16_S, align: a) => self.loadop(I32, I32, *a, 2),
-            meminstr!(opcodes::I32_LOAD16_U, align: a) => self.loadop(I32, I32, *a, 2),
-            meminstr!(opcodes::I64_LOAD8_S , align: a) => self.loadop(I32, I64, *a, 1),
-            meminstr!(opcodes::I64_LOAD8_U , align: a) => self.loadop(I32, I64, *a, 1),
-            meminstr!(opcodes::I64_LOAD16_S , align: a) => self.loadop(I32, I64, *a, 2),
-            meminstr!(opcodes::I64_LOAD16_U , align: a) => self.loadop(I32, I64, *a, 2),
-            meminstr!(opcodes::I64_LOAD32_S , align: a) => self.loadop(I32, I64, *a, 4),
-            meminstr!(opcodes::I64_LOAD32_U , align: a) => self.loadop(I32, I64, *a, 4),
+            meminstr!(opcodes::I32_LOAD, mem: m, align: a) => self.loadop(m, I32, *a, 4),
+            meminstr!(opcodes::I64_LOAD, mem: m, align: a) => self.loadop(m, I64, *a, 8),
+            meminstr!(opcodes::F32_LOAD, mem: m, align: a) => self.loadop(m, F32, *a, 4),
+            meminstr!(opcodes::F64_LOAD, mem: m, align: a) => self.loadop(m, F64, *a, 8),
+            meminstr!(opcodes::I32_LOAD8_S, mem: m, align: a) => self.loadop(m, I32, *a, 1),
+            meminstr!(opcodes::I32_LOAD8_U, mem: m, align: a) => self.loadop(m, I32, *a, 1),
+            meminstr!(opcodes::I32_LOAD16_S, mem: m, align: a) => self.loadop(m, I32, *a, 2),
+            meminstr!(opcodes::I32_LOAD16_U, mem: m, align: a) => self.loadop(m, I32, *a, 2),
+            meminstr!(opcodes::I64_LOAD8_S , mem: m, align: a) => self.loadop(m, I64, *a, 1),
+            meminstr!(opcodes::I64_LOAD8_U , mem: m, align: a) => self.loadop(m, I64, *a, 1),
+            meminstr!(opcodes::I64_LOAD16_S , mem: m, align: a) => self.loadop(m, I64, *a, 2),
+            meminstr!(opcodes::I64_LOAD16_U , mem: m, align: a) => self.loadop(m, I64, *a, 2),
+            meminstr!(opcodes::I64_LOAD32_S , mem: m, align: a) => self.loadop(m, I64, *a, 4),
+            meminstr!(opcodes::I64_LOAD32_U , mem: m, align: a) => self.loadop(m, I64, *a, 4),
 
             // 0x36
-            meminstr!(opcodes::I32_STORE, align: a) => self.storeop(I32, I32, *a, 4),
-            meminstr!(opcodes::I64_STORE, align: a) => self.storeop(I64, I32, *a, 8),
-            meminstr!(opcodes::F32_STORE, align: a) => self.storeop(F32, I32, *a, 4),
-            meminstr!(opcodes::F64_STORE, align: a) => self.storeop(F64, I32, *a, 8),
-            meminstr!(opcodes::I32_STORE8, align: a) => self.storeop(I32, I32, *a, 1),
-            meminstr!(opcodes::I32_STORE16, align: a) => self.storeop(I32, I32, *a, 2),
-            meminstr!(opcodes::I64_STORE8, align: a) => self.storeop(I64, I32, *a, 1),
-            meminstr!(opcodes::I64_STORE16, align: a) => self.storeop(I64, I32, *a, 2),
-            meminstr!(opcodes::I64_STORE32, align: a) => self.storeop(I64, I32, *a, 4),
-
-            instr!(opcodes::MEMORY_SIZE) => {
-                (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-                self.stacks.push_val(I32);
+            meminstr!(opcodes::I32_STORE, mem: m, align: a) => self.storeop(m, I32, *a, 4),
+            meminstr!(opcodes::I64_STORE, mem: m, align: a) => self.storeop(m, I64, *a, 8),
+            meminstr!(opcodes::F32_STORE, mem: m, align: a) => self.storeop(m, F32, *a, 4),
+            meminstr!(opcodes::F64_STORE, mem: m, align: a) => self.storeop(m, F64, *a, 8),
+            meminstr!(opcodes::I32_STORE8, mem: m, align: a) => self.storeop(m, I32, *a, 1),
+            meminstr!(opcodes::I32_STORE16, mem: m, align: a) => self.storeop(m, I32, *a, 2),
+            meminstr!(opcodes::I64_STORE8, mem: m, align: a) => self.storeop(m, I64, *a, 1),
+            meminstr!(opcodes::I64_STORE16, mem: m, align: a) => self.storeop(m, I64, *a, 2),
+            meminstr!(opcodes::I64_STORE32, mem: m, align: a) => self.storeop(m, I64, *a, 4),
+
+            instr!(opcodes::MEMORY_SIZE => Operands::MemoryIndex(idx)) => {
+                let it = self.mem(idx)?;
+                self.stacks.push_val(it);
                 Ok(())
             }
-            instr!(opcodes::MEMORY_GROW) => {
-                (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-                self.stacks.pop_val(I32)?;
-                self.stacks.push_val(I32);
+            instr!(opcodes::MEMORY_GROW => Operands::MemoryIndex(idx)) => {
+                let it = self.mem(idx)?;
+                self.stacks.pop_val(it)?;
+                self.stacks.push_val(it);
                 Ok(())
             }
             // 0x41
@@ -561,13 +713,13 @@ impl<'a> Validation<'a> {
             instr!(opcodes::I64_TRUNC_SAT_F64_U) => self.unop(F64, I64),
 
             // 0xFC 0x08
-            instr!(opcodes::MEMORY_INIT => Operands::DataIndex(idx)) => {
-                (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-                ((idx.value() as usize) < self.module.datas)
+            instr!(opcodes::MEMORY_INIT => Operands::MemoryInit(midx, didx)) => {
+                let it = self.mem(midx)?;
+                ((didx.value() as usize) < self.module.datas)
                     .true_or(ValidationErrorKind::UnknownData)?;
                 self.stacks.pop_val(I32)?;
                 self.stacks.pop_val(I32)?;
-                self.stacks.pop_val(I32)?;
+                self.stacks.pop_val(it)?;
                 Ok(())
             }
             instr!(opcodes::DATA_DROP => Operands::DataIndex(idx)) => {
@@ -575,11 +727,26 @@ impl<'a> Validation<'a> {
                     .true_or(ValidationErrorKind::UnknownData)?;
                 Ok(())
             }
-            instr!(opcodes::MEMORY_COPY) | instr!(opcodes::MEMORY_FILL) => {
-                (!self.module.mems.is_empty()).true_or(ValidationErrorKind::UnknownMemory)?;
-                self.stacks.pop_val(I32)?;
-                self.stacks.pop_val(I32)?;
+            instr!(opcodes::MEMORY_COPY => Operands::MemoryCopy(dstidx, srcidx)) => {
+                let dst_it = self.mem(dstidx)?;
+                let src_it = self.mem(srcidx)?;
+                // The count must fit in both memories, so it's only an i64 when
+                // both memories are.
+                let count_it = if dst_it == I64 && src_it == I64 {
+                    I64
+                } else {
+                    I32
+                };
+                self.stacks.pop_val(count_it)?;
+                self.stacks.pop_val(src_it)?;
+                self.stacks.pop_val(dst_it)?;
+                Ok(())
+            }
+            instr!(opcodes::MEMORY_FILL => Operands::MemoryIndex(idx)) => {
+                let it = self.mem(idx)?;
+                self.stacks.pop_val(it)?;
                 self.stacks.pop_val(I32)?;
+                self.stacks.pop_val(it)?;
                 Ok(())
             }
             instr!(opcodes::TABLE_INIT => Operands::TableInit(tidx, eidx)) => {
@@ -656,6 +823,509 @@ impl<'a> Validation<'a> {
                 Ok(())
             }
 
+            // 0xFD 0x00
+            meminstr!(opcodes::V128_LOAD, mem: m, align: a) => self.loadop(m, V128, *a, 16),
+            meminstr!(opcodes::V128_LOAD8X8_S, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD8X8_U, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD16X4_S, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD16X4_U, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD32X2_S, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD32X2_U, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_LOAD8_SPLAT, mem: m, align: a) => self.loadop(m, V128, *a, 1),
+            meminstr!(opcodes::V128_LOAD16_SPLAT, mem: m, align: a) => self.loadop(m, V128, *a, 2),
+            meminstr!(opcodes::V128_LOAD32_SPLAT, mem: m, align: a) => self.loadop(m, V128, *a, 4),
+            meminstr!(opcodes::V128_LOAD64_SPLAT, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            meminstr!(opcodes::V128_STORE, mem: m, align: a) => self.storeop(m, V128, *a, 16),
+            instr!(opcodes::V128_CONST => Operands::V128(_)) => self.constop(V128),
+            instr!(opcodes::I8X16_SHUFFLE => Operands::Shuffle(lanes)) => {
+                lanes.iter().try_for_each(|l| Self::lane(*l, 32))?;
+                self.binop(V128, V128, V128)
+            }
+            instr!(opcodes::I8X16_SWIZZLE) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_SPLAT) => self.unop(I32, V128),
+            instr!(opcodes::I16X8_SPLAT) => self.unop(I32, V128),
+            instr!(opcodes::I32X4_SPLAT) => self.unop(I32, V128),
+            instr!(opcodes::I64X2_SPLAT) => self.unop(I64, V128),
+            instr!(opcodes::F32X4_SPLAT) => self.unop(F32, V128),
+            instr!(opcodes::F64X2_SPLAT) => self.unop(F64, V128),
+            instr!(opcodes::I8X16_EXTRACT_LANE_S => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 16)?;
+                self.unop(V128, I32)
+            }
+            instr!(opcodes::I8X16_EXTRACT_LANE_U => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 16)?;
+                self.unop(V128, I32)
+            }
+            instr!(opcodes::I8X16_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 16)?;
+                self.binop(I32, V128, V128)
+            }
+            instr!(opcodes::I16X8_EXTRACT_LANE_S => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 8)?;
+                self.unop(V128, I32)
+            }
+            instr!(opcodes::I16X8_EXTRACT_LANE_U => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 8)?;
+                self.unop(V128, I32)
+            }
+            instr!(opcodes::I16X8_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 8)?;
+                self.binop(I32, V128, V128)
+            }
+            instr!(opcodes::I32X4_EXTRACT_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 4)?;
+                self.unop(V128, I32)
+            }
+            instr!(opcodes::I32X4_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 4)?;
+                self.binop(I32, V128, V128)
+            }
+            instr!(opcodes::I64X2_EXTRACT_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 2)?;
+                self.unop(V128, I64)
+            }
+            instr!(opcodes::I64X2_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 2)?;
+                self.binop(I64, V128, V128)
+            }
+            instr!(opcodes::F32X4_EXTRACT_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 4)?;
+                self.unop(V128, F32)
+            }
+            instr!(opcodes::F32X4_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 4)?;
+                self.binop(F32, V128, V128)
+            }
+            instr!(opcodes::F64X2_EXTRACT_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 2)?;
+                self.unop(V128, F64)
+            }
+            instr!(opcodes::F64X2_REPLACE_LANE => Operands::LaneIndex(l)) => {
+                Self::lane(*l, 2)?;
+                self.binop(F64, V128, V128)
+            }
+            instr!(opcodes::I8X16_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_LT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_LT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_GT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_GT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_LE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_LE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_GE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_GE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_LT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_LT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_GT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_GT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_LE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_LE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_GE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_GE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_LT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_LT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_GT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_GT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_LE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_LE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_GE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_GE_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_LT) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_GT) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_LE) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_GE) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_LT) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_GT) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_LE) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_GE) => self.binop(V128, V128, V128),
+            instr!(opcodes::V128_NOT) => self.unop(V128, V128),
+            instr!(opcodes::V128_AND) => self.binop(V128, V128, V128),
+            instr!(opcodes::V128_ANDNOT) => self.binop(V128, V128, V128),
+            instr!(opcodes::V128_OR) => self.binop(V128, V128, V128),
+            instr!(opcodes::V128_XOR) => self.binop(V128, V128, V128),
+            instr!(opcodes::V128_BITSELECT) => {
+                self.stacks.pop_val(V128)?;
+                self.binop(V128, V128, V128)
+            }
+            instr!(opcodes::V128_ANY_TRUE) => self.unop(V128, I32),
+            instr!(opcodes::V128_LOAD8_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.loadlaneop(m, *a, *l, 1)
+            }
+            instr!(opcodes::V128_LOAD16_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.loadlaneop(m, *a, *l, 2)
+            }
+            instr!(opcodes::V128_LOAD32_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.loadlaneop(m, *a, *l, 4)
+            }
+            instr!(opcodes::V128_LOAD64_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.loadlaneop(m, *a, *l, 8)
+            }
+            instr!(opcodes::V128_STORE8_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.storelaneop(m, *a, *l, 1)
+            }
+            instr!(opcodes::V128_STORE16_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.storelaneop(m, *a, *l, 2)
+            }
+            instr!(opcodes::V128_STORE32_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.storelaneop(m, *a, *l, 4)
+            }
+            instr!(opcodes::V128_STORE64_LANE => Operands::MemargsLane(m, a, _, l)) => {
+                self.storelaneop(m, *a, *l, 8)
+            }
+            meminstr!(opcodes::V128_LOAD32_ZERO, mem: m, align: a) => self.loadop(m, V128, *a, 4),
+            meminstr!(opcodes::V128_LOAD64_ZERO, mem: m, align: a) => self.loadop(m, V128, *a, 8),
+            instr!(opcodes::F32X4_DEMOTE_F64X2_ZERO) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_PROMOTE_LOW_F32X4) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_ABS) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_NEG) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_POPCNT) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_ALL_TRUE) => self.unop(V128, I32),
+            instr!(opcodes::I8X16_BITMASK) => self.unop(V128, I32),
+            instr!(opcodes::I8X16_NARROW_I16X8_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_NARROW_I16X8_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_CEIL) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_FLOOR) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_TRUNC) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_NEAREST) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_SHL) => self.binop(I32, V128, V128),
+            instr!(opcodes::I8X16_SHR_S) => self.binop(I32, V128, V128),
+            instr!(opcodes::I8X16_SHR_U) => self.binop(I32, V128, V128),
+            instr!(opcodes::I8X16_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_ADD_SAT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_ADD_SAT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_SUB_SAT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_SUB_SAT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_CEIL) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_FLOOR) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_MIN_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_MIN_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_MAX_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I8X16_MAX_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_TRUNC) => self.unop(V128, V128),
+            instr!(opcodes::I8X16_AVGR_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTADD_PAIRWISE_I8X16_S) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_EXTADD_PAIRWISE_I8X16_U) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_EXTADD_PAIRWISE_I16X8_S) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_EXTADD_PAIRWISE_I16X8_U) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_ABS) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_NEG) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_Q15MULR_SAT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_ALL_TRUE) => self.unop(V128, I32),
+            instr!(opcodes::I16X8_BITMASK) => self.unop(V128, I32),
+            instr!(opcodes::I16X8_NARROW_I32X4_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_NARROW_I32X4_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTEND_LOW_I8X16_S) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_EXTEND_HIGH_I8X16_S) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_EXTEND_LOW_I8X16_U) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_EXTEND_HIGH_I8X16_U) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_SHL) => self.binop(I32, V128, V128),
+            instr!(opcodes::I16X8_SHR_S) => self.binop(I32, V128, V128),
+            instr!(opcodes::I16X8_SHR_U) => self.binop(I32, V128, V128),
+            instr!(opcodes::I16X8_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_ADD_SAT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_ADD_SAT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_SUB_SAT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_SUB_SAT_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_NEAREST) => self.unop(V128, V128),
+            instr!(opcodes::I16X8_MUL) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_MIN_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_MIN_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_MAX_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_MAX_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_AVGR_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTMUL_LOW_I8X16_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTMUL_HIGH_I8X16_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTMUL_LOW_I8X16_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I16X8_EXTMUL_HIGH_I8X16_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_ABS) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_NEG) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_ALL_TRUE) => self.unop(V128, I32),
+            instr!(opcodes::I32X4_BITMASK) => self.unop(V128, I32),
+            instr!(opcodes::I32X4_EXTEND_LOW_I16X8_S) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_EXTEND_HIGH_I16X8_S) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_EXTEND_LOW_I16X8_U) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_EXTEND_HIGH_I16X8_U) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_SHL) => self.binop(I32, V128, V128),
+            instr!(opcodes::I32X4_SHR_S) => self.binop(I32, V128, V128),
+            instr!(opcodes::I32X4_SHR_U) => self.binop(I32, V128, V128),
+            instr!(opcodes::I32X4_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_MUL) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_MIN_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_MIN_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_MAX_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_MAX_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_DOT_I16X8_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_EXTMUL_LOW_I16X8_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_EXTMUL_HIGH_I16X8_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_EXTMUL_LOW_I16X8_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_EXTMUL_HIGH_I16X8_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_ABS) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_NEG) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_ALL_TRUE) => self.unop(V128, I32),
+            instr!(opcodes::I64X2_BITMASK) => self.unop(V128, I32),
+            instr!(opcodes::I64X2_EXTEND_LOW_I32X4_S) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_EXTEND_HIGH_I32X4_S) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_EXTEND_LOW_I32X4_U) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_EXTEND_HIGH_I32X4_U) => self.unop(V128, V128),
+            instr!(opcodes::I64X2_SHL) => self.binop(I32, V128, V128),
+            instr!(opcodes::I64X2_SHR_S) => self.binop(I32, V128, V128),
+            instr!(opcodes::I64X2_SHR_U) => self.binop(I32, V128, V128),
+            instr!(opcodes::I64X2_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_MUL) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_EQ) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_NE) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_LT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_GT_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_LE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_GE_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_EXTMUL_LOW_I32X4_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_EXTMUL_HIGH_I32X4_S) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_EXTMUL_LOW_I32X4_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::I64X2_EXTMUL_HIGH_I32X4_U) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_ABS) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_NEG) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_SQRT) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_MUL) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_DIV) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_MIN) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_MAX) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_PMIN) => self.binop(V128, V128, V128),
+            instr!(opcodes::F32X4_PMAX) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_ABS) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_NEG) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_SQRT) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_ADD) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_SUB) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_MUL) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_DIV) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_MIN) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_MAX) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_PMIN) => self.binop(V128, V128, V128),
+            instr!(opcodes::F64X2_PMAX) => self.binop(V128, V128, V128),
+            instr!(opcodes::I32X4_TRUNC_SAT_F32X4_S) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_TRUNC_SAT_F32X4_U) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_CONVERT_I32X4_S) => self.unop(V128, V128),
+            instr!(opcodes::F32X4_CONVERT_I32X4_U) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_TRUNC_SAT_F64X2_S_ZERO) => self.unop(V128, V128),
+            instr!(opcodes::I32X4_TRUNC_SAT_F64X2_U_ZERO) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_CONVERT_LOW_I32X4_S) => self.unop(V128, V128),
+            instr!(opcodes::F64X2_CONVERT_LOW_I32X4_U) => self.unop(V128, V128),
+
+            // 0xFE 0x00
+            meminstr!(opcodes::MEMORY_ATOMIC_NOTIFY, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::MEMORY_ATOMIC_WAIT32, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32, I64], Some(I32))
+            }
+            meminstr!(opcodes::MEMORY_ATOMIC_WAIT64, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64, I64], Some(I32))
+            }
+            instr!(opcodes::ATOMIC_FENCE) => Ok(()),
+            meminstr!(opcodes::I32_ATOMIC_LOAD, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_LOAD, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_LOAD8_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_LOAD16_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_LOAD8_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_LOAD16_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_LOAD32_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_STORE, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], None)
+            }
+            meminstr!(opcodes::I64_ATOMIC_STORE, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], None)
+            }
+            meminstr!(opcodes::I32_ATOMIC_STORE8, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], None)
+            }
+            meminstr!(opcodes::I32_ATOMIC_STORE16, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], None)
+            }
+            meminstr!(opcodes::I64_ATOMIC_STORE8, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], None)
+            }
+            meminstr!(opcodes::I64_ATOMIC_STORE16, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], None)
+            }
+            meminstr!(opcodes::I64_ATOMIC_STORE32, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], None)
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_ADD, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_ADD, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_ADD_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_ADD_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_ADD_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_ADD_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_ADD_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_SUB, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_SUB, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_SUB_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_SUB_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_SUB_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_SUB_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_SUB_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_AND, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_AND, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_AND_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_AND_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_AND_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_AND_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_AND_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_OR, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_OR, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_OR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_OR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_OR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_OR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_OR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_XOR, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_XOR, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_XOR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_XOR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_XOR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_XOR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_XOR_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_XCHG, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_XCHG, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_XCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_XCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_XCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_XCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_XCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW_CMPXCHG, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I32, I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW_CMPXCHG, mem: m, align: a) => {
+                self.atomicop(m, *a, 8, &[I64, I64], Some(I64))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW8_CMPXCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I32, I32], Some(I32))
+            }
+            meminstr!(opcodes::I32_ATOMIC_RMW16_CMPXCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I32, I32], Some(I32))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW8_CMPXCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 1, &[I64, I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW16_CMPXCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 2, &[I64, I64], Some(I64))
+            }
+            meminstr!(opcodes::I64_ATOMIC_RMW32_CMPXCHG_U, mem: m, align: a) => {
+                self.atomicop(m, *a, 4, &[I64, I64], Some(I64))
+            }
+
             _ => Err(ValidationErrorKind::UnhandledInstruction(instr.clone())),
         }
     }
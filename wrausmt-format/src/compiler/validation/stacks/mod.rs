@@ -89,6 +89,10 @@ impl Stacks {
         Ok(self.ctrl.label_types(idx)?.len())
     }
 
+    pub fn label_types(&self, idx: &Index<Resolved, LabelIndex>) -> Result<Vec<ValueType>> {
+        self.ctrl.label_types(idx)
+    }
+
     pub fn push_label_types(&mut self, idx: &Index<Resolved, LabelIndex>) -> Result<()> {
         let label_types = self.ctrl.label_types(idx)?;
         self.push_vals(&label_types);
@@ -104,6 +108,10 @@ impl Stacks {
         self.pop_vals(&label_types)
     }
 
+    pub fn return_types(&self) -> Result<Vec<ValueType>> {
+        self.ctrl.return_types()
+    }
+
     pub fn pop_return_types(&mut self) -> Result<()> {
         let label_types = self.ctrl.return_types()?;
         self.pop_vals(&label_types).map(|_| ())
@@ -1,23 +1,40 @@
 use {
-    crate::loader::{Loader, Result},
+    crate::loader::{compile_wasm_data, compile_wast_data, Loader, Result},
     std::{
         fs::File,
         io::{Read, Seek, SeekFrom},
         rc::Rc,
     },
-    wrausmt_runtime::runtime::{instance::ModuleInstance, Runtime},
+    wrausmt_runtime::runtime::{compiled::CompiledModule, instance::ModuleInstance, Runtime},
 };
 
+/// Whether the file starts with the magic bytes of the binary format. The file
+/// is left at its start.
+fn is_binary(file: &mut File) -> Result<bool> {
+    let mut magic: [u8; 4] = [0u8; 4];
+    file.read_exact(&mut magic)?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(&magic == b"\0asm")
+}
+
+/// Compile a WASM or WAST file, without instantiating it. Like
+/// [`FileLoader::load_file`], the format is detected from the magic bytes.
+pub fn compile_file(filename: &str) -> Result<CompiledModule> {
+    let mut file = File::open(filename)?;
+    if is_binary(&mut file)? {
+        compile_wasm_data(&mut file)
+    } else {
+        compile_wast_data(&mut file)
+    }
+}
+
 pub trait FileLoader: Loader {
     /// Load a WASM or WAST file. The loader will look for the magic binary
     /// bytes at the start. If those are not found, it will try loading the file
     /// as a text-format file.
     fn load_file(&mut self, filename: &str) -> Result<Rc<ModuleInstance>> {
         let mut file = File::open(filename)?;
-        let mut magic: [u8; 4] = [0u8; 4];
-        file.read_exact(&mut magic)?;
-        file.seek(SeekFrom::Start(0))?;
-        if &magic == b"\0asm" {
+        if is_binary(&mut file)? {
             println!("Magic header exists... Attemptin load as WASM binary format.");
             self.load_wasm_data(&mut file)
         } else {
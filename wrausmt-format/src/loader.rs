@@ -8,7 +8,9 @@ use crate::{
 };
 use {
     std::{io::Read, rc::Rc},
-    wrausmt_runtime::runtime::{error::RuntimeError, instance::ModuleInstance, Runtime},
+    wrausmt_runtime::runtime::{
+        compiled::CompiledModule, error::RuntimeError, instance::ModuleInstance, Runtime,
+    },
 };
 
 #[derive(Debug)]
@@ -79,20 +81,32 @@ pub trait Loader {
     fn load_wast_data(&mut self, read: &mut impl Read) -> Result<Rc<ModuleInstance>>;
 }
 
+/// Parse and compile a module in the binary format, so that it can be
+/// instantiated any number of times.
+pub fn compile_wasm_data(reader: &mut impl Read) -> Result<CompiledModule> {
+    let module = parse_wasm_data(reader)?;
+    // TODO Switch to fail when validation is complete.
+    let compiled = compile_module(module)?;
+    Ok(CompiledModule::new(compiled)?)
+}
+
+/// Parse and compile a module in the text format, so that it can be
+/// instantiated any number of times.
+pub fn compile_wast_data(reader: &mut impl Read) -> Result<CompiledModule> {
+    let module = parse_wast_data(reader)?;
+    // TODO Switch to fail when validation is complete.
+    let compiled = compile_module(module)?;
+    Ok(CompiledModule::new(compiled)?)
+}
+
 impl Loader for Runtime {
     fn load_wasm_data(&mut self, reader: &mut impl Read) -> Result<Rc<ModuleInstance>> {
-        let module = parse_wasm_data(reader)?;
-        // TODO Switch to fail when validation is complete.
-        let compiled = compile_module(module)?;
-        let mod_inst = self.load(compiled)?;
+        let mod_inst = self.load_compiled(&compile_wasm_data(reader)?)?;
         Ok(mod_inst)
     }
 
     fn load_wast_data(&mut self, reader: &mut impl Read) -> Result<Rc<ModuleInstance>> {
-        let module = parse_wast_data(reader)?;
-        // TODO Switch to fail when validation is complete.
-        let compiled = compile_module(module)?;
-        let mod_inst = self.load(compiled)?;
+        let mod_inst = self.load_compiled(&compile_wast_data(reader)?)?;
         Ok(mod_inst)
     }
 }
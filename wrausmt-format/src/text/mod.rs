@@ -12,6 +12,7 @@ pub mod token;
 pub mod macros;
 pub mod module_builder;
 pub mod parse;
+pub mod print;
 pub mod resolve;
 pub mod string;
 
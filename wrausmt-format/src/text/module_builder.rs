@@ -4,8 +4,8 @@ use {
     wrausmt_common::true_or::TrueOr,
     wrausmt_runtime::syntax::{
         DataField, ElemField, ExportDesc, ExportField, FuncField, GlobalField, Id, ImportDesc,
-        ImportField, Index, MemoryField, Module, Resolved, StartField, TableField, TypeField,
-        UncompiledExpr, Unresolved, Unvalidated,
+        ImportField, Index, MemoryField, Module, Resolved, StartField, TableField, TagField,
+        TypeField, UncompiledExpr, Unresolved, Unvalidated,
     },
 };
 
@@ -16,6 +16,7 @@ pub struct ModuleIdentifiers {
     pub tableindices:  HashMap<Id, u32>,
     pub memindices:    HashMap<Id, u32>,
     pub globalindices: HashMap<Id, u32>,
+    pub tagindices:    HashMap<Id, u32>,
     pub elemindices:   HashMap<Id, u32>,
     pub dataindices:   HashMap<Id, u32>,
 }
@@ -35,6 +36,7 @@ pub struct ModuleBuilder {
     tableidx_offset:    u32,
     memidx_offset:      u32,
     globalidx_offset:   u32,
+    tagidx_offset:      u32,
 }
 
 macro_rules! add_ident {
@@ -66,6 +68,7 @@ impl ModuleBuilder {
             && self.module.funcs.is_empty()
             && self.module.tables.is_empty()
             && self.module.memories.is_empty()
+            && self.module.tags.is_empty()
             && self.module.imports.is_empty()
             && self.module.exports.is_empty()
             && self.module.globals.is_empty()
@@ -144,11 +147,28 @@ impl ModuleBuilder {
         Ok(())
     }
 
+    pub fn add_tagfield(&mut self, f: TagField<Unresolved>) -> Result<()> {
+        add_ident!(self, f, tagindices, tags, self.tagidx_offset; DuplicateTag);
+
+        // export field may define new exports.
+        let tagidx = self.module.tags.len() as u32 + self.tagidx_offset;
+        for export_name in &f.exports {
+            self.module.exports.push(ExportField::new(
+                export_name.clone(),
+                ExportDesc::Tag(Index::unnamed(tagidx)),
+                f.location,
+            ))
+        }
+        self.module.tags.push(f);
+        Ok(())
+    }
+
     pub fn add_importfield(&mut self, f: ImportField<Unresolved, Unvalidated>) -> Result<()> {
         (self.module.funcs.is_empty()).true_or(ResolveError::ImportAfterFunction)?;
         (self.module.globals.is_empty()).true_or(ResolveError::ImportAfterGlobal)?;
         (self.module.memories.is_empty()).true_or(ResolveError::ImportAfterMemory)?;
         (self.module.tables.is_empty()).true_or(ResolveError::ImportAfterTable)?;
+        (self.module.tags.is_empty()).true_or(ResolveError::ImportAfterTag)?;
 
         // Imports contribute to index counts in their corresponding
         // space, and must appear before any declarations of that type
@@ -199,6 +219,17 @@ impl ModuleBuilder {
                 }
                 self.globalidx_offset += 1;
             }
+            ImportDesc::Tag(_) => {
+                add_ident!(self, f, tagindices, tags, self.tagidx_offset; DuplicateTag);
+                for export_name in &f.exports {
+                    self.module.exports.push(ExportField::new(
+                        export_name.clone(),
+                        ExportDesc::Tag(Index::unnamed(self.tagidx_offset)),
+                        f.location,
+                    ))
+                }
+                self.tagidx_offset += 1;
+            }
         }
         self.module.imports.push(f);
         Ok(())
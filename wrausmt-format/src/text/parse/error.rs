@@ -26,9 +26,11 @@ pub enum ParseErrorKind {
     Utf8Error(FromUtf8Error),
     ParseIntError(ParseIntError),
     ParseFloatError(ParseFloatError),
-    InvalidAlignment(u32),
+    InvalidAlignment(u64),
+    InvalidLaneLength,
     InvalidNaN(u64),
     ConstantOutOfRange,
+    MalformedLaneIndex,
     TooManyLocals,
     Incomplete,
     LabelMismatch(Option<Id>, Option<Id>),
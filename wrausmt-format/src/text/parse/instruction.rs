@@ -1,10 +1,14 @@
 use {
     super::{error::ParseErrorKind, pctx, Parser, Result},
-    crate::text::{num, parse::combinator::Groups, token::Token},
+    crate::text::{
+        num,
+        parse::combinator::Groups,
+        token::{NumToken, Sign, Token},
+    },
     std::io::Read,
     wrausmt_common::true_or::TrueOr,
     wrausmt_runtime::{
-        instructions::{instruction_by_name, Operands},
+        instructions::{instruction_by_name, opcodes, Operands},
         syntax::{self, Continuation, Id, Index, Instruction, Opcode, UncompiledExpr, Unresolved},
     },
 };
@@ -36,12 +40,27 @@ impl<R: Read> Parser<R> {
             Some(data) => {
                 let name = self.try_keyword()?.unwrap();
                 let operands = match data.operands {
-                    Operands::None
-                    | Operands::MemoryFill
-                    | Operands::MemorySize
-                    | Operands::MemoryGrow
-                    | Operands::MemoryCopy => syntax::Operands::None,
-                    Operands::MemoryInit => syntax::Operands::DataIndex(self.expect_index()?),
+                    Operands::None => syntax::Operands::None,
+                    Operands::MemorySize | Operands::MemoryGrow | Operands::MemoryFill => {
+                        let memidx = self.try_index()?.unwrap_or_else(|| Index::unnamed(0));
+                        syntax::Operands::MemoryIndex(memidx)
+                    }
+                    Operands::MemoryCopy => {
+                        let dstidx = self.try_index()?.unwrap_or_else(|| Index::unnamed(0));
+                        let srcidx = self.try_index()?.unwrap_or_else(|| Index::unnamed(0));
+                        syntax::Operands::MemoryCopy(dstidx, srcidx)
+                    }
+                    Operands::MemoryInit => {
+                        let memidx = self.try_index()?;
+                        let dataidx = self.try_index()?;
+                        let (memidx, dataidx) = match (memidx, dataidx) {
+                            (None, None) => Err(self.unexpected_token("data idx"))?,
+                            (None, Some(dataidx)) => (Index::unnamed(0), dataidx),
+                            (Some(memidx), None) => (Index::unnamed(0), memidx.convert()),
+                            (Some(memidx), Some(dataidx)) => (memidx, dataidx),
+                        };
+                        syntax::Operands::MemoryInit(memidx, dataidx)
+                    }
                     Operands::FuncIndex => syntax::Operands::FuncIndex(self.expect_index()?),
                     Operands::TableIndex => {
                         let tabidx = self.try_index()?.unwrap_or_else(|| Index::unnamed(0));
@@ -52,6 +71,7 @@ impl<R: Read> Parser<R> {
                     Operands::DataIndex => syntax::Operands::DataIndex(self.expect_index()?),
                     Operands::LocalIndex => syntax::Operands::LocalIndex(self.expect_index()?),
                     Operands::MemoryIndex => syntax::Operands::MemoryIndex(self.expect_index()?),
+                    Operands::TagIndex => syntax::Operands::TagIndex(self.expect_index()?),
                     Operands::Br => syntax::Operands::LabelIndex(self.expect_index()?),
                     Operands::BrTable => {
                         let mut idxs = self.zero_or_more(Self::try_index)?;
@@ -84,10 +104,57 @@ impl<R: Read> Parser<R> {
                     Operands::F32 => syntax::Operands::F32(self.expect_f32()?),
                     Operands::F64 => syntax::Operands::F64(self.expect_f64()?),
                     Operands::Memargs => {
+                        let memidx = self.try_index()?.unwrap_or_else(|| Index::unnamed(0));
                         let offset = self.try_offset()?.unwrap_or(0);
-                        let align = self.try_align()?.unwrap_or(0);
-                        syntax::Operands::Memargs(align, offset)
+                        let align = match self.try_align()? {
+                            Some(align) => align,
+                            // Atomic accesses must be naturally aligned, so that's their default.
+                            None if matches!(data.opcode, Opcode::Atomic(_)) => {
+                                atomic_natural_alignment(data.name)
+                            }
+                            None => 0,
+                        };
+                        syntax::Operands::Memargs(memidx, align, offset)
                     }
+                    Operands::MemargsLane => {
+                        let memidx = self.try_index()?;
+                        let offset = self.try_offset()?;
+                        let align = self.try_align()?;
+                        let (memidx, lane) = match (memidx, self.try_u8()?) {
+                            (memidx, Some(lane)) => {
+                                (memidx.unwrap_or_else(|| Index::unnamed(0)), lane)
+                            }
+                            // A lone number with no memargs is the lane, not a memory.
+                            (Some(idx), None)
+                                if idx.name().as_str().is_empty()
+                                    && offset.is_none()
+                                    && align.is_none() =>
+                            {
+                                let lane = u8::try_from(idx.value())
+                                    .map_err(|_| self.unexpected_token("lane index"))?;
+                                (Index::unnamed(0), lane)
+                            }
+                            _ => Err(self.unexpected_token("lane index"))?,
+                        };
+                        syntax::Operands::MemargsLane(
+                            memidx,
+                            align.unwrap_or(0),
+                            offset.unwrap_or(0),
+                            lane,
+                        )
+                    }
+                    Operands::V128 => syntax::Operands::V128(self.expect_v128()?),
+                    Operands::Shuffle => {
+                        let mut lanes = vec![];
+                        while matches!(self.current.token, Token::Number(_)) {
+                            lanes.push(self.expect_lane_index()?);
+                        }
+                        let lanes = lanes
+                            .try_into()
+                            .map_err(|_| self.err(ParseErrorKind::InvalidLaneLength))?;
+                        syntax::Operands::Shuffle(lanes)
+                    }
+                    Operands::LaneIndex => syntax::Operands::LaneIndex(self.expect_lane_index()?),
                     Operands::TableInit => {
                         let tabidx = self.try_index()?;
                         let elemidx = self.try_index()?;
@@ -107,6 +174,7 @@ impl<R: Read> Parser<R> {
                     Operands::Block => self.parse_plain_block(Continuation::End)?,
                     Operands::Loop => self.parse_plain_block(Continuation::Start)?,
                     Operands::If => self.parse_plain_if_operands()?,
+                    Operands::TryTable => self.parse_plain_try_table()?,
                     Operands::HeapType => syntax::Operands::HeapType(self.expect_heaptype()?),
                 };
                 Ok(Some(Instruction {
@@ -145,6 +213,43 @@ impl<R: Read> Parser<R> {
         ))
     }
 
+    fn parse_plain_try_table(&mut self) -> Result<syntax::Operands<Unresolved>> {
+        pctx!(self, "parse plain try_table");
+        let label = self.try_id()?;
+        let blocktype = self.parse_block_type()?;
+        let catches = self.zero_or_more(Self::try_catch)?;
+        let instr = self.parse_instructions()?;
+        self.expect_plain_end(&label)?;
+
+        Ok(syntax::Operands::TryTable(
+            label,
+            blocktype,
+            catches,
+            UncompiledExpr { instr },
+        ))
+    }
+
+    // catch := (catch <tagidx> <labelidx>)
+    //        | (catch_ref <tagidx> <labelidx>)
+    //        | (catch_all <labelidx>)
+    //        | (catch_all_ref <labelidx>)
+    fn try_catch(&mut self) -> Result<Option<syntax::Catch<Unresolved>>> {
+        pctx!(self, "try catch");
+        let catch = if self.try_expr_start("catch")? {
+            syntax::Catch::Catch(self.expect_index()?, self.expect_index()?)
+        } else if self.try_expr_start("catch_ref")? {
+            syntax::Catch::CatchRef(self.expect_index()?, self.expect_index()?)
+        } else if self.try_expr_start("catch_all")? {
+            syntax::Catch::CatchAll(self.expect_index()?)
+        } else if self.try_expr_start("catch_all_ref")? {
+            syntax::Catch::CatchAllRef(self.expect_index()?)
+        } else {
+            return Ok(None);
+        };
+        self.expect_close()?;
+        Ok(Some(catch))
+    }
+
     fn parse_plain_if_operands(&mut self) -> Result<syntax::Operands<Unresolved>> {
         pctx!(self, "parse plain if operands");
         let label = self.try_id()?;
@@ -171,11 +276,16 @@ impl<R: Read> Parser<R> {
         ))
     }
 
-    fn try_align_offset_value(&mut self, prefix: &str) -> Result<Option<u32>> {
+    fn try_align_offset_value(&mut self, prefix: &str) -> Result<Option<u64>> {
         pctx!(self, "try align/offset value");
         if let Some(kw) = self.take_keyword_if(|kw| kw.as_str().starts_with(prefix))? {
-            if let Some(nt) = num::maybe_number(&kw.as_str()[prefix.len()..]) {
-                return match nt.as_u32() {
+            let value = &kw.as_str()[prefix.len()..];
+            // The values are unsigned, so they can't be written with a sign.
+            if value.starts_with(['+', '-']) {
+                return Err(self.unexpected_token(kw.as_str()));
+            }
+            if let Some(nt) = num::maybe_number(value) {
+                return match nt.as_u64() {
                     Ok(n) => Ok(Some(n)),
                     Err(e) => Err(self.err(e)),
                 };
@@ -184,16 +294,33 @@ impl<R: Read> Parser<R> {
         Ok(None)
     }
 
+    /// Lane indices are unsigned bytes. Numbers that are too large are
+    /// malformed, but a signed number isn't a lane index at all.
+    fn expect_lane_index(&mut self) -> Result<u8> {
+        if let Token::Number(NumToken::Integer(Sign::Negative, ..)) = self.current.token {
+            return Err(self.unexpected_token("expected lane index"));
+        }
+        self.expect_u8().map_err(|e| match e.kind {
+            ParseErrorKind::ConstantOutOfRange => self.err(ParseErrorKind::MalformedLaneIndex),
+            _ => e,
+        })
+    }
+
+    /// Alignment is written in bytes, but stored as its base 2 logarithm, as
+    /// it is in the binary format. Any power of two can be written; validation
+    /// checks it against the natural alignment of the access.
     fn try_align(&mut self) -> Result<Option<u32>> {
         pctx!(self, "try align");
         match self.try_align_offset_value("align=")? {
-            Some(align) if [1u32, 2u32, 4u32, 8u32, 16u32].contains(&align) => Ok(Some(align)),
+            Some(align) if align.is_power_of_two() => Ok(Some(align.trailing_zeros())),
             Some(align) => Err(self.err(ParseErrorKind::InvalidAlignment(align))),
             None => Ok(None),
         }
     }
 
-    fn try_offset(&mut self) -> Result<Option<u32>> {
+    /// Offsets are parsed as 64-bit values; validation checks that they fit
+    /// the index type of the memory.
+    fn try_offset(&mut self) -> Result<Option<u64>> {
         pctx!(self, "try offset");
         self.try_align_offset_value("offset=")
     }
@@ -240,6 +367,24 @@ impl<R: Read> Parser<R> {
         })
     }
 
+    fn parse_folded_try_table(&mut self) -> Result<Instruction<Unresolved>> {
+        pctx!(self, "parse folded try_table");
+        let location = self.location();
+        let label = self.try_id()?;
+        let blocktype = self.parse_block_type()?;
+        let catches = self.zero_or_more(Self::try_catch)?;
+        let instr = self.parse_instructions()?;
+        self.expect_close()?;
+        let operands =
+            syntax::Operands::TryTable(label, blocktype, catches, UncompiledExpr { instr });
+        Ok(Instruction {
+            name: Id::literal("try_table"),
+            opcode: opcodes::TRY_TABLE,
+            operands,
+            location,
+        })
+    }
+
     fn parse_folded_if(&mut self) -> Result<Vec<Instruction<Unresolved>>> {
         pctx!(self, "parse folded if");
         let location = self.location();
@@ -283,6 +428,8 @@ impl<R: Read> Parser<R> {
     // loop <label> <bt> <instr>* end
     // <folded>* if <label> <bt> <instr>* <else <instr*>>? end
     // (if <label> <bt> <folded>* (then <instr>*) (else <instr>*)?)
+    // (try_table <label> <bt> <catch>* <instr>*)
+    // try_table <label> <bt> <catch>* <instr>* end
     pub fn try_folded_instruction(&mut self) -> Result<Option<Vec<Instruction<Unresolved>>>> {
         pctx!(self, "try folded instruction");
         if self.current.token != Token::Open {
@@ -316,6 +463,10 @@ impl<R: Read> Parser<R> {
             return Ok(Some(self.parse_folded_if()?));
         }
 
+        if self.try_expr_start("try_table")? {
+            return Ok(Some(vec![self.parse_folded_try_table()?]));
+        }
+
         self.advance()?;
 
         // First one must be plain
@@ -334,3 +485,20 @@ impl<R: Read> Parser<R> {
         Ok(Some(rest))
     }
 }
+
+/// The natural alignment of an atomic access, as the base 2 logarithm of its
+/// size in bytes. Narrow accesses have their width in bits in the name, like
+/// `i64.atomic.rmw8.add_u` or `memory.atomic.wait64`; the others access their
+/// whole value type. `memory.atomic.notify` accesses an i32.
+fn atomic_natural_alignment(name: &str) -> u32 {
+    let mut parts = name.split('.');
+    let valtype = parts.next().unwrap_or_default();
+    let access = parts.nth(1).unwrap_or_default();
+    let width: String = access.chars().filter(char::is_ascii_digit).collect();
+    let bits = match width.parse::<u32>() {
+        Ok(bits) => bits,
+        Err(_) if valtype == "i64" => 64,
+        Err(_) => 32,
+    };
+    (bits / 8).trailing_zeros()
+}
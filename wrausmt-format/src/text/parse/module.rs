@@ -8,11 +8,12 @@ use {
     },
     std::io::Read,
     wrausmt_runtime::syntax::{
-        types::{GlobalType, Limits, MemType, TableType},
+        types::{GlobalType, IndexType, Limits, MemType, TableType},
         BlockType, DataField, DataInit, ElemField, ExportDesc, ExportField, FParam, FResult,
         FuncField, FunctionType, GlobalField, Id, ImportDesc, ImportField, Index, IndexSpace,
         Local, MemoryField, MemoryIndex, ModeEntry, Module, Resolved, ResolvedState, StartField,
-        TableField, TypeField, TypeUse, UncompiledExpr, Unresolved, Unvalidated, ValidatedState,
+        TableField, TagField, TypeField, TypeUse, UncompiledExpr, Unresolved, Unvalidated,
+        ValidatedState,
     },
 };
 
@@ -25,6 +26,7 @@ pub enum Field<R: ResolvedState, V: ValidatedState> {
     Import(ImportField<R, V>),
     Export(ExportField<R, V>),
     Global(GlobalField<UncompiledExpr<R>>),
+    Tag(TagField<R>),
     Start(StartField<R, V>),
     Elem(ElemField<R, UncompiledExpr<R>>),
     Data(DataField<R, UncompiledExpr<R>>),
@@ -109,6 +111,10 @@ impl<R: Read> Parser<R> {
                 }
                 Field::Memory(f, d) => {
                     let memidx = module_builder.memories();
+                    let offset = match f.memtype.idxtype {
+                        IndexType::I32 => "i32.const 0",
+                        IndexType::I64 => "i64.const 0",
+                    };
                     module_builder.add_memoryfield(f).result(self)?;
                     if let Some(d) = d {
                         module_builder
@@ -117,7 +123,7 @@ impl<R: Read> Parser<R> {
                                 data: d,
                                 init: Some(DataInit {
                                     memidx: Index::unnamed(memidx),
-                                    offset: parse_text_unresolved_instructions("i32.const 0"),
+                                    offset: parse_text_unresolved_instructions(offset),
                                 }),
                                 location,
                             })
@@ -127,6 +133,7 @@ impl<R: Read> Parser<R> {
                 Field::Import(f) => module_builder.add_importfield(f).result(self)?,
                 Field::Export(f) => module_builder.add_exportfield(f),
                 Field::Global(f) => module_builder.add_globalfield(f).result(self)?,
+                Field::Tag(f) => module_builder.add_tagfield(f).result(self)?,
                 Field::Start(f) => module_builder.add_startfield(f).result(self)?,
                 Field::Elem(f) => module_builder.add_elemfield(f).result(self)?,
                 Field::Data(f) => module_builder.add_datafield(f).result(self)?,
@@ -158,6 +165,7 @@ impl<R: Read> Parser<R> {
             Self::try_import_field,
             Self::try_export_field,
             Self::try_global_field,
+            Self::try_tag_field,
             Self::try_start_field,
             Self::try_elem_field,
             Self::try_data_field,
@@ -302,18 +310,24 @@ impl<R: Read> Parser<R> {
 
         let import = self.try_inline_import()?;
 
+        let idxtype = self.try_indextype()?;
+
         let inline_data = self.try_inline_memory_data()?;
 
         if let Some(inline_data) = inline_data {
             self.expect_close()?;
-            let mut n = (inline_data.len() / PAGE_SIZE) as u32;
+            let mut n = (inline_data.len() / PAGE_SIZE) as u64;
             if inline_data.len() % PAGE_SIZE > 0 {
                 n += 1;
             }
-            let memtype = MemType::new(Limits {
-                lower: n,
-                upper: Some(n),
-            });
+            let memtype = MemType::new(
+                idxtype.unwrap_or_default(),
+                Limits {
+                    lower: n,
+                    upper: Some(n),
+                },
+                false,
+            );
             return Ok(Some(Field::Memory(
                 MemoryField {
                     id,
@@ -325,9 +339,7 @@ impl<R: Read> Parser<R> {
             )));
         }
 
-        let limits = self.expect_limits()?;
-
-        let memtype = MemType::new(limits);
+        let memtype = self.expect_memtype_limits(idxtype.unwrap_or_default())?;
 
         if let Some(import) = import {
             self.expect_close()?;
@@ -357,6 +369,7 @@ impl<R: Read> Parser<R> {
     //             | (table <id>? <tabletype>)
     //             | (memory <id?> <memtype>)
     //             | (global <id?> <globaltype>)
+    //             | (tag <id?> <typeuse>)
     pub fn try_import_field(&mut self) -> Result<Option<Field<Unresolved, Unvalidated>>> {
         pctx!(self, "try import field");
         let location = self.location();
@@ -397,19 +410,40 @@ impl<R: Read> Parser<R> {
             Ok((id, ImportDesc::Table(tabletype)))
         } else if self.try_expr_start("memory")? {
             let id = self.try_id()?;
-            let limits = self.expect_limits()?;
+            let memtype = self.expect_memtype()?;
             self.expect_close()?;
-            Ok((id, ImportDesc::Mem(MemType::new(limits))))
+            Ok((id, ImportDesc::Mem(memtype)))
         } else if self.try_expr_start("global")? {
             let id = self.try_id()?;
             let globaltype = self.expect_globaltype()?;
             self.expect_close()?;
             Ok((id, ImportDesc::Global(globaltype)))
+        } else if self.try_expr_start("tag")? {
+            let id = self.try_id()?;
+            let typeuse = self.parse_type_use(FParamId::Allowed)?;
+            self.expect_close()?;
+            Ok((id, ImportDesc::Tag(typeuse)))
         } else {
             Err(self.unexpected_token("expected importdesc"))
         }
     }
 
+    // memtype := (i32 | i64)? <limits> shared?
+    pub fn expect_memtype(&mut self) -> Result<MemType<Unvalidated>> {
+        pctx!(self, "expect memtype");
+        let idxtype = self.try_indextype()?.unwrap_or_default();
+        self.expect_memtype_limits(idxtype)
+    }
+
+    fn expect_memtype_limits(&mut self, idxtype: IndexType) -> Result<MemType<Unvalidated>> {
+        let limits = match idxtype {
+            IndexType::I32 => self.expect_limits()?,
+            IndexType::I64 => self.expect_limits64()?,
+        };
+        let shared = self.take_keyword_if(|kw| kw == "shared")?.is_some();
+        Ok(MemType::new(idxtype, limits, shared))
+    }
+
     pub fn expect_tabletype(&mut self) -> Result<TableType<Unvalidated>> {
         pctx!(self, "expect tabletype");
         let limits = self.expect_limits()?;
@@ -466,6 +500,10 @@ impl<R: Read> Parser<R> {
             let index = self.expect_index()?;
             self.expect_close()?;
             Ok(ExportDesc::Global(index))
+        } else if self.try_expr_start("tag")? {
+            let index = self.expect_index()?;
+            self.expect_close()?;
+            Ok(ExportDesc::Tag(index))
         } else {
             Err(self.unexpected_token("expected exportdesc"))
         }
@@ -510,6 +548,43 @@ impl<R: Read> Parser<R> {
         })))
     }
 
+    // tag := (tag <id>? (export <name>)* (import <modname> <name>)? <typeuse>)
+    pub fn try_tag_field(&mut self) -> Result<Option<Field<Unresolved, Unvalidated>>> {
+        pctx!(self, "try tag field");
+        let location = self.location();
+        if !self.try_expr_start("tag")? {
+            return Ok(None);
+        }
+
+        let id = self.try_id()?;
+
+        let exports = self.zero_or_more(Self::try_inline_export)?;
+
+        let import = self.try_inline_import()?;
+
+        let typeuse = self.parse_type_use(FParamId::Allowed)?;
+
+        self.expect_close()?;
+
+        if let Some((modname, name)) = import {
+            return Ok(Some(Field::Import(ImportField {
+                id,
+                modname,
+                name,
+                desc: ImportDesc::Tag(typeuse),
+                exports,
+                location,
+            })));
+        }
+
+        Ok(Some(Field::Tag(TagField {
+            id,
+            exports,
+            typeuse,
+            location,
+        })))
+    }
+
     pub fn try_start_field(&mut self) -> Result<Option<Field<Unresolved, Unvalidated>>> {
         pctx!(self, "try start field");
         let location = self.location();
@@ -6,7 +6,10 @@ use {
     },
     std::io::Read,
     wrausmt_common::true_or::TrueOr,
-    wrausmt_runtime::syntax::types::Limits,
+    wrausmt_runtime::{
+        runtime::simd::{self, Lane},
+        syntax::types::Limits,
+    },
 };
 
 macro_rules! try_num {
@@ -35,6 +38,14 @@ macro_rules! try_num {
 impl<R: Read> Parser<R> {
     try_num! { try_u32, expect_u32, as_u32, u32, "expected U32" }
 
+    try_num! { try_u64, expect_u64, as_u64, u64, "expected U64" }
+
+    try_num! { try_u8, expect_u8, as_u8, u8, "expected U8" }
+
+    try_num! { try_i8, expect_i8, as_i8, i8, "expected I8" }
+
+    try_num! { try_i16, expect_i16, as_i16, i16, "expected I16" }
+
     try_num! { try_i32, expect_i32, as_i32, i32, "expected I32" }
 
     try_num! { try_i64, expect_i64, as_i64, i64, "expected I64" }
@@ -43,9 +54,41 @@ impl<R: Read> Parser<R> {
 
     try_num! { try_f64, expect_f64, as_f64, f64, "expected F64" }
 
+    /// Parse the shape and lanes of a v128 constant, e.g. `i32x4 1 2 3 4`.
+    pub fn expect_v128(&mut self) -> Result<u128> {
+        let shape = self.try_keyword()?;
+        match shape.as_ref().map(|s| s.as_str()) {
+            Some("i8x16") => self.expect_lanes::<i8, 16>(Self::expect_i8),
+            Some("i16x8") => self.expect_lanes::<i16, 8>(Self::expect_i16),
+            Some("i32x4") => self.expect_lanes::<i32, 4>(Self::expect_i32),
+            Some("i64x2") => self.expect_lanes::<i64, 2>(Self::expect_i64),
+            Some("f32x4") => self.expect_lanes::<f32, 4>(Self::expect_f32),
+            Some("f64x2") => self.expect_lanes::<f64, 2>(Self::expect_f64),
+            _ => Err(self.unexpected_token("vector shape")),
+        }
+    }
+
+    fn expect_lanes<T: Lane, const N: usize>(
+        &mut self,
+        expect: impl Fn(&mut Self) -> Result<T>,
+    ) -> Result<u128> {
+        let mut lanes = [T::from_lane_bits(0); N];
+        for lane in lanes.iter_mut() {
+            *lane = expect(self)?;
+        }
+        Ok(simd::from_lanes(lanes))
+    }
+
     pub fn expect_limits(&mut self) -> Result<Limits> {
-        let lower = self.expect_u32()?;
-        let upper = self.try_u32()?;
+        let lower = self.expect_u32()?.into();
+        let upper = self.try_u32()?.map(u64::from);
+        Ok(Limits { lower, upper })
+    }
+
+    /// The limits of a memory with 64-bit indices.
+    pub fn expect_limits64(&mut self) -> Result<Limits> {
+        let lower = self.expect_u64()?;
+        let upper = self.try_u64()?;
         Ok(Limits { lower, upper })
     }
 }
@@ -141,6 +184,14 @@ macro_rules! parse_int {
 impl NumToken {
     parse_int! { as_u32, u32, u32, "u32" }
 
+    parse_int! { as_u64, u64, u64, "u64" }
+
+    parse_int! { as_u8, u8, u8, "u8" }
+
+    parse_int! { as_i8, i8, u8, "i8" }
+
+    parse_int! { as_i16, i16, u16, "i16" }
+
     parse_int! { as_i32, i32, u32, "i32" }
 
     parse_int! { as_i64, i64, u64, "i64" }
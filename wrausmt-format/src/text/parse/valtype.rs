@@ -1,7 +1,7 @@
 use {
     super::{super::token::Token, error::Result, Parser},
     std::io::Read,
-    wrausmt_runtime::syntax::types::{NumType, RefType, ValueType},
+    wrausmt_runtime::syntax::types::{IndexType, NumType, RefType, ValueType},
 };
 
 impl<R: Read> Parser<R> {
@@ -15,10 +15,28 @@ impl<R: Read> Parser<R> {
             Token::Keyword(kw) => match kw.as_str() {
                 "funcref" => Some(ValueType::Ref(RefType::Func)),
                 "externref" => Some(ValueType::Ref(RefType::Extern)),
+                "exnref" => Some(ValueType::Ref(RefType::Exn)),
                 "i32" => Some(ValueType::Num(NumType::I32)),
                 "i64" => Some(ValueType::Num(NumType::I64)),
                 "f32" => Some(ValueType::Num(NumType::F32)),
                 "f64" => Some(ValueType::Num(NumType::F64)),
+                "v128" => Some(ValueType::Num(NumType::V128)),
+                _ => None,
+            },
+            _ => None,
+        };
+        if result.is_some() {
+            self.advance()?;
+        }
+        Ok(result)
+    }
+
+    /// The optional index type of a memory, which is i32 when absent.
+    pub fn try_indextype(&mut self) -> Result<Option<IndexType>> {
+        let result = match &self.current.token {
+            Token::Keyword(kw) => match kw.as_str() {
+                "i32" => Some(IndexType::I32),
+                "i64" => Some(IndexType::I64),
                 _ => None,
             },
             _ => None,
@@ -39,6 +57,7 @@ impl<R: Read> Parser<R> {
             Token::Keyword(kw) => match kw.as_str() {
                 "funcref" => Some(RefType::Func),
                 "externref" => Some(RefType::Extern),
+                "exnref" => Some(RefType::Exn),
                 _ => None,
             },
             _ => None,
@@ -54,6 +73,7 @@ impl<R: Read> Parser<R> {
             Token::Keyword(kw) => match kw.as_str() {
                 "func" => Some(RefType::Func),
                 "extern" => Some(RefType::Extern),
+                "exn" => Some(RefType::Exn),
                 _ => None,
             },
             _ => None,
@@ -0,0 +1,765 @@
+//! Serialization of a [Module] into the text format. [Spec][Spec]
+//!
+//! The printer is the inverse of [parse_wast_data][super::parse_wast_data]:
+//! the output can be parsed again to produce an equivalent module. Indices are
+//! written using the identifiers that they were written with or, failing that,
+//! the identifier of the item that they refer to, and numerically otherwise.
+//!
+//! Inline exports and type uses have already been expanded into module fields
+//! by the time a module is resolved, so the printer always writes them as
+//! separate fields. Custom sections have no text representation, and are not
+//! printed.
+//!
+//! [Spec]: https://webassembly.github.io/spec/core/text/modules.html
+
+use {
+    std::io::Write,
+    wrausmt_runtime::{
+        instructions::instruction_data,
+        runtime::simd,
+        syntax::{
+            types::{
+                GlobalType, IndexType, Limits, MemType, NumType, RefType, TableType, ValueType,
+            },
+            BlockType, Catch, DataField, DataIndex, ElemField, ElemIndex, ExportDesc, FParam,
+            FResult, FuncIndex, FunctionType, GlobalIndex, Id, ImportDesc, Index, IndexSpace,
+            Instruction, LabelIndex, LocalIndex, MemoryIndex, ModeEntry, Module, Operands,
+            Resolved, TableIndex, TagIndex, TypeIndex, TypeUse, UncompiledExpr, ValidatedState,
+        },
+    },
+};
+
+type Result<T> = std::io::Result<T>;
+
+/// The way that instructions in function bodies are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InstructionStyle {
+    /// One plain instruction per line, with blocks closed by `end`.
+    #[default]
+    Flat,
+    /// Every instruction is written in parentheses, with the contents of
+    /// blocks nested inside of them.
+    Folded,
+}
+
+/// Write the provided module to `out` using the WebAssembly text format.
+pub fn print_wast_data<V: ValidatedState>(
+    module: &Module<Resolved, V, UncompiledExpr<Resolved>>,
+    style: InstructionStyle,
+    out: &mut impl Write,
+) -> Result<()> {
+    TextPrinter {
+        writer: out,
+        style,
+        indent: 0,
+        inline: false,
+        names: Names::new(module),
+    }
+    .write_module(module)
+}
+
+struct TextPrinter<W: Write> {
+    writer: W,
+    style:  InstructionStyle,
+    indent: usize,
+    /// When true, line breaks between instructions are written as spaces, for
+    /// the short constant expressions in globals and segments.
+    inline: bool,
+    names:  Names,
+}
+
+/// The identifiers of the items in each index space of the module, for
+/// writing the indices that refer to them by name.
+#[derive(Default)]
+struct Names {
+    types:   Vec<Option<Id>>,
+    funcs:   Vec<Option<Id>>,
+    tables:  Vec<Option<Id>>,
+    mems:    Vec<Option<Id>>,
+    globals: Vec<Option<Id>>,
+    tags:    Vec<Option<Id>>,
+    elems:   Vec<Option<Id>>,
+    datas:   Vec<Option<Id>>,
+    /// The parameters and locals of the function being written.
+    locals:  Vec<Option<Id>>,
+}
+
+impl Names {
+    fn new<V: ValidatedState>(module: &Module<Resolved, V, UncompiledExpr<Resolved>>) -> Self {
+        let mut names = Names {
+            types: module.types.iter().map(|t| t.id.clone()).collect(),
+            ..Names::default()
+        };
+        for import in &module.imports {
+            let space = match import.desc {
+                ImportDesc::Func(_) => &mut names.funcs,
+                ImportDesc::Table(_) => &mut names.tables,
+                ImportDesc::Mem(_) => &mut names.mems,
+                ImportDesc::Global(_) => &mut names.globals,
+                ImportDesc::Tag(_) => &mut names.tags,
+            };
+            space.push(import.id.clone());
+        }
+        names
+            .funcs
+            .extend(module.funcs.iter().map(|f| f.id.clone()));
+        names
+            .tables
+            .extend(module.tables.iter().map(|t| t.id.clone()));
+        names
+            .mems
+            .extend(module.memories.iter().map(|m| m.id.clone()));
+        names
+            .globals
+            .extend(module.globals.iter().map(|g| g.id.clone()));
+        names.tags.extend(module.tags.iter().map(|t| t.id.clone()));
+        names.elems = module.elems.iter().map(|e| e.id.clone()).collect();
+        names.datas = module.data.iter().map(|d| d.id.clone()).collect();
+        names
+    }
+}
+
+/// An index space whose items may have identifiers in [`Names`].
+trait NamedSpace: IndexSpace {
+    fn ids(names: &Names) -> &[Option<Id>];
+}
+
+macro_rules! named_space {
+    ( $space:ty, $field:ident ) => {
+        impl NamedSpace for $space {
+            fn ids(names: &Names) -> &[Option<Id>] {
+                &names.$field
+            }
+        }
+    };
+}
+
+named_space! { TypeIndex, types }
+named_space! { FuncIndex, funcs }
+named_space! { TableIndex, tables }
+named_space! { MemoryIndex, mems }
+named_space! { GlobalIndex, globals }
+named_space! { TagIndex, tags }
+named_space! { ElemIndex, elems }
+named_space! { DataIndex, datas }
+named_space! { LocalIndex, locals }
+
+/// Labels are relative to the blocks that enclose them, so they're only
+/// written by name if they were written that way.
+impl NamedSpace for LabelIndex {
+    fn ids(_: &Names) -> &[Option<Id>] {
+        &[]
+    }
+}
+
+impl<W: Write> TextPrinter<W> {
+    fn write_module<V: ValidatedState>(
+        &mut self,
+        module: &Module<Resolved, V, UncompiledExpr<Resolved>>,
+    ) -> Result<()> {
+        write!(self.writer, "(module")?;
+        self.write_id(&module.id)?;
+        self.indent += 1;
+
+        for typefield in &module.types {
+            self.write_break()?;
+            write!(self.writer, "(type")?;
+            self.write_id(&typefield.id)?;
+            write!(self.writer, " (func")?;
+            self.write_function_type(&typefield.functiontype, true)?;
+            write!(self.writer, "))")?;
+        }
+        for import in &module.imports {
+            self.write_break()?;
+            write!(self.writer, "(import ")?;
+            self.write_name(&import.modname)?;
+            write!(self.writer, " ")?;
+            self.write_name(&import.name)?;
+            write!(self.writer, " ")?;
+            self.write_import_desc(&import.id, &import.desc)?;
+            write!(self.writer, ")")?;
+        }
+        for func in &module.funcs {
+            self.write_break()?;
+            write!(self.writer, "(func")?;
+            self.write_id(&func.id)?;
+            self.write_type_use(&func.typeuse)?;
+            // Modules that failed validation may use types that don't exist.
+            let params = match &func.typeuse {
+                TypeUse::ByIndex(idx) => module
+                    .types
+                    .get(idx.value() as usize)
+                    .map_or(&[][..], |t| &t.functiontype.params),
+                TypeUse::AnonymousInline(functiontype)
+                | TypeUse::NamedInline { functiontype, .. } => &functiontype.params,
+            };
+            self.names.locals = params
+                .iter()
+                .map(|p| p.id.clone())
+                .chain(func.locals.iter().map(|l| l.id.clone()))
+                .collect();
+            self.indent += 1;
+            for local in &func.locals {
+                self.write_break()?;
+                write!(self.writer, "(local")?;
+                self.write_id(&local.id)?;
+                write!(self.writer, " {})", value_type(&local.valtype))?;
+            }
+            self.indent -= 1;
+            self.write_instrs(&func.body)?;
+            write!(self.writer, ")")?;
+        }
+        for table in &module.tables {
+            self.write_break()?;
+            write!(self.writer, "(table")?;
+            self.write_id(&table.id)?;
+            self.write_table_type(&table.tabletype)?;
+            write!(self.writer, ")")?;
+        }
+        for memory in &module.memories {
+            self.write_break()?;
+            write!(self.writer, "(memory")?;
+            self.write_id(&memory.id)?;
+            self.write_mem_type(&memory.memtype)?;
+            write!(self.writer, ")")?;
+        }
+        for tag in &module.tags {
+            self.write_break()?;
+            write!(self.writer, "(tag")?;
+            self.write_id(&tag.id)?;
+            self.write_type_use(&tag.typeuse)?;
+            write!(self.writer, ")")?;
+        }
+        for global in &module.globals {
+            self.write_break()?;
+            write!(self.writer, "(global")?;
+            self.write_id(&global.id)?;
+            self.write_global_type(&global.globaltype)?;
+            self.write_inline_instrs(&global.init)?;
+            write!(self.writer, ")")?;
+        }
+        for export in &module.exports {
+            self.write_break()?;
+            write!(self.writer, "(export ")?;
+            self.write_name(&export.name)?;
+            self.write_export_desc(&export.exportdesc)?;
+            write!(self.writer, ")")?;
+        }
+        if let Some(start) = &module.start {
+            self.write_break()?;
+            write!(self.writer, "(start")?;
+            self.write_index(&start.idx)?;
+            write!(self.writer, ")")?;
+        }
+        for elem in &module.elems {
+            self.write_break()?;
+            self.write_elem(elem)?;
+        }
+        for data in &module.data {
+            self.write_break()?;
+            self.write_data(data)?;
+        }
+
+        self.indent -= 1;
+        writeln!(self.writer, ")")
+    }
+
+    /// Start a new line at the current indentation, or just separate the next
+    /// item with a space when printing inline.
+    fn write_break(&mut self) -> Result<()> {
+        match self.inline {
+            true => write!(self.writer, " "),
+            false => write!(self.writer, "\n{:1$}", "", self.indent * 2),
+        }
+    }
+
+    fn write_id(&mut self, id: &Option<Id>) -> Result<()> {
+        match id {
+            Some(id) => write!(self.writer, " {id}"),
+            None => Ok(()),
+        }
+    }
+
+    fn write_index<S: NamedSpace>(&mut self, idx: &Index<Resolved, S>) -> Result<()> {
+        match idx.name().as_str() {
+            "" => match S::ids(&self.names).get(idx.value() as usize) {
+                Some(Some(id)) => write!(self.writer, " {id}"),
+                _ => write!(self.writer, " {}", idx.value()),
+            },
+            name => write!(self.writer, " {name}"),
+        }
+    }
+
+    /// Names must be valid UTF-8, so any non-ASCII characters are written as
+    /// they are. Only quotes, backslashes, and control characters need to be
+    /// escaped.
+    fn write_name(&mut self, name: &str) -> Result<()> {
+        write!(self.writer, "\"")?;
+        for c in name.chars() {
+            match c {
+                c if c.is_ascii() => self.write_escaped_byte(c as u8)?,
+                c => write!(self.writer, "{c}")?,
+            }
+        }
+        write!(self.writer, "\"")
+    }
+
+    /// Data strings may contain any bytes, so everything other than printable
+    /// ASCII is escaped.
+    fn write_string(&mut self, bytes: &[u8]) -> Result<()> {
+        write!(self.writer, "\"")?;
+        for b in bytes {
+            self.write_escaped_byte(*b)?;
+        }
+        write!(self.writer, "\"")
+    }
+
+    fn write_escaped_byte(&mut self, b: u8) -> Result<()> {
+        match b {
+            b'"' => write!(self.writer, "\\\""),
+            b'\\' => write!(self.writer, "\\\\"),
+            b'\t' => write!(self.writer, "\\t"),
+            b'\n' => write!(self.writer, "\\n"),
+            b'\r' => write!(self.writer, "\\r"),
+            0x20..=0x7E => write!(self.writer, "{}", b as char),
+            b => write!(self.writer, "\\{b:02x}"),
+        }
+    }
+
+    /// Parameter identifiers are only permitted in type definitions and
+    /// function headers, so `with_ids` should be false elsewhere.
+    fn write_function_type(&mut self, functiontype: &FunctionType, with_ids: bool) -> Result<()> {
+        for FParam { id, valuetype } in &functiontype.params {
+            write!(self.writer, " (param")?;
+            if with_ids {
+                self.write_id(id)?;
+            }
+            write!(self.writer, " {})", value_type(valuetype))?;
+        }
+        self.write_results(&functiontype.results)
+    }
+
+    fn write_results(&mut self, results: &[FResult]) -> Result<()> {
+        for FResult { valuetype } in results {
+            write!(self.writer, " (result {})", value_type(valuetype))?;
+        }
+        Ok(())
+    }
+
+    /// The type index is always written. Inline parameters are also written if
+    /// the original type use had them, since their identifiers may be used as
+    /// local indices in the function body.
+    fn write_type_use(&mut self, typeuse: &TypeUse<Resolved>) -> Result<()> {
+        self.write_type_index(typeuse)?;
+        match typeuse {
+            TypeUse::NamedInline { functiontype, .. } => {
+                self.write_function_type(functiontype, true)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn write_type_index(&mut self, typeuse: &TypeUse<Resolved>) -> Result<()> {
+        write!(self.writer, " (type")?;
+        self.write_index(typeuse.index())?;
+        write!(self.writer, ")")
+    }
+
+    fn write_limits(&mut self, limits: &Limits) -> Result<()> {
+        write!(self.writer, " {}", limits.lower)?;
+        match limits.upper {
+            Some(upper) => write!(self.writer, " {upper}"),
+            None => Ok(()),
+        }
+    }
+
+    fn write_mem_type<V: ValidatedState>(&mut self, memtype: &MemType<V>) -> Result<()> {
+        if memtype.idxtype == IndexType::I64 {
+            write!(self.writer, " i64")?;
+        }
+        self.write_limits(&memtype.limits)?;
+        if memtype.shared {
+            write!(self.writer, " shared")?;
+        }
+        Ok(())
+    }
+
+    fn write_table_type<V: ValidatedState>(&mut self, tabletype: &TableType<V>) -> Result<()> {
+        self.write_limits(&tabletype.limits)?;
+        write!(self.writer, " {}", ref_type(&tabletype.reftype))
+    }
+
+    fn write_global_type(&mut self, globaltype: &GlobalType) -> Result<()> {
+        match globaltype.mutable {
+            true => write!(self.writer, " (mut {})", value_type(&globaltype.valtype)),
+            false => write!(self.writer, " {}", value_type(&globaltype.valtype)),
+        }
+    }
+
+    fn write_import_desc<V: ValidatedState>(
+        &mut self,
+        id: &Option<Id>,
+        desc: &ImportDesc<Resolved, V>,
+    ) -> Result<()> {
+        match desc {
+            ImportDesc::Func(typeuse) => {
+                write!(self.writer, "(func")?;
+                self.write_id(id)?;
+                self.write_type_use(typeuse)?;
+            }
+            ImportDesc::Table(tabletype) => {
+                write!(self.writer, "(table")?;
+                self.write_id(id)?;
+                self.write_table_type(tabletype)?;
+            }
+            ImportDesc::Mem(memtype) => {
+                write!(self.writer, "(memory")?;
+                self.write_id(id)?;
+                self.write_mem_type(memtype)?;
+            }
+            ImportDesc::Global(globaltype) => {
+                write!(self.writer, "(global")?;
+                self.write_id(id)?;
+                self.write_global_type(globaltype)?;
+            }
+            ImportDesc::Tag(typeuse) => {
+                write!(self.writer, "(tag")?;
+                self.write_id(id)?;
+                self.write_type_use(typeuse)?;
+            }
+        }
+        write!(self.writer, ")")
+    }
+
+    fn write_export_desc(&mut self, desc: &ExportDesc<Resolved>) -> Result<()> {
+        match desc {
+            ExportDesc::Func(idx) => {
+                write!(self.writer, " (func")?;
+                self.write_index(idx)?;
+            }
+            ExportDesc::Table(idx) => {
+                write!(self.writer, " (table")?;
+                self.write_index(idx)?;
+            }
+            ExportDesc::Mem(idx) => {
+                write!(self.writer, " (memory")?;
+                self.write_index(idx)?;
+            }
+            ExportDesc::Global(idx) => {
+                write!(self.writer, " (global")?;
+                self.write_index(idx)?;
+            }
+            ExportDesc::Tag(idx) => {
+                write!(self.writer, " (tag")?;
+                self.write_index(idx)?;
+            }
+        }
+        write!(self.writer, ")")
+    }
+
+    fn write_elem(&mut self, elem: &ElemField<Resolved, UncompiledExpr<Resolved>>) -> Result<()> {
+        write!(self.writer, "(elem")?;
+        self.write_id(&elem.id)?;
+        match &elem.mode {
+            ModeEntry::Passive => (),
+            ModeEntry::Active(tp) => {
+                write!(self.writer, " (table")?;
+                self.write_index(&tp.tableuse.tableidx)?;
+                write!(self.writer, ") (offset")?;
+                self.write_inline_instrs(&tp.offset)?;
+                write!(self.writer, ")")?;
+            }
+            ModeEntry::Declarative => write!(self.writer, " declare")?,
+        }
+        write!(self.writer, " {}", ref_type(&elem.elemlist.reftype))?;
+        for item in &elem.elemlist.items {
+            write!(self.writer, " (item")?;
+            self.write_inline_instrs(item)?;
+            write!(self.writer, ")")?;
+        }
+        write!(self.writer, ")")
+    }
+
+    fn write_data(&mut self, data: &DataField<Resolved, UncompiledExpr<Resolved>>) -> Result<()> {
+        write!(self.writer, "(data")?;
+        self.write_id(&data.id)?;
+        if let Some(init) = &data.init {
+            write!(self.writer, " (memory")?;
+            self.write_index(&init.memidx)?;
+            write!(self.writer, ") (offset")?;
+            self.write_inline_instrs(&init.offset)?;
+            write!(self.writer, ")")?;
+        }
+        write!(self.writer, " ")?;
+        self.write_string(&data.data)?;
+        write!(self.writer, ")")
+    }
+
+    /// Write each instruction on its own line, indented one level deeper than
+    /// the enclosing item.
+    fn write_instrs(&mut self, expr: &UncompiledExpr<Resolved>) -> Result<()> {
+        self.indent += 1;
+        for instr in &expr.instr {
+            self.write_break()?;
+            self.write_instr(instr)?;
+        }
+        self.indent -= 1;
+        Ok(())
+    }
+
+    /// Write the instructions of a constant expression on the current line.
+    fn write_inline_instrs(&mut self, expr: &UncompiledExpr<Resolved>) -> Result<()> {
+        self.inline = true;
+        let result = self.write_instrs(expr);
+        self.inline = false;
+        result
+    }
+
+    fn write_instr(&mut self, instr: &Instruction<Resolved>) -> Result<()> {
+        let name = instruction_data(&instr.opcode).name;
+        let folded = self.style == InstructionStyle::Folded;
+        match &instr.operands {
+            Operands::Block(label, blocktype, expr, _) => {
+                match folded {
+                    true => write!(self.writer, "({name}")?,
+                    false => write!(self.writer, "{name}")?,
+                }
+                self.write_id(label)?;
+                self.write_blocktype(blocktype)?;
+                self.write_instrs(expr)?;
+                match folded {
+                    true => write!(self.writer, ")"),
+                    false => {
+                        self.write_break()?;
+                        write!(self.writer, "end")
+                    }
+                }
+            }
+            Operands::TryTable(label, blocktype, catches, expr) => {
+                match folded {
+                    true => write!(self.writer, "({name}")?,
+                    false => write!(self.writer, "{name}")?,
+                }
+                self.write_id(label)?;
+                self.write_blocktype(blocktype)?;
+                catches.iter().try_for_each(|c| self.write_catch(c))?;
+                self.write_instrs(expr)?;
+                match folded {
+                    true => write!(self.writer, ")"),
+                    false => {
+                        self.write_break()?;
+                        write!(self.writer, "end")
+                    }
+                }
+            }
+            Operands::If(label, blocktype, th, el) if folded => {
+                write!(self.writer, "(if")?;
+                self.write_id(label)?;
+                self.write_blocktype(blocktype)?;
+                self.indent += 1;
+                self.write_break()?;
+                write!(self.writer, "(then")?;
+                self.write_instrs(th)?;
+                write!(self.writer, ")")?;
+                if !el.instr.is_empty() {
+                    self.write_break()?;
+                    write!(self.writer, "(else")?;
+                    self.write_instrs(el)?;
+                    write!(self.writer, ")")?;
+                }
+                self.indent -= 1;
+                write!(self.writer, ")")
+            }
+            Operands::If(label, blocktype, th, el) => {
+                write!(self.writer, "if")?;
+                self.write_id(label)?;
+                self.write_blocktype(blocktype)?;
+                self.write_instrs(th)?;
+                if !el.instr.is_empty() {
+                    self.write_break()?;
+                    write!(self.writer, "else")?;
+                    self.write_instrs(el)?;
+                }
+                self.write_break()?;
+                write!(self.writer, "end")
+            }
+            operands if folded => {
+                write!(self.writer, "({name}")?;
+                self.write_operands(operands)?;
+                write!(self.writer, ")")
+            }
+            operands => {
+                write!(self.writer, "{name}")?;
+                self.write_operands(operands)
+            }
+        }
+    }
+
+    fn write_catch(&mut self, catch: &Catch<Resolved>) -> Result<()> {
+        match catch {
+            Catch::Catch(tagidx, labelidx) => {
+                write!(self.writer, " (catch")?;
+                self.write_index(tagidx)?;
+                self.write_index(labelidx)?;
+            }
+            Catch::CatchRef(tagidx, labelidx) => {
+                write!(self.writer, " (catch_ref")?;
+                self.write_index(tagidx)?;
+                self.write_index(labelidx)?;
+            }
+            Catch::CatchAll(labelidx) => {
+                write!(self.writer, " (catch_all")?;
+                self.write_index(labelidx)?;
+            }
+            Catch::CatchAllRef(labelidx) => {
+                write!(self.writer, " (catch_all_ref")?;
+                self.write_index(labelidx)?;
+            }
+        }
+        write!(self.writer, ")")
+    }
+
+    /// Block types are always written as a type index when they aren't a
+    /// single result, since parameter identifiers aren't allowed in them.
+    fn write_blocktype(&mut self, blocktype: &BlockType<Resolved>) -> Result<()> {
+        match blocktype {
+            BlockType::Void => Ok(()),
+            BlockType::SingleResult(valuetype) => {
+                write!(self.writer, " (result {})", value_type(valuetype))
+            }
+            BlockType::TypeUse(typeuse) => self.write_type_index(typeuse),
+        }
+    }
+
+    fn write_operands(&mut self, operands: &Operands<Resolved>) -> Result<()> {
+        match operands {
+            Operands::None => Ok(()),
+            Operands::Block(..) | Operands::If(..) | Operands::TryTable(..) => {
+                unreachable!("blocks are written by write_instr")
+            }
+            Operands::CallIndirect(idx, typeuse) => {
+                self.write_index(idx)?;
+                self.write_type_index(typeuse)
+            }
+            Operands::BrTable(idxs, last) => {
+                idxs.iter().try_for_each(|idx| self.write_index(idx))?;
+                self.write_index(last)
+            }
+            // An empty result list still has to be written, so that the typed
+            // form of select is used.
+            Operands::SelectT(results) if results.is_empty() => write!(self.writer, " (result)"),
+            Operands::SelectT(results) => self.write_results(results),
+            Operands::FuncIndex(idx) => self.write_index(idx),
+            Operands::TableIndex(idx) => self.write_index(idx),
+            Operands::GlobalIndex(idx) => self.write_index(idx),
+            Operands::ElemIndex(idx) => self.write_index(idx),
+            Operands::DataIndex(idx) => self.write_index(idx),
+            Operands::LocalIndex(idx) => self.write_index(idx),
+            Operands::LabelIndex(idx) => self.write_index(idx),
+            Operands::MemoryIndex(idx) => self.write_index(idx),
+            Operands::TagIndex(idx) => self.write_index(idx),
+            Operands::Memargs(memidx, align, offset) => self.write_memargs(memidx, *align, *offset),
+            Operands::MemargsLane(memidx, align, offset, lane) => {
+                self.write_memargs(memidx, *align, *offset)?;
+                write!(self.writer, " {lane}")
+            }
+            Operands::HeapType(reftype) => write!(self.writer, " {}", match reftype {
+                RefType::Func => "func",
+                RefType::Extern => "extern",
+                RefType::Exn => "exn",
+            }),
+            Operands::TableInit(tabidx, elemidx) => {
+                self.write_index(tabidx)?;
+                self.write_index(elemidx)
+            }
+            Operands::TableCopy(dstidx, srcidx) => {
+                self.write_index(dstidx)?;
+                self.write_index(srcidx)
+            }
+            Operands::MemoryInit(memidx, dataidx) => {
+                self.write_index(memidx)?;
+                self.write_index(dataidx)
+            }
+            Operands::MemoryCopy(dstidx, srcidx) => {
+                self.write_index(dstidx)?;
+                self.write_index(srcidx)
+            }
+            Operands::I32(n) => write!(self.writer, " {}", *n as i32),
+            Operands::I64(n) => write!(self.writer, " {}", *n as i64),
+            Operands::F32(f) => write!(self.writer, " {}", f32_text(*f)),
+            Operands::F64(f) => write!(self.writer, " {}", f64_text(*f)),
+            Operands::V128(v) => {
+                write!(self.writer, " i32x4")?;
+                simd::lanes::<u32, 4>(*v)
+                    .iter()
+                    .try_for_each(|l| write!(self.writer, " 0x{l:08x}"))
+            }
+            Operands::Shuffle(lanes) => lanes.iter().try_for_each(|l| write!(self.writer, " {l}")),
+            Operands::LaneIndex(lane) => write!(self.writer, " {lane}"),
+        }
+    }
+
+    /// The memory index and both memargs are omitted when zero, which is what
+    /// the parser assumes when they are absent. The alignment is stored as an
+    /// exponent, but written in bytes.
+    fn write_memargs(
+        &mut self,
+        memidx: &Index<Resolved, MemoryIndex>,
+        align: u32,
+        offset: u64,
+    ) -> Result<()> {
+        if memidx.value() != 0 || !memidx.name().as_str().is_empty() {
+            self.write_index(memidx)?;
+        }
+        if offset != 0 {
+            write!(self.writer, " offset={offset}")?;
+        }
+        if align != 0 {
+            write!(self.writer, " align={}", 1u64 << align)?;
+        }
+        Ok(())
+    }
+}
+
+fn value_type(valuetype: &ValueType) -> &'static str {
+    match valuetype {
+        ValueType::Num(NumType::I32) => "i32",
+        ValueType::Num(NumType::I64) => "i64",
+        ValueType::Num(NumType::F32) => "f32",
+        ValueType::Num(NumType::F64) => "f64",
+        ValueType::Num(NumType::V128) => "v128",
+        ValueType::Ref(reftype) => ref_type(reftype),
+    }
+}
+
+fn ref_type(reftype: &RefType) -> &'static str {
+    match reftype {
+        RefType::Func => "funcref",
+        RefType::Extern => "externref",
+        RefType::Exn => "exnref",
+    }
+}
+
+/// Finite floats are written in the shortest decimal form that parses back to
+/// the same value. NaNs are written with their payload, unless it's the
+/// canonical one.
+macro_rules! float_text {
+    ( $name:ident, $ty:ty, $payload_mask:literal ) => {
+        fn $name(f: $ty) -> String {
+            let sign = if f.is_sign_negative() { "-" } else { "" };
+            let payload = f.to_bits() & $payload_mask;
+            if f.is_infinite() {
+                format!("{sign}inf")
+            } else if f.is_nan() && payload == ($payload_mask >> 1) + 1 {
+                format!("{sign}nan")
+            } else if f.is_nan() {
+                format!("{sign}nan:0x{payload:x}")
+            } else {
+                format!("{f:?}")
+            }
+        }
+    };
+}
+
+float_text! { f32_text, f32, 0x007F_FFFF }
+float_text! { f64_text, f64, 0x000F_FFFF_FFFF_FFFF }
@@ -5,11 +5,11 @@ use {
     std::collections::HashSet,
     wrausmt_common::true_or::TrueOr,
     wrausmt_runtime::syntax::{
-        BlockType, DataField, DataIndex, DataInit, ElemField, ElemIndex, ElemList, ExportDesc,
-        ExportField, FParam, FuncField, FuncIndex, GlobalField, GlobalIndex, Id, ImportDesc,
-        ImportField, Index, Instruction, LabelIndex, LocalIndex, MemoryIndex, ModeEntry, Module,
-        Operands, Resolved, StartField, TableIndex, TablePosition, TableUse, TypeField, TypeIndex,
-        TypeUse, UncompiledExpr, Unresolved, Unvalidated,
+        BlockType, Catch, DataField, DataIndex, DataInit, ElemField, ElemIndex, ElemList,
+        ExportDesc, ExportField, FParam, FuncField, FuncIndex, GlobalField, GlobalIndex, Id,
+        ImportDesc, ImportField, Index, Instruction, LabelIndex, LocalIndex, MemoryIndex,
+        ModeEntry, Module, Operands, Resolved, StartField, TableIndex, TablePosition, TableUse,
+        TagField, TagIndex, TypeField, TypeIndex, TypeUse, UncompiledExpr, Unresolved, Unvalidated,
     },
 };
 
@@ -26,11 +26,13 @@ pub enum ResolveError {
     DuplicateData(Id),
     DuplicateElem(Id),
     DuplicateTable(Id),
+    DuplicateTag(Id),
     DuplicateLocal(Id),
     ImportAfterFunction,
     ImportAfterGlobal,
     ImportAfterTable,
     ImportAfterMemory,
+    ImportAfterTag,
     MultipleStartSections,
 }
 
@@ -65,6 +67,10 @@ impl<'a> ResolutionContext<'a> {
         self.modulescope.globalindices.get(name).copied()
     }
 
+    pub fn tagindex(&self, name: &Id) -> Option<u32> {
+        self.modulescope.tagindices.get(name).copied()
+    }
+
     pub fn dataindex(&self, name: &Id) -> Option<u32> {
         self.modulescope.dataindices.get(name).copied()
     }
@@ -192,6 +198,7 @@ index_resolver! {GlobalIndex, ic, globalindex}
 index_resolver! {MemoryIndex, ic, memindex}
 index_resolver! {ElemIndex, ic, elemindex}
 index_resolver! {DataIndex, ic, dataindex}
+index_resolver! {TagIndex, ic, tagindex}
 index_resolver! {LocalIndex, ic, localindex}
 index_resolver! {LabelIndex, ic, labelindex [UnresolvedLabel] }
 
@@ -239,6 +246,14 @@ impl Resolve<Operands<Resolved>> for Operands<Unresolved> {
                 let expr = expr.resolve(&mut bic)?;
                 Operands::Block(id, tu, expr, cnt)
             }
+            Operands::TryTable(id, bt, catches, expr) => {
+                // The labels of the catch clauses are outside of the block.
+                let catches = resolve_all!(catches, ic)?;
+                let mut bic = ic.with_label(id.or_empty());
+                let tu = bt.resolve(&mut bic)?;
+                let expr = expr.resolve(&mut bic)?;
+                Operands::TryTable(id, tu, catches, expr)
+            }
             Operands::FuncIndex(idx) => Operands::FuncIndex(idx.resolve(ic)?),
             Operands::TableIndex(idx) => Operands::TableIndex(idx.resolve(ic)?),
             Operands::GlobalIndex(idx) => Operands::GlobalIndex(idx.resolve(ic)?),
@@ -247,18 +262,40 @@ impl Resolve<Operands<Resolved>> for Operands<Unresolved> {
             Operands::LocalIndex(idx) => Operands::LocalIndex(idx.resolve(ic)?),
             Operands::LabelIndex(idx) => Operands::LabelIndex(idx.resolve(ic)?),
             Operands::MemoryIndex(idx) => Operands::MemoryIndex(idx.resolve(ic)?),
+            Operands::TagIndex(idx) => Operands::TagIndex(idx.resolve(ic)?),
             Operands::TableInit(tidx, eidx) => {
                 Operands::TableInit(tidx.resolve(ic)?, eidx.resolve(ic)?)
             }
             Operands::TableCopy(tidx, t2idx) => {
                 Operands::TableCopy(tidx.resolve(ic)?, t2idx.resolve(ic)?)
             }
-            Operands::Memargs(a, o) => Operands::Memargs(a, o),
+            Operands::MemoryInit(midx, didx) => {
+                Operands::MemoryInit(midx.resolve(ic)?, didx.resolve(ic)?)
+            }
+            Operands::MemoryCopy(midx, m2idx) => {
+                Operands::MemoryCopy(midx.resolve(ic)?, m2idx.resolve(ic)?)
+            }
+            Operands::Memargs(m, a, o) => Operands::Memargs(m.resolve(ic)?, a, o),
             Operands::HeapType(r) => Operands::HeapType(r),
             Operands::I32(v) => Operands::I32(v),
             Operands::I64(v) => Operands::I64(v),
             Operands::F32(v) => Operands::F32(v),
             Operands::F64(v) => Operands::F64(v),
+            Operands::V128(v) => Operands::V128(v),
+            Operands::Shuffle(l) => Operands::Shuffle(l),
+            Operands::LaneIndex(l) => Operands::LaneIndex(l),
+            Operands::MemargsLane(m, a, o, l) => Operands::MemargsLane(m.resolve(ic)?, a, o, l),
+        })
+    }
+}
+
+impl Resolve<Catch<Resolved>> for Catch<Unresolved> {
+    fn resolve(self, ic: &mut ResolutionContext) -> Result<Catch<Resolved>> {
+        Ok(match self {
+            Catch::Catch(t, l) => Catch::Catch(t.resolve(ic)?, l.resolve(ic)?),
+            Catch::CatchRef(t, l) => Catch::CatchRef(t.resolve(ic)?, l.resolve(ic)?),
+            Catch::CatchAll(l) => Catch::CatchAll(l.resolve(ic)?),
+            Catch::CatchAllRef(l) => Catch::CatchAllRef(l.resolve(ic)?),
         })
     }
 }
@@ -293,6 +330,7 @@ impl Resolve<ImportDesc<Resolved, Unvalidated>> for ImportDesc<Unresolved, Unval
             ImportDesc::Table(tt) => ImportDesc::Table(tt),
             ImportDesc::Mem(mt) => ImportDesc::Mem(mt),
             ImportDesc::Global(gt) => ImportDesc::Global(gt),
+            ImportDesc::Tag(tu) => ImportDesc::Tag(tu.resolve(ic)?),
         })
     }
 }
@@ -314,6 +352,7 @@ impl Resolve<ExportDesc<Resolved>> for ExportDesc<Unresolved> {
             ExportDesc::Table(idx) => ExportDesc::Table(idx.resolve(ic)?),
             ExportDesc::Mem(idx) => ExportDesc::Mem(idx.resolve(ic)?),
             ExportDesc::Global(idx) => ExportDesc::Global(idx.resolve(ic)?),
+            ExportDesc::Tag(idx) => ExportDesc::Tag(idx.resolve(ic)?),
         })
     }
 }
@@ -330,6 +369,17 @@ impl Resolve<GlobalField<UncompiledExpr<Resolved>>> for GlobalField<UncompiledEx
     }
 }
 
+impl Resolve<TagField<Resolved>> for TagField<Unresolved> {
+    fn resolve(self, ic: &mut ResolutionContext) -> Result<TagField<Resolved>> {
+        Ok(TagField {
+            id:       self.id,
+            exports:  self.exports,
+            typeuse:  self.typeuse.resolve(ic)?,
+            location: self.location,
+        })
+    }
+}
+
 impl Resolve<StartField<Resolved, Unvalidated>> for StartField<Unresolved, Unvalidated> {
     fn resolve(self, ic: &mut ResolutionContext) -> Result<StartField<Resolved, Unvalidated>> {
         Ok(StartField::new(self.idx.resolve(ic)?, self.location))
@@ -438,6 +488,7 @@ impl ResolveModule for Module<Unresolved, Unvalidated, UncompiledExpr<Unresolved
         let imports = resolve_all!(self.imports, &mut rc)?;
         let exports = resolve_all!(self.exports, &mut rc)?;
         let globals = resolve_all!(self.globals, &mut rc)?;
+        let tags = resolve_all!(self.tags, &mut rc)?;
         let elems = resolve_all!(self.elems, &mut rc)?;
         let start = resolve_option!(self.start, &mut rc);
         let data = resolve_all!(self.data, &mut rc)?;
@@ -449,6 +500,7 @@ impl ResolveModule for Module<Unresolved, Unvalidated, UncompiledExpr<Unresolved
             funcs,
             tables: self.tables,
             memories: self.memories,
+            tags,
             imports,
             exports,
             globals,
@@ -596,7 +648,7 @@ impl Resolve<FuncField<Resolved, UncompiledExpr<Resolved>>>
         Ok(FuncField {
             id: self.id,
             exports: self.exports,
-            typeuse: TypeUse::ByIndex(typeuse.index().clone()),
+            typeuse,
             locals: self.locals,
             body,
             location: self.location,
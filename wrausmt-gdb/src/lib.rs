@@ -0,0 +1,441 @@
+//! A stub for the GDB remote serial protocol, which lets GDB, LLDB, or
+//! anything else that speaks the protocol debug the WebAssembly code running
+//! in a [`Runtime`](wrausmt_runtime::runtime::Runtime) over a local TCP
+//! socket.
+//!
+//! ```ignore
+//! let listener = TcpListener::bind("127.0.0.1:1234")?;
+//! let stub = GdbStub::accept(&listener)?;
+//! runtime.attach_debugger(stub.clone());
+//! runtime.break_next();
+//! let result = runtime.call(&module, "_start", &[]);
+//! stub.finished(&result);
+//! ```
+//!
+//! The program looks like a single thread to the client, which is stopped
+//! whenever execution pauses. While it's stopped:
+//!
+//! * Register 0 is the 64-bit pc, as a code address: the index of the function
+//!   in its module in the upper 32 bits, and the offset in its compiled body,
+//!   as shown in backtraces, in the lower 32 bits.
+//! * Registers 1 to 32 are the first 32 locals of the paused function, starting
+//!   with its parameters. Each is 128 bits wide, holding the little-endian
+//!   encoding of the local's type, padded with zeros. References are the 32-bit
+//!   address of what they refer to, or all ones when they're null. The
+//!   registers of locals that the function doesn't have are unavailable, and
+//!   later locals can't be read.
+//! * Memory is memory 0 of the paused function's module.
+//! * Software breakpoints (`Z0`, and `Z1`) are set at code addresses, and write
+//!   watchpoints (`Z2`) on ranges of memory.
+//! * `c`, `s`, and their `vCont` forms continue and step.
+//!
+//! The register layout is described to GDB by the `target.xml` feature
+//! (`qXfer:features:read`), and to LLDB by `qRegisterInfo`. The description
+//! doesn't name an architecture, since neither debugger has one for this
+//! layout, so a client may still insist on its own.
+//!
+//! Programs can't be interrupted while they're running; set a breakpoint
+//! first.
+//!
+//! Only the subset of the protocol above is supported, and it has been
+//! exercised by the scripted client in the integration tests, not by GDB or
+//! LLDB themselves.
+//!
+//! [Docs](https://sourceware.org/gdb/current/onlinedocs/gdb.html/Remote-Protocol.html)
+
+mod packet;
+
+use {
+    packet::{PacketStream, MAX_PACKET_SIZE},
+    std::{
+        cell::RefCell,
+        io,
+        net::{TcpListener, TcpStream},
+        rc::Rc,
+    },
+    wrausmt_runtime::runtime::{
+        debug::{Breakpoint, DebugContext, Debugger, PauseReason, Resume, Watchpoint},
+        error::{Result, RuntimeErrorKind},
+        values::{Num, Ref, Value},
+    },
+};
+
+/// The signal reported when execution stops.
+const SIGTRAP: u8 = 5;
+
+/// The signal reported when the program is terminated by an error.
+const SIGILL: u8 = 4;
+
+/// The number of locals that have registers.
+const LOCAL_REGISTERS: usize = 32;
+
+/// The size of a local's register, which is enough for any value.
+const LOCAL_SIZE: usize = 16;
+
+/// A connection to a debugger. Clones share the connection, so that one can
+/// be attached to the runtime, and another kept to report how the program
+/// ended.
+#[derive(Clone)]
+pub struct GdbStub(Rc<RefCell<Session>>);
+
+struct Session {
+    /// The connection, until the client detaches or disconnects.
+    stream:      Option<PacketStream>,
+    /// Whether the client is waiting for the program to stop.
+    running:     bool,
+    /// The breakpoints and watchpoints that the client set, to remove when it
+    /// detaches.
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<Watchpoint>,
+}
+
+/// What to do after handling a packet.
+enum Action {
+    Reply(String),
+    Resume(Resume),
+    /// Stop acknowledging packets, after replying.
+    NoAck,
+    Detach,
+    Kill,
+}
+
+impl GdbStub {
+    /// Wait for a client to connect to the listener.
+    pub fn accept(listener: &TcpListener) -> io::Result<Self> {
+        let (stream, _) = listener.accept()?;
+        GdbStub::new(stream)
+    }
+
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        Ok(GdbStub(Rc::new(RefCell::new(Session {
+            stream:      Some(PacketStream::new(stream)?),
+            running:     false,
+            breakpoints: vec![],
+            watchpoints: vec![],
+        }))))
+    }
+
+    /// Tell the client how the call made by the host ended, and close the
+    /// connection. A program that exits through WASI exits with its own exit
+    /// code; other errors, like traps, terminate it with `SIGILL`.
+    pub fn finished<T>(&self, result: &Result<T>) {
+        let reply = match result.as_ref().map_err(|e| &e.kind) {
+            Ok(_) => "W00".to_owned(),
+            Err(RuntimeErrorKind::Exit(code)) => format!("W{:02x}", *code as u8),
+            Err(_) => format!("X{SIGILL:02x}"),
+        };
+        self.0.borrow_mut().finish(&reply);
+    }
+}
+
+impl Debugger for GdbStub {
+    fn pause(&mut self, context: &mut DebugContext) -> Result<Resume> {
+        self.0.borrow_mut().pause(context)
+    }
+}
+
+impl Session {
+    fn pause(&mut self, ctx: &mut DebugContext) -> Result<Resume> {
+        if self.running {
+            self.running = false;
+            self.write(&stop_reply(ctx));
+        }
+        loop {
+            let Some(packet) = self.stream.as_mut().and_then(|s| s.read_packet().ok()?) else {
+                // Without a client, the program runs on undisturbed.
+                self.detach(ctx);
+                return Ok(Resume::Continue);
+            };
+            match self.handle(ctx, &packet) {
+                Action::Reply(reply) => self.write(&reply),
+                Action::Resume(resume) => {
+                    self.running = true;
+                    return Ok(resume);
+                }
+                Action::NoAck => {
+                    self.write("OK");
+                    if let Some(stream) = self.stream.as_mut() {
+                        stream.set_no_ack();
+                    }
+                }
+                Action::Detach => {
+                    self.write("OK");
+                    self.detach(ctx);
+                    return Ok(Resume::Continue);
+                }
+                Action::Kill => {
+                    self.stream = None;
+                    Err(RuntimeErrorKind::Killed)?
+                }
+            }
+        }
+    }
+
+    fn handle(&mut self, ctx: &mut DebugContext, packet: &str) -> Action {
+        let reply = |reply: &str| Action::Reply(reply.to_owned());
+        match packet {
+            "?" => Action::Reply(stop_reply(ctx)),
+            "g" => Action::Reply(match registers(ctx) {
+                Some(registers) => registers.iter().map(register_hex).collect(),
+                None => error(),
+            }),
+            "c" => Action::Resume(Resume::Continue),
+            "s" => Action::Resume(Resume::Step),
+            "D" => Action::Detach,
+            "k" => Action::Kill,
+            "QStartNoAckMode" => Action::NoAck,
+            "vCont?" => reply("vCont;c;C;s;S"),
+            "qAttached" => reply("1"),
+            "qC" => reply("QC1"),
+            "qfThreadInfo" => reply("m1"),
+            "qsThreadInfo" => reply("l"),
+            _ if packet.starts_with("qSupported") => reply(&format!(
+                "PacketSize={MAX_PACKET_SIZE:x};QStartNoAckMode+;vContSupported+;\
+                     qXfer:features:read+"
+            )),
+            _ if packet.starts_with("qXfer:features:read:") => {
+                Action::Reply(read_features(&packet[20..]).unwrap_or_else(error))
+            }
+            _ if packet.starts_with("qRegisterInfo") => {
+                Action::Reply(register_info(&packet[13..]).unwrap_or_else(|| "E45".to_owned()))
+            }
+            // There's only one thread to select, and it's always alive.
+            _ if packet.starts_with('H') || packet.starts_with('T') => reply("OK"),
+            // Every thread is given the same action, so only the first one
+            // matters.
+            _ if packet.starts_with("vCont;") => match packet.as_bytes().get(6) {
+                Some(b'c' | b'C') => Action::Resume(Resume::Continue),
+                Some(b's' | b'S') => Action::Resume(Resume::Step),
+                _ => reply(""),
+            },
+            _ => match packet.split_at_checked(1) {
+                Some(("p", n)) => Action::Reply(read_register(ctx, n).unwrap_or_else(error)),
+                Some(("m", args)) => Action::Reply(read_memory(ctx, args).unwrap_or_else(error)),
+                Some(("Z", args)) => self.set_point(ctx, args, true),
+                Some(("z", args)) => self.set_point(ctx, args, false),
+                _ => reply(""),
+            },
+        }
+    }
+
+    /// Add or remove a breakpoint or watchpoint, as described by the
+    /// arguments of a `Z` or `z` packet: `type,addr,kind`.
+    fn set_point(&mut self, ctx: &mut DebugContext, args: &str, add: bool) -> Action {
+        let mut args = args.split(',');
+        let (Some(kind), Some(addr), Some(len)) = (args.next(), args.next(), args.next()) else {
+            return Action::Reply(error());
+        };
+        let (Ok(addr), Ok(len)) = (
+            u64::from_str_radix(addr, 16),
+            usize::from_str_radix(len, 16),
+        ) else {
+            return Action::Reply(error());
+        };
+        match kind {
+            "0" | "1" => {
+                let Some(breakpoint) = code_breakpoint(ctx, addr) else {
+                    return Action::Reply(error());
+                };
+                self.breakpoints.retain(|b| *b != breakpoint);
+                ctx.remove_breakpoint(breakpoint);
+                if add {
+                    self.breakpoints.push(breakpoint);
+                    ctx.add_breakpoint(breakpoint);
+                }
+            }
+            "2" => {
+                let Some(mem) = ctx.frame().module.mems().first().copied() else {
+                    return Action::Reply(error());
+                };
+                let start = addr as usize;
+                let watchpoint = Watchpoint {
+                    mem,
+                    range: start..start.saturating_add(len),
+                };
+                self.watchpoints.retain(|w| *w != watchpoint);
+                ctx.remove_watchpoint(&watchpoint);
+                if add {
+                    // Ranges that are too long or out of bounds are refused,
+                    // and the client watches them some other way.
+                    if ctx.add_watchpoint(watchpoint.clone()).is_err() {
+                        return Action::Reply(error());
+                    }
+                    self.watchpoints.push(watchpoint);
+                }
+            }
+            // Read and access watchpoints aren't supported.
+            _ => return Action::Reply(String::new()),
+        }
+        Action::Reply("OK".to_owned())
+    }
+
+    /// Remove the client's breakpoints and watchpoints, and close the
+    /// connection.
+    fn detach(&mut self, ctx: &mut DebugContext) {
+        for breakpoint in self.breakpoints.drain(..) {
+            ctx.remove_breakpoint(breakpoint);
+        }
+        for watchpoint in self.watchpoints.drain(..) {
+            ctx.remove_watchpoint(&watchpoint);
+        }
+        self.stream = None;
+        self.running = false;
+    }
+
+    fn finish(&mut self, reply: &str) {
+        self.write(reply);
+        self.stream = None;
+    }
+
+    /// Send a packet. If the client has gone, there's no one to tell.
+    fn write(&mut self, data: &str) {
+        if let Some(stream) = self.stream.as_mut() {
+            if stream.write_packet(data).is_err() {
+                self.stream = None;
+            }
+        }
+    }
+}
+
+/// The reply describing why the program stopped.
+fn stop_reply(ctx: &DebugContext) -> String {
+    match ctx.reason() {
+        PauseReason::Watchpoint { watchpoint, .. } => {
+            format!("T{SIGTRAP:02x}watch:{:x};thread:1;", watchpoint.range.start)
+        }
+        _ => format!("T{SIGTRAP:02x}thread:1;"),
+    }
+}
+
+/// The registers, in the order of the target description: the pc, and then
+/// the first locals of the paused function, or `None` for those that it
+/// doesn't have.
+fn registers(ctx: &DebugContext) -> Option<Vec<Option<Vec<u8>>>> {
+    let frame = ctx.frame();
+    let pc = ((frame.func_index()? as u64) << 32) | frame.offset as u64;
+    let locals = ctx.locals().ok()?;
+    let locals = (0..LOCAL_REGISTERS).map(|i| {
+        locals.get(i).map(|local| {
+            let mut bytes = value_bytes(local);
+            bytes.resize(LOCAL_SIZE, 0);
+            bytes
+        })
+    });
+    Some(
+        std::iter::once(Some(pc.to_le_bytes().to_vec()))
+            .chain(locals)
+            .collect(),
+    )
+}
+
+/// A register's value for a `g` or `p` reply, or `x`s if it's unavailable.
+fn register_hex(register: &Option<Vec<u8>>) -> String {
+    match register {
+        Some(bytes) => hex(bytes),
+        None => "xx".repeat(LOCAL_SIZE),
+    }
+}
+
+/// The arguments of a `p` packet: the register number.
+fn read_register(ctx: &DebugContext, n: &str) -> Option<String> {
+    let n = usize::from_str_radix(n, 16).ok()?;
+    registers(ctx)?.get(n).map(register_hex)
+}
+
+/// The target description, naming the registers and their sizes.
+fn target_xml() -> String {
+    let locals: String = (0..LOCAL_REGISTERS)
+        .map(|i| {
+            format!(
+                r#"<reg name="l{i}" bitsize="{}" type="uint128"/>"#,
+                LOCAL_SIZE * 8
+            )
+        })
+        .collect();
+    format!(
+        concat!(
+            r#"<?xml version="1.0"?><!DOCTYPE target SYSTEM "gdb-target.dtd">"#,
+            r#"<target version="1.0"><feature name="org.wrausmt.wasm">"#,
+            r#"<reg name="pc" bitsize="64" type="code_ptr" regnum="0"/>{}"#,
+            "</feature></target>",
+        ),
+        locals
+    )
+}
+
+/// The arguments of a `qXfer:features:read` packet: `annex:offset,length`.
+/// The reply is the requested part of the annex, marked `m` if there's more
+/// to read, or `l` if it's the last part.
+fn read_features(args: &str) -> Option<String> {
+    let ("target.xml", range) = args.split_once(':')? else {
+        return None;
+    };
+    let (offset, len) = range.split_once(',')?;
+    let offset = usize::from_str_radix(offset, 16).ok()?;
+    let len = usize::from_str_radix(len, 16).ok()?;
+    let xml = target_xml();
+    let start = offset.min(xml.len());
+    let end = offset.saturating_add(len).min(xml.len());
+    let marker = if end < xml.len() { 'm' } else { 'l' };
+    Some(format!("{marker}{}", &xml[start..end]))
+}
+
+/// The arguments of a `qRegisterInfo` packet: the register number. The reply
+/// describes the register for LLDB.
+fn register_info(n: &str) -> Option<String> {
+    let n = usize::from_str_radix(n, 16).ok()?;
+    match n {
+        0 => Some(
+            "name:pc;bitsize:64;offset:0;encoding:uint;format:hex;set:General Purpose \
+             Registers;generic:pc;"
+                .to_owned(),
+        ),
+        _ if n <= LOCAL_REGISTERS => Some(format!(
+            "name:l{};bitsize:{};offset:{};encoding:vector;format:vector-uint8;set:Locals;",
+            n - 1,
+            LOCAL_SIZE * 8,
+            8 + (n - 1) * LOCAL_SIZE
+        )),
+        _ => None,
+    }
+}
+
+/// The arguments of an `m` packet: `addr,length`.
+fn read_memory(ctx: &DebugContext, args: &str) -> Option<String> {
+    let (addr, len) = args.split_once(',')?;
+    let addr = usize::from_str_radix(addr, 16).ok()?;
+    let len = usize::from_str_radix(len, 16).ok()?;
+    let mem = ctx.memory(0).ok()?;
+    mem.data.get(addr..addr.checked_add(len)?).map(hex)
+}
+
+/// The breakpoint for a code address.
+fn code_breakpoint(ctx: &DebugContext, addr: u64) -> Option<Breakpoint> {
+    let func = *ctx.frame().module.funcs().get((addr >> 32) as usize)?;
+    Some(Breakpoint {
+        func,
+        offset: (addr & 0xffff_ffff) as usize,
+    })
+}
+
+fn value_bytes(val: &Value) -> Vec<u8> {
+    match val {
+        Value::Num(Num::I32(v)) => v.to_le_bytes().to_vec(),
+        Value::Num(Num::I64(v)) => v.to_le_bytes().to_vec(),
+        Value::Num(Num::F32(v)) => v.to_le_bytes().to_vec(),
+        Value::Num(Num::F64(v)) => v.to_le_bytes().to_vec(),
+        Value::Num(Num::V128(v)) => v.to_le_bytes().to_vec(),
+        Value::Ref(Ref::Func(a)) => a.0.to_le_bytes().to_vec(),
+        Value::Ref(Ref::Exn(a)) => a.0.to_le_bytes().to_vec(),
+        Value::Ref(Ref::Extern(a)) => a.0.to_le_bytes().to_vec(),
+        Value::Ref(Ref::Null(_)) => u32::MAX.to_le_bytes().to_vec(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn error() -> String {
+    "E01".to_owned()
+}
@@ -0,0 +1,93 @@
+//! The framing of packets in the remote serial protocol. [Docs]
+//!
+//! A packet is sent as `$data#cc`, where `cc` is the checksum of `data`, in
+//! hex. Each packet received is acknowledged with `+`, or rejected with `-`
+//! if its checksum is wrong, until the client turns acknowledgements off.
+//!
+//! [Docs]: https://sourceware.org/gdb/current/onlinedocs/gdb.html/Overview.html
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::TcpStream,
+};
+
+/// The most bytes of data that a packet can have, which the stub tells the
+/// client.
+pub(crate) const MAX_PACKET_SIZE: usize = 0x1000;
+
+pub(crate) struct PacketStream {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    no_ack: bool,
+}
+
+impl PacketStream {
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        Ok(PacketStream {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            no_ack: false,
+        })
+    }
+
+    /// Stop acknowledging packets, once the client has asked for it.
+    pub fn set_no_ack(&mut self) {
+        self.no_ack = true;
+    }
+
+    /// Read the next packet. Acknowledgements from the client, and interrupt
+    /// requests, which can't be handled while the program is paused, are
+    /// skipped. Returns `None` once the client has disconnected, and fails if
+    /// a packet is longer than [`MAX_PACKET_SIZE`].
+    pub fn read_packet(&mut self) -> io::Result<Option<String>> {
+        loop {
+            let mut byte = [0u8];
+            if self.reader.read(&mut byte)? == 0 {
+                return Ok(None);
+            }
+            if byte[0] != b'$' {
+                continue;
+            }
+
+            // Read at most one byte past the limit, to tell whether the packet
+            // ends within it.
+            let mut data = vec![];
+            let limit = MAX_PACKET_SIZE as u64 + 1;
+            let read = (&mut self.reader).take(limit).read_until(b'#', &mut data)?;
+            if data.last() != Some(&b'#') {
+                if read as u64 == limit {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "packet longer than the packet size",
+                    ));
+                }
+                return Ok(None);
+            }
+            data.pop();
+            let mut checksum = [0u8; 2];
+            self.reader.read_exact(&mut checksum)?;
+
+            let valid = std::str::from_utf8(&checksum)
+                .ok()
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                == Some(checksum_of(&data));
+            if !self.no_ack {
+                self.writer.write_all(if valid { b"+" } else { b"-" })?;
+            }
+            if valid {
+                return Ok(Some(String::from_utf8_lossy(&data).into_owned()));
+            }
+        }
+    }
+
+    pub fn write_packet(&mut self, data: &str) -> io::Result<()> {
+        let checksum = checksum_of(data.as_bytes());
+        write!(self.writer, "${data}#{checksum:02x}")?;
+        self.writer.flush()
+    }
+}
+
+/// The sum of the bytes, modulo 256.
+fn checksum_of(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
@@ -1,5 +1,7 @@
 fn main() {
+    println!("cargo:rerun-if-changed=codegen/master_atomic_ops_list.csv");
     println!("cargo:rerun-if-changed=codegen/master_extended_ops_list.csv");
     println!("cargo:rerun-if-changed=codegen/master_ops_list.csv");
+    println!("cargo:rerun-if-changed=codegen/master_simd_ops_list.csv");
     codegen::generate_exec_code().unwrap();
 }
@@ -17,8 +17,11 @@ mod exec_table {
 pub use code::opcodes;
 use {
     self::{
-        data_table::{EXTENDED_INSTRUCTION_DATA, INSTRUCTION_DATA, SIMD_INSTRUCTION_DATA},
-        exec_table::{EXEC_TABLE, EXTENDED_EXEC_TABLE, SIMD_EXEC_TABLE},
+        data_table::{
+            ATOMIC_INSTRUCTION_DATA, EXTENDED_INSTRUCTION_DATA, INSTRUCTION_DATA,
+            SIMD_INSTRUCTION_DATA,
+        },
+        exec_table::{ATOMIC_EXEC_TABLE, EXEC_TABLE, EXTENDED_EXEC_TABLE, SIMD_EXEC_TABLE},
     },
     crate::{
         impl_bug,
@@ -38,8 +41,8 @@ pub type Expr = [u8];
 ///
 /// The `opcode` field contains the byte used to represent the instruction in
 /// the WebAssembly format. The core instructions are one byte, but some are two
-/// bytes, with a prefix of 0xFC or 0xFD. The Opcode enum will contain an enum
-/// variant to select the space, and each variant holds the byte for opcode
+/// bytes, with a prefix of 0xFC, 0xFD, or 0xFE. The Opcode enum will contain an
+/// enum variant to select the space, and each variant holds the byte for opcode
 /// selection in that space.
 ///
 /// Tne `name` field contains the string name of the operation, as it appears in
@@ -48,11 +51,15 @@ pub type Expr = [u8];
 /// The `operands` field is in item from the [Operands] enum, describing the
 /// number of immediate operands to expect for this instruction, also used to
 /// guide parsing.
+///
+/// The `fuel_cost` field contains the amount of fuel charged for executing the
+/// instruction, when the runtime is metering fuel.
 #[derive(PartialEq, Debug)]
 pub struct InstructionData {
-    pub opcode:   Opcode,
-    pub name:     &'static str,
-    pub operands: Operands,
+    pub opcode:    Opcode,
+    pub name:      &'static str,
+    pub operands:  Operands,
+    pub fuel_cost: u32,
 }
 
 /// An enum representing the different combinations of immediate operands that a
@@ -63,6 +70,7 @@ pub enum Operands {
     Block,
     Loop,
     If,
+    TryTable,
     Br,
     BrTable,
     CallIndirect,
@@ -74,6 +82,7 @@ pub enum Operands {
     GlobalIndex,
     TableIndex,
     MemoryIndex,
+    TagIndex,
     Memargs,
     I32,
     I64,
@@ -88,6 +97,10 @@ pub enum Operands {
     TableInit,
     ElemIndex,
     TableCopy,
+    V128,
+    Shuffle,
+    LaneIndex,
+    MemargsLane,
 }
 
 /// A method for executing a function in the given provided [ExecutionContext].
@@ -104,15 +117,17 @@ pub fn bad(_ec: &mut ExecutionContext) -> Result<()> {
 }
 
 pub const BAD_INSTRUCTION: InstructionData = InstructionData {
-    opcode:   Opcode::Normal(0),
-    name:     "bad",
-    operands: Operands::None,
+    opcode:    Opcode::Normal(0),
+    name:      "bad",
+    operands:  Operands::None,
+    fuel_cost: 0,
 };
 
 pub fn exec_method(opcode: Opcode, ec: &mut ExecutionContext) -> Result<()> {
     let exec_fn = match opcode {
         Opcode::Extended(o) => EXTENDED_EXEC_TABLE.get(o as usize),
         Opcode::Simd(o) => SIMD_EXEC_TABLE.get(o as usize),
+        Opcode::Atomic(o) => ATOMIC_EXEC_TABLE.get(o as usize),
         Opcode::Normal(o) => EXEC_TABLE.get(o as usize),
     };
     match exec_fn {
@@ -126,6 +141,7 @@ pub fn instruction_data(opcode: &Opcode) -> &'static InstructionData {
         Opcode::Normal(o) => &INSTRUCTION_DATA[o as usize],
         Opcode::Extended(o) => &EXTENDED_INSTRUCTION_DATA[o as usize],
         Opcode::Simd(o) => &SIMD_INSTRUCTION_DATA[o as usize],
+        Opcode::Atomic(o) => &ATOMIC_INSTRUCTION_DATA[o as usize],
     }
 }
 
@@ -136,10 +152,12 @@ pub fn instruction_by_name(name: &Id) -> Option<&'static InstructionData> {
         .iter()
         .chain(EXTENDED_INSTRUCTION_DATA.iter())
         .chain(SIMD_INSTRUCTION_DATA.iter())
+        .chain(ATOMIC_INSTRUCTION_DATA.iter())
         .find(|&item| item.name == name.as_str())
 }
 
 pub mod op_consts {
     pub const EXTENDED_PREFIX: u8 = 0xFC;
     pub const SIMD_PREFIX: u8 = 0xFD;
+    pub const ATOMIC_PREFIX: u8 = 0xFE;
 }
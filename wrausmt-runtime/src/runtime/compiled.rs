@@ -0,0 +1,87 @@
+//! Modules that have been validated and compiled, ready to be instantiated.
+
+use {
+    super::error::{Result, RuntimeErrorKind},
+    crate::{
+        instructions::Expr,
+        syntax::{
+            self,
+            location::SourceMap,
+            types::{FunctionType, ValueType},
+            CompiledExpr, ExportType, Id, ImportType, Resolved, Validated,
+        },
+    },
+    std::rc::Rc,
+};
+
+/// A validated and compiled module. It can be instantiated any number of
+/// times, in any number of runtimes, without being parsed or compiled again;
+/// the instances share the code of its functions.
+#[derive(Debug)]
+pub struct CompiledModule {
+    /// The module, without the bodies of its functions, which are kept in
+    /// `funcs`.
+    pub(super) module: syntax::Module<Resolved, Validated, CompiledExpr>,
+    pub(super) types:  Box<[FunctionType]>,
+    pub(super) funcs:  Box<[CompiledFunc]>,
+}
+
+/// A function of a [`CompiledModule`].
+#[derive(Debug)]
+pub(super) struct CompiledFunc {
+    pub name:      Option<Id>,
+    pub functype:  FunctionType,
+    pub locals:    Box<[ValueType]>,
+    pub body:      Rc<Expr>,
+    pub locations: Rc<SourceMap>,
+}
+
+impl CompiledModule {
+    pub fn new(mut module: syntax::Module<Resolved, Validated, CompiledExpr>) -> Result<Self> {
+        let types: Box<[FunctionType]> = module
+            .types
+            .iter()
+            .map(|t| t.functiontype.clone().into())
+            .collect();
+        let functype = |typeuse: &syntax::TypeUse<Resolved>| {
+            let idx = typeuse.index().value();
+            types
+                .get(idx as usize)
+                .cloned()
+                .ok_or(RuntimeErrorKind::TypeNotFound(idx))
+        };
+
+        // The functions stay in the module, since the types of its exports
+        // depend on them.
+        let funcs = module
+            .funcs
+            .iter_mut()
+            .map(|f| {
+                let body = std::mem::take(&mut f.body);
+                Ok(CompiledFunc {
+                    name:      f.id.clone(),
+                    functype:  functype(&f.typeuse)?,
+                    locals:    f.locals.iter().map(|l| l.valtype).collect(),
+                    body:      body.instr.into(),
+                    locations: body.locations.into(),
+                })
+            })
+            .collect::<Result<Box<_>>>()?;
+
+        Ok(CompiledModule {
+            module,
+            types,
+            funcs,
+        })
+    }
+
+    /// The imports of the module, in order.
+    pub fn imports(&self) -> impl Iterator<Item = ImportType<'_>> {
+        self.module.imports()
+    }
+
+    /// The exports of the module, in order.
+    pub fn exports(&self) -> impl Iterator<Item = ExportType<'_>> {
+        self.module.exports()
+    }
+}
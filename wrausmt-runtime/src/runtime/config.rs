@@ -0,0 +1,64 @@
+//! Limits on the resources that the runtime will allow guest code to use.
+
+/// The default limit on the depth of the call stack.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 10_000;
+
+/// The default limit on the number of entries in the value stack, including
+/// the locals of every active function.
+pub const DEFAULT_MAX_VALUE_STACK: usize = 1 << 20;
+
+/// The default limit on the number of exceptions held by the store.
+pub const DEFAULT_MAX_EXCEPTIONS: usize = 1 << 20;
+
+/// Configuration for a [`Runtime`][super::Runtime].
+///
+/// The stack and exception limits always apply, since execution that exceeds
+/// them is almost certainly runaway recursion, or a runaway loop. Memory and
+/// table sizes are only limited by their WebAssembly types unless a cap is
+/// provided here.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// Calls beyond this depth fail with
+    /// `RuntimeErrorKind::CallStackExhaustion`.
+    pub max_call_depth: usize,
+
+    /// Calls that would need more value stack entries than this for the
+    /// callee's locals fail with `RuntimeErrorKind::ValueStackExhaustion`. The
+    /// limit is checked when a function is entered, so the operands of the
+    /// most recent call may exceed it.
+    pub max_value_stack: usize,
+
+    /// The number of exceptions that the store can hold. An exception is freed
+    /// once it's caught if nothing can refer to it. One that's been caught as
+    /// an `exnref` is kept until the limit is reached, and then freed if the
+    /// program no longer refers to it. Throwing a new exception when all of
+    /// them are still referred to fails with
+    /// `RuntimeErrorKind::ExceptionLimitExceeded`.
+    pub max_exceptions: usize,
+
+    /// The total size in bytes of all memories in the store. Instantiation or
+    /// growth beyond this fails with `RuntimeErrorKind::MemoryLimitExceeded`.
+    pub max_memory_bytes: Option<usize>,
+
+    /// The total number of elements of all tables in the store. Instantiation
+    /// or growth beyond this fails with `RuntimeErrorKind::TableLimitExceeded`.
+    pub max_table_elements: Option<usize>,
+
+    /// The fuel available for executing guest code. When provided, fuel is
+    /// metered from the start. See
+    /// [`Runtime::set_fuel`][super::Runtime::set_fuel].
+    pub fuel: Option<u64>,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        RuntimeConfig {
+            max_call_depth:     DEFAULT_MAX_CALL_DEPTH,
+            max_value_stack:    DEFAULT_MAX_VALUE_STACK,
+            max_exceptions:     DEFAULT_MAX_EXCEPTIONS,
+            max_memory_bytes:   None,
+            max_table_elements: None,
+            fuel:               None,
+        }
+    }
+}
@@ -0,0 +1,379 @@
+//! Support for debugging guest code: execution pauses at breakpoints, at
+//! watchpoints, and after steps, and a [`Debugger`] provided by the host
+//! inspects the paused function and decides how to continue.
+
+use {
+    super::{
+        error::{BacktraceFrame, Result, RuntimeErrorKind},
+        instance::{
+            addr::{self, Address},
+            FunctionCode, MemInstance,
+        },
+        stack::Label,
+        values::Value,
+        Runtime,
+    },
+    crate::{
+        impl_bug,
+        instructions::{instruction_data, InstructionData},
+        syntax::Opcode,
+    },
+    std::{fmt, ops::Range},
+    wrausmt_common::true_or::TrueOr,
+};
+
+/// A position in the compiled body of a function to pause at, before the
+/// instruction there executes. Like the offsets of a [`BacktraceFrame`], the
+/// offset must be the start of an instruction; 0 is the start of the first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoint {
+    pub func:   Address<addr::Function>,
+    pub offset: usize,
+}
+
+/// The most bytes that a watchpoint can watch. The watched bytes are compared
+/// before and after every instruction, so larger ranges would make every
+/// instruction slow.
+pub const MAX_WATCHPOINT_LEN: usize = 64;
+
+/// A range of bytes in a memory to watch. Execution pauses after an
+/// instruction changes any of them. The range can't be longer than
+/// [`MAX_WATCHPOINT_LEN`], and must be within the memory when the watchpoint
+/// is added; memories never shrink, so it stays within it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Watchpoint {
+    pub mem:   Address<addr::Memory>,
+    pub range: Range<usize>,
+}
+
+/// Why execution paused.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PauseReason {
+    /// The instruction at a breakpoint is about to execute.
+    Breakpoint(Breakpoint),
+    /// A step has finished, or a pause was requested with
+    /// [`Runtime::break_next`]. The instruction is about to execute.
+    Step,
+    /// The instruction changed the watched bytes. Unlike for the other
+    /// reasons, it has already executed. The bytes are the ones in the range
+    /// before and after it.
+    Watchpoint {
+        watchpoint: Watchpoint,
+        old:        Box<[u8]>,
+        new:        Box<[u8]>,
+    },
+}
+
+/// How execution continues after a pause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resume {
+    /// Run until a breakpoint or watchpoint is reached.
+    Continue,
+    /// Pause before the next instruction, which may be in a called function.
+    Step,
+    /// Pause before the next instruction of the paused function, letting any
+    /// function that it calls run to completion. If it returns, pause in the
+    /// caller instead.
+    StepOver,
+    /// Pause once the paused function has returned, before the next
+    /// instruction of its caller.
+    StepOut,
+}
+
+/// Implemented by the host to take control whenever execution pauses.
+pub trait Debugger {
+    /// Called when execution pauses. The paused function can be inspected,
+    /// and breakpoints and watchpoints changed, through the context. The
+    /// result says how to continue; an error stops execution, and is
+    /// returned from the call that the host made.
+    fn pause(&mut self, context: &mut DebugContext) -> Result<Resume>;
+}
+
+impl<F: FnMut(&mut DebugContext) -> Result<Resume>> Debugger for F {
+    fn pause(&mut self, context: &mut DebugContext) -> Result<Resume> {
+        self(context)
+    }
+}
+
+/// A step that's in progress, with the activation depth it started at.
+#[derive(Clone, Copy, Debug)]
+enum Stepping {
+    Into,
+    Over(usize),
+    Out(usize),
+}
+
+/// The debugging state of a runtime.
+#[derive(Default)]
+pub(super) struct DebugState {
+    debugger:    Option<Box<dyn Debugger>>,
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<Watchpoint>,
+    stepping:    Option<Stepping>,
+}
+
+impl fmt::Debug for DebugState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugState")
+            .field("attached", &self.debugger.is_some())
+            .field("breakpoints", &self.breakpoints)
+            .field("watchpoints", &self.watchpoints)
+            .field("stepping", &self.stepping)
+            .finish()
+    }
+}
+
+impl DebugState {
+    pub(super) fn is_attached(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    /// Give up on the step in progress, once the call from the host that it
+    /// was made in has ended.
+    pub(super) fn stop_stepping(&mut self) {
+        self.stepping = None;
+    }
+}
+
+/// The bytes of each watchpoint, taken before an instruction executes.
+pub(super) struct WatchedBytes(Vec<Box<[u8]>>);
+
+impl Runtime {
+    /// Attach a debugger, replacing any that's already attached. It's called
+    /// whenever execution pauses.
+    pub fn attach_debugger(&mut self, debugger: impl Debugger + 'static) {
+        self.debug.debugger = Some(Box::new(debugger));
+    }
+
+    /// Detach the debugger. Breakpoints and watchpoints are kept, but
+    /// execution won't pause until a debugger is attached again.
+    pub fn detach_debugger(&mut self) {
+        self.debug.debugger = None;
+        self.debug.stepping = None;
+    }
+
+    /// Pause before the next instruction that executes.
+    pub fn break_next(&mut self) {
+        self.debug.stepping = Some(Stepping::Into);
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.debug.breakpoints.contains(&breakpoint) {
+            self.debug.breakpoints.push(breakpoint);
+        }
+    }
+
+    /// Remove a breakpoint. Returns false if there was no such breakpoint.
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let len = self.debug.breakpoints.len();
+        self.debug.breakpoints.retain(|b| *b != breakpoint);
+        self.debug.breakpoints.len() != len
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.debug.breakpoints
+    }
+
+    /// Add a watchpoint. Fails if its range is too long, or isn't within the
+    /// memory.
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) -> Result<()> {
+        let mem = self.store.mem(watchpoint.mem)?;
+        (watchpoint.range.len() <= MAX_WATCHPOINT_LEN && watchpoint.range.end <= mem.data.len())
+            .true_or(RuntimeErrorKind::InvalidWatchpoint)?;
+        if !self.debug.watchpoints.contains(&watchpoint) {
+            self.debug.watchpoints.push(watchpoint);
+        }
+        Ok(())
+    }
+
+    /// Remove a watchpoint. Returns false if there was no such watchpoint.
+    pub fn remove_watchpoint(&mut self, watchpoint: &Watchpoint) -> bool {
+        let len = self.debug.watchpoints.len();
+        self.debug.watchpoints.retain(|w| w != watchpoint);
+        self.debug.watchpoints.len() != len
+    }
+
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        &self.debug.watchpoints
+    }
+
+    /// The reason to pause before the instruction at `offset` in the function
+    /// at `func` executes, if there is one. `depth` is the activation depth of
+    /// the function.
+    pub(super) fn pause_reason(
+        &self,
+        func: Address<addr::Function>,
+        offset: usize,
+        depth: usize,
+    ) -> Option<PauseReason> {
+        let breakpoint = Breakpoint { func, offset };
+        if self.debug.breakpoints.contains(&breakpoint) {
+            return Some(PauseReason::Breakpoint(breakpoint));
+        }
+        match self.debug.stepping? {
+            Stepping::Into => Some(PauseReason::Step),
+            Stepping::Over(start) if depth <= start => Some(PauseReason::Step),
+            Stepping::Out(start) if depth < start => Some(PauseReason::Step),
+            _ => None,
+        }
+    }
+
+    /// The bytes of each watchpoint, or `None` if there aren't any.
+    pub(super) fn watched_bytes(&self) -> Result<Option<WatchedBytes>> {
+        if self.debug.watchpoints.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(WatchedBytes(
+            self.debug
+                .watchpoints
+                .iter()
+                .map(|w| self.watchpoint_bytes(w))
+                .collect::<Result<_>>()?,
+        )))
+    }
+
+    /// The first watchpoint whose bytes are different from the ones in
+    /// `before`.
+    pub(super) fn changed_watchpoint(&self, before: WatchedBytes) -> Result<Option<PauseReason>> {
+        for (watchpoint, old) in self.debug.watchpoints.iter().zip(before.0) {
+            let new = self.watchpoint_bytes(watchpoint)?;
+            if new != old {
+                return Ok(Some(PauseReason::Watchpoint {
+                    watchpoint: watchpoint.clone(),
+                    old,
+                    new,
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    fn watchpoint_bytes(&self, watchpoint: &Watchpoint) -> Result<Box<[u8]>> {
+        let mem = self.store.mem(watchpoint.mem)?;
+        let bytes = mem
+            .data
+            .get(watchpoint.range.clone())
+            .ok_or_else(|| impl_bug!("watchpoint {watchpoint:?} out of bounds"))?;
+        Ok(bytes.into())
+    }
+
+    /// Hand control to the debugger, and set up the step that it resumes
+    /// with. `backtrace` describes the paused function, and its callers.
+    pub(super) fn pause_debugger(
+        &mut self,
+        reason: PauseReason,
+        opcode: Opcode,
+        backtrace: Vec<BacktraceFrame>,
+    ) -> Result<()> {
+        // The debugger is taken while it runs, so that the context can give
+        // it the rest of the runtime.
+        let Some(mut debugger) = self.debug.debugger.take() else {
+            return Ok(());
+        };
+        let depth = self.stack.activation_depth();
+        let resume = debugger.pause(&mut DebugContext {
+            runtime: self,
+            reason,
+            opcode,
+            backtrace,
+        });
+        self.debug.debugger = Some(debugger);
+        self.debug.stepping = match resume? {
+            Resume::Continue => None,
+            Resume::Step => Some(Stepping::Into),
+            Resume::StepOver => Some(Stepping::Over(depth)),
+            Resume::StepOut => Some(Stepping::Out(depth)),
+        };
+        Ok(())
+    }
+}
+
+/// The view of the runtime given to a [`Debugger`] while execution is
+/// paused.
+pub struct DebugContext<'a> {
+    runtime:   &'a mut Runtime,
+    reason:    PauseReason,
+    opcode:    Opcode,
+    backtrace: Vec<BacktraceFrame>,
+}
+
+impl<'a> DebugContext<'a> {
+    pub fn reason(&self) -> &PauseReason {
+        &self.reason
+    }
+
+    /// The paused function, and the position of its paused instruction.
+    pub fn frame(&self) -> &BacktraceFrame {
+        &self.backtrace[0]
+    }
+
+    /// The paused function and its callers, innermost first.
+    pub fn backtrace(&self) -> &[BacktraceFrame] {
+        &self.backtrace
+    }
+
+    /// The paused instruction.
+    pub fn instruction(&self) -> &'static InstructionData {
+        instruction_data(&self.opcode)
+    }
+
+    /// The values of the locals of the paused function, starting with its
+    /// parameters.
+    pub fn locals(&self) -> Result<&[Value]> {
+        let count = self.local_count()?;
+        Ok(&self.runtime.stack.frame_values()?[..count])
+    }
+
+    /// The operands on the value stack of the paused function, with the top
+    /// of the stack last.
+    pub fn operands(&self) -> Result<&[Value]> {
+        let count = self.local_count()?;
+        Ok(&self.runtime.stack.frame_values()?[count..])
+    }
+
+    /// The labels of the paused function, with the innermost last.
+    pub fn labels(&self) -> Result<&[Label]> {
+        self.runtime.stack.labels()
+    }
+
+    /// The memory at index `idx` of the paused function's module.
+    pub fn memory(&self, idx: u32) -> Result<&MemInstance> {
+        let addr = self
+            .frame()
+            .module
+            .mems()
+            .get(idx as usize)
+            .ok_or(RuntimeErrorKind::MemoryNotFound(idx))?;
+        self.runtime.store.mem(*addr)
+    }
+
+    /// The runtime, to look up anything else about the program.
+    pub fn runtime(&self) -> &Runtime {
+        self.runtime
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.runtime.add_breakpoint(breakpoint)
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.runtime.remove_breakpoint(breakpoint)
+    }
+
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) -> Result<()> {
+        self.runtime.add_watchpoint(watchpoint)
+    }
+
+    pub fn remove_watchpoint(&mut self, watchpoint: &Watchpoint) -> bool {
+        self.runtime.remove_watchpoint(watchpoint)
+    }
+
+    fn local_count(&self) -> Result<usize> {
+        let funcinst = self.runtime.store.func(self.frame().funcaddr)?;
+        let locals = match &funcinst.code {
+            FunctionCode::Module(func) => func.locals.len(),
+            FunctionCode::Host(_) => 0,
+        };
+        Ok(funcinst.functype.params.len() + locals)
+    }
+}
@@ -1,7 +1,14 @@
 use {
-    super::instance::ExternalVal,
-    crate::syntax::{ImportDesc, Resolved, Validated},
-    std::fmt,
+    super::{
+        instance::{addr, addr::Address, ExternalVal, ModuleInstance},
+        values::Value,
+    },
+    crate::syntax::{
+        location::Location,
+        types::{FunctionType, ValueType},
+        Id, ImportDesc, Resolved, Validated,
+    },
+    std::{fmt, rc::Rc},
 };
 
 /// An impl_bug is a place where we're doing a runtime check for something
@@ -18,15 +25,101 @@ macro_rules! impl_bug {
 #[derive(Debug)]
 pub struct RuntimeError {
     pub kind: RuntimeErrorKind,
-    context:  Vec<String>,
+    /// Boxed, since errors are returned through every instruction that can
+    /// fail, and most of them are handled without any details attached.
+    details:  Option<Box<ErrorDetails>>,
+}
+
+#[derive(Debug, Default)]
+struct ErrorDetails {
+    context:   Vec<String>,
+    backtrace: Vec<BacktraceFrame>,
+}
+
+/// A function that was executing when an error occurred.
+#[derive(Clone)]
+pub struct BacktraceFrame {
+    pub funcaddr: Address<addr::Function>,
+    pub module:   Rc<ModuleInstance>,
+    /// The identifier of the function, if its module named it.
+    pub name:     Option<Id>,
+    /// The offset in the compiled body of the function of the instruction
+    /// that failed or, for callers, of the call that was executing.
+    pub offset:   usize,
+    /// The location of that instruction in the module's source, if it's
+    /// known.
+    pub location: Option<Location>,
+}
+
+impl BacktraceFrame {
+    /// The index of the function in its module.
+    pub fn func_index(&self) -> Option<u32> {
+        self.module
+            .funcs()
+            .iter()
+            .position(|a| *a == self.funcaddr)
+            .map(|i| i as u32)
+    }
+}
+
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {:?} +{:#x}", self.funcaddr, self.name, self.offset)
+    }
+}
+
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name} ")?,
+            None => write!(f, "<anonymous> ")?,
+        }
+        match self.func_index() {
+            Some(idx) => write!(f, "(func[{idx}]+{:#x})", self.offset)?,
+            None => write!(f, "({:?}+{:#x})", self.funcaddr, self.offset)?,
+        }
+        match &self.location {
+            Some(location) => write!(f, " at {location}"),
+            None => Ok(()),
+        }
+    }
 }
 
 impl RuntimeError {
     pub fn with_context(mut self, msg: impl Into<String>) -> Self {
-        self.context.push(msg.into());
+        self.details_mut().context.push(msg.into());
         self
     }
 
+    /// Attach the functions that were executing when the error occurred,
+    /// innermost first, unless a backtrace was already attached.
+    pub fn with_backtrace(mut self, backtrace: Vec<BacktraceFrame>) -> Self {
+        if self.backtrace().is_empty() {
+            self.details_mut().backtrace = backtrace;
+        }
+        self
+    }
+
+    /// The functions that were executing when the error occurred, innermost
+    /// first. Empty if the error didn't occur while executing a function.
+    pub fn backtrace(&self) -> &[BacktraceFrame] {
+        match &self.details {
+            Some(details) => &details.backtrace,
+            None => &[],
+        }
+    }
+
+    fn context(&self) -> &[String] {
+        match &self.details {
+            Some(details) => &details.context,
+            None => &[],
+        }
+    }
+
+    fn details_mut(&mut self) -> &mut ErrorDetails {
+        self.details.get_or_insert_with(Default::default)
+    }
+
     pub fn as_trap_error(&self) -> Option<&TrapKind> {
         match self.kind {
             RuntimeErrorKind::Trap(ref tk) => Some(tk),
@@ -42,9 +135,66 @@ pub enum RuntimeErrorKind {
     TypeNotFound(u32),
     ImportNotFound(String, String),
     ImportMismatch(ImportDesc<Resolved, Validated>, ExternalVal),
+    /// A linker already has a definition for the module and name.
+    DuplicateDefinition(String, String),
+    /// The imports of a module that a linker has no definitions for, by
+    /// module name and name.
+    UnresolvedImports(Vec<(String, String)>),
     ImplementationBug(String),
-    ArgumentCountError { expected: usize, got: usize },
+    ArgumentCountError {
+        expected: usize,
+        got:      usize,
+    },
+    /// A value provided by the host doesn't have the type of the item it's
+    /// used with.
+    ValueTypeMismatch {
+        expected: ValueType,
+        got:      ValueType,
+    },
+    /// A typed handle was requested for a function of another type.
+    FunctionTypeMismatch {
+        expected: FunctionType,
+        got:      FunctionType,
+    },
+    /// An item was used with a runtime other than the one that it belongs
+    /// to: a typed handle, or the definitions of a linker.
+    RuntimeMismatch,
+    /// The host tried to set the value of an immutable global.
+    ImmutableGlobal(String),
+    /// Bytes read from memory by the host as a string aren't valid UTF-8.
+    InvalidUtf8(std::str::Utf8Error),
     CallStackExhaustion,
+    ValueStackExhaustion,
+    MemoryLimitExceeded,
+    /// The host couldn't allocate the bytes for a new memory.
+    OutOfMemory,
+    TableLimitExceeded,
+    ExceptionLimitExceeded,
+    OutOfFuel,
+    NothingSuspended,
+    Exit(u32),
+    CallerMemoryNotFound,
+    /// A debugger asked for a memory that the paused function's module
+    /// doesn't have.
+    MemoryNotFound(u32),
+    /// A watchpoint's range is longer than
+    /// [`MAX_WATCHPOINT_LEN`](super::debug::MAX_WATCHPOINT_LEN) bytes, or runs
+    /// past the end of its memory.
+    InvalidWatchpoint,
+    /// A debugger stopped the program.
+    Killed,
+    HostResultMismatch {
+        expected: Box<[ValueType]>,
+        got:      Vec<ValueType>,
+    },
+    /// An exception thrown by the program wasn't caught by any handler. The
+    /// tag can be compared with the tags that modules export, and the payload
+    /// holds the values that were thrown with it, in the order of the tag's
+    /// parameters.
+    UncaughtException {
+        tag:     Address<addr::Tag>,
+        payload: Vec<Value>,
+    },
     Trap(TrapKind),
 }
 
@@ -59,6 +209,29 @@ pub enum TrapKind {
     UndefinedElement,
     CallIndirectTypeMismatch,
     InvalidConversionToInteger,
+    NullExceptionReference,
+    UnalignedAtomic,
+    ExpectedSharedMemory,
+}
+
+/// The trap messages used by the spec test suite.
+impl fmt::Display for TrapKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrapKind::IntegerDivideByZero => write!(f, "integer divide by zero"),
+            TrapKind::IntegerOverflow => write!(f, "integer overflow"),
+            TrapKind::UninitializedElement => write!(f, "uninitialized element"),
+            TrapKind::OutOfBoundsMemoryAccess(..) => write!(f, "out of bounds memory access"),
+            TrapKind::OutOfBoundsTableAccess(..) => write!(f, "out of bounds table access"),
+            TrapKind::Unreachable => write!(f, "unreachable"),
+            TrapKind::UndefinedElement => write!(f, "undefined element"),
+            TrapKind::CallIndirectTypeMismatch => write!(f, "indirect call type mismatch"),
+            TrapKind::InvalidConversionToInteger => write!(f, "invalid conversion to integer"),
+            TrapKind::NullExceptionReference => write!(f, "null exception reference"),
+            TrapKind::UnalignedAtomic => write!(f, "unaligned atomic"),
+            TrapKind::ExpectedSharedMemory => write!(f, "expected shared memory"),
+        }
+    }
 }
 
 impl From<TrapKind> for RuntimeError {
@@ -71,14 +244,24 @@ impl From<RuntimeErrorKind> for RuntimeError {
     fn from(value: RuntimeErrorKind) -> Self {
         RuntimeError {
             kind:    value,
-            context: vec![],
+            details: None,
         }
     }
 }
 
 impl fmt::Display for RuntimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{:?}", self.kind)?;
+        for msg in self.context() {
+            write!(f, "\n  {msg}")?;
+        }
+        if !self.backtrace().is_empty() {
+            write!(f, "\nwasm backtrace:")?;
+            for (i, frame) in self.backtrace().iter().enumerate() {
+                write!(f, "\n  {i}: {frame}")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -1,34 +1,88 @@
 use {
     super::{
-        error::{Result, RuntimeError, TrapKind},
+        debug::{PauseReason, WatchedBytes},
+        error::{BacktraceFrame, Result, RuntimeError, RuntimeErrorKind, TrapKind},
         instance::{addr, addr::Address},
-        values::{Ref, Value},
+        simd::{self, Lane},
+        values::{Num, Ref, Value},
         Runtime,
     },
     crate::{
         impl_bug,
-        instructions::{exec_method, op_consts},
+        instructions::{exec_method, instruction_data, op_consts, Expr},
         log_tag::Tag,
-        runtime::{instance::MemInstance, stack::Label},
-        syntax::{types::RefType, Opcode},
+        runtime::{
+            instance::{ExnInstance, FunctionCode, FunctionInstance, MemInstance},
+            stack::Label,
+            store::WaitResult,
+        },
+        syntax::{
+            types::{IndexType, RefType},
+            Opcode,
+        },
+    },
+    std::{
+        convert::{TryFrom, TryInto},
+        rc::Rc,
+        time::Duration,
     },
-    std::convert::{TryFrom, TryInto},
     wrausmt_common::{logger::Logger, true_or::TrueOr},
 };
 
+/// The number of bytes or elements that a bulk memory or table instruction
+/// can operate on for each unit of fuel, beyond its fixed cost.
+const ELEMENTS_PER_FUEL: u64 = 64;
+
+/// Executes compiled code. Calls between module functions don't nest native
+/// frames: the caller's body and position are saved, and execution continues
+/// in the callee within the same dispatch loop, so call depth is bounded only
+/// by the runtime's stack limits.
 pub struct ExecutionContext<'l> {
     runtime: &'l mut Runtime,
-    body:    &'l [u8],
+    body:    Rc<Expr>,
+    pc:      usize,
+    /// The position of the instruction being executed.
+    instr:   usize,
+    /// Where to resume each caller once the function it called has finished.
+    /// The outermost body that the context was entered with has no entry.
+    callers: Vec<ReturnPoint>,
+    /// Whether instructions are charged to the runtime's fuel, if it has any.
+    metered: bool,
+}
+
+#[derive(Debug)]
+struct ReturnPoint {
+    body: Rc<Expr>,
+    pc:   usize,
+    /// The position of the call instruction, for backtraces.
+    call: usize,
+}
+
+/// The state of a call made by the host that ran out of fuel. The runtime
+/// stack is left as it was, so that execution can continue from the
+/// instruction that couldn't be paid for once more fuel is added.
+#[derive(Debug)]
+pub(crate) struct Suspended {
+    body:    Rc<Expr>,
     pc:      usize,
+    callers: Vec<ReturnPoint>,
+    /// The number of results that the host's call returns.
+    results: usize,
 }
 
 /// Passed to `push_label` to differentiate between blocks that return the param
-/// types (loops) and all other normal blocks.
+/// types (loops) and all other normal blocks. The blocks of `try_table` are
+/// followed by the catch clauses that handle exceptions thrown inside of them.
 pub enum LabelType {
     Start,
     End,
+    Handler,
 }
 
+/// The size of one compiled catch clause of a `try_table`: the kind of the
+/// clause, a tag index, and a label index.
+const CATCH_CLAUSE_SIZE: usize = 9;
+
 pub type TrapResult<T> = std::result::Result<T, TrapKind>;
 pub trait TryValue: TryFrom<Value, Error = RuntimeError> {}
 pub trait TryIntoValue: TryValue + TryFrom<Value, Error = RuntimeError> + Into<Value> {}
@@ -40,14 +94,16 @@ macro_rules! value {
         )+
     };
 }
-value!(i32, i64, u8, u32, u64, f32, f64, usize, Ref);
+value!(i32, i64, u8, u32, u64, u128, f32, f64, usize, Ref);
 
 pub trait ExecutionContextActions {
     fn log(&self, tag: Tag, msg: impl Fn() -> String);
     fn skip(&mut self, bytes: usize);
     fn next_byte(&mut self) -> u8;
+    fn op_u8(&mut self) -> Result<u8>;
     fn op_u32(&mut self) -> Result<u32>;
     fn op_u64(&mut self) -> Result<u64>;
+    fn op_u128(&mut self) -> Result<u128>;
     fn op_reftype(&mut self) -> Result<RefType>;
     fn get_local(&mut self, locidx: u32) -> Result<Value>;
     fn set_local(&mut self, locidx: u32, val: Value) -> Result<()>;
@@ -61,13 +117,19 @@ pub trait ExecutionContextActions {
     fn pop_label(&mut self) -> Result<Label>;
     fn pop<T: TryValue>(&mut self) -> Result<T>;
     fn call(&mut self, fidx: u32) -> Result<()>;
+    fn throw(&mut self, tagidx: u32) -> Result<()>;
+    fn throw_ref(&mut self, exn: Ref) -> Result<()>;
     fn call_addr(&mut self, addr: Address<addr::Function>, tyidx: u32) -> Result<()>;
+    fn return_call(&mut self, fidx: u32) -> Result<()>;
+    fn return_call_addr(&mut self, addr: Address<addr::Function>, tyidx: u32) -> Result<()>;
     fn mem(&mut self, midx: u32) -> Result<&mut MemInstance>;
     fn mem_init(&mut self) -> Result<()>;
     fn mem_size(&mut self) -> Result<()>;
     fn mem_grow(&mut self) -> Result<()>;
     fn mem_fill(&mut self) -> Result<()>;
     fn mem_copy(&mut self) -> Result<()>;
+    fn atomic_notify(&mut self, count: u32) -> Result<()>;
+    fn atomic_wait<const S: usize>(&mut self, expected: [u8; S], timeout: i64) -> Result<()>;
     fn table_init(&mut self) -> Result<()>;
     fn table_size(&mut self) -> Result<()>;
     fn table_grow(&mut self) -> Result<()>;
@@ -88,22 +150,55 @@ pub trait ExecutionContextActions {
     fn continuation(&mut self, cnt: u32) -> Result<()>;
     fn ret(&mut self) -> Result<()>;
 
-    fn get_mem<const S: usize>(&mut self) -> Result<[u8; S]> {
+    /// Pop a memory address, which is an i32 or an i64 depending on the index
+    /// type of the memory that it's used with.
+    fn pop_addr(&mut self) -> Result<usize> {
+        match self.pop_value()? {
+            Value::Num(Num::I32(a)) => Ok(a as usize),
+            Value::Num(Num::I64(a)) => Ok(a as usize),
+            v => Err(impl_bug!("not an address {v:?}"))?,
+        }
+    }
+
+    /// Read the memargs of a memory access and pop its address. Returns the
+    /// memory index, the offset, and the address.
+    fn memarg(&mut self) -> Result<(u32, usize, usize)> {
+        let m = self.op_u32()?;
         let _a = self.op_u32()?;
-        let o = self.op_u32()?;
-        let b = self.pop::<usize>()?;
+        let o = self.op_u64()?;
+        let b = self.pop_addr()?;
+        Ok((m, o as usize, b))
+    }
+
+    fn get_mem<const S: usize>(&mut self) -> Result<[u8; S]> {
+        let (m, o, b) = self.memarg()?;
         Ok(self
-            .mem(0)?
-            .read(o as usize, b, S)?
+            .mem(m)?
+            .read(o, b, S)?
             .try_into()
             .map_err(|e| impl_bug!("conversion error {:?}", e))?)
     }
 
     fn put_mem<const S: usize>(&mut self, bytes: [u8; S]) -> Result<()> {
-        let _a = self.op_u32()?;
-        let o = self.op_u32()?;
-        let b = self.pop::<usize>()?;
-        self.mem(0)?.write(o as usize, b, &bytes)
+        let (m, o, b) = self.memarg()?;
+        self.mem(m)?.write(o, b, &bytes)
+    }
+
+    fn get_mem_atomic<const S: usize>(&mut self) -> Result<[u8; S]> {
+        let (m, o, b) = self.memarg()?;
+        self.mem(m)?.atomic_read(o, b)
+    }
+
+    fn put_mem_atomic<const S: usize>(&mut self, bytes: [u8; S]) -> Result<()> {
+        let (m, o, b) = self.memarg()?;
+        self.mem(m)?.atomic_write(o, b, bytes)
+    }
+
+    /// Atomically replace the accessed bytes with the result of `op` applied
+    /// to them, returning the bytes that were replaced.
+    fn rmw_mem<const S: usize>(&mut self, op: impl FnOnce([u8; S]) -> [u8; S]) -> Result<[u8; S]> {
+        let (m, o, b) = self.memarg()?;
+        self.mem(m)?.atomic_rmw(o, b, op)
     }
 
     fn binop<T: TryIntoValue>(&mut self, op: impl Fn(T, T) -> T) -> Result<()> {
@@ -146,6 +241,33 @@ pub trait ExecutionContextActions {
         let i = self.pop::<T>()?;
         self.push(if op(i) { 1 } else { 0 })
     }
+
+    fn vunop<T: Lane, const N: usize>(&mut self, op: impl Fn(T) -> T) -> Result<()> {
+        self.unop::<u128>(|v| simd::map::<T, N>(v, &op))
+    }
+
+    fn vbinop<T: Lane, const N: usize>(&mut self, op: impl Fn(T, T) -> T) -> Result<()> {
+        self.binop::<u128>(|l, r| simd::zip::<T, N>(l, r, &op))
+    }
+
+    fn vrelop<T: Lane, const N: usize>(&mut self, op: impl Fn(T, T) -> bool) -> Result<()> {
+        self.binop::<u128>(|l, r| simd::mask::<T, N>(l, r, &op))
+    }
+
+    /// Vector shifts take the shift count modulo the lane width.
+    fn vshift<T: Lane, const N: usize>(&mut self, op: impl Fn(T, u32) -> T) -> Result<()> {
+        let s = self.pop::<u32>()? % T::BITS;
+        let v = self.pop::<u128>()?;
+        self.push(simd::map::<T, N>(v, |l| op(l, s)))
+    }
+}
+
+/// A page count or address as a value of the index type of a memory.
+fn index_value(idxtype: IndexType, v: u64) -> Value {
+    match idxtype {
+        IndexType::I32 => (v as u32).into(),
+        IndexType::I64 => v.into(),
+    }
 }
 
 impl<'l> ExecutionContextActions for ExecutionContext<'l> {
@@ -161,6 +283,12 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
         self.body[self.pc]
     }
 
+    fn op_u8(&mut self) -> Result<u8> {
+        let result = self.body[self.pc];
+        self.pc += 1;
+        Ok(result)
+    }
+
     fn op_u32(&mut self) -> Result<u32> {
         let result = u32::from_le_bytes(
             self.body[self.pc..self.pc + 4]
@@ -178,6 +306,7 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
         match byte {
             0x70 => Ok(RefType::Func),
             0x6F => Ok(RefType::Extern),
+            0x69 => Ok(RefType::Exn),
             _ => Err(impl_bug!("{byte} does not encode a ref type").into()),
         }
     }
@@ -192,6 +321,16 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
         Ok(result)
     }
 
+    fn op_u128(&mut self) -> Result<u128> {
+        let result = u128::from_le_bytes(
+            self.body[self.pc..self.pc + 16]
+                .try_into()
+                .map_err(|e| impl_bug!("conversion error {e:?}"))?,
+        );
+        self.pc += 16;
+        Ok(result)
+    }
+
     fn get_local(&mut self, locidx: u32) -> Result<Value> {
         let val = self.runtime.stack.get_local(locidx);
         self.log(Tag::Local, || format!("GET {locidx} {val:?}"));
@@ -247,12 +386,13 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
     }
 
     fn mem_init(&mut self) -> Result<()> {
+        let midx = self.op_u32()?;
         let didx = self.op_u32()?;
         let n = self.pop::<u32>()? as usize;
         let src = self.pop::<u32>()? as usize;
-        let dst = self.pop::<u32>()? as usize;
+        let dst = self.pop_addr()?;
         // TODO if s + n or d + n > sie of table 0, trap
-        let maddr = self.runtime.stack.active_module()?.mem(0);
+        let maddr = self.runtime.stack.active_module()?.mem(midx);
         let daddr = self.runtime.stack.active_module()?.data(didx);
         self.runtime
             .store
@@ -260,27 +400,30 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
     }
 
     fn mem_size(&mut self) -> Result<()> {
-        let maddr = self.runtime.stack.active_module()?.mem(0);
-        let size = self.runtime.store.mem(maddr)?.size() as u32;
-        self.runtime.stack.push_value(size.into());
+        let midx = self.op_u32()?;
+        let maddr = self.runtime.stack.active_module()?.mem(midx);
+        let mem = self.runtime.store.mem(maddr)?;
+        let size = mem.size() as u64;
+        let idxtype = mem.idxtype;
+        self.runtime.stack.push_value(index_value(idxtype, size));
         Ok(())
     }
 
     fn mem_grow(&mut self) -> Result<()> {
-        let pgs = self.pop::<u32>()?;
-        let maddr = self.runtime.stack.active_module()?.mem(0);
+        let midx = self.op_u32()?;
+        let pgs = self.pop_addr()? as u64;
+        let maddr = self.runtime.stack.active_module()?.mem(midx);
+        let idxtype = self.runtime.store.mem(maddr)?.idxtype;
         let result = self.runtime.store.grow_mem(maddr, pgs)?;
-        match result {
-            None => self.push_value((-1i32).into()),
-            Some(s) => self.push_value(s.into()),
-        }
+        self.push_value(index_value(idxtype, result.unwrap_or(u64::MAX)))
     }
 
     fn mem_fill(&mut self) -> Result<()> {
-        let n = self.pop::<usize>()?;
+        let midx = self.op_u32()?;
+        let n = self.pop_addr()?;
         let val = self.pop::<u8>()?;
-        let d = self.pop::<usize>()?;
-        let maddr = self.runtime.stack.active_module()?.mem(0);
+        let d = self.pop_addr()?;
+        let maddr = self.runtime.stack.active_module()?.mem(midx);
         // Note: the spec describes table fill as a recursive set of calls to table set
         // + table fill, we use a function here to emulate the same behavior with
         // less overhead.
@@ -288,14 +431,43 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
     }
 
     fn mem_copy(&mut self) -> Result<()> {
-        let n = self.pop::<usize>()?;
-        let s = self.pop::<usize>()?;
-        let d = self.pop::<usize>()?;
-        let maddr = self.runtime.stack.active_module()?.mem(0);
+        let dstidx = self.op_u32()?;
+        let srcidx = self.op_u32()?;
+        let n = self.pop_addr()?;
+        let s = self.pop_addr()?;
+        let d = self.pop_addr()?;
+        let dstaddr = self.runtime.stack.active_module()?.mem(dstidx);
+        let srcaddr = self.runtime.stack.active_module()?.mem(srcidx);
         // Note: the spec describes table fill as a recursive set of calls to table set
         // + table fill, we use a function here to emulate the same behavior with
         // less overhead.
-        self.runtime.store.copy_mem_to_mem(maddr, s, d, n)
+        self.runtime
+            .store
+            .copy_mem_to_mem(dstaddr, srcaddr, d, s, n)
+    }
+
+    fn atomic_notify(&mut self, count: u32) -> Result<()> {
+        let (m, o, b) = self.memarg()?;
+        let maddr = self.runtime.stack.active_module()?.mem(m);
+        let ea = self.runtime.store.mem(maddr)?.atomic_offset(o, b, 4)?.start;
+        let woken = self.runtime.store.notify(maddr, ea, count)?;
+        self.push(woken)
+    }
+
+    fn atomic_wait<const S: usize>(&mut self, expected: [u8; S], timeout: i64) -> Result<()> {
+        let (m, o, b) = self.memarg()?;
+        let maddr = self.runtime.stack.active_module()?.mem(m);
+        let mem = self.runtime.store.mem(maddr)?;
+        let range = mem.atomic_offset(o, b, S)?;
+        mem.shared.true_or(TrapKind::ExpectedSharedMemory)?;
+        let result = if mem.data[range.clone()] != expected {
+            WaitResult::NotEqual
+        } else {
+            // A negative timeout waits indefinitely.
+            let timeout = u64::try_from(timeout).ok().map(Duration::from_nanos);
+            self.runtime.store.wait(maddr, range.start, timeout)?
+        };
+        self.push(result as u32)
     }
 
     fn br(&mut self, labidx: u32) -> Result<()> {
@@ -425,13 +597,22 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
         let param_arity = self.op_u32()?;
         let result_arity = self.op_u32()?;
         let result_arity = match label_type {
-            LabelType::End => result_arity,
+            LabelType::End | LabelType::Handler => result_arity,
             LabelType::Start => param_arity,
         };
         let continuation = self.op_u32()?;
+        let handler = match label_type {
+            LabelType::Handler => {
+                let handler = self.pc as u32;
+                let clauses = self.op_u32()? as usize;
+                self.skip(clauses * CATCH_CLAUSE_SIZE);
+                Some(handler)
+            }
+            _ => None,
+        };
         self.runtime
             .stack
-            .push_label(param_arity, result_arity, continuation)?;
+            .push_label(param_arity, result_arity, continuation, handler)?;
         Ok(())
     }
 
@@ -453,22 +634,66 @@ impl<'l> ExecutionContextActions for ExecutionContext<'l> {
     }
 
     fn call(&mut self, fidx: u32) -> Result<()> {
-        self.runtime
-            .invoke_addr(self.runtime.stack.active_module()?.func(fidx))
+        let addr = self.runtime.stack.active_module()?.func(fidx);
+        let funcinst = self.runtime.store.func(addr)?;
+        self.invoke(addr, funcinst)
     }
 
     fn call_addr(&mut self, addr: Address<addr::Function>, tyidx: u32) -> Result<()> {
+        let funcinst = self.indirect_func(addr, tyidx)?;
+        self.invoke(addr, funcinst)
+    }
+
+    fn throw(&mut self, tagidx: u32) -> Result<()> {
+        let tag = self.runtime.stack.active_module()?.tag(tagidx);
+        let arity = self.runtime.store.tag(tag)?.tagtype.params.len();
+        // The fields may refer to other exceptions, so exceptions are
+        // collected while the fields are still on the stack.
+        if self.runtime.store.exns_full() {
+            self.runtime.collect_exns();
+        }
+        let mut fields = (0..arity)
+            .map(|_| self.pop_value())
+            .collect::<Result<Vec<_>>>()?;
+        fields.reverse();
+        let exn = self.runtime.store.alloc_exn(ExnInstance {
+            tag,
+            fields: fields.into_boxed_slice(),
+        })?;
+        self.log(Tag::Flow, || format!("THROW {exn:?} WITH {tag:?}"));
+        let caught_ref = self.unwind(exn);
+        // Unless a clause caught a reference to it, nothing can refer to the
+        // new exception any more.
+        if !matches!(caught_ref, Ok(true)) {
+            self.runtime.store.free_exn(exn);
+        }
+        caught_ref.map(|_| ())
+    }
+
+    fn throw_ref(&mut self, exn: Ref) -> Result<()> {
+        match exn {
+            Ref::Exn(exn) => self.unwind(exn).map(|_| ()),
+            Ref::Null(_) => Err(TrapKind::NullExceptionReference)?,
+            r => Err(impl_bug!("not an exception {r:?}"))?,
+        }
+    }
+
+    fn return_call(&mut self, fidx: u32) -> Result<()> {
+        let addr = self.runtime.stack.active_module()?.func(fidx);
         let funcinst = self.runtime.store.func(addr)?;
-        let expected_type = self.runtime.stack.active_module()?.func_type(tyidx);
-        (&funcinst.functype == expected_type)
-            .true_or_else(|| TrapKind::CallIndirectTypeMismatch)?;
-        self.runtime.invoke(funcinst)
+        self.tail_invoke(addr, funcinst)
+    }
+
+    fn return_call_addr(&mut self, addr: Address<addr::Function>, tyidx: u32) -> Result<()> {
+        let funcinst = self.indirect_func(addr, tyidx)?;
+        self.tail_invoke(addr, funcinst)
     }
 }
 
 impl<'l> ExecutionContext<'l> {
     pub fn run(&mut self) -> Result<()> {
-        while self.pc < self.body.len() {
+        while self.pc < self.body.len() || self.return_to_caller()? {
+            self.instr = self.pc;
             let op = self.body[self.pc];
             let opcode = match op {
                 op_consts::EXTENDED_PREFIX => {
@@ -479,15 +704,257 @@ impl<'l> ExecutionContext<'l> {
                     self.pc += 1;
                     Opcode::Simd(self.body[self.pc])
                 }
+                op_consts::ATOMIC_PREFIX => {
+                    self.pc += 1;
+                    Opcode::Atomic(self.body[self.pc])
+                }
                 _ => Opcode::Normal(op),
             };
+            if self.metered {
+                self.consume_fuel(opcode)
+                    .inspect_err(|_| self.pc = self.instr)?;
+            }
+            let watched = match self.runtime.debug.is_attached() {
+                true => self.debug_before(opcode)?,
+                false => None,
+            };
             self.log(Tag::Op, || format!("BEGIN 0x{opcode:x?}"));
             self.pc += 1;
             exec_method(opcode, self)?;
             self.log(Tag::Op, || format!("FINISHED 0x{opcode:x?}"));
+            if let Some(watched) = watched {
+                self.debug_after(opcode, watched)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Charge the runtime's fuel for executing the instruction. Fails without
+    /// charging anything if there isn't enough left.
+    fn consume_fuel(&mut self, opcode: Opcode) -> Result<()> {
+        if let Some(fuel) = self.runtime.fuel {
+            let cost = (instruction_data(&opcode).fuel_cost as u64)
+                .saturating_add(self.length_cost(opcode)?);
+            let remaining = fuel.checked_sub(cost).ok_or(RuntimeErrorKind::OutOfFuel)?;
+            self.runtime.fuel = Some(remaining);
+        }
+        Ok(())
+    }
+
+    /// The fuel charged for the length of a bulk memory or table instruction,
+    /// in addition to its fixed cost. The length is the last operand of each
+    /// of them, so it's charged for before any of the operands are popped,
+    /// and the instruction can be resumed if there isn't enough fuel.
+    fn length_cost(&self, opcode: Opcode) -> Result<u64> {
+        match opcode {
+            // memory.init, memory.copy, memory.fill, table.init, table.copy,
+            // and table.fill.
+            Opcode::Extended(0x08 | 0x0a | 0x0b | 0x0c | 0x0e | 0x11) => {
+                let n = match self.runtime.stack.peek_value()? {
+                    Value::Num(Num::I32(n)) => *n as u64,
+                    Value::Num(Num::I64(n)) => *n,
+                    v => Err(impl_bug!("not a length {v:?}"))?,
+                };
+                Ok(n / ELEMENTS_PER_FUEL)
+            }
+            _ => Ok(0),
+        }
+    }
+
+    /// Pause for the debugger if a breakpoint or a step ends at the
+    /// instruction that's about to execute. Returns the watched bytes, to
+    /// check once the instruction has executed.
+    fn debug_before(&mut self, opcode: Opcode) -> Result<Option<WatchedBytes>> {
+        // Constant expressions don't execute in a function.
+        let Some((Some(func), _)) = self.runtime.stack.active_funcs().next() else {
+            return Ok(None);
+        };
+        let depth = self.runtime.stack.activation_depth();
+        if let Some(reason) = self.runtime.pause_reason(func, self.instr, depth) {
+            self.pause(reason, opcode)?;
+        }
+        self.runtime.watched_bytes()
+    }
+
+    /// Pause for the debugger if the instruction that just executed changed
+    /// the bytes of a watchpoint.
+    fn debug_after(&mut self, opcode: Opcode, watched: WatchedBytes) -> Result<()> {
+        match self.runtime.changed_watchpoint(watched)? {
+            Some(reason) => self.pause(reason, opcode),
+            None => Ok(()),
         }
+    }
+
+    fn pause(&mut self, reason: PauseReason, opcode: Opcode) -> Result<()> {
+        self.log(Tag::Flow, || format!("PAUSE {reason:?}"));
+        let backtrace = self.backtrace();
+        self.runtime.pause_debugger(reason, opcode, backtrace)
+    }
+
+    /// Begin executing a function called from the current body. Host
+    /// functions run to completion immediately. For module functions, the
+    /// current position is saved, and execution continues at the start of the
+    /// callee's body.
+    fn invoke(
+        &mut self,
+        addr: Address<addr::Function>,
+        funcinst: Rc<FunctionInstance>,
+    ) -> Result<()> {
+        let func = match &funcinst.code {
+            FunctionCode::Module(func) => func,
+            FunctionCode::Host(func) => return self.runtime.exec_host(&funcinst.functype, func),
+        };
+        self.runtime.push_frame(addr, &funcinst, func)?;
+        let body = std::mem::replace(&mut self.body, func.body.clone());
+        self.callers.push(ReturnPoint {
+            body,
+            pc: self.pc,
+            call: self.instr,
+        });
+        self.pc = 0;
         Ok(())
     }
+
+    /// Begin executing a function that's tail called from the current body.
+    /// Module functions take over the current frame and run in place of the
+    /// caller, so nothing is saved to return to. Host functions can't reuse
+    /// the frame, so they're called normally, and the caller then returns.
+    fn tail_invoke(
+        &mut self,
+        addr: Address<addr::Function>,
+        funcinst: Rc<FunctionInstance>,
+    ) -> Result<()> {
+        let func = match &funcinst.code {
+            FunctionCode::Module(func) => func,
+            FunctionCode::Host(func) => {
+                self.runtime.exec_host(&funcinst.functype, func)?;
+                return self.ret();
+            }
+        };
+        self.runtime.replace_frame(addr, &funcinst, func)?;
+        self.body = func.body.clone();
+        self.pc = 0;
+        Ok(())
+    }
+
+    /// The function at `addr` in a table, checked against the type that an
+    /// indirect call expects.
+    fn indirect_func(
+        &mut self,
+        addr: Address<addr::Function>,
+        tyidx: u32,
+    ) -> Result<Rc<FunctionInstance>> {
+        let funcinst = self.runtime.store.func(addr)?;
+        let expected_type = self.runtime.stack.active_module()?.func_type(tyidx);
+        (&funcinst.functype == expected_type)
+            .true_or_else(|| TrapKind::CallIndirectTypeMismatch)?;
+        Ok(funcinst)
+    }
+
+    /// Unwind the label and activation stacks until a `try_table` with a
+    /// catch clause for the exception is found, and branch to the clause's
+    /// label. Returns whether the clause pushed a reference to the exception.
+    /// If the outermost function of this context is unwound without finding
+    /// one, the exception is returned to the host.
+    fn unwind(&mut self, exn: Address<addr::Exception>) -> Result<bool> {
+        loop {
+            while let Some(handler) = self.runtime.stack.pop_handler()? {
+                if let Some(caught_ref) = self.catch(exn, handler)? {
+                    return Ok(caught_ref);
+                }
+            }
+            match self.callers.pop() {
+                Some(ReturnPoint { body, pc, .. }) => {
+                    self.runtime.stack.discard_activation()?;
+                    self.body = body;
+                    self.pc = pc;
+                }
+                None => {
+                    let exn = self.runtime.store.exn(exn)?;
+                    Err(RuntimeErrorKind::UncaughtException {
+                        tag:     exn.tag,
+                        payload: exn.fields.to_vec(),
+                    })?
+                }
+            }
+        }
+    }
+
+    /// Check the catch clauses of a `try_table` at `handler` for one that
+    /// handles the exception. If one does, the values it passes are pushed,
+    /// execution branches to its label, and the result is whether one of
+    /// those values is a reference to the exception.
+    fn catch(&mut self, exn: Address<addr::Exception>, handler: u32) -> Result<Option<bool>> {
+        self.pc = handler as usize;
+        let clauses = self.op_u32()?;
+        for _ in 0..clauses {
+            // The clause kinds use their binary format encoding.
+            let kind = self.op_u8()?;
+            let tagidx = self.op_u32()?;
+            let labelidx = self.op_u32()?;
+            let exninst = self.runtime.store.exn(exn)?;
+            let catches = match kind {
+                0x00 | 0x01 => self.runtime.stack.active_module()?.tag(tagidx) == exninst.tag,
+                _ => true,
+            };
+            if !catches {
+                continue;
+            }
+            if matches!(kind, 0x00 | 0x01) {
+                for field in exninst.fields.iter() {
+                    self.runtime.stack.push_value(*field);
+                }
+            }
+            let caught_ref = matches!(kind, 0x01 | 0x03);
+            if caught_ref {
+                self.runtime.stack.push_value(Ref::Exn(exn).into());
+            }
+            self.br(labelidx)?;
+            return Ok(Some(caught_ref));
+        }
+        Ok(None)
+    }
+
+    /// The functions executing in this context, innermost first, with the
+    /// position of the instruction that each one is executing.
+    fn backtrace(&self) -> Vec<BacktraceFrame> {
+        let offsets = std::iter::once(self.instr).chain(self.callers.iter().rev().map(|c| c.call));
+        self.runtime
+            .stack
+            .active_funcs()
+            .zip(offsets)
+            .filter_map(|((funcaddr, module), offset)| {
+                let funcaddr = funcaddr?;
+                let funcinst = self.runtime.store.func(funcaddr).ok()?;
+                let location = match &funcinst.code {
+                    FunctionCode::Module(func) => func.locations.location(offset),
+                    FunctionCode::Host(_) => None,
+                };
+                Some(BacktraceFrame {
+                    funcaddr,
+                    module: module.clone(),
+                    name: funcinst.name.clone(),
+                    offset,
+                    location,
+                })
+            })
+            .collect()
+    }
+
+    /// Called when the end of the current body is reached. If the body was a
+    /// function called from this context, its frame is removed and execution
+    /// resumes in the caller. Returns false when there is no caller to resume.
+    fn return_to_caller(&mut self) -> Result<bool> {
+        match self.callers.pop() {
+            Some(ReturnPoint { body, pc, .. }) => {
+                self.runtime.pop_frame()?;
+                self.body = body;
+                self.pc = pc;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 struct Body<'a>(&'a [u8]);
@@ -510,25 +977,106 @@ impl Runtime {
         self.logger.log(tag, msg);
     }
 
-    pub fn enter(&mut self, body: &[u8]) -> Result<()> {
+    /// Execute a function body, charging fuel if metering is enabled. If the
+    /// fuel runs out, the execution state is saved so that a call made by the
+    /// host can be resumed.
+    pub fn enter(&mut self, body: Rc<Expr>) -> Result<()> {
         self.log(Tag::Enter, || {
-            format!("ENTER EXPR {expr}", expr = Body(body))
+            format!("ENTER EXPR {expr}", expr = Body(&body))
         });
+        self.run_context(body, 0, vec![], true)
+    }
+
+    fn run_context(
+        &mut self,
+        body: Rc<Expr>,
+        pc: usize,
+        callers: Vec<ReturnPoint>,
+        metered: bool,
+    ) -> Result<()> {
         let mut ic = ExecutionContext {
             runtime: self,
             body,
-            pc: 0,
+            pc,
+            instr: pc,
+            callers,
+            metered,
         };
-        let result = ic.run();
-        if let Err(ref e) = result {
-            println!("UNWINDING FOR ERROR {e:?}");
+        match ic.run() {
+            Err(
+                e @ RuntimeError {
+                    kind: RuntimeErrorKind::OutOfFuel,
+                    ..
+                },
+            ) => {
+                let ExecutionContext {
+                    body, pc, callers, ..
+                } = ic;
+                self.suspended = Some(Suspended {
+                    body,
+                    pc,
+                    callers,
+                    results: 0,
+                });
+                Err(e)
+            }
+            Err(e) => {
+                // The backtrace is taken from the stack, so it has to be
+                // captured before the stack is unwound.
+                let e = e.with_backtrace(ic.backtrace());
+                self.log(Tag::Flow, || format!("UNWINDING FOR ERROR {e:?}"));
+                self.stack.unwind();
+                self.debug.stop_stepping();
+                Err(e)
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// Record the number of results of the host call that was suspended while
+    /// producing `err`, if it ran out of fuel.
+    pub(super) fn suspend_call(&mut self, err: RuntimeError, results: usize) -> RuntimeError {
+        if let Some(suspended) = self.suspended.as_mut() {
+            suspended.results = results;
+        }
+        err
+    }
+
+    /// Continue executing the suspended host call. Returns the number of
+    /// results that the call leaves on the stack.
+    pub(super) fn continue_suspended(&mut self) -> Result<usize> {
+        let Suspended {
+            body,
+            pc,
+            callers,
+            results,
+        } = self
+            .suspended
+            .take()
+            .ok_or(RuntimeErrorKind::NothingSuspended)?;
+        self.run_context(body, pc, callers, true)
+            .map_err(|e| self.suspend_call(e, results))?;
+        Ok(results)
+    }
+
+    /// Discard a suspended host call, along with its stack.
+    pub(super) fn abandon_suspended(&mut self) {
+        if self.suspended.take().is_some() {
             self.stack.unwind();
         }
-        result
     }
 
+    /// Free the exceptions that the program can no longer refer to.
+    /// Exceptions that only the host holds references to aren't kept.
+    fn collect_exns(&mut self) {
+        self.log(Tag::Flow, || "COLLECT EXCEPTIONS".to_owned());
+        self.store.collect_exns(self.stack.values());
+    }
+
+    /// Execute a constant expression. These are evaluated during
+    /// instantiation, and are never charged fuel.
     pub fn exec_expr(&mut self, body: &[u8]) -> Result<()> {
-        self.enter(body)
+        self.run_context(body.into(), 0, vec![], false)
     }
 
     pub fn eval_expr(&mut self, body: &[u8]) -> Result<Value> {
@@ -0,0 +1,160 @@
+//! Support for host functions: functions implemented in Rust by the embedder,
+//! which WebAssembly modules can import like any other function.
+
+use {
+    super::{
+        error::{Result, RuntimeErrorKind},
+        instance::{
+            addr::{self, Address},
+            mem_instance::PAGE_SIZE,
+            FunctionInstance, GlobalInstance, HostFunc, MemInstance, TableInstance,
+        },
+        store::Store,
+        values::Value,
+        Runtime,
+    },
+    crate::{
+        log_tag::Tag,
+        syntax::{
+            types::{FunctionType, MemType, TableType},
+            Validated,
+        },
+    },
+    std::rc::Rc,
+    wrausmt_common::logger::Logger,
+};
+
+/// The view of the runtime given to a host function while it executes.
+pub struct Caller<'a> {
+    store:  &'a mut Store,
+    memory: Option<Address<addr::Memory>>,
+}
+
+impl<'a> Caller<'a> {
+    /// The memory of the module instance that called the host function.
+    pub fn memory(&mut self) -> Result<&mut MemInstance> {
+        let addr = self.memory.ok_or(RuntimeErrorKind::CallerMemoryNotFound)?;
+        self.store.mem_mut(addr)
+    }
+}
+
+impl Runtime {
+    /// Register a Rust closure as a host function with the provided
+    /// [`FunctionType`]. Modules instantiated afterwards can import it as
+    /// `modname`.`name`. Host functions take precedence over exports of a
+    /// module registered under the same name.
+    pub fn register_host_func(
+        &mut self,
+        modname: impl Into<String>,
+        name: impl Into<String>,
+        functype: FunctionType,
+        hostcode: impl Fn(&mut Caller, &[Value]) -> Result<Vec<Value>> + 'static,
+    ) -> Address<addr::Function> {
+        let addr = self.alloc_host_func(functype, hostcode);
+        self.host_funcs.insert((modname.into(), name.into()), addr);
+        addr
+    }
+
+    /// Add a Rust closure to the store as a host function with the provided
+    /// [`FunctionType`], without registering it. The function can be provided
+    /// to modules through a [`Linker`](super::linker::Linker).
+    pub fn alloc_host_func(
+        &mut self,
+        functype: FunctionType,
+        hostcode: impl Fn(&mut Caller, &[Value]) -> Result<Vec<Value>> + 'static,
+    ) -> Address<addr::Function> {
+        let addr = Address::new(self.store.funcs.len() as u32);
+        self.store
+            .funcs
+            .push(Rc::new(FunctionInstance::new_host(functype, hostcode)));
+        addr
+    }
+
+    /// Add a memory of the provided type to the store, for the host to
+    /// provide to modules.
+    pub fn alloc_memory(&mut self, memtype: MemType<Validated>) -> Result<Address<addr::Memory>> {
+        self.store
+            .check_mem_limit((memtype.limits.lower as usize).saturating_mul(PAGE_SIZE))?;
+        let addr = Address::new(self.store.mems.len() as u32);
+        self.store.mems.push(MemInstance::new(memtype)?);
+        Ok(addr)
+    }
+
+    /// Add a table of the provided type to the store, for the host to provide
+    /// to modules. Its elements are null.
+    pub fn alloc_table(&mut self, tabletype: TableType<Validated>) -> Result<Address<addr::Table>> {
+        self.store
+            .check_table_limit(tabletype.limits.lower as usize)?;
+        let addr = Address::new(self.store.tables.len() as u32);
+        self.store.tables.push(TableInstance::new(tabletype)?);
+        Ok(addr)
+    }
+
+    /// Add a global holding `val` to the store, for the host to provide to
+    /// modules.
+    pub fn alloc_global(&mut self, val: Value, mutable: bool) -> Address<addr::Global> {
+        let addr = Address::new(self.store.globals.len() as u32);
+        self.store.globals.push(GlobalInstance {
+            typ: val.valtype(),
+            mutable,
+            val,
+        });
+        addr
+    }
+
+    pub(crate) fn find_host_func(
+        &self,
+        modname: &str,
+        name: &str,
+    ) -> Option<Address<addr::Function>> {
+        self.host_funcs
+            .get(&(modname.to_owned(), name.to_owned()))
+            .copied()
+    }
+
+    /// Invoke a host function. The arguments are popped from the stack, and
+    /// the results are pushed back after checking them against the function
+    /// type. No activation frame is pushed, so the active module is still the
+    /// caller's.
+    pub(crate) fn invoke_host(&mut self, functype: &FunctionType, func: &HostFunc) -> Result<()> {
+        let result = self.exec_host(functype, func);
+        if result.is_err() {
+            self.stack.unwind();
+        }
+        result
+    }
+
+    /// Invoke a host function called by guest code, leaving the stack for the
+    /// caller to unwind if it fails.
+    pub(super) fn exec_host(&mut self, functype: &FunctionType, func: &HostFunc) -> Result<()> {
+        let mut args = functype
+            .params
+            .iter()
+            .map(|_| self.stack.pop_value())
+            .collect::<Result<Vec<_>>>()?;
+        args.reverse();
+
+        self.logger
+            .log(Tag::Host, || format!("INVOKE HOST FUNCTION {args:?}"));
+
+        let memory = self.stack.active_module()?.mems().first().copied();
+        let mut caller = Caller {
+            store: &mut self.store,
+            memory,
+        };
+        let results = (func.hostcode)(&mut caller, &args)?;
+
+        let got: Vec<_> = results.iter().map(Value::valtype).collect();
+        if *got != *functype.result {
+            Err(RuntimeErrorKind::HostResultMismatch {
+                expected: functype.result.clone(),
+                got,
+            })?;
+        }
+
+        for result in results {
+            self.stack.push_value(result);
+        }
+        Ok(())
+    }
+}
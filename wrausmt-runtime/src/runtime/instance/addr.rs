@@ -1,16 +1,18 @@
 use {
     super::{
-        DataInstance, ElemInstance, FunctionInstance, GlobalInstance, MemInstance, TableInstance,
+        DataInstance, ElemInstance, ExnInstance, FunctionInstance, GlobalInstance, HostObject,
+        MemInstance, TableInstance, TagInstance,
     },
     std::marker::PhantomData,
     wrausmt_common::marker,
 };
 
 /// Function instances, table instances, memory instances, and global instances,
-/// element instances, and data instances in the store are referenced with
+/// element instances, data instances, tag instances, and exception instances in
+/// the store are referenced with
 /// abstract addresses. These are simply indices into the respective store
 /// component. In addition, an embedder may supply an uninterpreted set of host
-/// addresses.
+/// addresses, which address the host objects in the store.
 ///
 /// This is a type-safe wrapper around a u32 to use for addressing in the
 /// runtime.
@@ -57,6 +59,9 @@ addressable!(DataInstance, Data);
 addressable!(MemInstance, Memory);
 addressable!(GlobalInstance, Global);
 addressable!(ElemInstance, Elem);
+addressable!(TagInstance, Tag);
+addressable!(ExnInstance, Exception);
+addressable!(HostObject, Extern);
 
 /// A contiguous range of [`Address`].
 ///
@@ -0,0 +1,17 @@
+use {
+    super::addr::{self, Address},
+    crate::runtime::values::Value,
+};
+
+/// An exception instance is the runtime representation of an exception.
+/// [Spec][Spec]
+///
+/// It records the address of the tag the exception was thrown with, and the
+/// values that were thrown along with it, in the order of the tag's parameters.
+///
+/// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#exception-instances
+#[derive(Debug)]
+pub struct ExnInstance {
+    pub tag:    Address<addr::Tag>,
+    pub fields: Box<[Value]>,
+}
@@ -1,10 +1,13 @@
-use super::addr::{self, Address};
+use {
+    super::addr::{self, Address},
+    crate::syntax::{types::ExternType, Validated},
+};
 
 /// An external value is the runtime representation of an entity that can be
 /// imported or exported. [Spec][Spec]
 ///
 /// It is an address denoting either a function instance, table instance, memory
-/// instance, or global instances in the shared store.
+/// instance, global instance, or tag instance in the shared store.
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#external-values
 #[derive(Debug, Clone, Copy)]
@@ -13,15 +16,18 @@ pub enum ExternalVal {
     Table(Address<addr::Table>),
     Memory(Address<addr::Memory>),
     Global(Address<addr::Global>),
+    Tag(Address<addr::Tag>),
 }
 
 /// An export instance is the runtime representation of an export. [Spec][Spec]
 ///
-/// It defines the export’s name and the associated external value.
+/// It defines the export’s name and the associated external value. Here, it
+/// also records the type of the value, as the exporting module declared it.
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#export-instances
 #[derive(Debug, Clone)]
 pub struct ExportInstance {
-    pub name: String,
-    pub addr: ExternalVal,
+    pub name:       String,
+    pub addr:       ExternalVal,
+    pub externtype: ExternType<Validated>,
 }
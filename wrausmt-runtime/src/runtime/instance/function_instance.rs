@@ -4,9 +4,14 @@ use {
         instructions::Expr,
         runtime::{
             error::{Result, RuntimeErrorKind},
+            host::Caller,
             Value,
         },
-        syntax::types::{FunctionType, ValueType},
+        syntax::{
+            location::SourceMap,
+            types::{FunctionType, ValueType},
+            Id,
+        },
     },
     std::rc::Rc,
     wrausmt_common::true_or::TrueOr,
@@ -15,14 +20,32 @@ use {
 /// A function instance is the runtime representation of a function.
 /// [Spec][Spec]
 ///
-/// It effectively is a closure of the original function over the runtime module
-/// instance of its originating module. The module instance is used to resolve
-/// references to other definitions during execution of the function.
+/// It is either a closure of the original function over the runtime module
+/// instance of its originating module, or a host function provided by the
+/// embedder. The module instance is used to resolve references to other
+/// definitions during execution of the function.
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#function-instances
 #[derive(Debug)]
 pub struct FunctionInstance {
-    pub functype:        FunctionType,
+    pub functype: FunctionType,
+    pub code:     FunctionCode,
+
+    /// The identifier of the function in its module, from the text format or
+    /// the `name` section of a binary module. It's only used for diagnostics.
+    pub name: Option<Id>,
+}
+
+/// The two forms of code that a [`FunctionInstance`] can execute.
+#[derive(Debug)]
+pub enum FunctionCode {
+    Module(ModuleFunc),
+    Host(HostFunc),
+}
+
+/// A function defined by a WebAssembly module.
+#[derive(Debug)]
+pub struct ModuleFunc {
     pub module_instance: Rc<ModuleInstance>,
 
     /// The locals declare a vector of mutable local variables and their types.
@@ -32,35 +55,80 @@ pub struct FunctionInstance {
     pub locals: Box<[ValueType]>,
 
     /// The body is an instruction sequence that upon termination must produce a
-    /// stack matching the function type's result type.
-    pub body: Box<Expr>,
+    /// stack matching the function type's result type. It's shared so that the
+    /// interpreter can hold on to it while the function is active.
+    pub body: Rc<Expr>,
+
+    /// The locations of the instructions that the body was compiled from.
+    pub locations: Rc<SourceMap>,
 }
 
+/// The signature of the Rust code backing a [`HostFunc`]. It receives a
+/// [`Caller`] for access to the calling module's memory, and the arguments in
+/// parameter order. It returns the results in result order.
+pub type HostCode = dyn Fn(&mut Caller, &[Value]) -> Result<Vec<Value>>;
+
 /// A host function is a function expressed outside WebAssembly but passed to a
 /// module as an import. The definition and behavior of host functions are
 /// outside the scope of this specification. For the purpose of this
 /// specification, it is assumed that when invoked, a host function behaves
 /// non-deterministically, but within certain constraints that ensure the
 /// integrity of the runtime.
-///
-/// Note: Host functions are not yet used in this implementation.
-#[allow(dead_code)]
-struct HostFunc {
-    functype: FunctionType,
-    // hostfunc: HostFunc,
+pub struct HostFunc {
+    pub hostcode: Box<HostCode>,
+}
+
+impl std::fmt::Debug for HostFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HostFunc")
+    }
 }
 
 impl FunctionInstance {
+    pub fn new_host(
+        functype: FunctionType,
+        hostcode: impl Fn(&mut Caller, &[Value]) -> Result<Vec<Value>> + 'static,
+    ) -> Self {
+        FunctionInstance {
+            functype,
+            code: FunctionCode::Host(HostFunc {
+                hostcode: Box::new(hostcode),
+            }),
+            name: None,
+        }
+    }
+
     pub fn validate_args(&self, args: &[Value]) -> Result<()> {
         let params_arity = self.functype.params.len();
         (params_arity == args.len()).true_or_else(|| RuntimeErrorKind::ArgumentCountError {
             expected: params_arity,
             got:      args.len(),
         })?;
+        for (param, arg) in self.functype.params.iter().zip(args) {
+            (*param == arg.valtype()).true_or_else(|| RuntimeErrorKind::ValueTypeMismatch {
+                expected: *param,
+                got:      arg.valtype(),
+            })?;
+        }
         Ok(())
     }
 
-    pub fn module_instance(&self) -> Rc<ModuleInstance> {
-        self.module_instance.clone()
+    /// The module instance of a module function, or `None` for a host
+    /// function.
+    pub fn module_instance(&self) -> Option<Rc<ModuleInstance>> {
+        match &self.code {
+            FunctionCode::Module(f) => Some(f.module_instance.clone()),
+            FunctionCode::Host(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FunctionInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.name, &self.code) {
+            (Some(name), _) => write!(f, "{name}"),
+            (None, FunctionCode::Module(_)) => f.write_str("<anonymous>"),
+            (None, FunctionCode::Host(_)) => f.write_str("<host>"),
+        }
     }
 }
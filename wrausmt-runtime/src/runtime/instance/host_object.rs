@@ -0,0 +1,13 @@
+use std::{any::Any, fmt, rc::Rc};
+
+/// An object supplied by the host. WebAssembly code can hold and pass around
+/// references to it as `externref` values, but it can't look inside; only the
+/// host can retrieve the object, by its type.
+#[derive(Clone)]
+pub struct HostObject(pub Rc<dyn Any>);
+
+impl fmt::Debug for HostObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HostObject")
+    }
+}
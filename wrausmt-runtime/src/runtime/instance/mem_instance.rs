@@ -1,9 +1,9 @@
 use {
     crate::{
         log_tag::Tag,
-        runtime::error::{Result, TrapKind},
+        runtime::error::{Result, RuntimeErrorKind, TrapKind},
         syntax::{
-            types::{Limits, MemType},
+            types::{IndexType, Limits, MemType},
             MemoryField, Validated,
         },
     },
@@ -27,68 +27,97 @@ use {
 /// It is an invariant of the semantics that the length of the byte vector,
 /// divided by page size, never exceeds the maximum size of memtype, if present.
 ///
+/// Shared memories may be the target of `memory.atomic.wait`. Since a store is
+/// only used by a single thread, they're otherwise the same as unshared ones.
+///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#memory-instances
 #[derive(Default, Debug)]
 pub struct MemInstance {
-    logger:     PrintLogger,
-    pub limits: Limits,
-    pub data:   Vec<u8>,
+    logger:      PrintLogger,
+    pub idxtype: IndexType,
+    pub limits:  Limits,
+    pub shared:  bool,
+    pub data:    Vec<u8>,
 }
 
-const PAGE_SIZE: usize = 65536;
+pub const PAGE_SIZE: usize = 65536;
 
 impl MemInstance {
     /// Create a new [MemInstance] for the provided [MemType].
     /// As per the [Spec][Spec], the meory is initialized to `n` pages of `0`s,
     /// where `n` is the lower value of the
-    /// [Limits] in the provided [MemType].
+    /// [Limits] in the provided [MemType]. Fails if the host can't allocate
+    /// that many bytes.
     ///
     /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#memory-instances
-    pub fn new(memtype: MemType<Validated>) -> MemInstance {
-        let data = vec![0u8; memtype.limits.lower as usize * PAGE_SIZE];
-        MemInstance {
+    pub fn new(memtype: MemType<Validated>) -> Result<MemInstance> {
+        let mut data = vec![];
+        usize::try_from(memtype.limits.lower)
+            .ok()
+            .and_then(|pgs| pgs.checked_mul(PAGE_SIZE))
+            .is_some_and(|len| try_resize(&mut data, len))
+            .true_or(RuntimeErrorKind::OutOfMemory)?;
+        Ok(MemInstance {
             logger: PrintLogger,
+            idxtype: memtype.idxtype,
             limits: memtype.limits,
+            shared: memtype.shared,
             data,
-        }
+        })
     }
 
     pub fn new_ast(memfield: MemoryField<Validated>) -> Result<MemInstance> {
-        let memtype = memfield.memtype;
-        let data = vec![0u8; memtype.limits.lower as usize * PAGE_SIZE];
-        Ok(MemInstance {
-            logger: PrintLogger,
-            limits: memtype.limits,
-            data,
-        })
+        Self::new(memfield.memtype)
     }
 
     pub fn size(&self) -> usize {
         self.data.len() / PAGE_SIZE
     }
 
-    pub fn grow(&mut self, pgs: u32) -> Option<u32> {
-        if pgs as usize > i32::MAX as usize / PAGE_SIZE {
+    /// The length in bytes that the memory would have after growing by `pgs`
+    /// pages, or `None` if its type doesn't allow it to grow that much.
+    ///
+    /// Memories with 32-bit indices are limited to 2GiB, so that their size
+    /// in bytes fits in a positive i32. 64-bit memories are only limited by
+    /// their type and the host's address space.
+    pub fn grown_len(&self, pgs: u64) -> Option<usize> {
+        let max_pages = match self.idxtype {
+            IndexType::I32 => i32::MAX as u64 / PAGE_SIZE as u64,
+            IndexType::I64 => self.idxtype.max_pages(),
+        };
+        let new_pages = (self.size() as u64).checked_add(pgs)?;
+        if new_pages > max_pages {
             return None;
         }
 
-        let old_size_in_pages = self.data.len() / PAGE_SIZE;
-
         if let Some(upper) = self.limits.upper {
-            if old_size_in_pages as u32 + pgs > upper {
+            if new_pages > upper {
                 return None;
             }
         }
 
-        let newsize = self.data.len() + (pgs as usize * PAGE_SIZE);
-        self.data.resize(newsize, 0);
+        usize::try_from(new_pages).ok()?.checked_mul(PAGE_SIZE)
+    }
+
+    /// Grow the memory by `pgs` pages, returning its previous size in pages,
+    /// or `None` if its type doesn't allow it to grow that much, or the host
+    /// can't allocate the bytes.
+    pub fn grow(&mut self, pgs: u64) -> Option<u64> {
+        let old_size_in_pages = self.size();
+        let newsize = self.grown_len(pgs)?;
+        if !try_resize(&mut self.data, newsize) {
+            return None;
+        }
 
-        Some(old_size_in_pages as u32)
+        Some(old_size_in_pages as u64)
     }
 
+    /// The range of `n` bytes at offset `o` from address `b`. The sum of a
+    /// 64-bit address and offset may overflow, which is also out of bounds.
     fn offset(&self, o: usize, b: usize, n: usize) -> Result<Range<usize>> {
-        let i = o + b;
-        let end = i + n;
+        let oob = || TrapKind::OutOfBoundsMemoryAccess(b, self.data.len());
+        let i = o.checked_add(b).ok_or_else(oob)?;
+        let end = i.checked_add(n).ok_or_else(oob)?;
         self.logger
             .log(Tag::Mem, || format!("READ {} IN {}", i, self.data.len()));
         (end <= self.data.len())
@@ -107,10 +136,61 @@ impl MemInstance {
         Ok(())
     }
 
+    /// The range of an atomic access of `n` bytes at offset `o` from address
+    /// `b`. Atomic accesses trap unless their effective address is a multiple
+    /// of their size.
+    pub fn atomic_offset(&self, o: usize, b: usize, n: usize) -> Result<Range<usize>> {
+        let range = self.offset(o, b, n)?;
+        (range.start % n == 0).true_or(TrapKind::UnalignedAtomic)?;
+        Ok(range)
+    }
+
+    pub fn atomic_read<const S: usize>(&self, o: usize, b: usize) -> Result<[u8; S]> {
+        let range = self.atomic_offset(o, b, S)?;
+        let mut bs = [0u8; S];
+        bs.copy_from_slice(&self.data[range]);
+        Ok(bs)
+    }
+
+    pub fn atomic_write<const S: usize>(&mut self, o: usize, b: usize, bs: [u8; S]) -> Result<()> {
+        let range = self.atomic_offset(o, b, S)?;
+        self.data[range].copy_from_slice(&bs);
+        Ok(())
+    }
+
+    /// Replace the `S` bytes at offset `o` from address `b` with the result of
+    /// `op` applied to them, returning the bytes that were replaced.
+    pub fn atomic_rmw<const S: usize>(
+        &mut self,
+        o: usize,
+        b: usize,
+        op: impl FnOnce([u8; S]) -> [u8; S],
+    ) -> Result<[u8; S]> {
+        let old = self.atomic_read::<S>(o, b)?;
+        self.atomic_write(o, b, op(old))?;
+        Ok(old)
+    }
+
     pub fn copy_within(&mut self, src: usize, dst: usize, count: usize) -> Result<()> {
-        (src + count <= self.data.len()).true_or(TrapKind::OutOfBoundsMemoryAccess(src, count))?;
-        (dst + count <= self.data.len()).true_or(TrapKind::OutOfBoundsMemoryAccess(dst, count))?;
+        let in_bounds = |i: usize| {
+            i.checked_add(count)
+                .is_some_and(|end| end <= self.data.len())
+        };
+        in_bounds(src).true_or(TrapKind::OutOfBoundsMemoryAccess(src, count))?;
+        in_bounds(dst).true_or(TrapKind::OutOfBoundsMemoryAccess(dst, count))?;
         self.data.copy_within(src..src + count, dst);
         Ok(())
     }
 }
+
+/// Grow `data` to `len` zeroed bytes, unless the allocation fails. The sizes
+/// of memories come from the modules being run, so failing to allocate them
+/// mustn't abort the host.
+fn try_resize(data: &mut Vec<u8>, len: usize) -> bool {
+    let additional = len.saturating_sub(data.len());
+    if data.try_reserve_exact(additional).is_err() {
+        return false;
+    }
+    data.resize(len, 0);
+    true
+}
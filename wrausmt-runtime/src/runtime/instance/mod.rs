@@ -1,20 +1,26 @@
 pub mod addr;
 pub mod data_instance;
 pub mod elem_instance;
+pub mod exn_instance;
 pub mod export_instance;
 pub mod function_instance;
 pub mod global_instance;
+pub mod host_object;
 pub mod mem_instance;
 pub mod module_instance;
 pub mod table_instance;
+pub mod tag_instance;
 
 pub use {
     data_instance::DataInstance,
     elem_instance::ElemInstance,
+    exn_instance::ExnInstance,
     export_instance::{ExportInstance, ExternalVal},
-    function_instance::FunctionInstance,
+    function_instance::{FunctionCode, FunctionInstance, HostFunc, ModuleFunc},
     global_instance::GlobalInstance,
+    host_object::HostObject,
     mem_instance::MemInstance,
     module_instance::ModuleInstance,
     table_instance::TableInstance,
+    tag_instance::TagInstance,
 };
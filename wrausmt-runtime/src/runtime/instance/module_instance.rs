@@ -21,17 +21,26 @@ use {
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#module-instances
 #[derive(Debug, Default, Clone)]
 pub struct ModuleInstance {
-    types:   Box<[FunctionType]>,
-    exports: Box<[ExportInstance]>,
-    funcs:   Box<[Address<addr::Function>]>,
-    tables:  Box<[Address<addr::Table>]>,
-    mems:    Box<[Address<addr::Memory>]>,
-    globals: Box<[Address<addr::Global>]>,
-    elems:   Box<[Address<addr::Elem>]>,
-    data:    Box<[Address<addr::Data>]>,
+    /// The id of the store that the items of the instance are in.
+    store_id: u64,
+    types:    Box<[FunctionType]>,
+    exports:  Box<[ExportInstance]>,
+    funcs:    Box<[Address<addr::Function>]>,
+    tables:   Box<[Address<addr::Table>]>,
+    mems:     Box<[Address<addr::Memory>]>,
+    globals:  Box<[Address<addr::Global>]>,
+    tags:     Box<[Address<addr::Tag>]>,
+    elems:    Box<[Address<addr::Elem>]>,
+    data:     Box<[Address<addr::Data>]>,
 }
 
 impl ModuleInstance {
+    /// The id of the store that the items of the instance are in, which is
+    /// the store of the runtime that instantiated it.
+    pub fn store_id(&self) -> u64 {
+        self.store_id
+    }
+
     pub fn func_type(&self, idx: u32) -> &FunctionType {
         &self.types[idx as usize]
     }
@@ -40,6 +49,10 @@ impl ModuleInstance {
         self.funcs[idx as usize]
     }
 
+    pub fn funcs(&self) -> &[Address<addr::Function>] {
+        &self.funcs
+    }
+
     pub fn table(&self, idx: u32) -> Address<addr::Table> {
         self.tables[idx as usize]
     }
@@ -48,10 +61,18 @@ impl ModuleInstance {
         self.mems[idx as usize]
     }
 
+    pub fn mems(&self) -> &[Address<addr::Memory>] {
+        &self.mems
+    }
+
     pub fn global(&self, idx: u32) -> Address<addr::Global> {
         self.globals[idx as usize]
     }
 
+    pub fn tag(&self, idx: u32) -> Address<addr::Tag> {
+        self.tags[idx as usize]
+    }
+
     pub fn elem(&self, idx: u32) -> Address<addr::Elem> {
         self.elems[idx as usize]
     }
@@ -60,6 +81,12 @@ impl ModuleInstance {
         self.data[idx as usize]
     }
 
+    /// The exports of the instance, in order, with their addresses and
+    /// types.
+    pub fn exports(&self) -> &[ExportInstance] {
+        &self.exports
+    }
+
     pub fn resolve(&self, name: &str) -> Option<&ExportInstance> {
         self.exports.iter().find(|e| e.name == name)
     }
@@ -67,14 +94,16 @@ impl ModuleInstance {
 
 #[derive(Debug, Default, Clone)]
 pub struct ModuleInstanceBuilder {
-    pub types:   Vec<FunctionType>,
-    pub exports: Vec<ExportInstance>,
-    pub funcs:   Vec<Address<addr::Function>>,
-    pub tables:  Vec<Address<addr::Table>>,
-    pub mems:    Vec<Address<addr::Memory>>,
-    pub globals: Vec<Address<addr::Global>>,
-    pub elems:   Vec<Address<addr::Elem>>,
-    pub data:    Vec<Address<addr::Data>>,
+    pub store_id: u64,
+    pub types:    Vec<FunctionType>,
+    pub exports:  Vec<ExportInstance>,
+    pub funcs:    Vec<Address<addr::Function>>,
+    pub tables:   Vec<Address<addr::Table>>,
+    pub mems:     Vec<Address<addr::Memory>>,
+    pub globals:  Vec<Address<addr::Global>>,
+    pub tags:     Vec<Address<addr::Tag>>,
+    pub elems:    Vec<Address<addr::Elem>>,
+    pub data:     Vec<Address<addr::Data>>,
 }
 
 impl ModuleInstanceBuilder {
@@ -84,19 +113,22 @@ impl ModuleInstanceBuilder {
             ExternalVal::Table(addr) => self.tables.push(addr),
             ExternalVal::Memory(addr) => self.mems.push(addr),
             ExternalVal::Global(addr) => self.globals.push(addr),
+            ExternalVal::Tag(addr) => self.tags.push(addr),
         }
     }
 
     pub fn build(self) -> ModuleInstance {
         ModuleInstance {
-            types:   self.types.into_boxed_slice(),
-            exports: self.exports.into_boxed_slice(),
-            funcs:   self.funcs.into_boxed_slice(),
-            tables:  self.tables.into_boxed_slice(),
-            mems:    self.mems.into_boxed_slice(),
-            globals: self.globals.into_boxed_slice(),
-            elems:   self.elems.into_boxed_slice(),
-            data:    self.data.into_boxed_slice(),
+            store_id: self.store_id,
+            types:    self.types.into_boxed_slice(),
+            exports:  self.exports.into_boxed_slice(),
+            funcs:    self.funcs.into_boxed_slice(),
+            tables:   self.tables.into_boxed_slice(),
+            mems:     self.mems.into_boxed_slice(),
+            globals:  self.globals.into_boxed_slice(),
+            tags:     self.tags.into_boxed_slice(),
+            elems:    self.elems.into_boxed_slice(),
+            data:     self.data.into_boxed_slice(),
         }
     }
 }
@@ -33,8 +33,9 @@ impl TableInstance {
         Ok(TableInstance { tabletype, elem })
     }
 
-    pub fn grow(&mut self, amt: u32, val: Ref) -> Option<u32> {
-        let oldsize = self.elem.len();
+    /// The number of elements that the table would have after growing by
+    /// `amt`, or `None` if its type doesn't allow it to grow that much.
+    pub fn grown_len(&self, amt: u32) -> Option<usize> {
         let newsize = self.elem.len() + amt as usize;
         if newsize > i32::MAX as usize {
             return None;
@@ -42,6 +43,12 @@ impl TableInstance {
         if matches!(self.tabletype.limits.upper, Some(upper) if newsize > upper as usize) {
             return None;
         }
+        Some(newsize)
+    }
+
+    pub fn grow(&mut self, amt: u32, val: Ref) -> Option<u32> {
+        let oldsize = self.elem.len();
+        let newsize = self.grown_len(amt)?;
         self.elem.resize(newsize, val);
         Some(oldsize as u32)
     }
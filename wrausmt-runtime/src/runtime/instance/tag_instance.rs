@@ -0,0 +1,12 @@
+use crate::syntax::types::FunctionType;
+
+/// A tag instance is the runtime representation of a tag. [Spec][Spec]
+///
+/// It records the type of the tag, which describes the values carried by the
+/// exceptions that are thrown with it. Tag types have no results.
+///
+/// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#tag-instances
+#[derive(Debug)]
+pub struct TagInstance {
+    pub tagtype: FunctionType,
+}
@@ -1,23 +1,23 @@
 use {
     super::{
+        compiled::{CompiledFunc, CompiledModule},
         error::{Result, RuntimeErrorKind},
-        instance::{ExportInstance, FunctionInstance, ModuleInstance},
+        instance::{ExportInstance, FunctionCode, FunctionInstance, ModuleFunc, ModuleInstance},
         Runtime,
     },
     crate::{
         log_tag::Tag,
         runtime::{
             instance::{
-                module_instance::ModuleInstanceBuilder, DataInstance, ElemInstance, ExternalVal,
-                GlobalInstance, MemInstance, TableInstance,
+                mem_instance::PAGE_SIZE, module_instance::ModuleInstanceBuilder, DataInstance,
+                ElemInstance, ExternalVal, GlobalInstance, MemInstance, TableInstance, TagInstance,
             },
             values::Ref,
         },
         syntax::{
             self,
-            types::{FunctionType, ValueType},
-            CompiledExpr, DataInit, FuncField, ImportDesc, ModeEntry, Resolved, TablePosition,
-            Validated,
+            types::{ExternType, FunctionType},
+            CompiledExpr, DataInit, ImportDesc, ModeEntry, Resolved, TablePosition, Validated,
         },
     },
     std::{convert::identity, rc::Rc},
@@ -31,7 +31,19 @@ impl Runtime {
         &mut self,
         module: syntax::Module<Resolved, Validated, CompiledExpr>,
     ) -> Result<Rc<ModuleInstance>> {
-        self.instantiate(module)
+        self.load_compiled(&CompiledModule::new(module)?)
+    }
+
+    /// Instantiate a [`CompiledModule`], resolving its imports against the
+    /// host functions and modules registered with the runtime.
+    pub fn load_compiled(&mut self, module: &CompiledModule) -> Result<Rc<ModuleInstance>> {
+        let imports = module
+            .module
+            .imports
+            .iter()
+            .map(|import| self.find_import(import))
+            .collect::<Result<Vec<_>>>()?;
+        self.instantiate(module, imports)
     }
 
     fn validate_import(
@@ -52,12 +64,19 @@ impl Runtime {
             }
             (ImportDesc::Mem(mi), ExternalVal::Memory(ma)) => {
                 let resolved = &self.store.mem(*ma)?;
-                resolved.limits.works_as(&mi.limits)
+                resolved.idxtype == mi.idxtype
+                    && resolved.shared == mi.shared
+                    && resolved.limits.works_as(&mi.limits)
             }
             (ImportDesc::Global(gi), ExternalVal::Global(ga)) => {
                 let existing = self.store.global_inst(*ga)?;
                 existing.val.valtype() == gi.valtype && existing.mutable == gi.mutable
             }
+            (ImportDesc::Tag(ti), ExternalVal::Tag(ta)) => {
+                let resolved = &self.store.tag(*ta)?.tagtype;
+                let imported = &types[ti.index().value() as usize];
+                resolved == imported
+            }
             _ => false,
         };
         Ok(matches.true_or_else(|| {
@@ -65,42 +84,43 @@ impl Runtime {
         })?)
     }
 
+    /// Resolve an import against the host functions and modules registered
+    /// with the runtime.
     fn find_import(
         &self,
         import: &syntax::ImportField<Resolved, Validated>,
-        types: &[FunctionType],
     ) -> Result<ExternalVal> {
-        let regmod = self
-            .registered
-            .get(&import.modname)
-            .ok_or_else(|| RuntimeErrorKind::ModuleNotFound(import.modname.clone()))?;
-
-        let exportinst = regmod.resolve(&import.name).ok_or_else(|| {
-            RuntimeErrorKind::ImportNotFound(import.modname.clone(), import.name.clone())
-        })?;
-
-        self.validate_import(import, &exportinst.addr, types)?;
-
-        Ok(exportinst.addr)
+        Ok(match self.find_host_func(&import.modname, &import.name) {
+            Some(funcaddr) => ExternalVal::Func(funcaddr),
+            None => {
+                let regmod = self
+                    .registered
+                    .get(&import.modname)
+                    .ok_or_else(|| RuntimeErrorKind::ModuleNotFound(import.modname.clone()))?;
+
+                let exportinst = regmod.resolve(&import.name).ok_or_else(|| {
+                    RuntimeErrorKind::ImportNotFound(import.modname.clone(), import.name.clone())
+                })?;
+                exportinst.addr
+            }
+        })
     }
 
-    /// Instantiate a function from the provided FuncField and module instance.
+    /// Instantiate a function from the provided CompiledFunc and module
+    /// instance. The function shares its code with the compiled module.
     fn instantiate_function(
-        f: FuncField<Resolved, CompiledExpr>,
-        types: &[FunctionType],
+        f: &CompiledFunc,
         modinst: Rc<ModuleInstance>,
     ) -> Result<FunctionInstance> {
-        let functype = types
-            .get(f.typeuse.index().value() as usize)
-            .ok_or(RuntimeErrorKind::TypeNotFound(f.typeuse.index().value()))?
-            .clone();
-        // when do params get added?
-        let locals: Box<[ValueType]> = f.locals.iter().map(|l| l.valtype).collect();
         Ok(FunctionInstance {
-            functype,
-            module_instance: modinst,
-            locals,
-            body: f.body.instr,
+            functype: f.functype.clone(),
+            code:     FunctionCode::Module(ModuleFunc {
+                module_instance: modinst,
+                locals:          f.locals.clone(),
+                body:            f.body.clone(),
+                locations:       f.locations.clone(),
+            }),
+            name:     f.name.clone(),
         })
     }
 
@@ -108,12 +128,12 @@ impl Runtime {
         self.exec_expr(&tp.offset.instr)
     }
 
-    fn init_mem(&mut self, datainit: DataInit<Resolved, CompiledExpr>) -> Result<()> {
+    fn init_mem(&mut self, datainit: &DataInit<Resolved, CompiledExpr>) -> Result<()> {
         self.exec_expr(&datainit.offset.instr)
     }
 
     fn instantiate_export_desc(
-        ast: syntax::ExportDesc<Resolved>,
+        ast: &syntax::ExportDesc<Resolved>,
         modinst: &ModuleInstance,
     ) -> ExternalVal {
         match ast {
@@ -121,34 +141,38 @@ impl Runtime {
             syntax::ExportDesc::Table(idx) => ExternalVal::Table(modinst.table(idx.value())),
             syntax::ExportDesc::Mem(idx) => ExternalVal::Memory(modinst.mem(idx.value())),
             syntax::ExportDesc::Global(idx) => ExternalVal::Global(modinst.global(idx.value())),
+            syntax::ExportDesc::Tag(idx) => ExternalVal::Tag(modinst.tag(idx.value())),
         }
     }
 
     pub fn instantiate_export(
-        ast: syntax::ExportField<Resolved, Validated>,
+        ast: &syntax::ExportField<Resolved, Validated>,
+        externtype: ExternType<Validated>,
         modinst: &ModuleInstance,
     ) -> ExportInstance {
         ExportInstance {
-            name: ast.name,
-            addr: Self::instantiate_export_desc(ast.exportdesc, modinst),
+            name: ast.name.clone(),
+            addr: Self::instantiate_export_desc(&ast.exportdesc, modinst),
+            externtype,
         }
     }
 
-    fn instantiate(
+    /// Instantiate the module, using the provided external values for its
+    /// imports, in order. The values are checked against the import types.
+    pub(super) fn instantiate(
         &mut self,
-        module: syntax::Module<Resolved, Validated, CompiledExpr>,
+        compiled: &CompiledModule,
+        imports: Vec<ExternalVal>,
     ) -> Result<Rc<ModuleInstance>> {
+        let module = &compiled.module;
         let mut modinst_builder = ModuleInstanceBuilder {
-            types: module
-                .types
-                .into_iter()
-                .map(|t| t.functiontype.into())
-                .collect(),
+            store_id: self.store.id(),
+            types: compiled.types.to_vec(),
             ..ModuleInstanceBuilder::default()
         };
 
-        for import in module.imports {
-            let found = self.find_import(&import, &modinst_builder.types)?;
+        for (import, found) in module.imports.iter().zip(imports) {
+            self.validate_import(import, &found, &modinst_builder.types)?;
             modinst_builder.add_external_val(found);
         }
 
@@ -161,10 +185,10 @@ impl Runtime {
         // (Alloc 2.) Allocate functions
         // https://webassembly.github.io/spec/core/exec/modules.html#functions
         // We hold onto these so we can update the module instance at the end.
-        let func_insts = module
+        let func_insts = compiled
             .funcs
-            .into_iter()
-            .map(|f| Self::instantiate_function(f, &modinst_builder.types, rcinst.clone()));
+            .iter()
+            .map(|f| Self::instantiate_function(f, rcinst.clone()));
 
         let range = self.store.alloc(|s| &mut s.funcs, func_insts, Rc::new)?;
         modinst_builder.funcs.extend(range);
@@ -173,10 +197,17 @@ impl Runtime {
             format!("LOADED FUNCTIONS {:?}", modinst_builder.funcs)
         });
 
+        self.store.check_table_limit(
+            module
+                .tables
+                .iter()
+                .map(|t| t.tabletype.limits.lower as usize)
+                .sum(),
+        )?;
         let table_insts = module
             .tables
-            .into_iter()
-            .map(|t| TableInstance::new(t.tabletype));
+            .iter()
+            .map(|t| TableInstance::new(t.tabletype.clone()));
 
         let range = self.store.alloc(|s| &mut s.tables, table_insts, identity)?;
         modinst_builder.tables.extend(range);
@@ -184,7 +215,17 @@ impl Runtime {
             format!("LOADED TABLES {:?}", modinst_builder.tables)
         });
 
-        let mem_insts = module.memories.into_iter().map(MemInstance::new_ast);
+        self.store.check_mem_limit(
+            module
+                .memories
+                .iter()
+                .map(|m| (m.memtype.limits.lower as usize).saturating_mul(PAGE_SIZE))
+                .fold(0, usize::saturating_add),
+        )?;
+        let mem_insts = module
+            .memories
+            .iter()
+            .map(|m| MemInstance::new(m.memtype.clone()));
 
         let range = self.store.alloc(|s| &mut s.mems, mem_insts, identity)?;
         modinst_builder.mems.extend(range);
@@ -192,6 +233,21 @@ impl Runtime {
             format!("LOADED MEMS {:?}", modinst_builder.mems)
         });
 
+        let tag_insts = module.tags.iter().map(|t| {
+            let tagtype = modinst_builder
+                .types
+                .get(t.typeuse.index().value() as usize)
+                .ok_or(RuntimeErrorKind::TypeNotFound(t.typeuse.index().value()))?
+                .clone();
+            Ok(TagInstance { tagtype })
+        });
+
+        let range = self.store.alloc(|s| &mut s.tags, tag_insts, identity)?;
+        modinst_builder.tags.extend(range);
+        self.logger.log(Tag::Load, || {
+            format!("LOADED TAGS {:?}", modinst_builder.tags)
+        });
+
         // (Instantiation 5-10.) Generate global and elem init values
         // (Instantiation 5.) Create the module instance for global initialization
         unsafe {
@@ -228,13 +284,10 @@ impl Runtime {
             format!("LOADED ELEMS {:?}", modinst_builder.elems)
         });
 
-        let mut data_inits: Vec<DataInit<Resolved, CompiledExpr>> = Vec::new();
-        let data_insts = module.data.into_iter().map(|d| {
-            // Pluck out the data inits now for step 15.
-            if let Some(init) = d.init {
-                data_inits.push(init);
-            }
-            Ok(DataInstance { bytes: d.data })
+        let data_insts = module.data.iter().map(|d| {
+            Ok(DataInstance {
+                bytes: d.data.clone(),
+            })
         });
 
         let range = self.store.alloc(|s| &mut s.datas, data_insts, identity)?;
@@ -293,7 +346,7 @@ impl Runtime {
         }
 
         // (Instantiation 15.) Active mem inits.
-        for init in data_inits.into_iter() {
+        for init in module.data.iter().filter_map(|d| d.init.as_ref()) {
             self.logger
                 .log(Tag::Load, || format!("INIT MEMORY !i {:?}", init));
             self.init_mem(init)?
@@ -306,8 +359,9 @@ impl Runtime {
 
         modinst_builder.exports = module
             .exports
-            .into_iter()
-            .map(|e| Self::instantiate_export(e, &rcinst))
+            .iter()
+            .zip(compiled.exports())
+            .map(|(e, t)| Self::instantiate_export(e, t.externtype, &rcinst))
             .collect();
 
         self.logger.log(Tag::Load, || {
@@ -321,10 +375,15 @@ impl Runtime {
             *rcptr = modinst_builder.build();
         }
 
-        if let Some(start) = module.start {
+        if let Some(start) = &module.start {
             let startaddr = rcinst.func(start.idx.value());
             self.stack.push_dummy_activation(rcinst.clone())?;
-            self.invoke_addr(startaddr)?;
+            if let Err(e) = self.invoke_addr(startaddr) {
+                // Instantiation can't be resumed if the start function runs
+                // out of fuel.
+                self.abandon_suspended();
+                return Err(e);
+            }
             self.stack.pop_activation()?;
         }
 
@@ -0,0 +1,151 @@
+//! Resolution of imports from explicit definitions.
+//!
+//! A [`Linker`] maps `module::name` pairs to items in a runtime's store:
+//! exports of module instances, or items created by the host. Modules
+//! instantiated with a linker have their imports resolved only against its
+//! definitions, so different instances can be given different imports. The
+//! definitions of a linker all belong to one runtime, and modules can only be
+//! instantiated with it in that runtime.
+
+use {
+    super::{
+        compiled::CompiledModule,
+        error::{Result, RuntimeErrorKind},
+        instance::{ExternalVal, ModuleInstance},
+        Runtime,
+    },
+    std::{collections::HashMap, rc::Rc},
+    wrausmt_common::true_or::TrueOr,
+};
+
+/// A set of definitions that module imports can be resolved against.
+///
+/// By default, each `module::name` pair can only be defined once. With
+/// [`Linker::allow_shadowing`], later definitions replace earlier ones
+/// instead.
+#[derive(Debug, Default, Clone)]
+pub struct Linker {
+    definitions:     HashMap<(String, String), ExternalVal>,
+    allow_shadowing: bool,
+    /// The id of the store that the definitions are in, once there are any.
+    store_id:        Option<u64>,
+}
+
+impl Linker {
+    pub fn new() -> Self {
+        Linker::default()
+    }
+
+    pub fn allow_shadowing(&mut self, allow: bool) -> &mut Self {
+        self.allow_shadowing = allow;
+        self
+    }
+
+    /// Define `modname::name` as the provided item of the runtime.
+    pub fn define(
+        &mut self,
+        runtime: &Runtime,
+        modname: impl Into<String>,
+        name: impl Into<String>,
+        item: ExternalVal,
+    ) -> Result<&mut Self> {
+        self.use_store(runtime.store.id())?;
+        self.insert(modname.into(), name.into(), item)
+    }
+
+    /// Define each export of the module instance as `modname::name`, using the
+    /// name of the export.
+    pub fn define_instance(
+        &mut self,
+        modname: impl Into<String>,
+        mod_instance: &ModuleInstance,
+    ) -> Result<&mut Self> {
+        self.use_store(mod_instance.store_id())?;
+        let modname = modname.into();
+        for export in mod_instance.exports() {
+            self.insert(modname.clone(), export.name.clone(), export.addr)?;
+        }
+        Ok(self)
+    }
+
+    /// Define `as_modname::as_name` as the item already defined as
+    /// `modname::name`.
+    pub fn alias(
+        &mut self,
+        modname: &str,
+        name: &str,
+        as_modname: impl Into<String>,
+        as_name: impl Into<String>,
+    ) -> Result<&mut Self> {
+        let item = self
+            .get(modname, name)
+            .ok_or_else(|| RuntimeErrorKind::ImportNotFound(modname.to_owned(), name.to_owned()))?;
+        self.insert(as_modname.into(), as_name.into(), item)
+    }
+
+    /// Define each item already defined in `modname` in `as_modname` too,
+    /// with the same name.
+    pub fn alias_module(&mut self, modname: &str, as_modname: &str) -> Result<&mut Self> {
+        let items: Vec<_> = self
+            .definitions
+            .iter()
+            .filter(|((m, _), _)| m == modname)
+            .map(|((_, name), item)| (name.clone(), *item))
+            .collect();
+        (!items.is_empty())
+            .true_or_else(|| RuntimeErrorKind::ModuleNotFound(modname.to_owned()))?;
+        for (name, item) in items {
+            self.insert(as_modname.to_owned(), name, item)?;
+        }
+        Ok(self)
+    }
+
+    fn insert(&mut self, modname: String, name: String, item: ExternalVal) -> Result<&mut Self> {
+        let key = (modname, name);
+        (self.allow_shadowing || !self.definitions.contains_key(&key))
+            .true_or_else(|| RuntimeErrorKind::DuplicateDefinition(key.0.clone(), key.1.clone()))?;
+        self.definitions.insert(key, item);
+        Ok(self)
+    }
+
+    /// Check that definitions from the store can be added, which they can if
+    /// they're the first ones or the others are from the same store.
+    fn use_store(&mut self, store_id: u64) -> Result<()> {
+        (*self.store_id.get_or_insert(store_id) == store_id)
+            .true_or(RuntimeErrorKind::RuntimeMismatch)?;
+        Ok(())
+    }
+
+    /// The item defined as `modname::name`, if there is one.
+    pub fn get(&self, modname: &str, name: &str) -> Option<ExternalVal> {
+        self.definitions
+            .get(&(modname.to_owned(), name.to_owned()))
+            .copied()
+    }
+
+    /// Instantiate the module in the runtime, resolving its imports against
+    /// the definitions in this linker. If any imports aren't defined, all of
+    /// them are reported together. Fails if the definitions belong to another
+    /// runtime.
+    pub fn instantiate(
+        &self,
+        runtime: &mut Runtime,
+        module: &CompiledModule,
+    ) -> Result<Rc<ModuleInstance>> {
+        if let Some(store_id) = self.store_id {
+            (runtime.store.id() == store_id).true_or(RuntimeErrorKind::RuntimeMismatch)?;
+        }
+        let mut imports = vec![];
+        let mut unresolved = vec![];
+        for import in module.imports() {
+            match self.get(import.modname, import.name) {
+                Some(item) => imports.push(item),
+                None => unresolved.push((import.modname.to_owned(), import.name.to_owned())),
+            }
+        }
+        unresolved
+            .is_empty()
+            .true_or(RuntimeErrorKind::UnresolvedImports(unresolved))?;
+        runtime.instantiate(module, imports)
+    }
+}
@@ -0,0 +1,132 @@
+//! Host access to the memories that modules export.
+
+use {
+    super::{
+        error::{Result, RuntimeErrorKind},
+        instance::{
+            addr::{self, Address},
+            MemInstance,
+        },
+        store::Store,
+        Runtime,
+    },
+    crate::runtime::instance::ModuleInstance,
+};
+
+/// A view of an exported memory, for the host to read and write while no
+/// WebAssembly code is running. Accesses are bounds checked like those made by
+/// memory instructions, and trap the same way.
+pub struct Memory<'a> {
+    store: &'a mut Store,
+    addr:  Address<addr::Memory>,
+}
+
+/// Values that can be read from and written to memory. Like the memory
+/// instructions, they are stored in little-endian byte order.
+pub trait MemoryValue: Sized {
+    const SIZE: usize;
+
+    /// Read a value from `bytes`, which has exactly `SIZE` bytes.
+    fn from_le(bytes: &[u8]) -> Self;
+
+    /// Write the value to `bytes`, which has exactly `SIZE` bytes.
+    fn to_le(self, bytes: &mut [u8]);
+}
+
+macro_rules! memory_value {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl MemoryValue for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_le(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; std::mem::size_of::<$ty>()];
+                    buf.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(buf)
+                }
+
+                fn to_le(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+memory_value!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl<'a> Memory<'a> {
+    /// The address was resolved from an export when the view was created, so
+    /// it's always valid.
+    fn mem(&self) -> &MemInstance {
+        &self.store.mems[self.addr.0 as usize]
+    }
+
+    fn mem_mut(&mut self) -> &mut MemInstance {
+        &mut self.store.mems[self.addr.0 as usize]
+    }
+
+    /// The size of the memory in pages.
+    pub fn size(&self) -> u64 {
+        self.mem().size() as u64
+    }
+
+    /// The contents of the memory.
+    pub fn data(&self) -> &[u8] {
+        &self.mem().data
+    }
+
+    /// The contents of the memory, for writing. Its size can only be changed
+    /// with [`Memory::grow`].
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.mem_mut().data
+    }
+
+    pub fn read<T: MemoryValue>(&self, addr: usize) -> Result<T> {
+        Ok(T::from_le(self.mem().read(0, addr, T::SIZE)?))
+    }
+
+    pub fn write<T: MemoryValue>(&mut self, addr: usize, val: T) -> Result<()> {
+        let mut buf = [0u8; 16];
+        val.to_le(&mut buf[..T::SIZE]);
+        self.mem_mut().write(0, addr, &buf[..T::SIZE])
+    }
+
+    pub fn read_bytes(&self, addr: usize, len: usize) -> Result<&[u8]> {
+        self.mem().read(0, addr, len)
+    }
+
+    pub fn write_bytes(&mut self, addr: usize, bytes: &[u8]) -> Result<()> {
+        self.mem_mut().write(0, addr, bytes)
+    }
+
+    /// Read `len` bytes of UTF-8 text.
+    pub fn read_str(&self, addr: usize, len: usize) -> Result<&str> {
+        let bytes = self.read_bytes(addr, len)?;
+        Ok(std::str::from_utf8(bytes).map_err(RuntimeErrorKind::InvalidUtf8)?)
+    }
+
+    /// Write the UTF-8 bytes of `s`, without any terminator.
+    pub fn write_str(&mut self, addr: usize, s: &str) -> Result<()> {
+        self.write_bytes(addr, s.as_bytes())
+    }
+
+    /// Grow the memory by `pgs` pages, as `memory.grow` would. Returns the
+    /// previous size in pages, or `None` if the memory's type doesn't allow
+    /// it to grow that much.
+    pub fn grow(&mut self, pgs: u64) -> Result<Option<u64>> {
+        self.store.grow_mem(self.addr, pgs)
+    }
+}
+
+impl Runtime {
+    /// A view of the memory exported as `name`.
+    pub fn memory(&mut self, mod_instance: &ModuleInstance, name: &str) -> Result<Memory<'_>> {
+        let addr = Self::exported_memory(mod_instance, name)?;
+        self.store.mem(addr)?;
+        Ok(Memory {
+            store: &mut self.store,
+            addr,
+        })
+    }
+}
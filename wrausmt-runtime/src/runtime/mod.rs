@@ -4,23 +4,39 @@ use {
     wrausmt_common::logger::{Logger, PrintLogger},
 };
 
+pub mod compiled;
+pub mod config;
+pub mod debug;
 pub mod error;
 pub mod exec;
+pub mod host;
 pub mod instance;
 pub mod instantiate;
+pub mod linker;
+pub mod memory;
+pub mod simd;
 pub mod stack;
 pub mod store;
+pub mod typed;
 pub mod values;
 
 use {
-    self::instance::FunctionInstance,
-    crate::{impl_bug, runtime::error::RuntimeErrorKind},
-    error::Result,
-    instance::{ExportInstance, ExternalVal, ModuleInstance},
+    self::instance::{FunctionCode, FunctionInstance, ModuleFunc},
+    crate::{
+        impl_bug,
+        runtime::error::RuntimeErrorKind,
+        syntax::{types::FunctionType, Id},
+    },
+    config::RuntimeConfig,
+    debug::DebugState,
+    error::{Result, TrapKind},
+    exec::Suspended,
+    instance::{ExportInstance, ExternalVal, HostObject, ModuleInstance},
     stack::Stack,
-    std::{collections::HashMap, rc::Rc},
+    std::{any::Any, collections::HashMap, rc::Rc},
     store::Store,
-    values::Value,
+    values::{ExternRef, Ref, Value},
+    wrausmt_common::true_or::TrueOr,
 };
 
 #[derive(Debug, Default)]
@@ -35,6 +51,18 @@ pub struct Runtime {
     /// Modules registered for import
     registered: HashMap<String, Rc<ModuleInstance>>,
 
+    /// Host functions registered for import, by module name and name.
+    host_funcs: HashMap<(String, String), Address<addr::Function>>,
+
+    /// The fuel remaining for executing guest code, if it's being metered.
+    fuel: Option<u64>,
+
+    /// A call made by the host that ran out of fuel, waiting to be resumed.
+    suspended: Option<Suspended>,
+
+    /// Breakpoints, watchpoints, and the debugger to pause for them.
+    debug: DebugState,
+
     logger: PrintLogger,
 }
 
@@ -43,6 +71,38 @@ impl Runtime {
         Runtime::default()
     }
 
+    /// Create a runtime whose resource usage is limited as described by the
+    /// provided [`RuntimeConfig`].
+    pub fn with_config(config: RuntimeConfig) -> Self {
+        Runtime {
+            store: Store::new(&config),
+            stack: Stack::new(&config),
+            fuel: config.fuel,
+            ..Runtime::default()
+        }
+    }
+
+    /// The fuel remaining for executing guest code, or `None` if fuel isn't
+    /// being metered.
+    pub fn fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Set the fuel available for executing guest code. Each instruction
+    /// executed consumes fuel, bulk memory and table instructions in
+    /// proportion to their length, and execution stops with
+    /// [`RuntimeErrorKind::OutOfFuel`] when there isn't enough left for the
+    /// next one. `None` disables metering.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+    }
+
+    /// Add to the fuel available for executing guest code, enabling metering
+    /// if it wasn't enabled.
+    pub fn add_fuel(&mut self, fuel: u64) {
+        self.fuel = Some(self.fuel.unwrap_or(0).saturating_add(fuel));
+    }
+
     pub fn register(&mut self, modname: impl Into<String>, module: Rc<ModuleInstance>) {
         self.registered.insert(modname.into(), module);
     }
@@ -51,10 +111,31 @@ impl Runtime {
         // 1. Assert S.funcaddr exists
         // 2. Let funcinst = S.funcs[funcaddr]
         let funcinst = self.store.func(addr)?;
-        self.invoke(funcinst)
+        let func = match &funcinst.code {
+            FunctionCode::Module(func) => func,
+            FunctionCode::Host(func) => return self.invoke_host(&funcinst.functype, func),
+        };
+
+        self.push_frame(addr, &funcinst, func)?;
+
+        // Calls made by the function are handled inside of the same dispatch
+        // loop, so this returns once the function itself has finished.
+        self.enter(func.body.clone())?;
+
+        self.pop_frame()
     }
 
-    pub fn invoke(&mut self, funcinst: Rc<FunctionInstance>) -> Result<()> {
+    /// Prepare the stack to execute the body of a module function, whose
+    /// arguments are already on the value stack.
+    fn push_frame(
+        &mut self,
+        addr: Address<addr::Function>,
+        funcinst: &FunctionInstance,
+        func: &ModuleFunc,
+    ) -> Result<()> {
+        self.logger
+            .log(Tag::Activate, || format!("CALL {funcinst}"));
+        let functype = &funcinst.functype;
         // 3. Let [tn_1] -> [tm_2] be the function type.
         // 4. Let t* be the list of locals.
         // 5. Let instr* end be the code body
@@ -63,32 +144,48 @@ impl Runtime {
         // 8. Let val0* be the list of zero values (other locals).
         // 9. Let F be the frame.
         // 10. Push activation w/ arity m onto the stack.
-        self.stack.push_activation(&funcinst)?;
+        self.stack.push_activation(addr, functype, func)?;
 
         // 11. Let L be the Label with continuation at function end.
         // 12. Enter the instruction sequence with the label.
-        let arity = funcinst.functype.result.len() as u32;
-        let continuation = funcinst.body.len() as u32;
+        let arity = functype.result.len() as u32;
+        let continuation = func.body.len() as u32;
+
+        self.stack.push_label(0, arity, continuation, None)
+    }
 
-        self.stack.push_label(0, arity, continuation)?;
+    /// Prepare the stack to execute the body of a module function that's tail
+    /// called, by replacing the current function's frame with its own.
+    fn replace_frame(
+        &mut self,
+        addr: Address<addr::Function>,
+        funcinst: &FunctionInstance,
+        func: &ModuleFunc,
+    ) -> Result<()> {
+        self.logger
+            .log(Tag::Activate, || format!("TAIL CALL {funcinst}"));
+        let functype = &funcinst.functype;
+        self.stack.replace_activation(addr, functype, func)?;
 
-        self.enter(&funcinst.body)?;
+        let arity = functype.result.len() as u32;
+        let continuation = func.body.len() as u32;
 
+        self.stack.push_label(0, arity, continuation, None)
+    }
+
+    /// Remove the frame of a module function whose body has finished, leaving
+    /// its results on the value stack.
+    fn pop_frame(&mut self) -> Result<()> {
         // NOTE: The compiled function has an `end` instruction at the end
         // which takes care of popping the label.
 
-        // Due to validation, this should be equal to the frame above.
+        // Due to validation, this should be equal to the frame pushed by
+        // `push_frame`.
         self.stack.pop_activation()?;
 
         self.logger.log(Tag::Activate, || {
-            format!(
-                "REMOVE FRAME {} {} {}",
-                funcinst.locals.len(),
-                funcinst.functype.params.len(),
-                funcinst.functype.result.len(),
-            )
+            format!("REMOVE FRAME depth {}", self.stack.activation_depth())
         });
-
         Ok(())
     }
 
@@ -99,13 +196,7 @@ impl Runtime {
         name: &str,
         vals: &[Value],
     ) -> Result<Vec<Value>> {
-        let funcaddr = match mod_instance.resolve(name) {
-            Some(ExportInstance {
-                name: _,
-                addr: ExternalVal::Func(idx),
-            }) => Ok(*idx),
-            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned())),
-        }?;
+        let funcaddr = Self::exported_func(mod_instance, name)?;
 
         self.logger
             .log(Tag::Host, || format!("calling {} at {:?}", name, funcaddr));
@@ -118,27 +209,75 @@ impl Runtime {
         // 5. For each value type, if not matching declared type, fail.
         funcinst.validate_args(vals)?;
 
+        let arity = funcinst.functype.result.len();
+        self.start_call(mod_instance, funcaddr, arity, |stack| {
+            // 8. Push the values to the stack.
+            for val in vals {
+                stack.push_value(*val);
+            }
+        })?;
+
+        self.finish_call(arity)
+    }
+
+    /// Invoke a function for the host, after its arguments have been checked.
+    /// The arguments are pushed by `push_args`, and the results are left on
+    /// the stack for [`Runtime::finish_call_with`].
+    fn start_call(
+        &mut self,
+        mod_instance: &Rc<ModuleInstance>,
+        funcaddr: Address<addr::Function>,
+        arity: usize,
+        push_args: impl FnOnce(&mut Stack),
+    ) -> Result<()> {
+        // Starting a new call gives up on any call that ran out of fuel.
+        self.abandon_suspended();
+
         // 6. Let F be a dummy frame. (Represents a dummy "caller" for the function to
         //    invoke).
         // 7. Push F to the stack.
         self.stack.push_dummy_activation(mod_instance.clone())?;
 
-        // 8. Push the values to the stack.
-        for val in vals {
-            self.stack.push_value(*val);
-        }
+        push_args(&mut self.stack);
 
         // 9. Invoke the function.
-        self.invoke_addr(funcaddr)?;
+        self.invoke_addr(funcaddr)
+            .map_err(|e| self.suspend_call(e, arity))
+    }
 
-        let mut results: Vec<Value> = vec![];
-        for _ in 0..funcinst.functype.result.len() {
-            let result = self.stack.pop_value()?;
+    /// Continue a call made by [`Runtime::call`] that failed with
+    /// [`RuntimeErrorKind::OutOfFuel`], after more fuel has been added.
+    /// Returns the results of the original call. If the fuel runs out again,
+    /// the call can be resumed again.
+    pub fn resume(&mut self) -> Result<Vec<Value>> {
+        let arity = self.continue_suspended()?;
 
-            self.logger
-                .log(Tag::Host, || format!("POPPED HOST RESULT {:?}", result));
-            results.push(result);
-        }
+        // The frame of the function that the host called is still on the
+        // stack, since it was interrupted before `invoke` could remove it.
+        self.pop_frame()?;
+
+        self.finish_call(arity)
+    }
+
+    /// Collect the results of a host call as values.
+    fn finish_call(&mut self, arity: usize) -> Result<Vec<Value>> {
+        let results = self.finish_call_with(|stack| {
+            (0..arity)
+                .map(|_| stack.pop_value())
+                .collect::<Result<Vec<_>>>()
+        })?;
+        self.logger
+            .log(Tag::Host, || format!("POPPED HOST RESULTS {:?}", results));
+        Ok(results)
+    }
+
+    /// Collect the results of a host call with `pop_results`, and remove the
+    /// dummy frame.
+    fn finish_call_with<T>(
+        &mut self,
+        pop_results: impl FnOnce(&mut Stack) -> Result<T>,
+    ) -> Result<T> {
+        let results = pop_results(&mut self.stack)?;
 
         // pop the dummy frame
         // due to validation, this will be the one we pushed above.
@@ -157,17 +296,23 @@ impl Runtime {
         if self.stack.activation_depth() != 0 {
             Err(impl_bug!("frames still on stack"))?;
         }
+        self.debug.stop_stepping();
         Ok(results)
     }
 
+    /// The type of the function at the provided address.
+    pub fn func_type(&self, addr: Address<addr::Function>) -> Result<FunctionType> {
+        Ok(self.store.func(addr)?.functype.clone())
+    }
+
+    /// The identifier of the function at the provided address, if its module
+    /// named it.
+    pub fn func_name(&self, addr: Address<addr::Function>) -> Result<Option<Id>> {
+        Ok(self.store.func(addr)?.name.clone())
+    }
+
     pub fn get_global(&mut self, mod_instance: &Rc<ModuleInstance>, name: &str) -> Result<Value> {
-        let globaladdr = match mod_instance.resolve(name) {
-            Some(ExportInstance {
-                name: _,
-                addr: ExternalVal::Global(idx),
-            }) => *idx,
-            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
-        };
+        let globaladdr = Self::exported_global(mod_instance, name)?;
 
         self.logger
             .log(Tag::Host, || format!("calling {name} at {globaladdr:?}"));
@@ -177,21 +322,142 @@ impl Runtime {
 
         Ok(globalinst)
     }
-}
 
-#[macro_export]
-macro_rules! runner {
-    ( $runtime:expr, $mod_inst:expr ) => {
-        macro_rules! exec_method {
-            ( $cmd:expr ) => {
-                $runtime.call(&$mod_inst, $cmd, &[]);
-            };
-            ( $cmd:expr, $v1:expr ) => {
-                $runtime.call(&$mod_inst, $cmd, &[$v1.into()])
-            };
-            ( $cmd:expr, $v1:expr, $v2:expr ) => {
-                $runtime.call(&$mod_inst, $cmd, &[$v1.into(), $v2.into()])
-            };
+    /// Set the value of the global exported as `name`, as `global.set` would.
+    /// The global must be mutable, and the value must have its type.
+    pub fn set_global(
+        &mut self,
+        mod_instance: &ModuleInstance,
+        name: &str,
+        val: Value,
+    ) -> Result<()> {
+        let globaladdr = Self::exported_global(mod_instance, name)?;
+        let global = self.store.global_inst(globaladdr)?;
+        global
+            .mutable
+            .true_or_else(|| RuntimeErrorKind::ImmutableGlobal(name.to_owned()))?;
+        (val.valtype() == global.typ).true_or(RuntimeErrorKind::ValueTypeMismatch {
+            expected: global.typ,
+            got:      val.valtype(),
+        })?;
+        self.store.set_global(globaladdr, val)
+    }
+
+    /// Add a host object to the store, returning a handle that can be passed
+    /// to WebAssembly code as an `externref`. The host can keep its own clone
+    /// of the object to share it with the program.
+    pub fn alloc_extern<T: Any>(&mut self, object: Rc<T>) -> ExternRef {
+        self.store.alloc_extern(HostObject(object))
+    }
+
+    /// The host object that an `externref` refers to, if it's a `T`. Null
+    /// references, and references to objects of other types, have no object.
+    pub fn extern_object<T: Any>(&self, r: Ref) -> Result<Option<Rc<T>>> {
+        match r {
+            Ref::Extern(addr) => Ok(self.store.host_object(addr)?.0.clone().downcast().ok()),
+            _ => Ok(None),
+        }
+    }
+
+    fn exported(mod_instance: &ModuleInstance, name: &str) -> Result<ExternalVal> {
+        match mod_instance.resolve(name) {
+            Some(ExportInstance { addr, .. }) => Ok(*addr),
+            None => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
+        }
+    }
+
+    fn exported_func(mod_instance: &ModuleInstance, name: &str) -> Result<Address<addr::Function>> {
+        match Self::exported(mod_instance, name)? {
+            ExternalVal::Func(addr) => Ok(addr),
+            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
+        }
+    }
+
+    fn exported_table(mod_instance: &ModuleInstance, name: &str) -> Result<Address<addr::Table>> {
+        match Self::exported(mod_instance, name)? {
+            ExternalVal::Table(addr) => Ok(addr),
+            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
+        }
+    }
+
+    fn exported_memory(mod_instance: &ModuleInstance, name: &str) -> Result<Address<addr::Memory>> {
+        match Self::exported(mod_instance, name)? {
+            ExternalVal::Memory(addr) => Ok(addr),
+            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
         }
-    };
+    }
+
+    fn exported_global(mod_instance: &ModuleInstance, name: &str) -> Result<Address<addr::Global>> {
+        match Self::exported(mod_instance, name)? {
+            ExternalVal::Global(addr) => Ok(addr),
+            _ => Err(RuntimeErrorKind::MethodNotFound(name.to_owned()))?,
+        }
+    }
+
+    /// The number of elements in the table exported as `name`.
+    pub fn table_size(&self, mod_instance: &ModuleInstance, name: &str) -> Result<u32> {
+        let table = self
+            .store
+            .table(Self::exported_table(mod_instance, name)?)?;
+        Ok(table.elem.len() as u32)
+    }
+
+    /// Grow the table exported as `name` by `delta` elements, filling them
+    /// with `init`, as `table.grow` would. Returns the previous size, or `None`
+    /// if the table's type doesn't allow it to grow that much.
+    pub fn table_grow(
+        &mut self,
+        mod_instance: &ModuleInstance,
+        name: &str,
+        delta: u32,
+        init: Ref,
+    ) -> Result<Option<u32>> {
+        let tableaddr = Self::exported_table(mod_instance, name)?;
+        let reftype = self.store.table(tableaddr)?.tabletype.reftype;
+        (init.reftype() == reftype).true_or(RuntimeErrorKind::ValueTypeMismatch {
+            expected: reftype.into(),
+            got:      init.reftype().into(),
+        })?;
+        self.store.grow_table(tableaddr, delta, init)
+    }
+
+    /// The element at index `idx` of the table exported as `name`, as
+    /// `table.get` would read it.
+    pub fn table_get(&self, mod_instance: &ModuleInstance, name: &str, idx: u32) -> Result<Ref> {
+        let table = self
+            .store
+            .table(Self::exported_table(mod_instance, name)?)?;
+        Ok(*table
+            .elem
+            .get(idx as usize)
+            .ok_or(TrapKind::OutOfBoundsTableAccess(
+                idx as usize,
+                table.elem.len(),
+            ))?)
+    }
+
+    /// Replace the element at index `idx` of the table exported as `name`, as
+    /// `table.set` would. The reference must have the table's element type.
+    pub fn table_set(
+        &mut self,
+        mod_instance: &ModuleInstance,
+        name: &str,
+        idx: u32,
+        val: Ref,
+    ) -> Result<()> {
+        let table = self
+            .store
+            .table_mut(Self::exported_table(mod_instance, name)?)?;
+        let reftype = table.tabletype.reftype;
+        (val.reftype() == reftype).true_or(RuntimeErrorKind::ValueTypeMismatch {
+            expected: reftype.into(),
+            got:      val.reftype().into(),
+        })?;
+        let len = table.elem.len();
+        *table
+            .elem
+            .get_mut(idx as usize)
+            .ok_or(TrapKind::OutOfBoundsTableAccess(idx as usize, len))? = val;
+        Ok(())
+    }
 }
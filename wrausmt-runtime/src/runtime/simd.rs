@@ -0,0 +1,206 @@
+//! Lane helpers for the vector instructions. [Spec][Spec]
+//!
+//! A v128 value is held as a `u128`. Vector instructions interpret it as a
+//! number of lanes of a particular shape, numbered from the least significant
+//! end, so that lane 0 occupies the lowest address when the vector is stored
+//! to memory.
+//!
+//! [Spec]: https://webassembly.github.io/spec/core/exec/numerics.html#vector-operations
+
+/// A value that can occupy one lane of a v128 value.
+pub trait Lane: Copy {
+    const BITS: u32;
+
+    /// Build a lane from the low [`Lane::BITS`] bits of the provided value.
+    fn from_lane_bits(bits: u128) -> Self;
+
+    /// The bits of the lane, zero-extended to 128 bits.
+    fn to_lane_bits(self) -> u128;
+}
+
+macro_rules! int_lane {
+    ( $ty:ty, $uty:ty ) => {
+        impl Lane for $ty {
+            const BITS: u32 = <$ty>::BITS;
+
+            fn from_lane_bits(bits: u128) -> Self {
+                bits as $uty as $ty
+            }
+
+            fn to_lane_bits(self) -> u128 {
+                self as $uty as u128
+            }
+        }
+    };
+}
+
+int_lane! { i8, u8 }
+int_lane! { u8, u8 }
+int_lane! { i16, u16 }
+int_lane! { u16, u16 }
+int_lane! { i32, u32 }
+int_lane! { u32, u32 }
+int_lane! { i64, u64 }
+int_lane! { u64, u64 }
+
+macro_rules! float_lane {
+    ( $ty:ty, $uty:ty ) => {
+        impl Lane for $ty {
+            const BITS: u32 = <$uty>::BITS;
+
+            fn from_lane_bits(bits: u128) -> Self {
+                <$ty>::from_bits(bits as $uty)
+            }
+
+            fn to_lane_bits(self) -> u128 {
+                self.to_bits() as u128
+            }
+        }
+
+        impl WasmFloat for $ty {
+            fn wasm_min(self, other: Self) -> Self {
+                if self.is_nan() || other.is_nan() {
+                    return <$ty>::NAN;
+                }
+                // Equal values can only differ in the sign of zero, and -0 is
+                // the smaller.
+                if self == other {
+                    return <$ty>::from_bits(self.to_bits() | other.to_bits());
+                }
+                self.min(other)
+            }
+
+            fn wasm_max(self, other: Self) -> Self {
+                if self.is_nan() || other.is_nan() {
+                    return <$ty>::NAN;
+                }
+                if self == other {
+                    return <$ty>::from_bits(self.to_bits() & other.to_bits());
+                }
+                self.max(other)
+            }
+        }
+    };
+}
+
+/// The floating point operations whose WebAssembly semantics differ from the
+/// Rust standard library.
+pub trait WasmFloat {
+    /// The minimum of the two values: NaN if either is NaN, and -0 is less
+    /// than +0.
+    fn wasm_min(self, other: Self) -> Self;
+
+    /// The maximum of the two values: NaN if either is NaN, and +0 is greater
+    /// than -0.
+    fn wasm_max(self, other: Self) -> Self;
+}
+
+float_lane! { f32, u32 }
+float_lane! { f64, u64 }
+
+/// Split a v128 value into `N` lanes of type `T`.
+pub fn lanes<T: Lane, const N: usize>(v: u128) -> [T; N] {
+    std::array::from_fn(|i| T::from_lane_bits(v >> (i as u32 * T::BITS)))
+}
+
+/// Join `N` lanes of type `T` into a v128 value.
+pub fn from_lanes<T: Lane, const N: usize>(lanes: [T; N]) -> u128 {
+    lanes
+        .iter()
+        .enumerate()
+        .fold(0, |v, (i, l)| v | l.to_lane_bits() << (i as u32 * T::BITS))
+}
+
+/// A v128 value with every lane set to `x`.
+pub fn splat<T: Lane, const N: usize>(x: T) -> u128 {
+    from_lanes([x; N])
+}
+
+/// Replace lane `l` of the v128 value with `x`.
+pub fn replace_lane<T: Lane, const N: usize>(v: u128, l: usize, x: T) -> u128 {
+    let mut lanes = lanes::<T, N>(v);
+    lanes[l] = x;
+    from_lanes(lanes)
+}
+
+/// Apply `op` to each lane.
+pub fn map<T: Lane, const N: usize>(v: u128, op: impl Fn(T) -> T) -> u128 {
+    from_lanes(lanes::<T, N>(v).map(op))
+}
+
+/// Apply `op` to each pair of corresponding lanes.
+pub fn zip<T: Lane, const N: usize>(l: u128, r: u128, op: impl Fn(T, T) -> T) -> u128 {
+    let l = lanes::<T, N>(l);
+    let r = lanes::<T, N>(r);
+    from_lanes::<T, N>(std::array::from_fn(|i| op(l[i], r[i])))
+}
+
+/// Compare each pair of corresponding lanes, producing a lane of all ones when
+/// `op` is true, and all zeros otherwise.
+pub fn mask<T: Lane, const N: usize>(l: u128, r: u128, op: impl Fn(T, T) -> bool) -> u128 {
+    let l = lanes::<T, N>(l);
+    let r = lanes::<T, N>(r);
+    let ones = u128::MAX >> (128 - T::BITS);
+    (0..N)
+        .filter(|i| op(l[*i], r[*i]))
+        .fold(0, |v, i| v | ones << (i as u32 * T::BITS))
+}
+
+/// Collect the most significant bit of each lane into the low bits of an i32.
+pub fn bitmask<T: Lane, const N: usize>(v: u128) -> u32 {
+    lanes::<T, N>(v).iter().enumerate().fold(0, |m, (i, l)| {
+        m | ((l.to_lane_bits() >> (T::BITS - 1)) as u32) << i
+    })
+}
+
+/// Convert the `NO` lanes of the input starting at lane `offset` into lanes
+/// of a different shape. Output lanes with no corresponding input lane are
+/// zero.
+pub fn convert<I: Lane, O: Lane, const NI: usize, const NO: usize>(
+    v: u128,
+    offset: usize,
+    op: impl Fn(I) -> O,
+) -> u128 {
+    let i = lanes::<I, NI>(v);
+    from_lanes::<O, NO>(std::array::from_fn(|n| match i.get(offset + n) {
+        Some(l) => op(*l),
+        None => O::from_lane_bits(0),
+    }))
+}
+
+/// Convert the lanes of two inputs into lanes of half the width. The lanes of
+/// `l` fill the low half of the result, and the lanes of `r` the high half.
+pub fn narrow<I: Lane, O: Lane, const NI: usize, const NO: usize>(
+    l: u128,
+    r: u128,
+    op: impl Fn(I) -> O,
+) -> u128 {
+    let l = lanes::<I, NI>(l);
+    let r = lanes::<I, NI>(r);
+    from_lanes::<O, NO>(std::array::from_fn(|n| match n < NI {
+        true => op(l[n]),
+        false => op(r[n - NI]),
+    }))
+}
+
+/// Combine the `NO` pairs of corresponding lanes of the inputs, starting at
+/// lane `offset`, into lanes of twice the width.
+pub fn extmul<I: Lane, O: Lane, const NI: usize, const NO: usize>(
+    l: u128,
+    r: u128,
+    offset: usize,
+    op: impl Fn(I, I) -> O,
+) -> u128 {
+    let l = lanes::<I, NI>(l);
+    let r = lanes::<I, NI>(r);
+    from_lanes::<O, NO>(std::array::from_fn(|n| op(l[offset + n], r[offset + n])))
+}
+
+/// Combine each pair of adjacent lanes into one lane of twice the width.
+pub fn pairwise<I: Lane, O: Lane, const NI: usize, const NO: usize>(
+    v: u128,
+    op: impl Fn(I, I) -> O,
+) -> u128 {
+    let i = lanes::<I, NI>(v);
+    from_lanes::<O, NO>(std::array::from_fn(|n| op(i[2 * n], i[2 * n + 1])))
+}
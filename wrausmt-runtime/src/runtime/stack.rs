@@ -1,11 +1,15 @@
 use {
     super::{
+        config::RuntimeConfig,
         error::{Result, RuntimeErrorKind},
-        instance::FunctionInstance,
+        instance::{
+            addr::{self, Address},
+            ModuleFunc,
+        },
         values::Value,
         ModuleInstance,
     },
-    crate::{impl_bug, log_tag::Tag},
+    crate::{impl_bug, log_tag::Tag, syntax::types::FunctionType},
     std::rc::Rc,
     wrausmt_common::{
         logger::{Logger, PrintLogger},
@@ -26,13 +30,21 @@ use {
 /// program.
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#stack
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Stack {
     value_stack:      Vec<Value>,
     activation_stack: Vec<ActivationFrame>,
+    max_activations:  usize,
+    max_values:       usize,
     logger:           PrintLogger,
 }
 
+impl Default for Stack {
+    fn default() -> Self {
+        Stack::new(&RuntimeConfig::default())
+    }
+}
+
 /// Labels carry an argument arity n and their associated branch target.
 /// [Spec][Spec]
 ///
@@ -54,6 +66,10 @@ pub struct Label {
     /// The location of the value stack when the label is pushed; block return
     /// values will be moved here when exiting a block.
     pub return_spot: usize,
+
+    /// For the label of a `try_table` block, the index of the block's catch
+    /// clauses in the currently executing function.
+    pub handler: Option<u32>,
 }
 
 /// Activation frames carry the return arity n of the respective function, hold
@@ -67,10 +83,23 @@ struct ActivationFrame {
     /// This value contains the index into the stack for the frame.
     pub local_start: usize,
     pub module:      Rc<ModuleInstance>,
+    /// The function executing in the frame. Frames pushed for calls made by
+    /// the host and for constant expressions don't have one.
+    pub func:        Option<Address<addr::Function>>,
     label_stack:     Vec<Label>,
 }
 
 impl Stack {
+    pub fn new(config: &RuntimeConfig) -> Self {
+        Stack {
+            value_stack:      vec![],
+            activation_stack: vec![],
+            max_activations:  config.max_call_depth,
+            max_values:       config.max_value_stack,
+            logger:           PrintLogger,
+        }
+    }
+
     pub fn push_value(&mut self, entry: Value) {
         self.value_stack.push(entry);
         self.logger.log(Tag::ValStack, || format!("PUSH {entry:?}"));
@@ -95,6 +124,7 @@ impl Stack {
         param_arity: u32,
         result_arity: u32,
         continuation: u32,
+        handler: Option<u32>,
     ) -> Result<()> {
         self.logger
             .log(Tag::DumpStack, || format!("STACK {:?}", self.value_stack));
@@ -102,6 +132,7 @@ impl Stack {
             arity: result_arity,
             continuation,
             return_spot: self.value_stack.len() - param_arity as usize,
+            handler,
         };
         self.logger
             .log(Tag::LabelStack, || format!("PUSH {label:?}"));
@@ -111,21 +142,30 @@ impl Stack {
         Ok(())
     }
 
-    pub fn push_activation(&mut self, funcinst: &FunctionInstance) -> Result<()> {
-        (self.activation_stack.len() < 256).true_or(RuntimeErrorKind::CallStackExhaustion)?;
+    pub fn push_activation(
+        &mut self,
+        funcaddr: Address<addr::Function>,
+        functype: &FunctionType,
+        func: &ModuleFunc,
+    ) -> Result<()> {
+        (self.activation_stack.len() < self.max_activations)
+            .true_or(RuntimeErrorKind::CallStackExhaustion)?;
+        (self.value_stack.len() + func.locals.len() <= self.max_values)
+            .true_or(RuntimeErrorKind::ValueStackExhaustion)?;
 
-        let frame_start = self.value_stack.len() - funcinst.functype.params.len();
+        let frame_start = self.value_stack.len() - functype.params.len();
         // 8. Let val0* be the list of zero values (other locals).
-        for localtype in funcinst.locals.iter() {
+        for localtype in func.locals.iter() {
             self.push_value(localtype.default());
         }
 
-        let arity = funcinst.functype.result.len() as u32;
+        let arity = functype.result.len() as u32;
 
         self.activation_stack.push(ActivationFrame {
             arity,
             local_start: frame_start,
-            module: funcinst.module_instance(),
+            module: func.module_instance.clone(),
+            func: Some(funcaddr),
             label_stack: vec![],
         });
         self.logger.log(Tag::Activate, || {
@@ -137,11 +177,47 @@ impl Stack {
         Ok(())
     }
 
+    /// Reuse the current activation frame for a tail call. The callee's
+    /// arguments on top of the stack replace the caller's locals and
+    /// operands, and the caller's labels are discarded.
+    pub fn replace_activation(
+        &mut self,
+        funcaddr: Address<addr::Function>,
+        functype: &FunctionType,
+        func: &ModuleFunc,
+    ) -> Result<()> {
+        let local_start = self.peek_activation()?.local_start;
+        let params = functype.params.len();
+        (local_start + params + func.locals.len() <= self.max_values)
+            .true_or(RuntimeErrorKind::ValueStackExhaustion)?;
+
+        let args_start = self.value_stack.len() - params;
+        self.value_stack.copy_within(args_start.., local_start);
+        self.value_stack.truncate(local_start + params);
+        for localtype in func.locals.iter() {
+            self.push_value(localtype.default());
+        }
+
+        let frame = self.peek_activation_mut()?;
+        frame.arity = functype.result.len() as u32;
+        frame.module = func.module_instance.clone();
+        frame.func = Some(funcaddr);
+        frame.label_stack.clear();
+        self.logger.log(Tag::Activate, || {
+            format!(
+                "REPLACE local_start {local_start} stack size {stacksize}",
+                stacksize = self.activation_stack.len()
+            )
+        });
+        Ok(())
+    }
+
     pub fn push_dummy_activation(&mut self, modinst: Rc<ModuleInstance>) -> Result<()> {
         self.activation_stack.push(ActivationFrame {
             arity:       0,
             local_start: self.value_stack.len(),
             module:      modinst,
+            func:        None,
             label_stack: vec![],
         });
         Ok(())
@@ -154,6 +230,18 @@ impl Stack {
             .ok_or_else(|| impl_bug!("value stack underflow"))?)
     }
 
+    /// Every value on the stack, including the locals of every frame.
+    pub fn values(&self) -> &[Value] {
+        &self.value_stack
+    }
+
+    pub fn peek_value(&self) -> Result<&Value> {
+        Ok(self
+            .value_stack
+            .last()
+            .ok_or_else(|| impl_bug!("value stack underflow"))?)
+    }
+
     pub fn pop_label(&mut self) -> Result<Label> {
         let label = self
             .label_stack_mut()?
@@ -166,6 +254,20 @@ impl Stack {
         // no adjustment needed.
     }
 
+    /// Pop labels of the current activation until one with catch clauses is
+    /// found, returning the location of its clauses. Returns `None` once the
+    /// activation has no more labels.
+    pub fn pop_handler(&mut self) -> Result<Option<u32>> {
+        while let Some(label) = self.label_stack_mut()?.pop() {
+            if let Some(handler) = label.handler {
+                self.logger
+                    .log(Tag::LabelStack, || format!("HANDLER {:?}", label));
+                return Ok(Some(handler));
+            }
+        }
+        Ok(None)
+    }
+
     pub fn break_to_label(&mut self, labelidx: u32) -> Result<Label> {
         let label = {
             let label_stack = self.label_stack_mut()?;
@@ -218,10 +320,44 @@ impl Stack {
         self.move_return_values(frame.arity, frame.local_start)
     }
 
+    /// Remove the current activation frame while unwinding for an exception,
+    /// discarding its locals and operands instead of keeping any results.
+    pub fn discard_activation(&mut self) -> Result<()> {
+        let frame = self
+            .activation_stack
+            .pop()
+            .ok_or_else(|| impl_bug!("activation stack underflow"))?;
+        self.value_stack.truncate(frame.local_start);
+        Ok(())
+    }
+
     pub fn activation_depth(&self) -> usize {
         self.activation_stack.len()
     }
 
+    /// The functions executing in the activation frames, with their module
+    /// instances, innermost first.
+    pub fn active_funcs(
+        &self,
+    ) -> impl Iterator<Item = (Option<Address<addr::Function>>, &Rc<ModuleInstance>)> {
+        self.activation_stack
+            .iter()
+            .rev()
+            .map(|frame| (frame.func, &frame.module))
+    }
+
+    /// The labels of the current activation frame, innermost last.
+    pub fn labels(&self) -> Result<&[Label]> {
+        Ok(self.label_stack()?)
+    }
+
+    /// The values of the current activation frame: its locals, followed by
+    /// its operands.
+    pub fn frame_values(&self) -> Result<&[Value]> {
+        let local_start = self.peek_activation()?.local_start;
+        Ok(&self.value_stack[local_start..])
+    }
+
     pub fn peek_label(&self) -> Result<&Label> {
         Ok(self
             .label_stack()?
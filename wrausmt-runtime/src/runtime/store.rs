@@ -1,16 +1,22 @@
 use {
     super::{
-        error::{Result, TrapKind},
+        config::RuntimeConfig,
+        error::{Result, RuntimeErrorKind, TrapKind},
         instance::{
             addr,
             addr::{Address, AddressRange, Addressable},
-            DataInstance, ElemInstance, FunctionInstance, GlobalInstance, MemInstance,
-            TableInstance,
+            DataInstance, ElemInstance, ExnInstance, FunctionInstance, GlobalInstance, HostObject,
+            MemInstance, TableInstance, TagInstance,
         },
         values::{Ref, Value},
     },
     crate::impl_bug,
-    std::{iter::Iterator, rc::Rc},
+    std::{
+        iter::Iterator,
+        rc::Rc,
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    },
     wrausmt_common::true_or::TrueOr,
 };
 
@@ -33,9 +39,12 @@ use {
 /// * [GlobalInstance]
 /// * [ElemInstance]
 /// * [DataInstance]
+/// * [TagInstance]
+/// * [ExnInstance]
+/// * [HostObject]
 ///
 /// [Spec]: https://webassembly.github.io/spec/core/exec/runtime.html#store
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct Store {
     // Functions need to be refcounted, because they can be recursively referenced.
     // (A function can eventually lead to code that calls it again).
@@ -45,9 +54,89 @@ pub struct Store {
     pub globals: Vec<GlobalInstance>,
     pub elems:   Vec<ElemInstance>,
     pub datas:   Vec<DataInstance>,
+    pub tags:    Vec<TagInstance>,
+    pub exns:    Vec<ExnInstance>,
+    pub externs: Vec<HostObject>,
+
+    /// The addresses of exceptions that have been freed, for new exceptions
+    /// to reuse.
+    free_exns: Vec<Address<addr::Exception>>,
+
+    /// Distinguishes this store from every other, since the addresses of
+    /// different stores overlap.
+    id: u64,
+
+    max_memory_bytes:   Option<usize>,
+    max_table_elements: Option<usize>,
+    max_exceptions:     usize,
+}
+
+/// The id of the next store that's created.
+static NEXT_STORE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The outcome of a `memory.atomic.wait`, which is its result value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The waiter was woken by a notify.
+    Ok       = 0,
+    /// The memory didn't hold the expected value, so there was no wait.
+    NotEqual = 1,
+    /// The timeout passed without a notify.
+    TimedOut = 2,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Store::new(&RuntimeConfig::default())
+    }
 }
 
 impl Store {
+    pub fn new(config: &RuntimeConfig) -> Self {
+        Store {
+            funcs:              vec![],
+            tables:             vec![],
+            mems:               vec![],
+            globals:            vec![],
+            elems:              vec![],
+            datas:              vec![],
+            tags:               vec![],
+            exns:               vec![],
+            externs:            vec![],
+            free_exns:          vec![],
+            id:                 NEXT_STORE_ID.fetch_add(1, Ordering::Relaxed),
+            max_memory_bytes:   config.max_memory_bytes,
+            max_table_elements: config.max_table_elements,
+            max_exceptions:     config.max_exceptions,
+        }
+    }
+
+    /// An id that's unique to this store, for checking that handles to its
+    /// items are used with it.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Check that `bytes` more bytes of memory can be allocated without
+    /// exceeding the configured limit.
+    pub fn check_mem_limit(&self, bytes: usize) -> Result<()> {
+        if let Some(max) = self.max_memory_bytes {
+            let total: usize = self.mems.iter().map(|m| m.data.len()).sum();
+            (total.saturating_add(bytes) <= max).true_or(RuntimeErrorKind::MemoryLimitExceeded)?;
+        }
+        Ok(())
+    }
+
+    /// Check that `elems` more table elements can be allocated without
+    /// exceeding the configured limit.
+    pub fn check_table_limit(&self, elems: usize) -> Result<()> {
+        if let Some(max) = self.max_table_elements {
+            let total: usize = self.tables.iter().map(|t| t.elem.len()).sum();
+            (total + elems <= max).true_or(RuntimeErrorKind::TableLimitExceeded)?;
+        }
+        Ok(())
+    }
+
     pub fn func(&self, addr: Address<addr::Function>) -> Result<Rc<FunctionInstance>> {
         Ok(self
             .funcs
@@ -81,6 +170,103 @@ impl Store {
         Ok(())
     }
 
+    pub fn tag(&self, addr: Address<addr::Tag>) -> Result<&TagInstance> {
+        Ok(self
+            .tags
+            .get(addr.0 as usize)
+            .ok_or_else(|| impl_bug!("no tag at addr {addr:?}"))?)
+    }
+
+    pub fn exn(&self, addr: Address<addr::Exception>) -> Result<&ExnInstance> {
+        Ok(self
+            .exns
+            .get(addr.0 as usize)
+            .ok_or_else(|| impl_bug!("no exception at addr {addr:?}"))?)
+    }
+
+    /// Allocate an exception thrown by `throw`, reusing the address of one
+    /// that's been freed if there is one. Exceptions may be held by `exnref`
+    /// values, so they're only freed by [`free_exn`][Self::free_exn] and
+    /// [`collect_exns`][Self::collect_exns].
+    pub fn alloc_exn(&mut self, exn: ExnInstance) -> Result<Address<addr::Exception>> {
+        if let Some(addr) = self.free_exns.pop() {
+            self.exns[addr.0 as usize] = exn;
+            return Ok(addr);
+        }
+        (self.exns.len() < self.max_exceptions)
+            .true_or(RuntimeErrorKind::ExceptionLimitExceeded)?;
+        self.exns.push(exn);
+        Ok(Address::new(self.exns.len() as u32 - 1))
+    }
+
+    /// Whether allocating another exception would exceed the limit, unless
+    /// some are collected first.
+    pub fn exns_full(&self) -> bool {
+        self.free_exns.is_empty() && self.exns.len() >= self.max_exceptions
+    }
+
+    /// Free an exception that nothing refers to, so that its address can be
+    /// reused.
+    pub fn free_exn(&mut self, addr: Address<addr::Exception>) {
+        self.free_exns.push(addr);
+    }
+
+    /// Free every exception that can't be reached from `roots`, the globals,
+    /// tables, or element segments of the store, or the fields of the
+    /// exceptions that can.
+    pub fn collect_exns(&mut self, roots: &[Value]) {
+        let refs = roots
+            .iter()
+            .chain(self.globals.iter().map(|g| &g.val))
+            .filter_map(|v| match v {
+                Value::Ref(r) => Some(r),
+                _ => None,
+            })
+            .chain(self.tables.iter().flat_map(|t| t.elem.iter()))
+            .chain(self.elems.iter().flat_map(|e| e.elems.iter()));
+        let mut pending: Vec<_> = refs
+            .filter_map(|r| match r {
+                Ref::Exn(addr) => Some(*addr),
+                _ => None,
+            })
+            .collect();
+        let mut live = vec![false; self.exns.len()];
+        while let Some(addr) = pending.pop() {
+            if live[addr.0 as usize] {
+                continue;
+            }
+            live[addr.0 as usize] = true;
+            pending.extend(
+                self.exns[addr.0 as usize]
+                    .fields
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::Ref(Ref::Exn(addr)) => Some(*addr),
+                        _ => None,
+                    }),
+            );
+        }
+        self.free_exns = (0..self.exns.len())
+            .filter(|&i| !live[i])
+            .map(|i| Address::new(i as u32))
+            .collect();
+    }
+
+    pub fn host_object(&self, addr: Address<addr::Extern>) -> Result<&HostObject> {
+        Ok(self
+            .externs
+            .get(addr.0 as usize)
+            .ok_or_else(|| impl_bug!("no host object at addr {addr:?}"))?)
+    }
+
+    /// Allocate a host object, so that WebAssembly code can refer to it. Like
+    /// exceptions, host objects are kept for the life of the store, since
+    /// they may be held by `externref` values.
+    pub fn alloc_extern(&mut self, object: HostObject) -> Address<addr::Extern> {
+        self.externs.push(object);
+        Address::new(self.externs.len() as u32 - 1)
+    }
+
     pub fn mem(&self, addr: Address<addr::Memory>) -> Result<&MemInstance> {
         Ok(self
             .mems
@@ -109,21 +295,57 @@ impl Store {
             .ok_or_else(|| impl_bug!("no table at addr {addr:?}"))?)
     }
 
-    pub fn grow_mem(&mut self, addr: Address<addr::Memory>, pgs: u32) -> Result<Option<u32>> {
+    /// Grow the memory by `pgs` pages. Returns the previous size in pages, or
+    /// `None` if the memory's type doesn't allow it to grow that much. Growth
+    /// that the type allows, but that exceeds the configured limit, is an
+    /// error.
+    pub fn grow_mem(&mut self, addr: Address<addr::Memory>, pgs: u64) -> Result<Option<u64>> {
+        let mem = self.mem(addr)?;
+        if let Some(newlen) = mem.grown_len(pgs) {
+            self.check_mem_limit(newlen - mem.data.len())?;
+        }
         let mem = self.mem_mut(addr)?;
         let old_size = mem.grow(pgs);
         if old_size.is_some() {
-            mem.limits.lower = mem.size() as u32;
+            mem.limits.lower = mem.size() as u64;
         }
         Ok(old_size)
     }
 
+    /// Suspend the caller until it's notified at the effective address `ea`
+    /// of the memory, or until `timeout` passes. A timeout of `None` waits
+    /// indefinitely.
+    ///
+    /// The store is only used by one thread, so there is nobody else to send
+    /// a notification, and waits time out immediately rather than hang.
+    pub fn wait(
+        &mut self,
+        addr: Address<addr::Memory>,
+        _ea: usize,
+        _timeout: Option<Duration>,
+    ) -> Result<WaitResult> {
+        self.mem(addr)?;
+        Ok(WaitResult::TimedOut)
+    }
+
+    /// Wake at most `count` of the waiters at the effective address `ea` of
+    /// the memory. Returns the number of waiters that were woken, which is
+    /// always 0 while the store is only used by one thread.
+    pub fn notify(&mut self, addr: Address<addr::Memory>, _ea: usize, _count: u32) -> Result<u32> {
+        self.mem(addr)?;
+        Ok(0)
+    }
+
     pub fn grow_table(
         &mut self,
         addr: Address<addr::Table>,
         elems: u32,
         val: Ref,
     ) -> Result<Option<u32>> {
+        let table = self.table(addr)?;
+        if let Some(newlen) = table.grown_len(elems) {
+            self.check_table_limit(newlen - table.elem.len())?;
+        }
         let table = self.table_mut(addr)?;
         let growres = table.grow(elems, val);
         Ok(growres)
@@ -138,7 +360,10 @@ impl Store {
     ) -> Result<()> {
         self.mem_mut(addr)?
             .data
-            .get_mut(i..i + n)
+            .get_mut(
+                i..i.checked_add(n)
+                    .ok_or(TrapKind::OutOfBoundsMemoryAccess(i, n))?,
+            )
             .ok_or(TrapKind::OutOfBoundsMemoryAccess(i, n))?
             .fill(val);
         Ok(())
@@ -233,13 +458,21 @@ impl Store {
 
     pub fn copy_mem_to_mem(
         &mut self,
-        memaddr: Address<addr::Memory>,
-        src: usize,
+        dstaddr: Address<addr::Memory>,
+        srcaddr: Address<addr::Memory>,
         dst: usize,
+        src: usize,
         count: usize,
     ) -> Result<()> {
-        let mem = self.mem_mut(memaddr)?;
-        mem.copy_within(src, dst, count)
+        if dstaddr.0 == srcaddr.0 {
+            return self.mem_mut(srcaddr)?.copy_within(src, dst, count);
+        }
+        let [src_mem, dst_mem] = self
+            .mems
+            .get_many_mut([srcaddr.0 as usize, dstaddr.0 as usize])
+            .map_err(|_| impl_bug!("Couldn't get both memories {dstaddr:?} {srcaddr:?}"))?;
+        let bytes = src_mem.read(0, src, count)?;
+        dst_mem.write(0, dst, bytes)
     }
 
     pub fn fill_table(
@@ -0,0 +1,225 @@
+//! Typed handles to exported functions, which the host can call with Rust
+//! values instead of slices of [`Value`].
+//!
+//! The type of the function is checked once, when the handle is created with
+//! [`Runtime::typed_func`]. Calls through the handle pass their arguments and
+//! results directly on the runtime stack, without collecting them into
+//! vectors.
+
+use {
+    super::{
+        error::{Result, RuntimeErrorKind},
+        instance::{
+            addr::{self, Address},
+            ModuleInstance,
+        },
+        stack::Stack,
+        values::{ExternRef, Num, Ref, Value},
+        Runtime,
+    },
+    crate::{
+        impl_bug,
+        syntax::types::{FunctionType, NumType, RefType, ValueType},
+    },
+    std::{marker::PhantomData, rc::Rc},
+};
+
+/// A Rust type that corresponds to a WebAssembly value type.
+pub trait WasmType: Sized {
+    const VALTYPE: ValueType;
+
+    fn into_value(self) -> Value;
+
+    fn from_value(val: Value) -> Result<Self>;
+}
+
+macro_rules! num_wasm_type {
+    ( $ty:ty, $sty:ty, $name:ident ) => {
+        impl WasmType for $ty {
+            const VALTYPE: ValueType = ValueType::Num(NumType::$name);
+
+            fn into_value(self) -> Value {
+                Value::Num(Num::$name(self as $sty))
+            }
+
+            fn from_value(val: Value) -> Result<Self> {
+                match val {
+                    Value::Num(Num::$name(v)) => Ok(v as $ty),
+                    _ => Err(impl_bug!("{val:?} is not {}", stringify!($name)))?,
+                }
+            }
+        }
+    };
+}
+
+num_wasm_type! { u32, u32, I32 }
+num_wasm_type! { i32, u32, I32 }
+num_wasm_type! { u64, u64, I64 }
+num_wasm_type! { i64, u64, I64 }
+num_wasm_type! { f32, f32, F32 }
+num_wasm_type! { f64, f64, F64 }
+num_wasm_type! { u128, u128, V128 }
+
+/// An `externref`, which is `None` when it's null.
+impl WasmType for Option<ExternRef> {
+    const VALTYPE: ValueType = ValueType::Ref(RefType::Extern);
+
+    fn into_value(self) -> Value {
+        Value::Ref(self.map_or(Ref::Null(RefType::Extern), Ref::Extern))
+    }
+
+    fn from_value(val: Value) -> Result<Self> {
+        match val {
+            Value::Ref(Ref::Extern(r)) => Ok(Some(r)),
+            Value::Ref(Ref::Null(RefType::Extern)) => Ok(None),
+            _ => Err(impl_bug!("{val:?} is not an externref"))?,
+        }
+    }
+}
+
+/// A `funcref`, which is `None` when it's null.
+impl WasmType for Option<Address<addr::Function>> {
+    const VALTYPE: ValueType = ValueType::Ref(RefType::Func);
+
+    fn into_value(self) -> Value {
+        Value::Ref(self.map_or(Ref::Null(RefType::Func), Ref::Func))
+    }
+
+    fn from_value(val: Value) -> Result<Self> {
+        match val {
+            Value::Ref(Ref::Func(f)) => Ok(Some(f)),
+            Value::Ref(Ref::Null(RefType::Func)) => Ok(None),
+            _ => Err(impl_bug!("{val:?} is not a funcref"))?,
+        }
+    }
+}
+
+/// The parameters or results of a function: a single [`WasmType`], or a tuple
+/// of them.
+pub trait WasmTypeList: Sized {
+    fn valtypes() -> Vec<ValueType>;
+
+    /// Push the values onto the stack, first to last.
+    fn push(self, stack: &mut Stack);
+
+    /// Pop the values from the stack, last to first.
+    fn pop(stack: &mut Stack) -> Result<Self>;
+}
+
+impl<T: WasmType> WasmTypeList for T {
+    fn valtypes() -> Vec<ValueType> {
+        vec![T::VALTYPE]
+    }
+
+    fn push(self, stack: &mut Stack) {
+        stack.push_value(self.into_value());
+    }
+
+    fn pop(stack: &mut Stack) -> Result<Self> {
+        T::from_value(stack.pop_value()?)
+    }
+}
+
+macro_rules! tuple_wasm_type_list {
+    ( $( $t:ident ),* ) => {
+        impl<$( $t: WasmType ),*> WasmTypeList for ( $( $t, )* ) {
+            fn valtypes() -> Vec<ValueType> {
+                vec![$( $t::VALTYPE ),*]
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn push(self, stack: &mut Stack) {
+                let ( $( $t, )* ) = self;
+                $( stack.push_value($t.into_value()); )*
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn pop(stack: &mut Stack) -> Result<Self> {
+                tuple_wasm_type_list!(@pop stack; $( $t )*; );
+                Ok(( $( $t, )* ))
+            }
+        }
+    };
+    // Reverse the names, so that the values are popped last to first.
+    ( @pop $stack:ident; $first:ident $( $rest:ident )*; $( $rev:ident )* ) => {
+        tuple_wasm_type_list!(@pop $stack; $( $rest )*; $first $( $rev )*);
+    };
+    ( @pop $stack:ident; ; $( $rev:ident )* ) => {
+        $( let $rev = $rev::from_value($stack.pop_value()?)?; )*
+    };
+}
+
+tuple_wasm_type_list!();
+tuple_wasm_type_list!(A);
+tuple_wasm_type_list!(A, B);
+tuple_wasm_type_list!(A, B, C);
+tuple_wasm_type_list!(A, B, C, D);
+tuple_wasm_type_list!(A, B, C, D, E);
+tuple_wasm_type_list!(A, B, C, D, E, F);
+tuple_wasm_type_list!(A, B, C, D, E, F, G);
+tuple_wasm_type_list!(A, B, C, D, E, F, G, H);
+
+/// An exported function whose type has been checked against `Params` and
+/// `Results`. Created by [`Runtime::typed_func`], and only callable with the
+/// same runtime.
+pub struct TypedFunc<Params, Results> {
+    /// The id of the store of the runtime that created the handle.
+    store_id:     u64,
+    mod_instance: Rc<ModuleInstance>,
+    addr:         Address<addr::Function>,
+    arity:        usize,
+    types:        PhantomData<fn(Params) -> Results>,
+}
+
+impl<Params, Results> std::fmt::Debug for TypedFunc<Params, Results> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TypedFunc {:?}", self.addr)
+    }
+}
+
+impl<Params: WasmTypeList, Results: WasmTypeList> TypedFunc<Params, Results> {
+    /// Invoke the function, like [`Runtime::call`]. If the call runs out of
+    /// fuel, it can be continued with [`Runtime::resume`], which returns the
+    /// results as values. Fails if `runtime` isn't the one that created the
+    /// handle.
+    pub fn call(&self, runtime: &mut Runtime, params: Params) -> Result<Results> {
+        if runtime.store.id() != self.store_id {
+            Err(RuntimeErrorKind::RuntimeMismatch)?;
+        }
+        runtime.start_call(&self.mod_instance, self.addr, self.arity, |stack| {
+            params.push(stack)
+        })?;
+        runtime.finish_call_with(Results::pop)
+    }
+}
+
+impl Runtime {
+    /// A handle to the function exported as `name`, for calling it with
+    /// `Params` and getting back `Results`. Fails if the function doesn't
+    /// have the corresponding type.
+    pub fn typed_func<Params: WasmTypeList, Results: WasmTypeList>(
+        &self,
+        mod_instance: &Rc<ModuleInstance>,
+        name: &str,
+    ) -> Result<TypedFunc<Params, Results>> {
+        let addr = Self::exported_func(mod_instance, name)?;
+        let functype = &self.store.func(addr)?.functype;
+        let requested = FunctionType {
+            params: Params::valtypes().into(),
+            result: Results::valtypes().into(),
+        };
+        if *functype != requested {
+            Err(RuntimeErrorKind::FunctionTypeMismatch {
+                expected: functype.clone(),
+                got:      requested,
+            })?;
+        }
+        Ok(TypedFunc {
+            store_id: self.store.id(),
+            mod_instance: mod_instance.clone(),
+            addr,
+            arity: functype.result.len(),
+            types: PhantomData,
+        })
+    }
+}
@@ -1,8 +1,8 @@
 //! The values that WebAssembly programs can manipulate. [Spec][Spec]
 //!
 //! WebAssembly computations manipulate values of either the four basic number
-//! types, i.e., integers and floating-point data of 32 or 64 bit width each, or
-//! of reference type.
+//! types, i.e., integers and floating-point data of 32 or 64 bit width each, of
+//! the 128 bit vector type, or of reference type.
 //!
 //! In most places of the semantics, values of different types can occur. In
 //! order to avoid ambiguities, values are therefore represented with an
@@ -13,7 +13,9 @@
 //! instructions. They either are function references, pointing to a specific
 //! function address, or external references pointing to an uninterpreted form
 //! of extern address that can be defined by the embedder to represent its own
-//! objects.
+//! objects. Here, extern addresses are [ExternRef] handles to host objects
+//! kept in the store. Exception references point to an exception that was
+//! thrown.
 //!
 //! [Spec]: https://webassembly.github.io/spec/core/syntax/values.html#values
 
@@ -45,6 +47,7 @@ pub enum Num {
     I64(u64),
     F32(f32),
     F64(f64),
+    V128(u128),
 }
 
 impl Num {
@@ -54,6 +57,7 @@ impl Num {
             Num::I64(_) => NumType::I64,
             Num::F32(_) => NumType::F32,
             Num::F64(_) => NumType::F64,
+            Num::V128(_) => NumType::V128,
         }
     }
 }
@@ -63,6 +67,7 @@ impl Ref {
         match self {
             Ref::Func(_) => RefType::Func,
             Ref::Extern(_) => RefType::Extern,
+            Ref::Exn(_) => RefType::Exn,
             Ref::Null(rt) => *rt,
         }
     }
@@ -95,6 +100,7 @@ impl std::fmt::Debug for Num {
             Num::F64(i) => write!(f, "F64 {} ({:016x})", i, i.to_bits()),
             Num::I32(i) => write!(f, "I32 {} ({:08x})", i, i),
             Num::I64(i) => write!(f, "I64 {} ({:016x})", i, i),
+            Num::V128(i) => write!(f, "V128 {:032x}", i),
         }
     }
 }
@@ -105,15 +111,22 @@ impl std::fmt::Debug for Num {
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Ref {
     Func(Address<addr::Function>),
-    Extern(u32),
+    Extern(ExternRef),
+    Exn(Address<addr::Exception>),
     Null(RefType),
 }
 
+/// A handle to a host object in the store, which is what a non-null
+/// `externref` refers to. Handles are created by
+/// [`Runtime::alloc_extern`](crate::runtime::Runtime::alloc_extern).
+pub type ExternRef = Address<addr::Extern>;
+
 impl RefType {
     pub fn default(&self) -> Ref {
         match &self {
             RefType::Func => Ref::Null(RefType::Func),
             RefType::Extern => Ref::Null(RefType::Extern),
+            RefType::Exn => Ref::Null(RefType::Exn),
         }
     }
 }
@@ -125,6 +138,7 @@ impl NumType {
             NumType::I64 => Num::I64(0),
             NumType::F32 => Num::F32(0f32),
             NumType::F64 => Num::F64(0f64),
+            NumType::V128 => Num::V128(0),
         }
     }
 }
@@ -189,6 +203,7 @@ froms! { i32, u32, I32 }
 froms! { i64, u64, I64 }
 froms! { f32, f32, F32 }
 froms! { f64, f64, F64 }
+froms! { u128, u128, V128 }
 
 impl From<Num> for Value {
     fn from(n: Num) -> Value {
@@ -202,6 +217,12 @@ impl From<Ref> for Value {
     }
 }
 
+impl From<ExternRef> for Value {
+    fn from(r: ExternRef) -> Value {
+        Value::Ref(Ref::Extern(r))
+    }
+}
+
 impl TryFrom<Value> for Ref {
     type Error = RuntimeError;
 
@@ -27,3 +27,4 @@ marker!(DataIndex: IndexSpace);
 marker!(ElemIndex: IndexSpace);
 marker!(LocalIndex: IndexSpace);
 marker!(LabelIndex: IndexSpace);
+marker!(TagIndex: IndexSpace);
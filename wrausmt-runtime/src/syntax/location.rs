@@ -22,3 +22,55 @@ impl Location {
         self.pos += amt as u32;
     }
 }
+
+/// Locations in the text format are written as `line:pos`. Instructions of
+/// binary modules don't have a line, so the `pos` of their locations is the
+/// byte offset of the instruction in the module, which is written in hex.
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            0 => write!(f, "{:#x}", self.pos),
+            line => write!(f, "{line}:{}", self.pos),
+        }
+    }
+}
+
+/// Maps offsets in the compiled body of a function back to the locations of
+/// the instructions that they were compiled from.
+///
+/// An entry is only kept for each offset where the location changes, so an
+/// offset's location is the one of the closest entry at or before it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SourceMap {
+    entries: Box<[(u32, Location)]>,
+}
+
+impl SourceMap {
+    /// Create a map from compiled offsets and their locations, in the order
+    /// that they were emitted.
+    pub fn new(entries: impl IntoIterator<Item = (u32, Location)>) -> Self {
+        let mut deduped: Vec<(u32, Location)> = vec![];
+        for (offset, location) in entries {
+            if deduped.last().map(|(_, l)| *l) != Some(location) {
+                deduped.push((offset, location));
+            }
+        }
+        SourceMap {
+            entries: deduped.into_boxed_slice(),
+        }
+    }
+
+    /// The location of the instruction compiled at or before `offset`.
+    pub fn location(&self, offset: usize) -> Option<Location> {
+        let idx = self
+            .entries
+            .partition_point(|(o, _)| *o as usize <= offset)
+            .checked_sub(1)?;
+        Some(self.entries[idx].1)
+    }
+
+    /// The entries of the map, ordered by offset.
+    pub fn entries(&self) -> &[(u32, Location)] {
+        &self.entries
+    }
+}
@@ -4,17 +4,14 @@
 
 mod indices;
 pub mod location;
+mod module_types;
 pub mod types;
 
 #[cfg(test)]
 mod tests;
 
-pub use indices::{
-    DataIndex, ElemIndex, FuncIndex, GlobalIndex, IndexSpace, LabelIndex, LocalIndex, MemoryIndex,
-    Resolved, ResolvedState, TableIndex, TypeIndex, Unresolved,
-};
 use {
-    self::location::Location,
+    self::location::{Location, SourceMap},
     crate::instructions::op_consts,
     std::{
         borrow::Cow,
@@ -25,6 +22,13 @@ use {
     types::{GlobalType, MemType, RefType, TableType, ValueType},
     wrausmt_common::marker,
 };
+pub use {
+    indices::{
+        DataIndex, ElemIndex, FuncIndex, GlobalIndex, IndexSpace, LabelIndex, LocalIndex,
+        MemoryIndex, Resolved, ResolvedState, TableIndex, TagIndex, TypeIndex, Unresolved,
+    },
+    module_types::{ExportType, ImportType},
+};
 
 /// ValidatedState tracks whether or not a module syntax tree has passed thorugh
 /// the validation algorithm. The runtime only accepts validated modules.
@@ -220,6 +224,7 @@ pub struct Module<R: ResolvedState, V: ValidatedState, E> {
     pub funcs:    Vec<FuncField<R, E>>,
     pub tables:   Vec<TableField<V>>,
     pub memories: Vec<MemoryField<V>>,
+    pub tags:     Vec<TagField<R>>,
     pub imports:  Vec<ImportField<R, V>>,
     pub exports:  Vec<ExportField<R, V>>,
     pub globals:  Vec<GlobalField<E>>,
@@ -246,17 +251,16 @@ impl<I: ResolvedState, V: ValidatedState, E: Debug> fmt::Debug for Module<I, V,
         print_all!(&self.funcs);
         print_all!(&self.tables);
         print_all!(&self.memories);
+        print_all!(&self.tags);
         print_all!(&self.globals);
         print_all!(&self.imports);
         print_all!(&self.exports);
-        print_all!(&self.globals);
         if let Some(st) = &self.start {
             write!(f, "\n{:?}", st)?;
         }
         print_all!(&self.elems);
         print_all!(&self.data);
-        write!(f, "\n)")?;
-        write!(f, ".IdentifierContext:")
+        write!(f, "\n)")
     }
 }
 
@@ -495,6 +499,28 @@ pub struct MemoryField<V: ValidatedState> {
     pub location: Location,
 }
 
+// tag := (tag <id>? <typeuse>)
+//
+// Abbreviations:
+// Inline import/export
+#[derive(PartialEq)]
+pub struct TagField<R: ResolvedState> {
+    pub id:       Option<Id>,
+    pub exports:  Vec<String>,
+    pub typeuse:  TypeUse<R>,
+    pub location: Location,
+}
+
+impl<R: ResolvedState> fmt::Debug for TagField<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(tag")?;
+        if let Some(id) = &self.id {
+            write!(f, " {}", id)?;
+        }
+        write!(f, " {:?})", self.typeuse)
+    }
+}
+
 // global := (global <id>? <globaltype> <expr>)
 #[derive(PartialEq)]
 pub struct GlobalField<E> {
@@ -520,6 +546,7 @@ pub enum ImportDesc<R: ResolvedState, V: ValidatedState> {
     Table(TableType<V>),
     Mem(MemType<V>),
     Global(GlobalType),
+    Tag(TypeUse<R>),
 }
 
 #[derive(PartialEq)]
@@ -552,6 +579,7 @@ pub enum ExportDesc<R: ResolvedState> {
     Table(Index<R, TableIndex>),
     Mem(Index<R, MemoryIndex>),
     Global(Index<R, GlobalIndex>),
+    Tag(Index<R, TagIndex>),
 }
 
 // export := (export <name> <exportdesc>)
@@ -586,7 +614,9 @@ pub struct UncompiledExpr<R: ResolvedState> {
 }
 #[derive(Debug, Default, PartialEq)]
 pub struct CompiledExpr {
-    pub instr: Box<[u8]>,
+    pub instr:     Box<[u8]>,
+    /// The locations of the instructions that were compiled into `instr`.
+    pub locations: SourceMap,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -596,6 +626,8 @@ pub enum Opcode {
     Extended(u8),
     // 0xFD-prefix instructions
     Simd(u8),
+    // 0xFE-prefix instructions
+    Atomic(u8),
 }
 
 impl Opcode {
@@ -604,6 +636,7 @@ impl Opcode {
             Opcode::Normal(o) => vec![*o],
             Opcode::Extended(o) => vec![op_consts::EXTENDED_PREFIX, *o],
             Opcode::Simd(o) => vec![op_consts::SIMD_PREFIX, *o],
+            Opcode::Atomic(o) => vec![op_consts::ATOMIC_PREFIX, *o],
         }
     }
 }
@@ -622,6 +655,7 @@ impl std::fmt::Display for Opcode {
             Self::Normal(o) => write!(f, "{:#x}", o),
             Self::Extended(o) => write!(f, "0xFC {:#x}", o),
             Self::Simd(o) => write!(f, "0xFD {:#x}", o),
+            Self::Atomic(o) => write!(f, "0xFE {:#x}", o),
         }
     }
 }
@@ -632,6 +666,18 @@ pub enum Continuation {
     End,
 }
 
+/// A handler clause of a `try_table` instruction. Each clause names the tag of
+/// the exceptions it catches (unless it catches all of them), and the label
+/// that it branches to. The `Ref` variants also pass the caught exception to
+/// the label as an `exnref`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Catch<R: ResolvedState> {
+    Catch(Index<R, TagIndex>, Index<R, LabelIndex>),
+    CatchRef(Index<R, TagIndex>, Index<R, LabelIndex>),
+    CatchAll(Index<R, LabelIndex>),
+    CatchAllRef(Index<R, LabelIndex>),
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum Operands<R: ResolvedState> {
     None,
@@ -643,6 +689,7 @@ pub enum Operands<R: ResolvedState> {
         UncompiledExpr<R>,
         UncompiledExpr<R>,
     ),
+    TryTable(Option<Id>, BlockType<R>, Vec<Catch<R>>, UncompiledExpr<R>),
     BrTable(Vec<Index<R, LabelIndex>>, Index<R, LabelIndex>),
     SelectT(Vec<FResult>),
     FuncIndex(Index<R, FuncIndex>),
@@ -653,14 +700,23 @@ pub enum Operands<R: ResolvedState> {
     LocalIndex(Index<R, LocalIndex>),
     LabelIndex(Index<R, LabelIndex>),
     MemoryIndex(Index<R, MemoryIndex>),
-    Memargs(u32, u32),
+    TagIndex(Index<R, TagIndex>),
+    /// The memory, alignment and offset of a memory access. Offsets are 64
+    /// bits wide, for memories with 64-bit indices.
+    Memargs(Index<R, MemoryIndex>, u32, u64),
     HeapType(RefType),
     TableInit(Index<R, TableIndex>, Index<R, ElemIndex>),
     TableCopy(Index<R, TableIndex>, Index<R, TableIndex>),
+    MemoryInit(Index<R, MemoryIndex>, Index<R, DataIndex>),
+    MemoryCopy(Index<R, MemoryIndex>, Index<R, MemoryIndex>),
     I32(u32),
     I64(u64),
     F32(f32),
     F64(f64),
+    V128(u128),
+    Shuffle([u8; 16]),
+    LaneIndex(u8),
+    MemargsLane(Index<R, MemoryIndex>, u32, u64, u8),
 }
 
 impl<R: ResolvedState> std::fmt::Display for Operands<R> {
@@ -671,6 +727,11 @@ impl<R: ResolvedState> std::fmt::Display for Operands<R> {
                 writeln!(f, "  {:?}", e)?;
                 write!(f, ")")
             }
+            Operands::TryTable(id, ft, catches, e) => {
+                writeln!(f, "{:?} {:?} {:?}", id, ft, catches)?;
+                writeln!(f, "  {:?}", e)?;
+                write!(f, ")")
+            }
             Operands::If(id, ft, th, el) => {
                 writeln!(f, "{:?} {:?}", id, ft)?;
                 writeln!(f, "  (then  {:?})", th)?;
@@ -0,0 +1,119 @@
+//! The types of the imports and exports of a validated module, which describe
+//! what the module needs and provides without instantiating it.
+
+use super::{
+    types::{ExternType, FunctionType},
+    ExportDesc, ImportDesc, Module, Resolved, TypeUse, Validated,
+};
+
+/// An import of a module, with its fully resolved type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportType<'a> {
+    pub modname:    &'a str,
+    pub name:       &'a str,
+    pub externtype: ExternType<Validated>,
+}
+
+/// An export of a module, with its fully resolved type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportType<'a> {
+    pub name:       &'a str,
+    pub externtype: ExternType<Validated>,
+}
+
+impl<E> Module<Resolved, Validated, E> {
+    /// The imports of the module, in order.
+    pub fn imports(&self) -> impl Iterator<Item = ImportType<'_>> {
+        self.imports.iter().map(|import| ImportType {
+            modname:    &import.modname,
+            name:       &import.name,
+            externtype: self.import_type(&import.desc),
+        })
+    }
+
+    /// The exports of the module, in order. Exports of imported items have
+    /// the type of the import.
+    pub fn exports(&self) -> impl Iterator<Item = ExportType<'_>> {
+        self.exports.iter().map(|export| ExportType {
+            name:       &export.name,
+            externtype: self.export_type(&export.exportdesc),
+        })
+    }
+
+    /// Validation has checked that the type index refers to a type of the
+    /// module.
+    fn functype(&self, typeuse: &TypeUse<Resolved>) -> FunctionType {
+        self.types[typeuse.index().value() as usize]
+            .functiontype
+            .clone()
+            .into()
+    }
+
+    fn import_type(&self, desc: &ImportDesc<Resolved, Validated>) -> ExternType<Validated> {
+        match desc {
+            ImportDesc::Func(typeuse) => ExternType::Func(self.functype(typeuse)),
+            ImportDesc::Table(tabletype) => ExternType::Table(tabletype.clone()),
+            ImportDesc::Mem(memtype) => ExternType::Memory(memtype.clone()),
+            ImportDesc::Global(globaltype) => ExternType::Global(globaltype.clone()),
+            ImportDesc::Tag(typeuse) => ExternType::Tag(self.functype(typeuse)),
+        }
+    }
+
+    /// The type of the imported item at `idx` in the index space selected by
+    /// `select`. If the item isn't imported, returns the number of imported
+    /// items in the index space instead.
+    fn imported<T>(
+        &self,
+        idx: u32,
+        select: impl Fn(&ImportDesc<Resolved, Validated>) -> Option<T>,
+    ) -> Result<T, usize> {
+        let imported: Vec<T> = self
+            .imports
+            .iter()
+            .filter_map(|i| select(&i.desc))
+            .collect();
+        let count = imported.len();
+        imported.into_iter().nth(idx as usize).ok_or(count)
+    }
+
+    /// Validation has checked that the index refers to an item of the module.
+    fn export_type(&self, desc: &ExportDesc<Resolved>) -> ExternType<Validated> {
+        match desc {
+            ExportDesc::Func(idx) => ExternType::Func(
+                self.imported(idx.value(), |d| match d {
+                    ImportDesc::Func(typeuse) => Some(self.functype(typeuse)),
+                    _ => None,
+                })
+                .unwrap_or_else(|n| self.functype(&self.funcs[idx.value() as usize - n].typeuse)),
+            ),
+            ExportDesc::Table(idx) => ExternType::Table(
+                self.imported(idx.value(), |d| match d {
+                    ImportDesc::Table(tabletype) => Some(tabletype.clone()),
+                    _ => None,
+                })
+                .unwrap_or_else(|n| self.tables[idx.value() as usize - n].tabletype.clone()),
+            ),
+            ExportDesc::Mem(idx) => ExternType::Memory(
+                self.imported(idx.value(), |d| match d {
+                    ImportDesc::Mem(memtype) => Some(memtype.clone()),
+                    _ => None,
+                })
+                .unwrap_or_else(|n| self.memories[idx.value() as usize - n].memtype.clone()),
+            ),
+            ExportDesc::Global(idx) => ExternType::Global(
+                self.imported(idx.value(), |d| match d {
+                    ImportDesc::Global(globaltype) => Some(globaltype.clone()),
+                    _ => None,
+                })
+                .unwrap_or_else(|n| self.globals[idx.value() as usize - n].globaltype.clone()),
+            ),
+            ExportDesc::Tag(idx) => ExternType::Tag(
+                self.imported(idx.value(), |d| match d {
+                    ImportDesc::Tag(typeuse) => Some(self.functype(typeuse)),
+                    _ => None,
+                })
+                .unwrap_or_else(|n| self.functype(&self.tags[idx.value() as usize - n].typeuse)),
+            ),
+        }
+    }
+}
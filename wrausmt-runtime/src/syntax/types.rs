@@ -20,6 +20,10 @@ use {super::ValidatedState, std::marker::PhantomData};
 /// Number types are transparent, meaning that their bit patterns can be
 /// observed. Values of number type can be stored in memories.
 ///
+/// The vector type v128 from the SIMD proposal is represented here as well.
+/// It is also transparent, and the instructions that may use any number type
+/// (like `select`) accept it in the same way.
+///
 /// [Spec]: https://webassembly.github.io/spec/core/syntax/types.html#number-types
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NumType {
@@ -27,6 +31,7 @@ pub enum NumType {
     I64,
     F32,
     F64,
+    V128,
 }
 
 /// Reference types classify first-class references to objects in the runtime
@@ -39,6 +44,10 @@ pub enum NumType {
 /// owned by the embedder and that can be passed into WebAssembly under this
 /// type.
 ///
+/// The type exnref denotes references to exceptions that were thrown, so that
+/// they can be caught and rethrown, as described by the exception handling
+/// proposal.
+///
 /// Reference types are opaque, meaning that neither their size nor their bit
 /// pattern can be observed. Values of reference type can be stored in tables.
 ///
@@ -47,6 +56,7 @@ pub enum NumType {
 pub enum RefType {
     Func,
     Extern,
+    Exn,
 }
 
 /// Value types classify the individual values that WebAssembly code can compute
@@ -85,11 +95,14 @@ pub struct FunctionType {
 /// Limits classify the size range of resizeable storage associated with [memory
 /// types][MemType] and [table types][TableType]. [Spec][Spec]
 ///
+/// Limits are 64 bits wide, so that they can describe the memories of the
+/// memory64 proposal. Table limits always fit in 32 bits.
+///
 /// [Spec]: https://webassembly.github.io/spec/core/syntax/types.html#limits
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Limits {
-    pub lower: u32,
-    pub upper: Option<u32>,
+    pub lower: u64,
+    pub upper: Option<u64>,
 }
 
 impl Limits {
@@ -107,22 +120,63 @@ impl Limits {
     }
 }
 
+/// The index type of a memory determines the type of the addresses used to
+/// access it, as described by the memory64 proposal.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum IndexType {
+    #[default]
+    I32,
+    I64,
+}
+
+impl IndexType {
+    /// The maximum number of pages a memory of this index type may have.
+    pub fn max_pages(&self) -> u64 {
+        match self {
+            IndexType::I32 => 1 << 16,
+            IndexType::I64 => 1 << 48,
+        }
+    }
+}
+
+impl From<IndexType> for NumType {
+    fn from(it: IndexType) -> NumType {
+        match it {
+            IndexType::I32 => NumType::I32,
+            IndexType::I64 => NumType::I64,
+        }
+    }
+}
+
+impl From<IndexType> for ValueType {
+    fn from(it: IndexType) -> ValueType {
+        ValueType::Num(it.into())
+    }
+}
+
 /// Memory types classify linear memories and their size range. [Spec][Spec]
 ///
 /// The limits constrain the minimum and optionally the maximum size of a
 /// memory. The limits are given in units of page size.
 ///
+/// Shared memories, from the threads proposal, may be accessed by multiple
+/// agents at once, and must declare a maximum size.
+///
 /// [Spec]: https://webassembly.github.io/spec/core/syntax/types.html#memory-types
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct MemType<V: ValidatedState> {
+    pub idxtype:     IndexType,
     pub limits:      Limits,
+    pub shared:      bool,
     validated_state: PhantomData<V>,
 }
 
 impl<V: ValidatedState> MemType<V> {
-    pub fn new(limits: Limits) -> Self {
+    pub fn new(idxtype: IndexType, limits: Limits, shared: bool) -> Self {
         Self {
+            idxtype,
             limits,
+            shared,
             validated_state: PhantomData {},
         }
     }
@@ -157,8 +211,8 @@ impl<V: ValidatedState> TableType<V> {
     pub fn fixed_size(size: u32) -> Self {
         Self::new(
             Limits {
-                lower: size,
-                upper: Some(size),
+                lower: size as u64,
+                upper: Some(size as u64),
             },
             RefType::Func,
         )
@@ -176,6 +230,19 @@ pub struct GlobalType {
     pub valtype: ValueType,
 }
 
+/// External types classify imports and external values with their respective
+/// types. [Spec][Spec]
+///
+/// [Spec]: https://webassembly.github.io/spec/core/syntax/types.html#external-types
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExternType<V: ValidatedState> {
+    Func(FunctionType),
+    Table(TableType<V>),
+    Memory(MemType<V>),
+    Global(GlobalType),
+    Tag(FunctionType),
+}
+
 impl From<NumType> for ValueType {
     fn from(nt: NumType) -> ValueType {
         ValueType::Num(nt)
@@ -0,0 +1,68 @@
+//! Error codes returned to the program by WASI functions.
+
+use {std::io, wrausmt_runtime::runtime::error::RuntimeError};
+
+/// The subset of the WASI `errno` values produced by this implementation.
+///
+/// [Spec](https://github.com/WebAssembly/WASI/blob/main/legacy/preview1/docs.md#errno)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Errno {
+    Success    = 0,
+    Acces      = 2,
+    Badf       = 8,
+    Exist      = 20,
+    Ilseq      = 25,
+    Inval      = 28,
+    Io         = 29,
+    Isdir      = 31,
+    Noent      = 44,
+    Notdir     = 54,
+    Notempty   = 55,
+    Spipe      = 70,
+    Notcapable = 76,
+}
+
+impl From<io::Error> for Errno {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::NotFound => Errno::Noent,
+            io::ErrorKind::PermissionDenied => Errno::Acces,
+            io::ErrorKind::AlreadyExists => Errno::Exist,
+            io::ErrorKind::InvalidInput => Errno::Inval,
+            io::ErrorKind::NotADirectory => Errno::Notdir,
+            io::ErrorKind::IsADirectory => Errno::Isdir,
+            io::ErrorKind::DirectoryNotEmpty => Errno::Notempty,
+            _ => Errno::Io,
+        }
+    }
+}
+
+/// The ways that a WASI function can fail: either by returning an [`Errno`]
+/// to the program, or by stopping execution with a [`RuntimeError`], for
+/// example when the program passes a pointer that's outside of its memory.
+#[derive(Debug)]
+pub enum WasiError {
+    Errno(Errno),
+    Runtime(RuntimeError),
+}
+
+impl From<Errno> for WasiError {
+    fn from(e: Errno) -> Self {
+        WasiError::Errno(e)
+    }
+}
+
+impl From<io::Error> for WasiError {
+    fn from(e: io::Error) -> Self {
+        WasiError::Errno(e.into())
+    }
+}
+
+impl From<RuntimeError> for WasiError {
+    fn from(e: RuntimeError) -> Self {
+        WasiError::Runtime(e)
+    }
+}
+
+pub type WasiResult<T> = std::result::Result<T, WasiError>;
@@ -0,0 +1,151 @@
+//! The table of file descriptors that a WASI program can use.
+
+use {
+    crate::errno::{Errno, WasiResult},
+    std::{
+        fs,
+        io::{self, Read, Write},
+        path::{Component, Path, PathBuf},
+    },
+};
+
+/// Something that a file descriptor refers to.
+pub enum Descriptor {
+    Reader(Box<dyn Read>),
+    Writer(Box<dyn Write>),
+    /// A directory on the host. Only paths inside of it can be opened through
+    /// it. Preopened directories also have the name that the program sees.
+    Dir {
+        host:    PathBuf,
+        preopen: Option<String>,
+    },
+    File(fs::File),
+}
+
+/// File types, as reported by `fd_fdstat_get`.
+pub mod filetype {
+    pub const CHARACTER_DEVICE: u8 = 2;
+    pub const DIRECTORY: u8 = 3;
+    pub const REGULAR_FILE: u8 = 4;
+}
+
+/// The bits of the WASI `rights` values that are used here.
+pub mod rights {
+    pub const FD_READ: u64 = 1 << 1;
+    pub const FD_SEEK: u64 = 1 << 2;
+    pub const FD_TELL: u64 = 1 << 5;
+    pub const FD_WRITE: u64 = 1 << 6;
+}
+
+impl Descriptor {
+    pub fn filetype(&self) -> u8 {
+        match self {
+            Descriptor::Reader(_) | Descriptor::Writer(_) => filetype::CHARACTER_DEVICE,
+            Descriptor::Dir { .. } => filetype::DIRECTORY,
+            Descriptor::File(_) => filetype::REGULAR_FILE,
+        }
+    }
+
+    /// The rights reported for the descriptor. Rights aren't enforced, but
+    /// programs use them to tell streams apart from files: wasi-libc's
+    /// `isatty` checks for a character device that can't seek.
+    pub fn rights(&self) -> u64 {
+        match self {
+            Descriptor::Reader(_) | Descriptor::Writer(_) => !(rights::FD_SEEK | rights::FD_TELL),
+            _ => u64::MAX,
+        }
+    }
+
+    pub fn reader(&mut self) -> WasiResult<&mut dyn Read> {
+        match self {
+            Descriptor::Reader(r) => Ok(r.as_mut()),
+            Descriptor::File(f) => Ok(f),
+            _ => Err(Errno::Badf)?,
+        }
+    }
+
+    pub fn writer(&mut self) -> WasiResult<&mut dyn Write> {
+        match self {
+            Descriptor::Writer(w) => Ok(w.as_mut()),
+            Descriptor::File(f) => Ok(f),
+            _ => Err(Errno::Badf)?,
+        }
+    }
+
+    /// Resolve a path relative to this directory. Paths that could refer to
+    /// something outside of the directory are refused, including paths that
+    /// lead outside of it through symbolic links.
+    pub fn resolve(&self, path: &str) -> WasiResult<PathBuf> {
+        let Descriptor::Dir { host, .. } = self else {
+            Err(Errno::Notdir)?
+        };
+        let mut resolved = host.clone();
+        for component in Path::new(path).components() {
+            match component {
+                Component::Normal(c) => resolved.push(c),
+                Component::CurDir => {}
+                _ => Err(Errno::Notcapable)?,
+            }
+        }
+
+        let real = match resolved.canonicalize() {
+            Ok(real) => real,
+            // A dangling symbolic link could be used to create a file
+            // anywhere, so it isn't followed.
+            Err(_) if resolved.symlink_metadata().is_ok() => Err(Errno::Notcapable)?,
+            // A file that's about to be created doesn't exist yet, but the
+            // directory that it's created in must.
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let name = resolved.file_name().ok_or(Errno::Noent)?;
+                resolved
+                    .parent()
+                    .ok_or(Errno::Noent)?
+                    .canonicalize()?
+                    .join(name)
+            }
+            Err(e) => Err(e)?,
+        };
+        if !real.starts_with(host.canonicalize()?) {
+            Err(Errno::Notcapable)?
+        }
+        Ok(real)
+    }
+}
+
+/// The open file descriptors, indexed by descriptor number.
+#[derive(Default)]
+pub struct FdTable {
+    fds: Vec<Option<Descriptor>>,
+}
+
+impl FdTable {
+    pub fn get(&mut self, fd: u32) -> WasiResult<&mut Descriptor> {
+        Ok(self
+            .fds
+            .get_mut(fd as usize)
+            .and_then(Option::as_mut)
+            .ok_or(Errno::Badf)?)
+    }
+
+    /// Add a descriptor, using the lowest free descriptor number.
+    pub fn insert(&mut self, desc: Descriptor) -> u32 {
+        match self.fds.iter().position(Option::is_none) {
+            Some(fd) => {
+                self.fds[fd] = Some(desc);
+                fd as u32
+            }
+            None => {
+                self.fds.push(Some(desc));
+                self.fds.len() as u32 - 1
+            }
+        }
+    }
+
+    pub fn remove(&mut self, fd: u32) -> WasiResult<Descriptor> {
+        Ok(self
+            .fds
+            .get_mut(fd as usize)
+            .and_then(Option::take)
+            .ok_or(Errno::Badf)?)
+    }
+}
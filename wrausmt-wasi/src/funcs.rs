@@ -0,0 +1,286 @@
+//! The implementations of the WASI functions. Each one takes the arguments
+//! provided by the program, and returns an [`Errno`] to it, unless the
+//! program needs to be stopped.
+//!
+//! [Spec](https://github.com/WebAssembly/WASI/blob/main/legacy/preview1/docs.md)
+
+use {
+    crate::{
+        errno::{Errno, WasiResult},
+        fd::{rights, Descriptor},
+        mem::{bytes_mut, read_bytes, read_iovecs, write_bytes, write_u32, write_u64, write_u8},
+        WasiState,
+    },
+    std::{
+        fs,
+        io::{Read, Seek, SeekFrom},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    wrausmt_runtime::runtime::{
+        error::{RuntimeError, RuntimeErrorKind},
+        host::Caller,
+        instance::MemInstance,
+        values::Value,
+    },
+};
+
+/// The signature of a WASI function that returns an errno.
+pub type WasiFn = fn(&mut WasiState, &mut Caller, &[Value]) -> WasiResult<()>;
+
+/// Parameters are given as `i32` or `i64` values.
+#[derive(Clone, Copy)]
+pub enum Param {
+    I32,
+    I64,
+}
+
+use Param::*;
+
+/// The WASI functions that return an errno, with their parameter types.
+pub static FUNCS: &[(&str, &[Param], WasiFn)] = &[
+    ("args_get", &[I32, I32], args_get),
+    ("args_sizes_get", &[I32, I32], args_sizes_get),
+    ("environ_get", &[I32, I32], environ_get),
+    ("environ_sizes_get", &[I32, I32], environ_sizes_get),
+    ("clock_time_get", &[I32, I64, I32], clock_time_get),
+    ("random_get", &[I32, I32], random_get),
+    ("fd_write", &[I32, I32, I32, I32], fd_write),
+    ("fd_read", &[I32, I32, I32, I32], fd_read),
+    ("fd_close", &[I32], fd_close),
+    ("fd_seek", &[I32, I64, I32, I32], fd_seek),
+    ("fd_fdstat_get", &[I32, I32], fd_fdstat_get),
+    ("fd_prestat_get", &[I32, I32], fd_prestat_get),
+    ("fd_prestat_dir_name", &[I32, I32, I32], fd_prestat_dir_name),
+    (
+        "path_open",
+        &[I32, I32, I32, I32, I32, I64, I64, I32, I32],
+        path_open,
+    ),
+];
+
+fn arg<T: TryFrom<Value, Error = RuntimeError>>(args: &[Value], i: usize) -> WasiResult<T> {
+    Ok(args[i].try_into()?)
+}
+
+/// `proc_exit` doesn't return, so it stops execution with an error holding
+/// the exit code.
+pub fn proc_exit(args: &[Value]) -> RuntimeError {
+    match u32::try_from(args[0]) {
+        Ok(code) => RuntimeErrorKind::Exit(code).into(),
+        Err(e) => e,
+    }
+}
+
+/// Write a list of strings in the layout used by `args_get` and
+/// `environ_get`: an array of pointers, and a buffer of the nul-terminated
+/// strings that they point to.
+fn write_strings(mem: &mut MemInstance, strs: &[String], ptrs: u32, buf: u32) -> WasiResult<()> {
+    let mut pos = buf;
+    for (i, s) in strs.iter().enumerate() {
+        write_u32(mem, ptrs + i as u32 * 4, pos)?;
+        write_bytes(mem, pos, s.as_bytes())?;
+        write_u8(mem, pos + s.len() as u32, 0)?;
+        pos += s.len() as u32 + 1;
+    }
+    Ok(())
+}
+
+/// Write the count and total buffer size of a list of strings, for
+/// `args_sizes_get` and `environ_sizes_get`.
+fn write_string_sizes(
+    mem: &mut MemInstance,
+    strs: &[String],
+    count: u32,
+    size: u32,
+) -> WasiResult<()> {
+    write_u32(mem, count, strs.len() as u32)?;
+    let total: usize = strs.iter().map(|s| s.len() + 1).sum();
+    write_u32(mem, size, total as u32)?;
+    Ok(())
+}
+
+fn args_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    write_strings(caller.memory()?, &state.args, arg(args, 0)?, arg(args, 1)?)
+}
+
+fn args_sizes_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    write_string_sizes(caller.memory()?, &state.args, arg(args, 0)?, arg(args, 1)?)
+}
+
+fn environ_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    write_strings(caller.memory()?, &state.env, arg(args, 0)?, arg(args, 1)?)
+}
+
+fn environ_sizes_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    write_string_sizes(caller.memory()?, &state.env, arg(args, 0)?, arg(args, 1)?)
+}
+
+/// The realtime clock is the system time. The monotonic clock counts from
+/// when the program's environment was created. There's no CPU time
+/// accounting, so the CPU time clocks are also measured from then.
+fn clock_time_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let nanos = match arg::<u32>(args, 0)? {
+        0 => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| Errno::Inval)?
+            .as_nanos(),
+        1..=3 => state.start.elapsed().as_nanos(),
+        _ => Err(Errno::Inval)?,
+    };
+    write_u64(caller.memory()?, arg(args, 2)?, nanos as u64)?;
+    Ok(())
+}
+
+/// Random bytes come from the host's `/dev/urandom`.
+fn random_get(_: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let bytes = bytes_mut(caller.memory()?, arg(args, 0)?, arg(args, 1)?)?;
+    fs::File::open("/dev/urandom")?.read_exact(bytes)?;
+    Ok(())
+}
+
+fn fd_write(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let mem = caller.memory()?;
+    let writer = state.fds.get(arg(args, 0)?)?.writer()?;
+    // Each buffer is written from guest memory as it is, since many iovecs
+    // can refer to the same bytes.
+    let mut total = 0;
+    for (buf, len) in read_iovecs(mem, arg(args, 1)?, arg(args, 2)?)? {
+        writer.write_all(read_bytes(mem, buf, len)?)?;
+        total += len as usize;
+    }
+    writer.flush()?;
+    write_u32(mem, arg(args, 3)?, total as u32)?;
+    Ok(())
+}
+
+fn fd_read(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let mem = caller.memory()?;
+    let reader = state.fds.get(arg(args, 0)?)?.reader()?;
+    let mut total = 0;
+    for (buf, len) in read_iovecs(mem, arg(args, 1)?, arg(args, 2)?)? {
+        let bytes = bytes_mut(mem, buf, len)?;
+        let count = reader.read(bytes)?;
+        total += count;
+        if count < bytes.len() {
+            break;
+        }
+    }
+    write_u32(mem, arg(args, 3)?, total as u32)?;
+    Ok(())
+}
+
+fn fd_close(state: &mut WasiState, _: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    state.fds.remove(arg(args, 0)?)?;
+    Ok(())
+}
+
+fn fd_seek(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let offset: i64 = arg(args, 1)?;
+    let pos = match arg::<u32>(args, 2)? {
+        0 => SeekFrom::Start(u64::try_from(offset).map_err(|_| Errno::Inval)?),
+        1 => SeekFrom::Current(offset),
+        2 => SeekFrom::End(offset),
+        _ => Err(Errno::Inval)?,
+    };
+    let newoffset = match state.fds.get(arg(args, 0)?)? {
+        Descriptor::File(f) => f.seek(pos)?,
+        Descriptor::Dir { .. } => Err(Errno::Badf)?,
+        _ => Err(Errno::Spipe)?,
+    };
+    write_u64(caller.memory()?, arg(args, 3)?, newoffset)?;
+    Ok(())
+}
+
+/// Writes an `fdstat` structure. No descriptor flags are supported.
+fn fd_fdstat_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let desc = state.fds.get(arg(args, 0)?)?;
+    let buf: u32 = arg(args, 1)?;
+    let mem = caller.memory()?;
+    write_bytes(mem, buf, &[desc.filetype(), 0, 0, 0, 0, 0, 0, 0])?;
+    write_u64(mem, buf + 8, desc.rights())?;
+    write_u64(mem, buf + 16, desc.rights())?;
+    Ok(())
+}
+
+/// Programs find their preopened directories by calling this for each
+/// descriptor after the standard streams, until it fails.
+fn fd_prestat_get(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let Descriptor::Dir {
+        preopen: Some(name),
+        ..
+    } = state.fds.get(arg(args, 0)?)?
+    else {
+        Err(Errno::Badf)?
+    };
+    let buf: u32 = arg(args, 1)?;
+    let mem = caller.memory()?;
+    // The only kind of prestat is a directory, tag 0.
+    write_u32(mem, buf, 0)?;
+    write_u32(mem, buf + 4, name.len() as u32)?;
+    Ok(())
+}
+
+fn fd_prestat_dir_name(
+    state: &mut WasiState,
+    caller: &mut Caller,
+    args: &[Value],
+) -> WasiResult<()> {
+    let Descriptor::Dir {
+        preopen: Some(name),
+        ..
+    } = state.fds.get(arg(args, 0)?)?
+    else {
+        Err(Errno::Badf)?
+    };
+    let len = name.len().min(arg::<u32>(args, 2)? as usize);
+    write_bytes(caller.memory()?, arg(args, 1)?, &name.as_bytes()[..len])?;
+    Ok(())
+}
+
+mod oflags {
+    pub const CREAT: u32 = 1 << 0;
+    pub const DIRECTORY: u32 = 1 << 1;
+    pub const EXCL: u32 = 1 << 2;
+    pub const TRUNC: u32 = 1 << 3;
+}
+
+const FDFLAGS_APPEND: u32 = 1 << 0;
+
+/// Open a path relative to a directory descriptor. The rights requested
+/// determine whether a file is opened for reading, writing, or both.
+/// Symbolic links are followed regardless of the lookup flags, but only to
+/// something inside of the directory.
+fn path_open(state: &mut WasiState, caller: &mut Caller, args: &[Value]) -> WasiResult<()> {
+    let mem = caller.memory()?;
+    let path = read_bytes(mem, arg(args, 2)?, arg(args, 3)?)?;
+    let path = std::str::from_utf8(path).map_err(|_| Errno::Ilseq)?;
+    let host = state.fds.get(arg(args, 0)?)?.resolve(path)?;
+    let oflags: u32 = arg(args, 4)?;
+    let rights_base: u64 = arg(args, 5)?;
+    let fdflags: u32 = arg(args, 7)?;
+
+    let desc = if oflags & oflags::DIRECTORY != 0 || host.is_dir() {
+        if !host.is_dir() {
+            Err(Errno::Notdir)?
+        }
+        Descriptor::Dir {
+            host,
+            preopen: None,
+        }
+    } else {
+        let write = rights_base & rights::FD_WRITE != 0;
+        let file = fs::OpenOptions::new()
+            .read(rights_base & rights::FD_READ != 0 || !write)
+            .write(write)
+            .append(fdflags & FDFLAGS_APPEND != 0)
+            .create(oflags & oflags::CREAT != 0)
+            .create_new(oflags & oflags::CREAT != 0 && oflags & oflags::EXCL != 0)
+            .truncate(oflags & oflags::TRUNC != 0)
+            .open(host)?;
+        Descriptor::File(file)
+    };
+
+    let fd = state.fds.insert(desc);
+    write_u32(mem, arg(args, 8)?, fd)?;
+    Ok(())
+}
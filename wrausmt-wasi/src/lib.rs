@@ -0,0 +1,179 @@
+//! A subset of the WASI preview 1 interface, which lets programs compiled for
+//! `wasm32-wasi` write to the console, read their arguments and environment,
+//! check the time, and use files in directories that the embedder chooses to
+//! share with them.
+//!
+//! The functions are provided as host functions under the
+//! `wasi_snapshot_preview1` module name:
+//!
+//! ```ignore
+//! WasiCtx::new()
+//!     .args(["prog.wasm", "input.txt"])
+//!     .preopen_dir(".", ".")
+//!     .register(&mut runtime);
+//! let module = runtime.load_file("prog.wasm")?;
+//! runtime.call(&module, "_start", &[])?;
+//! ```
+//!
+//! When the program calls `proc_exit`, execution stops with
+//! [`RuntimeErrorKind::Exit`][wrausmt_runtime::runtime::error::RuntimeErrorKind::Exit]
+//! holding the exit code.
+//!
+//! [Spec](https://github.com/WebAssembly/WASI/blob/main/legacy/preview1/docs.md)
+
+mod errno;
+mod fd;
+mod funcs;
+mod mem;
+
+pub use errno::Errno;
+use {
+    errno::WasiError,
+    fd::{Descriptor, FdTable},
+    funcs::{Param, FUNCS},
+    std::{
+        cell::RefCell,
+        io::{self, Read, Write},
+        path::PathBuf,
+        rc::Rc,
+        time::Instant,
+    },
+    wrausmt_runtime::{
+        runtime::Runtime,
+        syntax::types::{FunctionType, NumType, ValueType},
+    },
+};
+
+/// The module name that WASI preview 1 programs import from.
+pub const MODULE_NAME: &str = "wasi_snapshot_preview1";
+
+/// The environment that a WASI program runs in. By default, the program has
+/// no arguments, no environment variables, and no access to the host's files,
+/// and its standard streams are those of the host process.
+pub struct WasiCtx {
+    args:     Vec<String>,
+    env:      Vec<String>,
+    stdin:    Box<dyn Read>,
+    stdout:   Box<dyn Write>,
+    stderr:   Box<dyn Write>,
+    preopens: Vec<(String, PathBuf)>,
+}
+
+impl Default for WasiCtx {
+    fn default() -> Self {
+        WasiCtx {
+            args:     vec![],
+            env:      vec![],
+            stdin:    Box::new(io::stdin()),
+            stdout:   Box::new(io::stdout()),
+            stderr:   Box::new(io::stderr()),
+            preopens: vec![],
+        }
+    }
+}
+
+impl WasiCtx {
+    pub fn new() -> Self {
+        WasiCtx::default()
+    }
+
+    /// The program's arguments. By convention, the first is the program name.
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env.push(format!("{key}={value}"));
+        self
+    }
+
+    /// Give the program all of the host process's environment variables.
+    pub fn inherit_env(mut self) -> Self {
+        self.env
+            .extend(std::env::vars().map(|(k, v)| format!("{k}={v}")));
+        self
+    }
+
+    pub fn stdin(mut self, stdin: impl Read + 'static) -> Self {
+        self.stdin = Box::new(stdin);
+        self
+    }
+
+    pub fn stdout(mut self, stdout: impl Write + 'static) -> Self {
+        self.stdout = Box::new(stdout);
+        self
+    }
+
+    pub fn stderr(mut self, stderr: impl Write + 'static) -> Self {
+        self.stderr = Box::new(stderr);
+        self
+    }
+
+    /// Let the program use the files in the `host` directory, which it will
+    /// see as `guest`.
+    pub fn preopen_dir(mut self, guest: impl Into<String>, host: impl Into<PathBuf>) -> Self {
+        self.preopens.push((guest.into(), host.into()));
+        self
+    }
+
+    /// Register the WASI functions with the runtime, so that modules loaded
+    /// afterwards can import them.
+    pub fn register(self, runtime: &mut Runtime) {
+        let mut fds = FdTable::default();
+        fds.insert(Descriptor::Reader(self.stdin));
+        fds.insert(Descriptor::Writer(self.stdout));
+        fds.insert(Descriptor::Writer(self.stderr));
+        for (guest, host) in self.preopens {
+            fds.insert(Descriptor::Dir {
+                host,
+                preopen: Some(guest),
+            });
+        }
+        let state = Rc::new(RefCell::new(WasiState {
+            args: self.args,
+            env: self.env,
+            fds,
+            start: Instant::now(),
+        }));
+
+        for (name, params, func) in FUNCS {
+            let state = state.clone();
+            let functype = FunctionType {
+                params: params.iter().map(|p| valtype(*p)).collect(),
+                result: Box::new([NumType::I32.into()]),
+            };
+            runtime.register_host_func(MODULE_NAME, *name, functype, move |caller, args| {
+                let errno = match func(&mut state.borrow_mut(), caller, args) {
+                    Ok(()) => Errno::Success,
+                    Err(WasiError::Errno(errno)) => errno,
+                    Err(WasiError::Runtime(e)) => Err(e)?,
+                };
+                Ok(vec![(errno as u32).into()])
+            });
+        }
+
+        let proc_exit = FunctionType {
+            params: Box::new([NumType::I32.into()]),
+            result: Box::new([]),
+        };
+        runtime.register_host_func(MODULE_NAME, "proc_exit", proc_exit, |_, args| {
+            Err(funcs::proc_exit(args))
+        });
+    }
+}
+
+fn valtype(param: Param) -> ValueType {
+    match param {
+        Param::I32 => NumType::I32.into(),
+        Param::I64 => NumType::I64.into(),
+    }
+}
+
+/// The state shared by the WASI functions registered with a runtime.
+pub(crate) struct WasiState {
+    args:  Vec<String>,
+    env:   Vec<String>,
+    fds:   FdTable,
+    start: Instant,
+}
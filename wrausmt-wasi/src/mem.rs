@@ -0,0 +1,50 @@
+//! Access to values in the memory of the calling program. Accesses outside of
+//! the memory trap, as they would for the program's own loads and stores.
+
+use wrausmt_runtime::runtime::{error::Result, instance::MemInstance};
+
+pub fn read_bytes(mem: &MemInstance, addr: u32, len: u32) -> Result<&[u8]> {
+    mem.read(0, addr as usize, len as usize)
+}
+
+/// The `len` bytes at `addr`, for the host to fill in place. The range is
+/// checked before anything is written, so a program can't make the host
+/// allocate a buffer of the size it asks for.
+pub fn bytes_mut(mem: &mut MemInstance, addr: u32, len: u32) -> Result<&mut [u8]> {
+    let start = addr as usize;
+    mem.read(0, start, len as usize)?;
+    Ok(&mut mem.data[start..start + len as usize])
+}
+
+pub fn write_bytes(mem: &mut MemInstance, addr: u32, bytes: &[u8]) -> Result<()> {
+    mem.write(0, addr as usize, bytes)
+}
+
+pub fn read_u32(mem: &MemInstance, addr: u32) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(read_bytes(mem, addr, 4)?);
+    Ok(u32::from_le_bytes(bytes))
+}
+
+pub fn write_u8(mem: &mut MemInstance, addr: u32, val: u8) -> Result<()> {
+    write_bytes(mem, addr, &[val])
+}
+
+pub fn write_u32(mem: &mut MemInstance, addr: u32, val: u32) -> Result<()> {
+    write_bytes(mem, addr, &val.to_le_bytes())
+}
+
+pub fn write_u64(mem: &mut MemInstance, addr: u32, val: u64) -> Result<()> {
+    write_bytes(mem, addr, &val.to_le_bytes())
+}
+
+/// Read a list of `ciovec` or `iovec` structures: pairs of a buffer address
+/// and its length.
+pub fn read_iovecs(mem: &MemInstance, iovs: u32, count: u32) -> Result<Vec<(u32, u32)>> {
+    (0..count)
+        .map(|i| {
+            let iov = iovs + i * 8;
+            Ok((read_u32(mem, iov)?, read_u32(mem, iov + 4)?))
+        })
+        .collect()
+}